@@ -0,0 +1,52 @@
+//! Procedural helpers of `vct_cfg`, users should not use this crate directly.
+
+use proc_macro::{Delimiter, Group, Ident, Punct, Spacing, TokenStream, TokenTree};
+
+/// Defines `pub const fn <name>_enabled() -> bool` for an alias.
+///
+/// Input: `name, true|false, <doc expression>`.
+#[doc(hidden)]
+#[proc_macro]
+pub fn alias_fn(input: TokenStream) -> TokenStream {
+    let mut iter = input.into_iter();
+
+    let (Some(TokenTree::Ident(name)), Some(TokenTree::Punct(_))) = (iter.next(), iter.next())
+    else {
+        return error("expected `name, true|false, doc`");
+    };
+    let (Some(TokenTree::Ident(value)), Some(TokenTree::Punct(_))) = (iter.next(), iter.next())
+    else {
+        return error("expected `name, true|false, doc`");
+    };
+    let doc: TokenStream = iter.collect();
+
+    let fn_name = Ident::new(&format!("{name}_enabled"), name.span());
+
+    let mut doc_attr: TokenStream = "doc =".parse().unwrap();
+    doc_attr.extend(doc);
+
+    let mut output = TokenStream::new();
+    output.extend(attribute(doc_attr));
+    output.extend(attribute("inline".parse().unwrap()));
+    output.extend("pub const fn".parse::<TokenStream>().unwrap());
+    output.extend([TokenTree::Ident(fn_name)]);
+    output.extend("() -> bool".parse::<TokenStream>().unwrap());
+    output.extend([TokenTree::Group(Group::new(
+        Delimiter::Brace,
+        TokenTree::Ident(value).into(),
+    ))]);
+    output
+}
+
+fn attribute(content: TokenStream) -> TokenStream {
+    TokenStream::from_iter([
+        TokenTree::Punct(Punct::new('#', Spacing::Alone)),
+        TokenTree::Group(Group::new(Delimiter::Bracket, content)),
+    ])
+}
+
+fn error(message: &str) -> TokenStream {
+    format!("::core::compile_error!({message:?});")
+        .parse()
+        .unwrap()
+}
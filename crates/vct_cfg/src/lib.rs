@@ -58,61 +58,134 @@ macro_rules! enabled {
 
 /// A conditional compilation macro similar to `switch``
 ///
+/// Each arm is tried in order, the first active one is expanded:
+///
+/// - `#[cfg(...)] => { ... }`: a `cfg` predicate.
+/// - `path::to::alias => { ... }`: an alias from [`define_alias!`].
+/// - `all(a, b) => { ... }`, `any(a, b) => { ... }`, `not(a) => { ... }`:
+///   several aliases combined.
+/// - `_ => { ... }`: always active.
+///
 /// # Example
 ///
 /// ```
 /// use vct_cfg as cfg;
 /// 
-/// let mut x = 0;
-/// cfg::switch! {
-///     #[cfg(test)] => {
-///         x += 1;
-///     }
-///     cfg::enabled => {
-///         x += 10;
-///     }
-///     _ => {
-///         x += 100;
-///     }
-/// }
+/// let x = cfg::switch! {
+///     #[cfg(test)] => { 1 }
+///     cfg::enabled => { 10 }
+///     _ => { 100 }
+/// };
 /// assert!(x == 1 || x == 10);
 /// ```
+///
+/// Used as an expression, each arm must hold a single expression,
+/// statements go in an inner block.
+///
+/// `#[cfg(...)]` arms expand to [`core::cfg_select!`], stable since Rust 1.95.
+///
+/// ```
+/// use vct_cfg as cfg;
+///
+/// let backend = cfg::switch! {
+///     all(cfg::std, cfg::panic_unwind) => { "std-unwind" }
+///     not(cfg::std) => { "no-std" }
+///     #[cfg(test)] => { "test" }
+///     _ => {{
+///         let name = "std";
+///         name
+///     }}
+/// };
+/// assert!(backend == "std-unwind" || backend == "std");
+/// ```
 #[doc(hidden)]
 #[macro_export]
 macro_rules! switch {
+    () => {};
     ({ $($tt:tt)* }) => {{
         $crate::switch! { $($tt)* }
     }};
     (_ => { $($output:tt)* }) => {
         $($output)*
     };
+    (@all [] $output:tt [$($rest:tt)*]) => {
+        $crate::switch! { _ => $output }
+    };
+    (@all [$($head:ident)::+ $(, $($tail:ident)::+)*] $output:tt [$($rest:tt)*]) => {
+        $($head)::+! {
+            if {
+                $crate::switch! { @all [$($($tail)::+),*] $output [$($rest)*] }
+            } else {
+                $crate::switch! { $($rest)* }
+            }
+        }
+    };
+    (@any [] $output:tt [$($rest:tt)*]) => {
+        $crate::switch! { $($rest)* }
+    };
+    (@any [$($head:ident)::+ $(, $($tail:ident)::+)*] $output:tt [$($rest:tt)*]) => {
+        $($head)::+! {
+            if {
+                $crate::switch! { _ => $output }
+            } else {
+                $crate::switch! { @any [$($($tail)::+),*] $output [$($rest)*] }
+            }
+        }
+    };
     (
-        $cond:path => $output:tt
-        $($( $rest:tt )+)?
+        all($($($cond:ident)::+),* $(,)?) => $output:tt
+        $( $rest:tt )*
+    ) => {
+        $crate::switch! { @all [$($($cond)::+),*] $output [$($rest)*] }
+    };
+    (
+        any($($($cond:ident)::+),* $(,)?) => $output:tt
+        $( $rest:tt )*
+    ) => {
+        $crate::switch! { @any [$($($cond)::+),*] $output [$($rest)*] }
+    };
+    (
+        not($($cond:ident)::+) => $output:tt
+        $( $rest:tt )*
     ) => {
-        $cond! {
+        $($cond)::+! {
+            if {
+                $crate::switch! { $($rest)* }
+            } else {
+                $crate::switch! { _ => $output }
+            }
+        }
+    };
+    (
+        $($cond:ident)::+ => $output:tt
+        $( $rest:tt )*
+    ) => {
+        $($cond)::+! {
             if {
                 $crate::switch! { _ => $output }
             } else {
-                $(
-                    $crate::switch! { $($rest)+ }
-                )?
+                $crate::switch! { $($rest)* }
             }
         }
     };
     (
         #[cfg($cfg:meta)] => $output:tt
-        $($( $rest:tt )+)?
+        $( $rest:tt )*
     ) => {
-        #[cfg($cfg)]
-        $crate::switch! { _ => $output }
-        $(
-            #[cfg(not($cfg))]
-            $crate::switch! { $($rest)+ }
-        )?
-    }
+        ::core::cfg_select! {
+            $cfg => {
+                $crate::switch! { _ => $output }
+            }
+            _ => {
+                $crate::switch! { $($rest)* }
+            }
+        }
+    };
 }
 
+#[doc(hidden)]
+pub use vct_cfg_macros::alias_fn as __alias_fn;
+
 /// Define aliases for compilation options
 ///
 /// # Example
@@ -126,34 +199,137 @@ macro_rules! switch {
 ///
 /// // `enable_test` is eq to 'cfg::enabled' in testing.
 /// // Otherwise it is eq to 'cfg::disabled'.
-/// let mut x = false;
-/// enable_test!{ x = true; };
+/// let x = enable_test!{ if { true } else { false } };
 /// 
 /// // Docs test is not Unit Test.
 /// // So `enable_test!` is eq to 'cfg::disabled'.
 /// assert!(x == false);
 /// ```
+///
+/// # Composition
+///
+/// A `cfg` predicate can use `all`/`any`/`not` as usual, and several `#[cfg]`
+/// attributes on one alias must all be active.
+///
+/// `all(...)`, `any(...)` and `not(...)` can also combine aliases that are
+/// already defined, referenced by path.
+///
+/// ```
+/// use vct_cfg as cfg;
+///
+/// cfg::define_alias! {
+///     #[cfg(all(test, not(target_arch = "wasm32")))] => native_test,
+///     #[cfg(target_pointer_width = "64")]
+///     #[cfg(not(test))] => wide_doc,
+///     not(cfg::std) => no_std,
+///     any(cfg::panic_unwind, cfg::panic_abort) => known_panic,
+///     all(wide_doc, cfg::std) => wide_doc_std,
+/// }
+///
+/// assert!(!native_test!());
+/// assert!(known_panic!());
+/// assert_eq!(no_std!(), !cfg::std!());
+/// assert_eq!(wide_doc_std!(), wide_doc!() && cfg::std!());
+/// ```
+///
+/// # Introspection
+///
+/// Each alias also gets a `pub const fn <name>_enabled() -> bool`.
+/// The active aliases can be listed with [`active_aliases!`].
+///
+/// ```
+/// use vct_cfg as cfg;
+///
+/// cfg::define_alias! {
+///     #[cfg(test)] => enable_test,
+///     #[cfg(doctest)] => enable_doctest,
+/// }
+///
+/// assert!(!enable_test_enabled());
+/// assert_eq!(enable_doctest_enabled(), enable_doctest!());
+/// assert_eq!(cfg::std_enabled(), cfg::std!());
+/// ```
 #[doc(hidden)]
 #[macro_export]
 macro_rules! define_alias {
+    (@enabled [$(#[$id_meta:meta])* $id:ident] [$($desc:tt)*]) => {
+        #[doc = concat!("This macro is eq to `cfg::enabled` because `", stringify!($($desc)*), "` is currently active.")]
+        #[doc(inline)]
+        $(#[$id_meta])*
+        pub use $crate::enabled as $id;
+
+        $crate::__alias_fn!(
+            $id,
+            true,
+            concat!("Returns `true` because `", stringify!($($desc)*), "` is currently active.")
+        );
+    };
+    (@disabled [$(#[$id_meta:meta])* $id:ident] [$($desc:tt)*]) => {
+        #[doc = concat!("This macro is eq to `cfg::disabled` because `", stringify!($($desc)*), "` is _not_ currently active.")]
+        #[doc(inline)]
+        $(#[$id_meta])*
+        pub use $crate::disabled as $id;
+
+        $crate::__alias_fn!(
+            $id,
+            false,
+            concat!("Returns `false` because `", stringify!($($desc)*), "` is _not_ currently active.")
+        );
+    };
+    (@all [] $item:tt $desc:tt) => {
+        $crate::define_alias! { @enabled $item $desc }
+    };
+    (@all [$($head:ident)::+ $(, $($tail:ident)::+)*] $item:tt $desc:tt) => {
+        $crate::switch! {
+            $($head)::+ => {
+                $crate::define_alias! { @all [$($($tail)::+),*] $item $desc }
+            }
+            _ => {
+                $crate::define_alias! { @disabled $item $desc }
+            }
+        }
+    };
+    (@any [] $item:tt $desc:tt) => {
+        $crate::define_alias! { @disabled $item $desc }
+    };
+    (@any [$($head:ident)::+ $(, $($tail:ident)::+)*] $item:tt $desc:tt) => {
+        $crate::switch! {
+            $($head)::+ => {
+                $crate::define_alias! { @enabled $item $desc }
+            }
+            _ => {
+                $crate::define_alias! { @any [$($($tail)::+),*] $item $desc }
+            }
+        }
+    };
+    (@not [$($cond:ident)::+] $item:tt $desc:tt) => {
+        $crate::switch! {
+            $($cond)::+ => {
+                $crate::define_alias! { @disabled $item $desc }
+            }
+            _ => {
+                $crate::define_alias! { @enabled $item $desc }
+            }
+        }
+    };
     (
-        #[cfg($meta:meta)] => $p:ident
+        @define #[cfg($meta:meta)] => $p:ident
         $(, $( $rest:tt )+)?
     ) => {
         $crate::define_alias! {
-            #[cfg($meta)] => { $p }
+            @define #[cfg($meta)] => { $p }
             $( $($rest)+ )?
         }
     };
     (
-        #[cfg($meta:meta)] => $p:ident,
+        @define #[cfg($meta:meta)] => $p:ident,
     ) => {
         $crate::define_alias! {
-            #[cfg($meta)] => { $p }
+            @define #[cfg($meta)] => { $p }
         }
     };
     (
-        #[cfg($meta:meta)] => {
+        @define #[cfg($meta:meta)] => {
             $(#[$id_meta:meta])*
             $id:ident
         }
@@ -161,25 +337,141 @@ macro_rules! define_alias {
     ) => {
         $crate::switch! {
             #[cfg($meta)] => {
-                #[doc = concat!("This macro is eq to `cfg::enabled` because `#[cfg(", stringify!($meta), ")]` is currently active.")]
-                #[doc(inline)]
-                $(#[$id_meta])*
-                pub use $crate::enabled as $id;
+                $crate::define_alias! { @enabled [$(#[$id_meta])* $id] [#[cfg($meta)]] }
             }
             _ => {
-                #[doc = concat!("This macro is eq to `cfg::disabled` because `#[cfg(", stringify!($meta), ")]` is _not_ currently active.")]
-                #[doc(inline)]
+                $crate::define_alias! { @disabled [$(#[$id_meta])* $id] [#[cfg($meta)]] }
+            }
+        }
+
+        $(
+            $crate::define_alias! {
+                @define $($rest)+
+            }
+        )?
+    };
+    (
+        @define $(#[cfg($meta:meta)])+ => $p:ident
+        $(, $( $rest:tt )*)?
+    ) => {
+        $crate::define_alias! {
+            @define #[cfg(all($($meta),+))] => { $p }
+            $( $($rest)* )?
+        }
+    };
+    (
+        @define $(#[cfg($meta:meta)])+ => {
+            $(#[$id_meta:meta])*
+            $id:ident
+        }
+        $($( $rest:tt )+)?
+    ) => {
+        $crate::define_alias! {
+            @define #[cfg(all($($meta),+))] => {
                 $(#[$id_meta])*
-                pub use $crate::disabled as $id;
+                $id
             }
+            $( $($rest)+ )?
+        }
+    };
+    (
+        @define $op:ident($($($cond:ident)::+),* $(,)?) => $p:ident
+        $(, $( $rest:tt )*)?
+    ) => {
+        $crate::define_alias! {
+            @define $op($($($cond)::+),*) => { $p }
+            $( $($rest)* )?
+        }
+    };
+    (
+        @define all($($($cond:ident)::+),* $(,)?) => {
+            $(#[$id_meta:meta])*
+            $id:ident
+        }
+        $($( $rest:tt )+)?
+    ) => {
+        $crate::define_alias! {
+            @all [$($($cond)::+),*] [$(#[$id_meta])* $id] [all($($($cond)::+),*)]
         }
 
         $(
             $crate::define_alias! {
-                $($rest)+
+                @define $($rest)+
             }
         )?
-    }
+    };
+    (
+        @define any($($($cond:ident)::+),* $(,)?) => {
+            $(#[$id_meta:meta])*
+            $id:ident
+        }
+        $($( $rest:tt )+)?
+    ) => {
+        $crate::define_alias! {
+            @any [$($($cond)::+),*] [$(#[$id_meta])* $id] [any($($($cond)::+),*)]
+        }
+
+        $(
+            $crate::define_alias! {
+                @define $($rest)+
+            }
+        )?
+    };
+    (
+        @define not($($cond:ident)::+) => {
+            $(#[$id_meta:meta])*
+            $id:ident
+        }
+        $($( $rest:tt )+)?
+    ) => {
+        $crate::define_alias! {
+            @not [$($cond)::+] [$(#[$id_meta])* $id] [not($($cond)::+)]
+        }
+
+        $(
+            $crate::define_alias! {
+                @define $($rest)+
+            }
+        )?
+    };
+    (@define $($tt:tt)*) => {
+        ::core::compile_error!(concat!("invalid alias definition: `", stringify!($($tt)*), "`"));
+    };
+    ($($tt:tt)+) => {
+        $crate::define_alias! { @define $($tt)+ }
+    };
+}
+
+/// Lists the names of the given aliases that are currently active,
+/// as a `&'static [&'static str]`.
+///
+/// Names are kept as written, e.g. `cfg::std`.
+///
+/// # Example
+///
+/// ```
+/// use vct_cfg as cfg;
+///
+/// cfg::define_alias! {
+///     #[cfg(test)] => enable_test,
+///     not(enable_test) => not_test,
+/// }
+///
+/// let active = cfg::active_aliases![enable_test, not_test, cfg::std];
+/// assert_eq!(active.len(), if cfg::std!() { 2 } else { 1 });
+/// assert!(active.contains(&"not_test"));
+/// ```
+#[doc(hidden)]
+#[macro_export]
+macro_rules! active_aliases {
+    ($($head:ident $(:: $tail:ident)*),* $(,)?) => {{
+        const ALIASES: &[(&str, bool)] = &[$((
+            concat!(stringify!($head) $(, "::", stringify!($tail))*),
+            $head $(:: $tail)*!(),
+        )),*];
+        const ACTIVE: [&str; $crate::__count_active(ALIASES)] = $crate::__collect_active(ALIASES);
+        &ACTIVE
+    }};
 }
 
 define_alias! {
@@ -187,3 +479,39 @@ define_alias! {
     #[cfg(panic = "unwind")] => panic_unwind,
     #[cfg(panic = "abort")] => panic_abort,
 }
+
+/// Returns the names of the aliases of this crate that are currently active,
+/// see [`active_aliases!`].
+pub const fn active_aliases() -> &'static [&'static str] {
+    active_aliases![std, panic_unwind, panic_abort]
+}
+
+#[doc(hidden)]
+pub const fn __count_active(aliases: &[(&str, bool)]) -> usize {
+    let mut count = 0;
+    let mut i = 0;
+    while i < aliases.len() {
+        if aliases[i].1 {
+            count += 1;
+        }
+        i += 1;
+    }
+    count
+}
+
+#[doc(hidden)]
+pub const fn __collect_active<const N: usize>(
+    aliases: &[(&'static str, bool)],
+) -> [&'static str; N] {
+    let mut active = [""; N];
+    let mut count = 0;
+    let mut i = 0;
+    while i < aliases.len() {
+        if aliases[i].1 {
+            active[count] = aliases[i].0;
+            count += 1;
+        }
+        i += 1;
+    }
+    active
+}
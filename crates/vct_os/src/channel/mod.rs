@@ -0,0 +1,53 @@
+//! Multi-producer, single-consumer channels and a oneshot channel.
+//!
+//! [`unbounded`] and [`bounded`] wrap `std::sync::mpsc` on native platforms,
+//! and use a lock-protected queue on web and `no_std`, so every platform
+//! shares one import path and one set of error types.
+//!
+//! The browser main thread cannot block, so on web the blocking operations
+//! (`send` on a full bounded channel, `recv` on an empty one) panic instead
+//! of deadlocking; use `try_send`/`try_recv` there.
+//!
+//! # Example
+//!
+//! ```
+//! use vct_os::channel;
+//!
+//! let (tx, rx) = channel::unbounded();
+//! tx.send(1).unwrap();
+//! tx.clone().send(2).unwrap();
+//! drop(tx);
+//! assert_eq!(rx.iter().collect::<Vec<_>>(), [1, 2]);
+//! ```
+
+mod error;
+pub mod oneshot;
+
+pub use channel_impl::{Receiver, Sender, bounded, unbounded};
+pub use error::{RecvError, RecvTimeoutError, SendError, TryRecvError, TrySendError};
+
+/// Creates a oneshot channel, which carries a single value.
+///
+/// See the [`oneshot`](mod@oneshot) module.
+#[inline]
+pub fn oneshot<T>() -> (oneshot::Sender<T>, oneshot::Receiver<T>) {
+    oneshot::channel()
+}
+
+crate::cfg::switch! {
+    crate::cfg::web => {
+        mod queue;
+        use queue as channel_impl;
+    }
+    crate::cfg::std => {
+        mod native;
+        use native as channel_impl;
+    }
+    crate::cfg::no_std => {
+        mod queue;
+        use queue as channel_impl;
+    }
+    _ => {
+        compile_error!("This platform is not supported");
+    }
+}
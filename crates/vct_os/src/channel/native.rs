@@ -0,0 +1,123 @@
+//! Channels backed by `std::sync::mpsc`.
+
+use core::{fmt, time::Duration};
+use std::sync::mpsc;
+
+use super::{RecvError, RecvTimeoutError, SendError, TryRecvError, TrySendError};
+
+/// Creates a channel of unbounded capacity.
+#[inline]
+pub fn unbounded<T>() -> (Sender<T>, Receiver<T>) {
+    let (tx, rx) = mpsc::channel();
+    (Sender(Flavor::Unbounded(tx)), Receiver(rx))
+}
+
+/// Creates a channel that holds at most `cap` values, `send` blocks while it is full.
+///
+/// A capacity of zero makes every `send` wait for the matching `recv`.
+#[inline]
+pub fn bounded<T>(cap: usize) -> (Sender<T>, Receiver<T>) {
+    let (tx, rx) = mpsc::sync_channel(cap);
+    (Sender(Flavor::Bounded(tx)), Receiver(rx))
+}
+
+enum Flavor<T> {
+    Unbounded(mpsc::Sender<T>),
+    Bounded(mpsc::SyncSender<T>),
+}
+
+/// The sending half of a channel, can be cloned to send from multiple threads.
+pub struct Sender<T>(Flavor<T>);
+
+impl<T> Sender<T> {
+    /// Sends a value, blocking while a bounded channel is full.
+    ///
+    /// Fails if the receiver has been dropped.
+    #[inline]
+    pub fn send(&self, value: T) -> Result<(), SendError<T>> {
+        match &self.0 {
+            Flavor::Unbounded(tx) => tx.send(value),
+            Flavor::Bounded(tx) => tx.send(value),
+        }
+        .map_err(|err| SendError(err.0))
+    }
+
+    /// Sends a value without blocking.
+    #[inline]
+    pub fn try_send(&self, value: T) -> Result<(), TrySendError<T>> {
+        match &self.0 {
+            Flavor::Unbounded(tx) => tx
+                .send(value)
+                .map_err(|err| TrySendError::Disconnected(err.0)),
+            Flavor::Bounded(tx) => tx.try_send(value).map_err(|err| match err {
+                mpsc::TrySendError::Full(value) => TrySendError::Full(value),
+                mpsc::TrySendError::Disconnected(value) => TrySendError::Disconnected(value),
+            }),
+        }
+    }
+}
+
+impl<T> Clone for Sender<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(match &self.0 {
+            Flavor::Unbounded(tx) => Flavor::Unbounded(tx.clone()),
+            Flavor::Bounded(tx) => Flavor::Bounded(tx.clone()),
+        })
+    }
+}
+
+impl<T> fmt::Debug for Sender<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Sender").finish_non_exhaustive()
+    }
+}
+
+/// The receiving half of a channel.
+pub struct Receiver<T>(mpsc::Receiver<T>);
+
+impl<T> Receiver<T> {
+    /// Receives a value, blocking while the channel is empty.
+    ///
+    /// Fails once the channel is empty and every sender has been dropped.
+    #[inline]
+    pub fn recv(&self) -> Result<T, RecvError> {
+        self.0.recv().map_err(|_| RecvError)
+    }
+
+    /// Receives a value without blocking.
+    #[inline]
+    pub fn try_recv(&self) -> Result<T, TryRecvError> {
+        self.0.try_recv().map_err(|err| match err {
+            mpsc::TryRecvError::Empty => TryRecvError::Empty,
+            mpsc::TryRecvError::Disconnected => TryRecvError::Disconnected,
+        })
+    }
+
+    /// Receives a value, blocking for at most `timeout`.
+    #[inline]
+    pub fn recv_timeout(&self, timeout: Duration) -> Result<T, RecvTimeoutError> {
+        self.0.recv_timeout(timeout).map_err(|err| match err {
+            mpsc::RecvTimeoutError::Timeout => RecvTimeoutError::Timeout,
+            mpsc::RecvTimeoutError::Disconnected => RecvTimeoutError::Disconnected,
+        })
+    }
+
+    /// Returns an iterator that blocks for each value, ending once every sender has been dropped.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = T> + '_ {
+        self.0.iter()
+    }
+
+    /// Returns an iterator over the values already in the channel, without blocking.
+    #[inline]
+    pub fn try_iter(&self) -> impl Iterator<Item = T> + '_ {
+        self.0.try_iter()
+    }
+}
+
+impl<T> fmt::Debug for Receiver<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Receiver").finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,148 @@
+//! A channel that carries a single value, such as the result of a background job.
+//!
+//! # Example
+//!
+//! ```
+//! use vct_os::channel;
+//!
+//! let (tx, rx) = channel::oneshot();
+//! tx.send(42).unwrap();
+//! assert_eq!(rx.recv(), Ok(42));
+//! ```
+
+use core::{fmt, time::Duration};
+
+use super::{RecvError, RecvTimeoutError, TryRecvError};
+use crate::sync::{Arc, Condvar, Mutex, MutexGuard, PoisonError};
+
+/// Creates a oneshot channel.
+pub fn channel<T>() -> (Sender<T>, Receiver<T>) {
+    let shared = Arc::new(Shared {
+        state: Mutex::new(State {
+            value: None,
+            sender: true,
+            receiver: true,
+        }),
+        changed: Condvar::new(),
+    });
+    (
+        Sender {
+            shared: shared.clone(),
+        },
+        Receiver { shared },
+    )
+}
+
+struct State<T> {
+    value: Option<T>,
+    sender: bool,
+    receiver: bool,
+}
+
+struct Shared<T> {
+    state: Mutex<State<T>>,
+    changed: Condvar,
+}
+
+impl<T> Shared<T> {
+    #[inline]
+    fn lock(&self) -> MutexGuard<'_, State<T>> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// The sending half of a oneshot channel.
+pub struct Sender<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> Sender<T> {
+    /// Sends the value, returns it back if the receiver has been dropped.
+    pub fn send(self, value: T) -> Result<(), T> {
+        let mut state = self.shared.lock();
+        if !state.receiver {
+            return Err(value);
+        }
+        // Dropping `self` afterwards wakes the receiver.
+        state.value = Some(value);
+        Ok(())
+    }
+
+    /// Returns `true` if the receiver has been dropped.
+    #[inline]
+    pub fn is_closed(&self) -> bool {
+        !self.shared.lock().receiver
+    }
+}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        self.shared.lock().sender = false;
+        self.shared.changed.notify_all();
+    }
+}
+
+impl<T> fmt::Debug for Sender<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Sender").finish_non_exhaustive()
+    }
+}
+
+/// The receiving half of a oneshot channel.
+pub struct Receiver<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> Receiver<T> {
+    /// Receives the value, blocking until it is sent.
+    ///
+    /// Fails if the sender was dropped without sending.
+    pub fn recv(self) -> Result<T, RecvError> {
+        let mut state = self
+            .shared
+            .changed
+            .wait_while(self.shared.lock(), |state| {
+                state.value.is_none() && state.sender
+            })
+            .unwrap_or_else(PoisonError::into_inner);
+        state.value.take().ok_or(RecvError)
+    }
+
+    /// Receives the value without blocking.
+    pub fn try_recv(&self) -> Result<T, TryRecvError> {
+        let mut state = self.shared.lock();
+        match state.value.take() {
+            Some(value) => Ok(value),
+            None if state.sender => Err(TryRecvError::Empty),
+            None => Err(TryRecvError::Disconnected),
+        }
+    }
+
+    /// Receives the value, blocking for at most `timeout`.
+    pub fn recv_timeout(&self, timeout: Duration) -> Result<T, RecvTimeoutError> {
+        let (mut state, result) = self
+            .shared
+            .changed
+            .wait_timeout_while(self.shared.lock(), timeout, |state| {
+                state.value.is_none() && state.sender
+            })
+            .unwrap_or_else(PoisonError::into_inner);
+        match state.value.take() {
+            Some(value) => Ok(value),
+            None if result.timed_out() => Err(RecvTimeoutError::Timeout),
+            None => Err(RecvTimeoutError::Disconnected),
+        }
+    }
+}
+
+impl<T> Drop for Receiver<T> {
+    fn drop(&mut self) {
+        self.shared.lock().receiver = false;
+    }
+}
+
+impl<T> fmt::Debug for Receiver<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Receiver").finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,212 @@
+//! Channels backed by a lock-protected queue, for platforms without `std::sync::mpsc` threads.
+
+use alloc::collections::VecDeque;
+use core::{fmt, iter, time::Duration};
+
+use super::{RecvError, RecvTimeoutError, SendError, TryRecvError, TrySendError};
+use crate::sync::{Arc, Condvar, Mutex, MutexGuard, PoisonError};
+
+/// Creates a channel of unbounded capacity.
+#[inline]
+pub fn unbounded<T>() -> (Sender<T>, Receiver<T>) {
+    channel(None)
+}
+
+/// Creates a channel that holds at most `cap` values, `send` blocks while it is full.
+///
+/// A capacity of zero is treated as one, since a `send` cannot wait for the
+/// matching `recv` when there is only one thread.
+#[inline]
+pub fn bounded<T>(cap: usize) -> (Sender<T>, Receiver<T>) {
+    channel(Some(cap.max(1)))
+}
+
+fn channel<T>(cap: Option<usize>) -> (Sender<T>, Receiver<T>) {
+    let shared = Arc::new(Shared {
+        state: Mutex::new(State {
+            queue: VecDeque::new(),
+            senders: 1,
+            receiver: true,
+        }),
+        changed: Condvar::new(),
+        cap,
+    });
+    (
+        Sender {
+            shared: shared.clone(),
+        },
+        Receiver { shared },
+    )
+}
+
+struct State<T> {
+    queue: VecDeque<T>,
+    senders: usize,
+    receiver: bool,
+}
+
+struct Shared<T> {
+    state: Mutex<State<T>>,
+    changed: Condvar,
+    cap: Option<usize>,
+}
+
+impl<T> Shared<T> {
+    #[inline]
+    fn lock(&self) -> MutexGuard<'_, State<T>> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    #[inline]
+    fn is_full(&self, state: &State<T>) -> bool {
+        self.cap.is_some_and(|cap| state.queue.len() >= cap)
+    }
+}
+
+/// The sending half of a channel, can be cloned to send from multiple threads.
+pub struct Sender<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> Sender<T> {
+    /// Sends a value, blocking while a bounded channel is full.
+    ///
+    /// Fails if the receiver has been dropped.
+    pub fn send(&self, value: T) -> Result<(), SendError<T>> {
+        let mut state = self
+            .shared
+            .changed
+            .wait_while(self.shared.lock(), |state| {
+                state.receiver && self.shared.is_full(state)
+            })
+            .unwrap_or_else(PoisonError::into_inner);
+        if !state.receiver {
+            return Err(SendError(value));
+        }
+        state.queue.push_back(value);
+        drop(state);
+        self.shared.changed.notify_all();
+        Ok(())
+    }
+
+    /// Sends a value without blocking.
+    pub fn try_send(&self, value: T) -> Result<(), TrySendError<T>> {
+        let mut state = self.shared.lock();
+        if !state.receiver {
+            return Err(TrySendError::Disconnected(value));
+        }
+        if self.shared.is_full(&state) {
+            return Err(TrySendError::Full(value));
+        }
+        state.queue.push_back(value);
+        drop(state);
+        self.shared.changed.notify_all();
+        Ok(())
+    }
+}
+
+impl<T> Clone for Sender<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        self.shared.lock().senders += 1;
+        Self {
+            shared: self.shared.clone(),
+        }
+    }
+}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        self.shared.lock().senders -= 1;
+        self.shared.changed.notify_all();
+    }
+}
+
+impl<T> fmt::Debug for Sender<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Sender").finish_non_exhaustive()
+    }
+}
+
+/// The receiving half of a channel.
+pub struct Receiver<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> Receiver<T> {
+    /// Receives a value, blocking while the channel is empty.
+    ///
+    /// Fails once the channel is empty and every sender has been dropped.
+    pub fn recv(&self) -> Result<T, RecvError> {
+        let mut state = self
+            .shared
+            .changed
+            .wait_while(self.shared.lock(), |state| {
+                state.queue.is_empty() && state.senders > 0
+            })
+            .unwrap_or_else(PoisonError::into_inner);
+        let value = state.queue.pop_front().ok_or(RecvError)?;
+        drop(state);
+        self.shared.changed.notify_all();
+        Ok(value)
+    }
+
+    /// Receives a value without blocking.
+    pub fn try_recv(&self) -> Result<T, TryRecvError> {
+        let mut state = self.shared.lock();
+        match state.queue.pop_front() {
+            Some(value) => {
+                drop(state);
+                self.shared.changed.notify_all();
+                Ok(value)
+            }
+            None if state.senders == 0 => Err(TryRecvError::Disconnected),
+            None => Err(TryRecvError::Empty),
+        }
+    }
+
+    /// Receives a value, blocking for at most `timeout`.
+    pub fn recv_timeout(&self, timeout: Duration) -> Result<T, RecvTimeoutError> {
+        let (mut state, result) = self
+            .shared
+            .changed
+            .wait_timeout_while(self.shared.lock(), timeout, |state| {
+                state.queue.is_empty() && state.senders > 0
+            })
+            .unwrap_or_else(PoisonError::into_inner);
+        match state.queue.pop_front() {
+            Some(value) => {
+                drop(state);
+                self.shared.changed.notify_all();
+                Ok(value)
+            }
+            None if result.timed_out() => Err(RecvTimeoutError::Timeout),
+            None => Err(RecvTimeoutError::Disconnected),
+        }
+    }
+
+    /// Returns an iterator that blocks for each value, ending once every sender has been dropped.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = T> + '_ {
+        iter::from_fn(|| self.recv().ok())
+    }
+
+    /// Returns an iterator over the values already in the channel, without blocking.
+    #[inline]
+    pub fn try_iter(&self) -> impl Iterator<Item = T> + '_ {
+        iter::from_fn(|| self.try_recv().ok())
+    }
+}
+
+impl<T> Drop for Receiver<T> {
+    fn drop(&mut self) {
+        self.shared.lock().receiver = false;
+        self.shared.changed.notify_all();
+    }
+}
+
+impl<T> fmt::Debug for Receiver<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Receiver").finish_non_exhaustive()
+    }
+}
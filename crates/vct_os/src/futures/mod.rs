@@ -0,0 +1,68 @@
+//! Minimal helpers for driving futures without pulling in an async runtime.
+//!
+//! - [`block_on`] runs a future to completion on the current thread.
+//! - [`poll_once`] polls a future a single time, without blocking.
+//! - `spawn_local` (web only) runs a future on the browser's event loop.
+
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll, Waker},
+};
+
+pub use block_impl::block_on;
+
+crate::cfg::switch! {
+    crate::cfg::web => {
+        mod spin;
+        use spin as block_impl;
+    }
+    crate::cfg::std => {
+        mod park;
+        use park as block_impl;
+    }
+    crate::cfg::no_std => {
+        mod spin;
+        use spin as block_impl;
+    }
+    _ => {
+        compile_error!("This platform is not supported");
+    }
+}
+
+/// Polls a future once, returning its output if it is already complete.
+///
+/// The future is polled with a waker that does nothing, so a pending future
+/// must be polled again later to make progress.
+///
+/// # Example
+///
+/// ```
+/// use core::pin::pin;
+/// use vct_os::futures::poll_once;
+///
+/// let mut ready = pin!(async { 1 });
+/// assert_eq!(poll_once(&mut ready), Some(1));
+///
+/// let mut pending = pin!(core::future::pending::<()>());
+/// assert_eq!(poll_once(&mut pending), None);
+/// ```
+#[inline]
+pub fn poll_once<F: Future + Unpin + ?Sized>(future: &mut F) -> Option<F::Output> {
+    let mut cx = Context::from_waker(Waker::noop());
+    match Pin::new(future).poll(&mut cx) {
+        Poll::Ready(output) => Some(output),
+        Poll::Pending => None,
+    }
+}
+
+crate::cfg::web! {
+    /// Runs a future on the browser's event loop, see `wasm_bindgen_futures::spawn_local`.
+    ///
+    /// The browser main thread cannot block, so this is the way to drive
+    /// futures that wait on JavaScript promises or events.
+    #[inline]
+    pub fn spawn_local<F: Future<Output = ()> + 'static>(future: F) {
+        wasm_bindgen_futures::spawn_local(future);
+    }
+}
@@ -0,0 +1,43 @@
+//! [`block_on`] that spins while the future is pending, for platforms that cannot park threads.
+
+use alloc::{sync::Arc, task::Wake};
+use core::{
+    future::Future,
+    hint::spin_loop,
+    pin::pin,
+    sync::atomic::{AtomicBool, Ordering},
+    task::{Context, Poll, Waker},
+};
+
+struct FlagWaker(AtomicBool);
+
+impl Wake for FlagWaker {
+    #[inline]
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref();
+    }
+
+    #[inline]
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.0.store(true, Ordering::Release);
+    }
+}
+
+/// Runs a future to completion on the current thread, spinning until it is woken.
+///
+/// On web, the future must not wait on the browser's event loop (promises, timers),
+/// which cannot run while this function blocks; use `spawn_local` for those.
+pub fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let flag = Arc::new(FlagWaker(AtomicBool::new(false)));
+    let waker = Waker::from(flag.clone());
+    let mut cx = Context::from_waker(&waker);
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return output;
+        }
+        while !flag.0.swap(false, Ordering::Acquire) {
+            spin_loop();
+        }
+    }
+}
@@ -7,20 +7,35 @@ pub mod cfg {
     pub(crate) use vct_cfg::switch;
 
     vct_cfg::define_alias! {
-        #[cfg(all(target_arch = "wasm32", feature = "web"))] => web
+        #[cfg(all(target_arch = "wasm32", feature = "web"))] => web,
+        #[cfg(feature = "no_std")] => no_std,
+    }
+
+    /// Returns the names of the aliases of this crate that are currently active.
+    pub const fn active_aliases() -> &'static [&'static str] {
+        vct_cfg::active_aliases![std, web, no_std]
     }
 }
 
+extern crate alloc;
+
 cfg::std! {
     extern crate std;
 }
 
+pub mod channel;
+pub mod futures;
 pub mod sync;
+pub mod task_pool;
 pub mod thread;
 pub mod time;
 
 #[doc(hidden)]
 pub mod exports {
+    crate::cfg::std! {
+        pub use std::thread_local;
+    }
+
     crate::cfg::web! {
         pub use js_sys;
         pub use wasm_bindgen;
@@ -0,0 +1,172 @@
+use core::{fmt, sync::atomic::Ordering};
+
+use super::atomic::{AtomicU32, AtomicU64};
+
+macro_rules! atomic_float {
+    ($(#[$meta:meta])* $name:ident, $float:ty, $atomic:ty) => {
+        $(#[$meta])*
+        #[repr(transparent)]
+        pub struct $name($atomic);
+
+        impl $name {
+            /// Creates a new atomic float.
+            #[inline]
+            pub const fn new(value: $float) -> Self {
+                Self(<$atomic>::new(value.to_bits()))
+            }
+
+            /// Consumes the atomic and returns the contained value.
+            #[inline]
+            pub fn into_inner(self) -> $float {
+                <$float>::from_bits(self.0.into_inner())
+            }
+
+            /// Loads a value from the atomic float.
+            #[inline]
+            pub fn load(&self, order: Ordering) -> $float {
+                <$float>::from_bits(self.0.load(order))
+            }
+
+            /// Stores a value into the atomic float.
+            #[inline]
+            pub fn store(&self, value: $float, order: Ordering) {
+                self.0.store(value.to_bits(), order);
+            }
+
+            /// Stores a value into the atomic float, returning the previous value.
+            #[inline]
+            pub fn swap(&self, value: $float, order: Ordering) -> $float {
+                <$float>::from_bits(self.0.swap(value.to_bits(), order))
+            }
+
+            /// Stores `new` if the current value is bitwise equal to `current`.
+            ///
+            /// Bitwise comparison means `0.0` and `-0.0` differ, and a NaN can match itself.
+            #[inline]
+            pub fn compare_exchange(
+                &self,
+                current: $float,
+                new: $float,
+                success: Ordering,
+                failure: Ordering,
+            ) -> Result<$float, $float> {
+                self.0
+                    .compare_exchange(current.to_bits(), new.to_bits(), success, failure)
+                    .map(<$float>::from_bits)
+                    .map_err(<$float>::from_bits)
+            }
+
+            /// Like [`compare_exchange`](Self::compare_exchange), but may fail spuriously.
+            #[inline]
+            pub fn compare_exchange_weak(
+                &self,
+                current: $float,
+                new: $float,
+                success: Ordering,
+                failure: Ordering,
+            ) -> Result<$float, $float> {
+                self.0
+                    .compare_exchange_weak(current.to_bits(), new.to_bits(), success, failure)
+                    .map(<$float>::from_bits)
+                    .map_err(<$float>::from_bits)
+            }
+
+            /// Fetches the value, and applies `f` to it until the update succeeds
+            /// or `f` returns `None`.
+            #[inline]
+            pub fn fetch_update<F>(
+                &self,
+                set_order: Ordering,
+                fetch_order: Ordering,
+                mut f: F,
+            ) -> Result<$float, $float>
+            where
+                F: FnMut($float) -> Option<$float>,
+            {
+                self.0
+                    .fetch_update(set_order, fetch_order, |bits| {
+                        f(<$float>::from_bits(bits)).map(<$float>::to_bits)
+                    })
+                    .map(<$float>::from_bits)
+                    .map_err(<$float>::from_bits)
+            }
+
+            /// Adds to the current value, returning the previous value.
+            #[inline]
+            pub fn fetch_add(&self, value: $float, order: Ordering) -> $float {
+                self.update(order, |current| current + value)
+            }
+
+            /// Subtracts from the current value, returning the previous value.
+            #[inline]
+            pub fn fetch_sub(&self, value: $float, order: Ordering) -> $float {
+                self.update(order, |current| current - value)
+            }
+
+            /// Stores the maximum of the current value and `value`, returning the previous value.
+            #[inline]
+            pub fn fetch_max(&self, value: $float, order: Ordering) -> $float {
+                self.update(order, |current| current.max(value))
+            }
+
+            /// Stores the minimum of the current value and `value`, returning the previous value.
+            #[inline]
+            pub fn fetch_min(&self, value: $float, order: Ordering) -> $float {
+                self.update(order, |current| current.min(value))
+            }
+
+            /// CAS loop shared by the `fetch_*` operations.
+            #[inline]
+            fn update(&self, order: Ordering, mut f: impl FnMut($float) -> $float) -> $float {
+                let fetch_order = match order {
+                    Ordering::AcqRel => Ordering::Acquire,
+                    Ordering::Release => Ordering::Relaxed,
+                    order => order,
+                };
+                match self.fetch_update(order, fetch_order, |current| Some(f(current))) {
+                    Ok(previous) | Err(previous) => previous,
+                }
+            }
+        }
+
+        impl Default for $name {
+            #[inline]
+            fn default() -> Self {
+                Self::new(0.0)
+            }
+        }
+
+        impl From<$float> for $name {
+            #[inline]
+            fn from(value: $float) -> Self {
+                Self::new(value)
+            }
+        }
+
+        impl fmt::Debug for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Debug::fmt(&self.load(Ordering::Relaxed), f)
+            }
+        }
+    };
+}
+
+atomic_float! {
+    /// An `f32` that can be safely shared between threads, stored as its bits in an [`AtomicU32`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vct_os::sync::atomic::{AtomicF32, Ordering};
+    ///
+    /// let total = AtomicF32::new(1.5);
+    /// assert_eq!(total.fetch_add(2.0, Ordering::Relaxed), 1.5);
+    /// assert_eq!(total.load(Ordering::Relaxed), 3.5);
+    /// ```
+    AtomicF32, f32, AtomicU32
+}
+
+atomic_float! {
+    /// An `f64` that can be safely shared between threads, stored as its bits in an [`AtomicU64`].
+    AtomicF64, f64, AtomicU64
+}
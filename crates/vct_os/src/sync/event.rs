@@ -0,0 +1,94 @@
+use core::{fmt, time::Duration};
+
+use super::{Condvar, Mutex, MutexGuard, PoisonError};
+
+/// A manual-reset event: once [`set`](Event::set), every waiter passes until
+/// it is [`reset`](Event::reset).
+///
+/// Blocking on web panics on the browser main thread, like `Condvar::wait`;
+/// poll [`is_set`](Event::is_set) there.
+///
+/// # Example
+///
+/// ```
+/// use vct_os::sync::Event;
+///
+/// let event = Event::new(false);
+/// assert!(!event.is_set());
+/// event.set();
+/// event.wait();
+/// event.reset();
+/// assert!(!event.is_set());
+/// ```
+pub struct Event {
+    state: Mutex<bool>,
+    changed: Condvar,
+}
+
+impl Event {
+    /// Creates an event in the given state.
+    #[inline]
+    pub const fn new(set: bool) -> Self {
+        Self {
+            state: Mutex::new(set),
+            changed: Condvar::new(),
+        }
+    }
+
+    #[inline]
+    fn lock(&self) -> MutexGuard<'_, bool> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Sets the event, waking every waiting thread.
+    pub fn set(&self) {
+        *self.lock() = true;
+        self.changed.notify_all();
+    }
+
+    /// Resets the event, so later waits block again.
+    #[inline]
+    pub fn reset(&self) {
+        *self.lock() = false;
+    }
+
+    /// Returns `true` if the event is set.
+    #[inline]
+    pub fn is_set(&self) -> bool {
+        *self.lock()
+    }
+
+    /// Blocks until the event is set.
+    pub fn wait(&self) {
+        let _set = self
+            .changed
+            .wait_while(self.lock(), |set| !*set)
+            .unwrap_or_else(PoisonError::into_inner);
+    }
+
+    /// Blocks until the event is set, for at most `timeout`.
+    ///
+    /// Returns `true` if the event was set.
+    pub fn wait_timeout(&self, timeout: Duration) -> bool {
+        let (set, _) = self
+            .changed
+            .wait_timeout_while(self.lock(), timeout, |set| !*set)
+            .unwrap_or_else(PoisonError::into_inner);
+        *set
+    }
+}
+
+impl Default for Event {
+    #[inline]
+    fn default() -> Self {
+        Self::new(false)
+    }
+}
+
+impl fmt::Debug for Event {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Event")
+            .field("set", &self.is_set())
+            .finish()
+    }
+}
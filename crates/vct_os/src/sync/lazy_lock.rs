@@ -0,0 +1,108 @@
+#![expect(
+    unsafe_code,
+    reason = "The initializer is taken exactly once inside `OnceLock::get_or_init`."
+)]
+
+use core::{cell::UnsafeCell, fmt, ops::Deref};
+
+use super::OnceLock;
+
+/// A value which is initialized on the first access, mirrors `std::sync::LazyLock`.
+///
+/// Adds [`try_get`](LazyLock::try_get) and [`force_with`](LazyLock::force_with),
+/// which `std` does not offer on stable.
+pub struct LazyLock<T, F = fn() -> T> {
+    cell: OnceLock<T>,
+    init: UnsafeCell<Option<F>>,
+}
+
+// SAFETY: `init` is only accessed inside the initialization of `cell`,
+// which runs on a single thread.
+unsafe impl<T: Send + Sync, F: Send> Sync for LazyLock<T, F> {}
+
+impl<T, F: FnOnce() -> T> LazyLock<T, F> {
+    /// Creates a new lazy value with the given initializing function.
+    #[inline]
+    pub const fn new(f: F) -> Self {
+        Self {
+            cell: OnceLock::new(),
+            init: UnsafeCell::new(Some(f)),
+        }
+    }
+
+    /// Forces the evaluation of this lazy value and returns a reference to result.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a previous initialization panicked.
+    #[inline]
+    pub fn force(this: &Self) -> &T {
+        this.cell.get_or_init(|| {
+            // SAFETY: Only one thread runs the initialization of `cell`.
+            let init = unsafe { (*this.init.get()).take() };
+            match init {
+                Some(f) => f(),
+                None => panic!("LazyLock instance has previously been poisoned"),
+            }
+        })
+    }
+
+    /// Returns the value if it has already been initialized, without forcing it.
+    #[inline]
+    pub fn try_get(this: &Self) -> Option<&T> {
+        this.cell.get()
+    }
+
+    /// Forces the evaluation of this lazy value with `f` instead of the stored
+    /// initializer, which is dropped. Does nothing if the value is already initialized.
+    ///
+    /// Useful to override a global default, e.g. with a test configuration.
+    #[inline]
+    pub fn force_with(this: &Self, f: impl FnOnce() -> T) -> &T {
+        this.cell.get_or_init(|| {
+            // SAFETY: Only one thread runs the initialization of `cell`.
+            drop(unsafe { (*this.init.get()).take() });
+            f()
+        })
+    }
+
+    /// Consumes this `LazyLock` returning the stored value,
+    /// or `Err(f)` if the value was not initialized.
+    #[inline]
+    pub fn into_inner(this: Self) -> Result<T, F> {
+        let Self { cell, init } = this;
+        match cell.into_inner() {
+            Some(value) => Ok(value),
+            None => Err(init
+                .into_inner()
+                .expect("LazyLock instance has previously been poisoned")),
+        }
+    }
+}
+
+impl<T, F: FnOnce() -> T> Deref for LazyLock<T, F> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        Self::force(self)
+    }
+}
+
+impl<T: Default> Default for LazyLock<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(T::default)
+    }
+}
+
+impl<T: fmt::Debug, F> fmt::Debug for LazyLock<T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut d = f.debug_tuple("LazyLock");
+        match self.cell.get() {
+            Some(value) => d.field(value),
+            None => d.field(&format_args!("<uninit>")),
+        };
+        d.finish()
+    }
+}
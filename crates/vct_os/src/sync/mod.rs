@@ -1,10 +1,11 @@
 pub use sync_impl::{
-    Arc, Barrier, BarrierWaitResult, LazyLock, LockResult, Mutex, MutexGuard, Once, OnceLock,
+    Arc, Barrier, BarrierWaitResult, Condvar, LockResult, Mutex, MutexGuard, Once, OnceLock,
     OnceState, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard, TryLockError, TryLockResult,
-    Weak,
+    WaitTimeoutResult, Weak,
 };
 
 pub mod atomic {
+    pub use super::atomic_float::{AtomicF32, AtomicF64};
     pub use super::atomic_impl::{
         AtomicBool, AtomicI8, AtomicI16, AtomicI32, AtomicI64, AtomicIsize, AtomicPtr, AtomicU8,
         AtomicU16, AtomicU32, AtomicU64, AtomicUsize,
@@ -12,15 +13,29 @@ pub mod atomic {
     pub use core::sync::atomic::Ordering;
 }
 
+mod atomic_float;
+
+mod event;
+mod lazy_lock;
 mod once_flag;
+pub mod poison_free;
+mod semaphore;
 pub use crate::once;
-pub use once_flag::OnceFlag;
+pub use event::Event;
+pub use lazy_lock::LazyLock;
+pub use once_flag::{OnceFlag, OnceResult};
+pub use semaphore::{Semaphore, SemaphoreGuard};
 
 crate::cfg::switch! {
     crate::cfg::std => {
         use std::sync as sync_impl;
         use core::sync::atomic as atomic_impl;
     }
+    crate::cfg::no_std => {
+        mod spin;
+        use spin as sync_impl;
+        use core::sync::atomic as atomic_impl;
+    }
     _ => {
         compile_error!("This platform is not supported");
     }
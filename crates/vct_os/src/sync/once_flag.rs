@@ -1,4 +1,6 @@
-use crate::sync::atomic;
+use core::fmt;
+
+use crate::sync::{OnceLock, atomic};
 
 /// Wrapper around an [`AtomicBool`]
 ///
@@ -48,6 +50,78 @@ impl Default for OnceFlag {
     }
 }
 
+/// Caches the outcome of a fallible initialization, success or failure.
+///
+/// Unlike retrying on every access, a failed initialization is remembered and
+/// returned as `Err` by later calls, so an expensive failure (a missing GPU,
+/// a broken registry) is only paid for once.
+///
+/// # Example
+///
+/// ```
+/// # use vct_os::sync::OnceResult;
+///
+/// let once = OnceResult::<u32, &str>::new();
+/// assert_eq!(once.call_once_result(|| Err("no device")), Err(&"no device"));
+/// // The failure is cached, `f` is not called again.
+/// assert_eq!(once.call_once_result(|| Ok(1)), Err(&"no device"));
+/// ```
+pub struct OnceResult<T, E> {
+    cell: OnceLock<Result<T, E>>,
+}
+
+impl<T, E> OnceResult<T, E> {
+    /// Creates an uninitialized value.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            cell: OnceLock::new(),
+        }
+    }
+
+    /// Runs `f` if no call has completed yet, and returns the cached outcome.
+    #[inline]
+    pub fn call_once_result(&self, f: impl FnOnce() -> Result<T, E>) -> Result<&T, &E> {
+        self.cell.get_or_init(f).as_ref()
+    }
+
+    /// Returns the cached outcome, or `None` if no call has completed yet.
+    #[inline]
+    pub fn get(&self) -> Option<Result<&T, &E>> {
+        self.cell.get().map(Result::as_ref)
+    }
+
+    /// Returns `true` if a call has completed, successfully or not.
+    #[inline]
+    pub fn is_completed(&self) -> bool {
+        self.cell.get().is_some()
+    }
+
+    /// Consumes this value, returning the cached outcome.
+    #[inline]
+    pub fn into_inner(self) -> Option<Result<T, E>> {
+        self.cell.into_inner()
+    }
+}
+
+impl<T, E> Default for OnceResult<T, E> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: fmt::Debug, E: fmt::Debug> fmt::Debug for OnceResult<T, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut d = f.debug_tuple("OnceResult");
+        match self.cell.get() {
+            Some(result) => d.field(result),
+            None => d.field(&format_args!("<uninit>")),
+        };
+        d.finish()
+    }
+}
+
 /// Call some expression only once per call site.
 ///
 /// # Example
@@ -0,0 +1,121 @@
+use core::{fmt, time::Duration};
+
+use super::{Condvar, Mutex, MutexGuard, PoisonError};
+
+/// A counting semaphore, limits how many holders can use a resource at once.
+///
+/// Blocking on web panics on the browser main thread, like `Condvar::wait`;
+/// use [`try_acquire`](Semaphore::try_acquire) there.
+///
+/// # Example
+///
+/// ```
+/// use vct_os::sync::Semaphore;
+///
+/// let semaphore = Semaphore::new(2);
+/// let a = semaphore.acquire();
+/// let _b = semaphore.acquire();
+/// assert!(semaphore.try_acquire().is_none());
+/// drop(a);
+/// assert!(semaphore.try_acquire().is_some());
+/// ```
+pub struct Semaphore {
+    permits: Mutex<usize>,
+    released: Condvar,
+}
+
+impl Semaphore {
+    /// Creates a semaphore with the given number of permits.
+    #[inline]
+    pub const fn new(permits: usize) -> Self {
+        Self {
+            permits: Mutex::new(permits),
+            released: Condvar::new(),
+        }
+    }
+
+    #[inline]
+    fn lock(&self) -> MutexGuard<'_, usize> {
+        self.permits.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Acquires a permit, blocking until one is available.
+    pub fn acquire(&self) -> SemaphoreGuard<'_> {
+        let mut permits = self
+            .released
+            .wait_while(self.lock(), |permits| *permits == 0)
+            .unwrap_or_else(PoisonError::into_inner);
+        *permits -= 1;
+        SemaphoreGuard { semaphore: self }
+    }
+
+    /// Acquires a permit, blocking for at most `timeout`.
+    ///
+    /// Returns `None` if no permit became available in time.
+    pub fn acquire_timeout(&self, timeout: Duration) -> Option<SemaphoreGuard<'_>> {
+        let (mut permits, result) = self
+            .released
+            .wait_timeout_while(self.lock(), timeout, |permits| *permits == 0)
+            .unwrap_or_else(PoisonError::into_inner);
+        if result.timed_out() {
+            return None;
+        }
+        *permits -= 1;
+        Some(SemaphoreGuard { semaphore: self })
+    }
+
+    /// Acquires a permit if one is available, without blocking.
+    pub fn try_acquire(&self) -> Option<SemaphoreGuard<'_>> {
+        let mut permits = self.lock();
+        if *permits == 0 {
+            return None;
+        }
+        *permits -= 1;
+        Some(SemaphoreGuard { semaphore: self })
+    }
+
+    /// Adds `n` permits, waking blocked threads.
+    pub fn add_permits(&self, n: usize) {
+        *self.lock() += n;
+        self.released.notify_all();
+    }
+
+    /// Returns the number of currently available permits.
+    #[inline]
+    pub fn available_permits(&self) -> usize {
+        *self.lock()
+    }
+}
+
+impl fmt::Debug for Semaphore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Semaphore")
+            .field("available_permits", &self.available_permits())
+            .finish()
+    }
+}
+
+/// A permit of a [`Semaphore`], released when dropped.
+#[must_use = "if unused the permit is immediately released"]
+#[derive(Debug)]
+pub struct SemaphoreGuard<'a> {
+    semaphore: &'a Semaphore,
+}
+
+impl SemaphoreGuard<'_> {
+    /// Consumes the guard without releasing the permit.
+    ///
+    /// The permit is lost until [`Semaphore::add_permits`] gives it back.
+    #[inline]
+    pub fn forget(self) {
+        core::mem::forget(self);
+    }
+}
+
+impl Drop for SemaphoreGuard<'_> {
+    #[inline]
+    fn drop(&mut self) {
+        *self.semaphore.lock() += 1;
+        self.semaphore.released.notify_one();
+    }
+}
@@ -0,0 +1,78 @@
+use core::{fmt, hint::spin_loop};
+
+use super::{Mutex, PoisonError};
+
+/// A barrier enables multiple threads to synchronize the beginning of some computation,
+/// mirrors `std::sync::Barrier`.
+pub struct Barrier {
+    state: Mutex<BarrierState>,
+    num_threads: usize,
+}
+
+struct BarrierState {
+    count: usize,
+    generation: usize,
+}
+
+/// Returned by [`Barrier::wait`] when all threads in the [`Barrier`] have rendezvoused.
+pub struct BarrierWaitResult(bool);
+
+impl BarrierWaitResult {
+    /// Returns `true` if this thread is the "leader thread" for the call to [`Barrier::wait`].
+    #[inline]
+    pub fn is_leader(&self) -> bool {
+        self.0
+    }
+}
+
+impl fmt::Debug for BarrierWaitResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BarrierWaitResult")
+            .field("is_leader", &self.0)
+            .finish()
+    }
+}
+
+impl Barrier {
+    /// Creates a new barrier that can block a given number of threads.
+    #[inline]
+    pub const fn new(n: usize) -> Self {
+        Self {
+            state: Mutex::new(BarrierState {
+                count: 0,
+                generation: 0,
+            }),
+            num_threads: n,
+        }
+    }
+
+    /// Spins until all `n` threads have rendezvoused here.
+    pub fn wait(&self) -> BarrierWaitResult {
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        let generation = state.generation;
+        state.count += 1;
+        if state.count < self.num_threads {
+            drop(state);
+            while self
+                .state
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .generation
+                == generation
+            {
+                spin_loop();
+            }
+            BarrierWaitResult(false)
+        } else {
+            state.count = 0;
+            state.generation = state.generation.wrapping_add(1);
+            BarrierWaitResult(true)
+        }
+    }
+}
+
+impl fmt::Debug for Barrier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Barrier").finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,134 @@
+use core::{
+    fmt,
+    hint::spin_loop,
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+};
+
+use super::{LockResult, MutexGuard, PoisonError};
+use crate::time::Instant;
+
+/// A type indicating whether a timed wait on a [`Condvar`] returned due to a time out or not.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct WaitTimeoutResult(bool);
+
+impl WaitTimeoutResult {
+    /// Returns `true` if the wait was known to have timed out.
+    #[inline]
+    pub fn timed_out(&self) -> bool {
+        self.0
+    }
+}
+
+/// A condition variable that spins while waiting, mirrors `std::sync::Condvar`.
+///
+/// Both notify methods wake every waiter, which `std` allows as spurious wakeups.
+pub struct Condvar {
+    generation: AtomicUsize,
+}
+
+impl Condvar {
+    /// Creates a new condition variable which is ready to be waited on and notified.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            generation: AtomicUsize::new(0),
+        }
+    }
+
+    /// Blocks the current thread until this condition variable receives a notification.
+    pub fn wait<'a, T>(&self, guard: MutexGuard<'a, T>) -> LockResult<MutexGuard<'a, T>> {
+        let generation = self.generation.load(Ordering::Acquire);
+        let mutex = guard.lock;
+        drop(guard);
+        while self.generation.load(Ordering::Acquire) == generation {
+            spin_loop();
+        }
+        mutex.lock()
+    }
+
+    /// Blocks the current thread until `condition` returns `false`.
+    pub fn wait_while<'a, T, F>(
+        &self,
+        mut guard: MutexGuard<'a, T>,
+        mut condition: F,
+    ) -> LockResult<MutexGuard<'a, T>>
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        while condition(&mut *guard) {
+            guard = self.wait(guard)?;
+        }
+        Ok(guard)
+    }
+
+    /// Waits on this condition variable for a notification, timing out after `dur`.
+    pub fn wait_timeout<'a, T>(
+        &self,
+        guard: MutexGuard<'a, T>,
+        dur: Duration,
+    ) -> LockResult<(MutexGuard<'a, T>, WaitTimeoutResult)> {
+        let start = Instant::now();
+        let generation = self.generation.load(Ordering::Acquire);
+        let mutex = guard.lock;
+        drop(guard);
+        let mut timed_out = false;
+        while self.generation.load(Ordering::Acquire) == generation {
+            if start.elapsed() >= dur {
+                timed_out = true;
+                break;
+            }
+            spin_loop();
+        }
+        let result = WaitTimeoutResult(timed_out);
+        match mutex.lock() {
+            Ok(guard) => Ok((guard, result)),
+            Err(err) => Err(PoisonError::new((err.into_inner(), result))),
+        }
+    }
+
+    /// Waits on this condition variable until `condition` returns `false`, timing out after `dur`.
+    pub fn wait_timeout_while<'a, T, F>(
+        &self,
+        mut guard: MutexGuard<'a, T>,
+        dur: Duration,
+        mut condition: F,
+    ) -> LockResult<(MutexGuard<'a, T>, WaitTimeoutResult)>
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        let start = Instant::now();
+        while condition(&mut *guard) {
+            let Some(remaining) = dur.checked_sub(start.elapsed()) else {
+                return Ok((guard, WaitTimeoutResult(true)));
+            };
+            guard = self.wait_timeout(guard, remaining)?.0;
+        }
+        Ok((guard, WaitTimeoutResult(false)))
+    }
+
+    /// Wakes up one blocked thread on this condvar.
+    #[inline]
+    pub fn notify_one(&self) {
+        self.generation.fetch_add(1, Ordering::Release);
+    }
+
+    /// Wakes up all blocked threads on this condvar.
+    #[inline]
+    pub fn notify_all(&self) {
+        self.generation.fetch_add(1, Ordering::Release);
+    }
+}
+
+impl Default for Condvar {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for Condvar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Condvar").finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,26 @@
+//! Spin-based fallback of `std::sync` for platforms without `std`.
+//!
+//! The API mirrors `std::sync`, so code can use the same call paths on every platform.
+//! Locks are never poisoned, [`PoisonError`] only exists for API compatibility.
+//!
+//! Spinning wastes CPU time while waiting, which is acceptable on single-core
+//! or embedded targets with short critical sections, but not a replacement
+//! for OS-backed locks.
+
+mod barrier;
+mod condvar;
+mod mutex;
+mod once;
+mod once_lock;
+mod poison;
+mod rwlock;
+
+pub use alloc::sync::{Arc, Weak};
+
+pub use barrier::{Barrier, BarrierWaitResult};
+pub use condvar::{Condvar, WaitTimeoutResult};
+pub use mutex::{Mutex, MutexGuard};
+pub use once::{Once, OnceState};
+pub use once_lock::OnceLock;
+pub use poison::{LockResult, PoisonError, TryLockError, TryLockResult};
+pub use rwlock::{RwLock, RwLockReadGuard, RwLockWriteGuard};
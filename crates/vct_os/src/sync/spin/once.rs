@@ -0,0 +1,129 @@
+use core::{
+    fmt,
+    hint::spin_loop,
+    sync::atomic::{AtomicU8, Ordering},
+};
+
+const INCOMPLETE: u8 = 0;
+const RUNNING: u8 = 1;
+const COMPLETE: u8 = 2;
+const POISONED: u8 = 3;
+
+/// A synchronization primitive which can be used to run a one-time initialization,
+/// mirrors `std::sync::Once`.
+pub struct Once {
+    state: AtomicU8,
+}
+
+/// State yielded to [`Once::call_once_force`]'s closure.
+#[derive(Debug)]
+pub struct OnceState {
+    poisoned: bool,
+}
+
+impl OnceState {
+    /// Returns `true` if a previous initialization panicked.
+    #[inline]
+    pub fn is_poisoned(&self) -> bool {
+        self.poisoned
+    }
+}
+
+/// Poisons the `Once` if the initialization closure panics.
+struct CompletionGuard<'a> {
+    state: &'a AtomicU8,
+    set_to: u8,
+}
+
+impl Drop for CompletionGuard<'_> {
+    #[inline]
+    fn drop(&mut self) {
+        self.state.store(self.set_to, Ordering::Release);
+    }
+}
+
+impl Once {
+    /// Creates a new `Once` value.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            state: AtomicU8::new(INCOMPLETE),
+        }
+    }
+
+    /// Performs an initialization routine once and only once.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a previous initialization panicked.
+    #[inline]
+    pub fn call_once<F: FnOnce()>(&self, f: F) {
+        if self.is_completed() {
+            return;
+        }
+        let mut f = Some(f);
+        self.call(false, &mut |_| (f.take().unwrap())());
+    }
+
+    /// Performs the same function as [`call_once`](Once::call_once) except ignores poisoning.
+    #[inline]
+    pub fn call_once_force<F: FnOnce(&OnceState)>(&self, f: F) {
+        if self.is_completed() {
+            return;
+        }
+        let mut f = Some(f);
+        self.call(true, &mut |state| (f.take().unwrap())(state));
+    }
+
+    /// Returns `true` if some initialization has completed successfully.
+    #[inline]
+    pub fn is_completed(&self) -> bool {
+        self.state.load(Ordering::Acquire) == COMPLETE
+    }
+
+    #[cold]
+    fn call(&self, ignore_poisoning: bool, f: &mut dyn FnMut(&OnceState)) {
+        let mut f = Some(f);
+        loop {
+            let state = self.state.load(Ordering::Acquire);
+            match state {
+                COMPLETE => return,
+                POISONED if !ignore_poisoning => {
+                    panic!("Once instance has previously been poisoned");
+                }
+                INCOMPLETE | POISONED => {
+                    if self
+                        .state
+                        .compare_exchange_weak(state, RUNNING, Ordering::Acquire, Ordering::Acquire)
+                        .is_err()
+                    {
+                        continue;
+                    }
+                    let mut guard = CompletionGuard {
+                        state: &self.state,
+                        set_to: POISONED,
+                    };
+                    (f.take().unwrap())(&OnceState {
+                        poisoned: state == POISONED,
+                    });
+                    guard.set_to = COMPLETE;
+                    return;
+                }
+                _ => spin_loop(),
+            }
+        }
+    }
+}
+
+impl Default for Once {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for Once {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Once").finish_non_exhaustive()
+    }
+}
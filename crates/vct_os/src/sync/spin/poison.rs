@@ -0,0 +1,89 @@
+use core::{error::Error, fmt};
+
+/// A type of error which can be returned whenever a lock is acquired.
+///
+/// Spin locks are never poisoned, this type only exists for API compatibility with `std`.
+pub struct PoisonError<T> {
+    guard: T,
+}
+
+impl<T> PoisonError<T> {
+    /// Creates a `PoisonError`.
+    #[inline]
+    pub const fn new(guard: T) -> Self {
+        Self { guard }
+    }
+
+    /// Consumes this error, returning the underlying guard.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.guard
+    }
+
+    /// Returns a reference to the underlying guard.
+    #[inline]
+    pub const fn get_ref(&self) -> &T {
+        &self.guard
+    }
+
+    /// Returns a mutable reference to the underlying guard.
+    #[inline]
+    pub const fn get_mut(&mut self) -> &mut T {
+        &mut self.guard
+    }
+}
+
+impl<T> fmt::Debug for PoisonError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PoisonError").finish_non_exhaustive()
+    }
+}
+
+impl<T> fmt::Display for PoisonError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("poisoned lock: another task failed inside")
+    }
+}
+
+impl<T> Error for PoisonError<T> {}
+
+/// An enumeration of possible errors of the `try_lock` methods.
+pub enum TryLockError<T> {
+    /// The lock is poisoned, never returned by spin locks.
+    Poisoned(PoisonError<T>),
+    /// The lock could not be acquired because it is already locked.
+    WouldBlock,
+}
+
+impl<T> From<PoisonError<T>> for TryLockError<T> {
+    #[inline]
+    fn from(error: PoisonError<T>) -> Self {
+        Self::Poisoned(error)
+    }
+}
+
+impl<T> fmt::Debug for TryLockError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Poisoned(error) => fmt::Debug::fmt(error, f),
+            Self::WouldBlock => f.write_str("WouldBlock"),
+        }
+    }
+}
+
+impl<T> fmt::Display for TryLockError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Poisoned(error) => fmt::Display::fmt(error, f),
+            Self::WouldBlock => f.write_str("try_lock failed because the operation would block"),
+        }
+    }
+}
+
+impl<T> Error for TryLockError<T> {}
+
+/// A type alias for the result of a lock method which can be poisoned.
+pub type LockResult<T> = Result<T, PoisonError<T>>;
+
+/// A type alias for the result of a nonblocking locking method.
+pub type TryLockResult<T> = Result<T, TryLockError<T>>;
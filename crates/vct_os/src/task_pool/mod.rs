@@ -0,0 +1,216 @@
+//! A pool of worker threads for short-lived tasks.
+//!
+//! [`TaskPool::scope`] lets tasks borrow data from the caller's stack, and
+//! [`TaskPool::map_batched`] splits slices into batches so each task carries
+//! enough work to pay for its scheduling.
+//!
+//! On platforms without threads (web, `no_std`) the pool runs every task inline
+//! on the calling thread, so the same code path works everywhere.
+//!
+//! [`ComputeTaskPool`] and [`IoTaskPool`] are global presets for CPU-bound and
+//! blocking work respectively.
+
+use alloc::{string::String, vec::Vec};
+use core::ops::Deref;
+
+use crate::sync::OnceLock;
+
+pub use pool_impl::{Scope, Task, TaskPool};
+
+crate::cfg::switch! {
+    crate::cfg::web => {
+        mod single_threaded;
+        use single_threaded as pool_impl;
+    }
+    crate::cfg::std => {
+        mod threaded;
+        use threaded as pool_impl;
+    }
+    crate::cfg::no_std => {
+        mod single_threaded;
+        use single_threaded as pool_impl;
+    }
+    _ => {
+        compile_error!("This platform is not supported");
+    }
+}
+
+/// Configures and creates a [`TaskPool`].
+#[derive(Debug, Default, Clone)]
+pub struct TaskPoolBuilder {
+    num_threads: Option<usize>,
+    thread_name: Option<String>,
+    stack_size: Option<usize>,
+}
+
+impl TaskPoolBuilder {
+    /// Creates a builder with the default configuration.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the number of worker threads, defaults to the available parallelism.
+    ///
+    /// Ignored on platforms without threads.
+    #[inline]
+    pub fn num_threads(mut self, num_threads: usize) -> Self {
+        self.num_threads = Some(num_threads);
+        self
+    }
+
+    /// Sets the name prefix of the worker threads, each thread appends its index.
+    #[inline]
+    pub fn thread_name(mut self, thread_name: impl Into<String>) -> Self {
+        self.thread_name = Some(thread_name.into());
+        self
+    }
+
+    /// Sets the stack size (in bytes) of the worker threads.
+    #[inline]
+    pub fn stack_size(mut self, stack_size: usize) -> Self {
+        self.stack_size = Some(stack_size);
+        self
+    }
+
+    /// Creates the [`TaskPool`], spawning its worker threads.
+    #[inline]
+    pub fn build(self) -> TaskPool {
+        TaskPool::from_builder(self)
+    }
+}
+
+impl TaskPool {
+    /// Creates a task pool with the default configuration.
+    #[inline]
+    pub fn new() -> Self {
+        TaskPoolBuilder::new().build()
+    }
+
+    /// Calls `f` on every batch of at most `batch_size` items, in parallel.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `batch_size` is zero, or if `f` panics.
+    pub fn for_each_batched<I, F>(&self, items: &[I], batch_size: usize, f: F)
+    where
+        I: Sync,
+        F: Fn(&[I]) + Sync,
+    {
+        let f = &f;
+        self.scope(|s| {
+            for batch in items.chunks(batch_size) {
+                s.spawn(move || f(batch));
+            }
+        });
+    }
+
+    /// Maps every item with `f`, spreading batches of at most `batch_size` items
+    /// across the pool, and returns the results in the order of `items`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `batch_size` is zero, or if `f` panics.
+    pub fn map_batched<I, T, F>(&self, items: &[I], batch_size: usize, f: F) -> Vec<T>
+    where
+        I: Sync,
+        T: Send + 'static,
+        F: Fn(&I) -> T + Sync,
+    {
+        let f = &f;
+        let batches = self.scope(|s| {
+            for batch in items.chunks(batch_size) {
+                s.spawn(move || batch.iter().map(f).collect::<Vec<T>>());
+            }
+        });
+        batches.into_iter().flatten().collect()
+    }
+}
+
+impl Default for TaskPool {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+macro_rules! task_pool_preset {
+    ($(#[$meta:meta])* $name:ident, $storage:ident) => {
+        $(#[$meta])*
+        #[derive(Debug)]
+        pub struct $name(TaskPool);
+
+        static $storage: OnceLock<$name> = OnceLock::new();
+
+        impl $name {
+            /// Returns the global pool, initializing it with `f` on first use.
+            #[inline]
+            pub fn get_or_init(f: impl FnOnce() -> TaskPool) -> &'static Self {
+                $storage.get_or_init(|| Self(f()))
+            }
+
+            /// Returns the global pool, initializing it with the preset configuration on first use.
+            #[inline]
+            pub fn get_or_default() -> &'static Self {
+                Self::get_or_init(|| Self::builder().build())
+            }
+
+            /// Returns the global pool if it has been initialized.
+            #[inline]
+            pub fn try_get() -> Option<&'static Self> {
+                $storage.get()
+            }
+
+            /// Returns the global pool.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the pool has not been initialized.
+            #[inline]
+            pub fn get() -> &'static Self {
+                Self::try_get().expect(concat!(
+                    "`",
+                    stringify!($name),
+                    "` has not been initialized, call `get_or_init` first"
+                ))
+            }
+        }
+
+        impl Deref for $name {
+            type Target = TaskPool;
+
+            #[inline]
+            fn deref(&self) -> &TaskPool {
+                &self.0
+            }
+        }
+    };
+}
+
+task_pool_preset! {
+    /// The global pool for CPU-bound work that must finish within the current frame.
+    ComputeTaskPool, COMPUTE_TASK_POOL
+}
+
+task_pool_preset! {
+    /// The global pool for blocking work, such as file or network access.
+    IoTaskPool, IO_TASK_POOL
+}
+
+impl ComputeTaskPool {
+    /// Returns the preset configuration, one thread per available core.
+    #[inline]
+    pub fn builder() -> TaskPoolBuilder {
+        TaskPoolBuilder::new().thread_name("Compute Task Pool")
+    }
+}
+
+impl IoTaskPool {
+    /// Returns the preset configuration, a quarter of the available cores (at least one).
+    #[inline]
+    pub fn builder() -> TaskPoolBuilder {
+        TaskPoolBuilder::new()
+            .thread_name("IO Task Pool")
+            .num_threads((crate::thread::available_parallelism() / 4).max(1))
+    }
+}
@@ -0,0 +1,144 @@
+//! Task pool for platforms without threads, every task runs inline on the calling thread.
+
+use alloc::vec::Vec;
+use core::{fmt, marker::PhantomData, mem};
+
+use super::TaskPoolBuilder;
+use crate::{
+    sync::{Mutex, PoisonError},
+    thread,
+};
+
+/// A task pool that runs every task inline on the calling thread.
+#[derive(Debug)]
+pub struct TaskPool {
+    _private: (),
+}
+
+impl TaskPool {
+    pub(super) fn from_builder(_builder: TaskPoolBuilder) -> Self {
+        Self { _private: () }
+    }
+
+    /// Returns the number of threads running tasks, always one.
+    #[inline]
+    pub fn thread_num(&self) -> usize {
+        1
+    }
+
+    /// Runs the task immediately on the calling thread.
+    pub fn spawn<F, T>(&self, f: F) -> Task<T>
+    where
+        F: FnOnce() -> T + Send + 'static,
+        T: Send + 'static,
+    {
+        Task(f())
+    }
+
+    /// Creates a scope whose tasks may borrow non-`'static` data, and returns
+    /// the task results in the order they were spawned.
+    pub fn scope<'env, F, T>(&self, f: F) -> Vec<T>
+    where
+        F: for<'scope> FnOnce(&'scope Scope<'scope, 'env, T>),
+        T: Send + 'static,
+    {
+        let scope = Scope {
+            results: Mutex::new(Vec::new()),
+            scope: PhantomData,
+            env: PhantomData,
+        };
+        f(&scope);
+        let mut results = scope.results.lock().unwrap_or_else(PoisonError::into_inner);
+        mem::take(&mut *results)
+            .into_iter()
+            .map(|value| value.expect("every scoped task has finished"))
+            .collect()
+    }
+}
+
+/// A handle to a task spawned with [`TaskPool::spawn`], which has already finished.
+#[derive(Debug)]
+pub struct Task<T>(T);
+
+impl<T> Task<T> {
+    /// Returns the result of the task.
+    #[inline]
+    pub fn join(self) -> thread::Result<T> {
+        Ok(self.0)
+    }
+
+    /// Checks if the task has finished, always `true`.
+    #[inline]
+    pub fn is_finished(&self) -> bool {
+        true
+    }
+}
+
+/// A scope to spawn tasks that borrow data in, see [`TaskPool::scope`].
+pub struct Scope<'scope, 'env: 'scope, T> {
+    results: Mutex<Vec<Option<T>>>,
+    scope: PhantomData<&'scope mut &'scope ()>,
+    env: PhantomData<&'env mut &'env ()>,
+}
+
+impl<'scope, 'env, T: Send + 'static> Scope<'scope, 'env, T> {
+    /// Runs the task immediately, its result is returned by [`TaskPool::scope`].
+    pub fn spawn<F>(&self, f: F)
+    where
+        F: FnOnce() -> T + Send + 'scope,
+    {
+        // Reserve the slot first, so nested spawns keep the spawn order.
+        let index = {
+            let mut results = self.results.lock().unwrap_or_else(PoisonError::into_inner);
+            results.push(None);
+            results.len() - 1
+        };
+        let value = f();
+        self.results.lock().unwrap_or_else(PoisonError::into_inner)[index] = Some(value);
+    }
+}
+
+impl<T> fmt::Debug for Scope<'_, '_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Scope").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::TaskPool;
+    use crate::task_pool::TaskPoolBuilder;
+
+    #[test]
+    fn inline_tasks() {
+        let pool = TaskPoolBuilder::new().num_threads(4).build();
+        assert_eq!(pool.thread_num(), 1);
+
+        let task = pool.spawn(|| 3);
+        assert!(task.is_finished());
+        assert_eq!(task.join().ok(), Some(3));
+
+        let results = pool.scope(|s| {
+            s.spawn(|| 1);
+            s.spawn(|| {
+                TaskPool::new()
+                    .scope(|s| {
+                        s.spawn(|| 2);
+                        s.spawn(|| 3);
+                    })
+                    .iter()
+                    .sum()
+            });
+            s.spawn(|| 4);
+        });
+        assert_eq!(results, [1, 5, 4]);
+
+        let items = (0..5u32).collect::<Vec<_>>();
+        assert_eq!(
+            pool.map_batched(&items, 2, |item| item + 1),
+            [1, 2, 3, 4, 5]
+        );
+    }
+}
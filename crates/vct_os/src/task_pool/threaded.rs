@@ -0,0 +1,414 @@
+//! Task pool backed by OS threads.
+
+#![expect(
+    unsafe_code,
+    reason = "Scoped tasks erase their lifetime to run on the shared worker threads."
+)]
+
+use alloc::{boxed::Box, collections::VecDeque, format, sync::Arc, vec::Vec};
+use core::{any::Any, fmt, marker::PhantomData, mem, panic::AssertUnwindSafe, time::Duration};
+use std::{
+    panic,
+    sync::{Condvar, Mutex, MutexGuard, PoisonError},
+};
+
+use super::TaskPoolBuilder;
+use crate::thread::{self, Builder, JoinHandle};
+
+type Job = Box<dyn FnOnce() + Send + 'static>;
+
+/// How long a waiting thread sleeps before checking the queue for work to help with.
+const HELP_INTERVAL: Duration = Duration::from_millis(1);
+
+/// Jobs never panic while holding a lock, so poisoning can be ignored.
+#[inline]
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+struct Queue {
+    jobs: VecDeque<Job>,
+    shutdown: bool,
+}
+
+struct Shared {
+    queue: Mutex<Queue>,
+    ready: Condvar,
+}
+
+impl Shared {
+    fn push(&self, job: Job) {
+        lock(&self.queue).jobs.push_back(job);
+        self.ready.notify_one();
+    }
+
+    fn try_pop(&self) -> Option<Job> {
+        lock(&self.queue).jobs.pop_front()
+    }
+
+    fn run_worker(&self) {
+        let mut queue = lock(&self.queue);
+        loop {
+            if let Some(job) = queue.jobs.pop_front() {
+                drop(queue);
+                job();
+                queue = lock(&self.queue);
+            } else if queue.shutdown {
+                return;
+            } else {
+                queue = self
+                    .ready
+                    .wait(queue)
+                    .unwrap_or_else(PoisonError::into_inner);
+            }
+        }
+    }
+
+    /// Runs queued jobs on the current thread until `done` returns `Some`,
+    /// so waiting on a task never blocks the work it depends on.
+    fn help_until<S, R>(
+        &self,
+        state: &Mutex<S>,
+        changed: &Condvar,
+        mut done: impl FnMut(&mut S) -> Option<R>,
+    ) -> R {
+        loop {
+            if let Some(result) = done(&mut lock(state)) {
+                return result;
+            }
+            if let Some(job) = self.try_pop() {
+                job();
+                continue;
+            }
+            let (mut guard, _) = changed
+                .wait_timeout(lock(state), HELP_INTERVAL)
+                .unwrap_or_else(PoisonError::into_inner);
+            if let Some(result) = done(&mut guard) {
+                return result;
+            }
+        }
+    }
+}
+
+/// A pool of worker threads that run spawned tasks.
+///
+/// Dropping the pool finishes the queued tasks, then joins the worker threads.
+pub struct TaskPool {
+    shared: Arc<Shared>,
+    threads: Vec<JoinHandle<()>>,
+}
+
+impl TaskPool {
+    pub(super) fn from_builder(builder: TaskPoolBuilder) -> Self {
+        let shared = Arc::new(Shared {
+            queue: Mutex::new(Queue {
+                jobs: VecDeque::new(),
+                shutdown: false,
+            }),
+            ready: Condvar::new(),
+        });
+
+        let num_threads = builder
+            .num_threads
+            .unwrap_or_else(thread::available_parallelism)
+            .max(1);
+        let threads = (0..num_threads)
+            .map(|index| {
+                let name = match &builder.thread_name {
+                    Some(name) => format!("{name} ({index})"),
+                    None => format!("TaskPool ({index})"),
+                };
+                let mut thread = Builder::new().name(name);
+                if let Some(stack_size) = builder.stack_size {
+                    thread = thread.stack_size(stack_size);
+                }
+                let shared = shared.clone();
+                thread
+                    .spawn(move || shared.run_worker())
+                    .expect("failed to spawn a task pool thread")
+            })
+            .collect();
+
+        Self { shared, threads }
+    }
+
+    /// Returns the number of worker threads.
+    #[inline]
+    pub fn thread_num(&self) -> usize {
+        self.threads.len()
+    }
+
+    /// Spawns a task that runs on one of the worker threads.
+    ///
+    /// The task keeps running if the returned [`Task`] is dropped.
+    pub fn spawn<F, T>(&self, f: F) -> Task<T>
+    where
+        F: FnOnce() -> T + Send + 'static,
+        T: Send + 'static,
+    {
+        let slot = Arc::new(Slot {
+            value: Mutex::new(None),
+            done: Condvar::new(),
+        });
+        let task_slot = slot.clone();
+        self.shared.push(Box::new(move || {
+            let value = panic::catch_unwind(AssertUnwindSafe(f));
+            *lock(&task_slot.value) = Some(value);
+            task_slot.done.notify_all();
+        }));
+        Task {
+            slot,
+            shared: self.shared.clone(),
+        }
+    }
+
+    /// Creates a scope whose tasks may borrow non-`'static` data, and returns
+    /// the task results in the order they were spawned.
+    ///
+    /// Every task spawned in the scope finishes before this function returns.
+    ///
+    /// # Panics
+    ///
+    /// Resumes the panic of `f` or of the first panicking task, after all tasks have finished.
+    pub fn scope<'env, F, T>(&self, f: F) -> Vec<T>
+    where
+        F: for<'scope> FnOnce(&'scope Scope<'scope, 'env, T>),
+        T: Send + 'static,
+    {
+        let scope = Scope {
+            shared: &self.shared,
+            state: Arc::new(ScopeState {
+                results: Mutex::new(Vec::new()),
+                pending: Mutex::new(0),
+                done: Condvar::new(),
+                panic: Mutex::new(None),
+            }),
+            scope: PhantomData,
+            env: PhantomData,
+        };
+
+        let result = panic::catch_unwind(AssertUnwindSafe(|| f(&scope)));
+        self.shared
+            .help_until(&scope.state.pending, &scope.state.done, |pending| {
+                (*pending == 0).then_some(())
+            });
+
+        if let Err(payload) = result {
+            panic::resume_unwind(payload);
+        }
+        if let Some(payload) = lock(&scope.state.panic).take() {
+            panic::resume_unwind(payload);
+        }
+        mem::take(&mut *lock(&scope.state.results))
+            .into_iter()
+            .map(|value| value.expect("every scoped task has finished"))
+            .collect()
+    }
+}
+
+impl Drop for TaskPool {
+    fn drop(&mut self) {
+        lock(&self.shared.queue).shutdown = true;
+        self.shared.ready.notify_all();
+        for thread in self.threads.drain(..) {
+            // Jobs catch their own panics, so the workers never panic.
+            let _ = thread.join();
+        }
+    }
+}
+
+impl fmt::Debug for TaskPool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TaskPool")
+            .field("thread_num", &self.thread_num())
+            .finish_non_exhaustive()
+    }
+}
+
+struct Slot<T> {
+    value: Mutex<Option<thread::Result<T>>>,
+    done: Condvar,
+}
+
+/// A handle to a task spawned with [`TaskPool::spawn`].
+pub struct Task<T> {
+    slot: Arc<Slot<T>>,
+    shared: Arc<Shared>,
+}
+
+impl<T> Task<T> {
+    /// Waits for the task to finish, running other queued tasks meanwhile.
+    ///
+    /// The error holds the panic payload if the task panicked.
+    pub fn join(self) -> thread::Result<T> {
+        self.shared
+            .help_until(&self.slot.value, &self.slot.done, Option::take)
+    }
+
+    /// Checks if the task has finished.
+    #[inline]
+    pub fn is_finished(&self) -> bool {
+        lock(&self.slot.value).is_some()
+    }
+}
+
+impl<T> fmt::Debug for Task<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Task")
+            .field("finished", &self.is_finished())
+            .finish()
+    }
+}
+
+struct ScopeState<T> {
+    results: Mutex<Vec<Option<T>>>,
+    pending: Mutex<usize>,
+    done: Condvar,
+    panic: Mutex<Option<Box<dyn Any + Send>>>,
+}
+
+/// A scope to spawn tasks that borrow data in, see [`TaskPool::scope`].
+pub struct Scope<'scope, 'env: 'scope, T> {
+    shared: &'scope Shared,
+    state: Arc<ScopeState<T>>,
+    scope: PhantomData<&'scope mut &'scope ()>,
+    env: PhantomData<&'env mut &'env ()>,
+}
+
+impl<'scope, 'env, T: Send + 'static> Scope<'scope, 'env, T> {
+    /// Spawns a task whose result is returned by [`TaskPool::scope`].
+    pub fn spawn<F>(&self, f: F)
+    where
+        F: FnOnce() -> T + Send + 'scope,
+    {
+        let index = {
+            let mut results = lock(&self.state.results);
+            results.push(None);
+            results.len() - 1
+        };
+        *lock(&self.state.pending) += 1;
+
+        let state = self.state.clone();
+        let job: Box<dyn FnOnce() + Send + 'scope> = Box::new(move || {
+            match panic::catch_unwind(AssertUnwindSafe(f)) {
+                Ok(value) => lock(&state.results)[index] = Some(value),
+                Err(payload) => {
+                    lock(&state.panic).get_or_insert(payload);
+                }
+            }
+            let mut pending = lock(&state.pending);
+            *pending -= 1;
+            if *pending == 0 {
+                state.done.notify_all();
+            }
+        });
+        // SAFETY: `TaskPool::scope` waits until every job spawned in the scope
+        // has run, so nothing borrowed for `'scope` is used after it ends.
+        let job = unsafe { mem::transmute::<Box<dyn FnOnce() + Send + 'scope>, Job>(job) };
+        self.shared.push(job);
+    }
+}
+
+impl<T> fmt::Debug for Scope<'_, '_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Scope").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use core::{
+        sync::atomic::{AtomicBool, AtomicUsize, Ordering},
+        time::Duration,
+    };
+    use std::{panic, sync::Mutex};
+
+    use super::TaskPool;
+    use crate::{task_pool::TaskPoolBuilder, thread};
+
+    fn pool(num_threads: usize) -> TaskPool {
+        TaskPoolBuilder::new().num_threads(num_threads).build()
+    }
+
+    #[test]
+    fn scope_returns_results_in_spawn_order() {
+        let pool = pool(4);
+        let results = pool.scope(|s| {
+            for index in 0..8u64 {
+                // Later tasks finish first.
+                s.spawn(move || {
+                    thread::sleep(Duration::from_millis(8 - index));
+                    index
+                });
+            }
+        });
+        assert_eq!(results, (0..8).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn nested_scope_in_worker_task() {
+        // A single worker must help with the inner tasks instead of blocking on them.
+        let pool = pool(1);
+        let results = pool.scope(|s| {
+            for outer in 0..2usize {
+                let pool = &pool;
+                s.spawn(move || {
+                    pool.scope(|s| {
+                        for inner in 0..3usize {
+                            s.spawn(move || outer * 10 + inner);
+                        }
+                    })
+                });
+            }
+        });
+        assert_eq!(results, [[0, 1, 2], [10, 11, 12]]);
+    }
+
+    #[test]
+    fn scope_panic_waits_for_siblings() {
+        let pool = pool(2);
+        let sibling_done = AtomicBool::new(false);
+        let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+            pool.scope(|s| {
+                s.spawn(|| panic!("scoped task"));
+                s.spawn(|| {
+                    thread::sleep(Duration::from_millis(20));
+                    sibling_done.store(true, Ordering::Release);
+                });
+            });
+        }));
+        assert!(result.is_err());
+        assert!(sibling_done.load(Ordering::Acquire));
+    }
+
+    #[test]
+    fn join_returns_panic() {
+        let pool = pool(1);
+        assert!(pool.spawn(|| panic!("task")).join().is_err());
+        assert_eq!(pool.spawn(|| 7).join().ok(), Some(7));
+    }
+
+    #[test]
+    fn batched_ordering() {
+        let pool = pool(3);
+        let items = (0..10u32).collect::<Vec<_>>();
+
+        let doubled = pool.map_batched(&items, 3, |item| item * 2);
+        assert_eq!(
+            doubled,
+            items.iter().map(|item| item * 2).collect::<Vec<_>>()
+        );
+
+        let batches = Mutex::new(Vec::new());
+        let calls = AtomicUsize::new(0);
+        pool.for_each_batched(&items, 4, |batch| {
+            calls.fetch_add(1, Ordering::Relaxed);
+            batches.lock().unwrap().push(batch.to_vec());
+        });
+        let mut batches = batches.into_inner().unwrap();
+        batches.sort();
+        assert_eq!(calls.into_inner(), 3);
+        assert_eq!(batches, [vec![0, 1, 2, 3], vec![4, 5, 6, 7], vec![8, 9]]);
+    }
+}
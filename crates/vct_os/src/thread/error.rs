@@ -0,0 +1,32 @@
+use core::{error::Error, fmt};
+
+/// The error returned when a thread could not be spawned.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum SpawnError {
+    /// The platform cannot spawn threads.
+    Unsupported,
+    /// The OS failed to create the thread.
+    #[cfg(feature = "std")]
+    Io(std::io::Error),
+}
+
+impl fmt::Display for SpawnError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unsupported => f.write_str("threads are not supported on this platform"),
+            #[cfg(feature = "std")]
+            Self::Io(err) => write!(f, "failed to spawn thread: {err}"),
+        }
+    }
+}
+
+impl Error for SpawnError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Unsupported => None,
+            #[cfg(feature = "std")]
+            Self::Io(err) => Some(err),
+        }
+    }
+}
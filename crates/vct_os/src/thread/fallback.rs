@@ -0,0 +1,125 @@
+//! Fallback thread functions for platforms without `std`.
+
+use core::{fmt, hint::spin_loop, time::Duration};
+
+use crate::{sync::OnceLock, time::Instant};
+
+/// Puts the current thread to sleep for at least the specified amount of time.
+///
+/// Without an OS scheduler, this spins until the duration has elapsed.
+pub fn sleep(dur: Duration) {
+    let start = Instant::now();
+    while start.elapsed() < dur {
+        spin_loop();
+    }
+}
+
+/// Declares statics that `std` would make thread-local.
+///
+/// Without `std` there is no thread-local storage, so the statics are shared
+/// by every thread and their type must be `Sync`.
+#[macro_export]
+macro_rules! thread_local_cell {
+    ($($(#[$attr:meta])* $vis:vis static $name:ident: $ty:ty = $init:expr;)+) => {
+        $(
+            $(#[$attr])*
+            $vis static $name: $crate::thread::LocalKey<$ty> = $crate::thread::LocalKey::new(|| $init);
+        )+
+    };
+}
+
+/// A lazily-initialized static declared by [`thread_local_cell!`](crate::thread_local_cell),
+/// shared by every thread on this platform.
+pub struct LocalKey<T: 'static> {
+    value: OnceLock<T>,
+    init: fn() -> T,
+}
+
+impl<T: 'static> LocalKey<T> {
+    #[doc(hidden)]
+    #[inline]
+    pub const fn new(init: fn() -> T) -> Self {
+        Self {
+            value: OnceLock::new(),
+            init,
+        }
+    }
+
+    /// Acquires a reference to the value, initializing it on first access.
+    #[inline]
+    pub fn with<F, R>(&'static self, f: F) -> R
+    where
+        F: FnOnce(&T) -> R,
+    {
+        f(self.value.get_or_init(self.init))
+    }
+}
+
+impl<T: 'static> fmt::Debug for LocalKey<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LocalKey").finish_non_exhaustive()
+    }
+}
+
+/// A container with one lazily-initialized value, shared by every thread on this platform.
+pub struct ThreadLocal<T> {
+    value: OnceLock<T>,
+}
+
+impl<T> ThreadLocal<T> {
+    /// Creates an empty container.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            value: OnceLock::new(),
+        }
+    }
+
+    /// Returns the value, if it has been initialized.
+    #[inline]
+    pub fn get(&self) -> Option<&T> {
+        self.value.get()
+    }
+
+    /// Returns the value, initializing it with `create` if needed.
+    #[inline]
+    pub fn get_or(&self, create: impl FnOnce() -> T) -> &T {
+        self.value.get_or_init(create)
+    }
+
+    /// Returns the value, initializing it with `T::default()` if needed.
+    #[inline]
+    pub fn get_or_default(&self) -> &T
+    where
+        T: Default,
+    {
+        self.get_or(T::default)
+    }
+
+    /// Returns an iterator over the value, if it has been initialized.
+    #[inline]
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.value.get_mut().into_iter()
+    }
+
+    /// Removes the value.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.value = OnceLock::new();
+    }
+}
+
+impl<T> Default for ThreadLocal<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for ThreadLocal<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ThreadLocal")
+            .field("local", &self.get())
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,53 @@
+use crate::sync::OnceLock;
+
+/// Scheduling priority hint for a thread.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ThreadPriority {
+    /// Background work that may be starved by other threads.
+    Low,
+    /// The default priority.
+    #[default]
+    Normal,
+    /// Latency-sensitive work, such as audio or the main loop.
+    High,
+}
+
+/// Platform callbacks behind [`set_affinity`] and [`set_priority`].
+///
+/// `std` has no portable API for either, so applications that need them install
+/// hooks backed by their platform crate of choice through [`set_thread_hooks`].
+#[derive(Debug, Clone, Copy)]
+pub struct ThreadHooks {
+    /// Pins the current thread to the given core indices, returns `true` on success.
+    pub set_affinity: fn(cores: &[usize]) -> bool,
+    /// Sets the priority of the current thread, returns `true` on success.
+    pub set_priority: fn(priority: ThreadPriority) -> bool,
+}
+
+static HOOKS: OnceLock<ThreadHooks> = OnceLock::new();
+
+/// Installs the thread hooks, can only be set once.
+///
+/// Returns `false` if hooks were already installed.
+#[inline]
+pub fn set_thread_hooks(hooks: ThreadHooks) -> bool {
+    HOOKS.set(hooks).is_ok()
+}
+
+/// Pins the current thread to the given core indices, if the platform supports it.
+///
+/// Best-effort: returns `false` when no hook is installed or the hook failed.
+#[inline]
+pub fn set_affinity(cores: &[usize]) -> bool {
+    HOOKS.get().is_some_and(|hooks| (hooks.set_affinity)(cores))
+}
+
+/// Sets the priority of the current thread, if the platform supports it.
+///
+/// Best-effort: returns `false` when no hook is installed or the hook failed.
+#[inline]
+pub fn set_priority(priority: ThreadPriority) -> bool {
+    HOOKS
+        .get()
+        .is_some_and(|hooks| (hooks.set_priority)(priority))
+}
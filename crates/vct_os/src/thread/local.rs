@@ -0,0 +1,136 @@
+//! Thread-local storage backed by `std`.
+
+#![expect(
+    unsafe_code,
+    reason = "`ThreadLocal` hands out references into boxed slots that outlive the lock guard."
+)]
+
+use alloc::{boxed::Box, vec::Vec};
+use core::fmt;
+use std::{
+    sync::{Mutex, MutexGuard, PoisonError},
+    thread::{self, ThreadId},
+};
+
+pub use std::thread::LocalKey;
+
+/// Declares thread-local statics, forwards to `std::thread_local!`.
+///
+/// Without `std`, the statics are shared by every thread instead, so their
+/// type must be `Sync` to compile on such platforms.
+///
+/// # Example
+///
+/// ```
+/// use core::cell::Cell;
+///
+/// vct_os::thread_local_cell! {
+///     static COUNTER: Cell<u32> = Cell::new(0);
+/// }
+///
+/// COUNTER.with(|counter| counter.set(counter.get() + 1));
+/// assert_eq!(COUNTER.with(Cell::get), 1);
+/// ```
+#[macro_export]
+macro_rules! thread_local_cell {
+    ($($(#[$attr:meta])* $vis:vis static $name:ident: $ty:ty = $init:expr;)+) => {
+        $crate::exports::thread_local! {
+            $($(#[$attr])* $vis static $name: $ty = $init;)+
+        }
+    };
+}
+
+/// A container with one lazily-initialized value per thread that accesses it.
+///
+/// Unlike a `thread_local!` static, it can be stored in a struct, and all
+/// values can be visited through [`iter_mut`](ThreadLocal::iter_mut).
+///
+/// Lookups scan the slots linearly, which is fast for the few threads of a task pool.
+pub struct ThreadLocal<T> {
+    slots: Mutex<Vec<(ThreadId, Box<T>)>>,
+}
+
+// SAFETY: Each thread only gets a shared reference to its own slot, so `T`
+// does not need to be `Sync`; `T: Send` covers `iter_mut` and dropping.
+unsafe impl<T: Send> Sync for ThreadLocal<T> {}
+
+impl<T> ThreadLocal<T> {
+    /// Creates an empty container.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            slots: Mutex::new(Vec::new()),
+        }
+    }
+
+    #[inline]
+    fn lock(&self) -> MutexGuard<'_, Vec<(ThreadId, Box<T>)>> {
+        self.slots.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Returns the value of the current thread, if it has been initialized.
+    pub fn get(&self) -> Option<&T> {
+        let id = thread::current().id();
+        let slots = self.lock();
+        let value: *const T = &*slots.iter().find(|(slot, _)| *slot == id)?.1;
+        // SAFETY: Slots are boxed and only removed through `&mut self`, so the
+        // value outlives `&self` even after the guard is dropped.
+        Some(unsafe { &*value })
+    }
+
+    /// Returns the value of the current thread, initializing it with `create` if needed.
+    pub fn get_or(&self, create: impl FnOnce() -> T) -> &T {
+        if let Some(value) = self.get() {
+            return value;
+        }
+        // `create` runs without the lock, so it may access this container itself.
+        let boxed = Box::new(create());
+        let value: *const T = &*boxed;
+        self.lock().push((thread::current().id(), boxed));
+        // SAFETY: See `get`, moving the `Box` does not move its contents.
+        unsafe { &*value }
+    }
+
+    /// Returns the value of the current thread, initializing it with `T::default()` if needed.
+    #[inline]
+    pub fn get_or_default(&self) -> &T
+    where
+        T: Default,
+    {
+        self.get_or(T::default)
+    }
+
+    /// Returns an iterator over the values of every thread.
+    #[inline]
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.slots
+            .get_mut()
+            .unwrap_or_else(PoisonError::into_inner)
+            .iter_mut()
+            .map(|(_, value)| &mut **value)
+    }
+
+    /// Removes the values of every thread.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.slots
+            .get_mut()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clear();
+    }
+}
+
+impl<T> Default for ThreadLocal<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for ThreadLocal<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ThreadLocal")
+            .field("local", &self.get())
+            .finish_non_exhaustive()
+    }
+}
@@ -1,8 +1,58 @@
+//! Thread functions that share one call path on every platform.
+//!
+//! Spawning returns a [`SpawnError`] instead of panicking, so callers can fall
+//! back to running work inline on platforms without threads (web, `no_std`).
+
+mod error;
+mod hooks;
+
+pub use error::SpawnError;
+pub use hooks::{ThreadHooks, ThreadPriority, set_affinity, set_priority, set_thread_hooks};
 pub use implementation::sleep;
+pub use local_impl::{LocalKey, ThreadLocal};
+pub use spawn_impl::{
+    Builder, JoinHandle, Scope, ScopedJoinHandle, available_parallelism, current_name, scope,
+};
+
+use alloc::boxed::Box;
+use core::any::Any;
+
+/// The result of joining a thread, the error holds the panic payload.
+pub type Result<T> = core::result::Result<T, Box<dyn Any + Send + 'static>>;
+
+/// Spawns a new thread with the default [`Builder`] configuration.
+///
+/// Returns [`SpawnError::Unsupported`] on platforms without threads.
+#[inline]
+pub fn spawn<F, T>(f: F) -> core::result::Result<JoinHandle<T>, SpawnError>
+where
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static,
+{
+    Builder::new().spawn(f)
+}
 
 crate::cfg::switch! {
+    crate::cfg::web => {
+        use std::thread as implementation;
+        mod local;
+        use local as local_impl;
+        mod unsupported;
+        use unsupported as spawn_impl;
+    }
     crate::cfg::std => {
         use std::thread as implementation;
+        mod local;
+        use local as local_impl;
+        mod native;
+        use native as spawn_impl;
+    }
+    crate::cfg::no_std => {
+        mod fallback;
+        use fallback as implementation;
+        use fallback as local_impl;
+        mod unsupported;
+        use unsupported as spawn_impl;
     }
     _ => {
         compile_error!("This platform is not supported");
@@ -0,0 +1,141 @@
+//! Thread spawning backed by `std::thread`.
+
+use alloc::string::String;
+use core::num::NonZero;
+use std::thread;
+
+use super::{Result, SpawnError};
+
+/// Returns an estimate of the number of threads that can run in parallel, at least one.
+#[inline]
+pub fn available_parallelism() -> usize {
+    thread::available_parallelism().map_or(1, NonZero::get)
+}
+
+/// Returns the name of the current thread, if it has one.
+#[inline]
+pub fn current_name() -> Option<String> {
+    thread::current().name().map(String::from)
+}
+
+/// Thread factory, which can be used to configure the properties of a new thread.
+#[derive(Debug)]
+pub struct Builder(thread::Builder);
+
+impl Builder {
+    /// Generates the base configuration for spawning a thread.
+    #[inline]
+    pub fn new() -> Self {
+        Self(thread::Builder::new())
+    }
+
+    /// Names the thread-to-be.
+    #[inline]
+    pub fn name(self, name: String) -> Self {
+        Self(self.0.name(name))
+    }
+
+    /// Sets the size of the stack (in bytes) for the new thread.
+    #[inline]
+    pub fn stack_size(self, size: usize) -> Self {
+        Self(self.0.stack_size(size))
+    }
+
+    /// Spawns a new thread by taking ownership of the `Builder`.
+    #[inline]
+    pub fn spawn<F, T>(self, f: F) -> core::result::Result<JoinHandle<T>, SpawnError>
+    where
+        F: FnOnce() -> T + Send + 'static,
+        T: Send + 'static,
+    {
+        self.0.spawn(f).map(JoinHandle).map_err(SpawnError::Io)
+    }
+
+    /// Spawns a new scoped thread using the settings set through this `Builder`.
+    #[inline]
+    pub fn spawn_scoped<'scope, 'env, F, T>(
+        self,
+        scope: &Scope<'scope, 'env>,
+        f: F,
+    ) -> core::result::Result<ScopedJoinHandle<'scope, T>, SpawnError>
+    where
+        F: FnOnce() -> T + Send + 'scope,
+        T: Send + 'scope,
+    {
+        self.0
+            .spawn_scoped(scope.0, f)
+            .map(ScopedJoinHandle)
+            .map_err(SpawnError::Io)
+    }
+}
+
+impl Default for Builder {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// An owned permission to join on a thread (block on its termination).
+#[derive(Debug)]
+pub struct JoinHandle<T>(thread::JoinHandle<T>);
+
+impl<T> JoinHandle<T> {
+    /// Waits for the associated thread to finish.
+    #[inline]
+    pub fn join(self) -> Result<T> {
+        self.0.join()
+    }
+
+    /// Checks if the associated thread has finished running its main function.
+    #[inline]
+    pub fn is_finished(&self) -> bool {
+        self.0.is_finished()
+    }
+}
+
+/// A scope to spawn scoped threads in, see [`scope`].
+#[derive(Debug)]
+pub struct Scope<'scope, 'env: 'scope>(&'scope thread::Scope<'scope, 'env>);
+
+impl<'scope, 'env> Scope<'scope, 'env> {
+    /// Spawns a new thread within a scope, returning a [`ScopedJoinHandle`] for it.
+    #[inline]
+    pub fn spawn<F, T>(&self, f: F) -> core::result::Result<ScopedJoinHandle<'scope, T>, SpawnError>
+    where
+        F: FnOnce() -> T + Send + 'scope,
+        T: Send + 'scope,
+    {
+        Builder::new().spawn_scoped(self, f)
+    }
+}
+
+/// An owned permission to join on a scoped thread (block on its termination).
+#[derive(Debug)]
+pub struct ScopedJoinHandle<'scope, T>(thread::ScopedJoinHandle<'scope, T>);
+
+impl<T> ScopedJoinHandle<'_, T> {
+    /// Waits for the associated thread to finish.
+    #[inline]
+    pub fn join(self) -> Result<T> {
+        self.0.join()
+    }
+
+    /// Checks if the associated thread has finished running its main function.
+    #[inline]
+    pub fn is_finished(&self) -> bool {
+        self.0.is_finished()
+    }
+}
+
+/// Creates a scope for spawning scoped threads.
+///
+/// All threads spawned within the scope that haven't been manually joined
+/// are automatically joined before this function returns.
+#[inline]
+pub fn scope<'env, F, T>(f: F) -> T
+where
+    F: for<'scope> FnOnce(&Scope<'scope, 'env>) -> T,
+{
+    thread::scope(|s| f(&Scope(s)))
+}
@@ -0,0 +1,150 @@
+//! Thread spawning for platforms without threads, every spawn fails with
+//! [`SpawnError::Unsupported`].
+
+use alloc::string::String;
+use core::{convert::Infallible, fmt, marker::PhantomData};
+
+use super::{Result, SpawnError};
+
+/// Returns the number of threads that can run in parallel, always one.
+#[inline]
+pub fn available_parallelism() -> usize {
+    1
+}
+
+/// Returns the name of the current thread, always `None` on this platform.
+#[inline]
+pub fn current_name() -> Option<String> {
+    None
+}
+
+/// Thread factory, which can be used to configure the properties of a new thread.
+///
+/// The settings are ignored, spawning always fails on this platform.
+#[derive(Debug, Default)]
+pub struct Builder {
+    _private: (),
+}
+
+impl Builder {
+    /// Generates the base configuration for spawning a thread.
+    #[inline]
+    pub fn new() -> Self {
+        Self { _private: () }
+    }
+
+    /// Names the thread-to-be.
+    #[inline]
+    pub fn name(self, _name: String) -> Self {
+        self
+    }
+
+    /// Sets the size of the stack (in bytes) for the new thread.
+    #[inline]
+    pub fn stack_size(self, _size: usize) -> Self {
+        self
+    }
+
+    /// Always returns [`SpawnError::Unsupported`].
+    #[inline]
+    pub fn spawn<F, T>(self, _f: F) -> core::result::Result<JoinHandle<T>, SpawnError>
+    where
+        F: FnOnce() -> T + Send + 'static,
+        T: Send + 'static,
+    {
+        Err(SpawnError::Unsupported)
+    }
+
+    /// Always returns [`SpawnError::Unsupported`].
+    #[inline]
+    pub fn spawn_scoped<'scope, 'env, F, T>(
+        self,
+        _scope: &Scope<'scope, 'env>,
+        _f: F,
+    ) -> core::result::Result<ScopedJoinHandle<'scope, T>, SpawnError>
+    where
+        F: FnOnce() -> T + Send + 'scope,
+        T: Send + 'scope,
+    {
+        Err(SpawnError::Unsupported)
+    }
+}
+
+/// An owned permission to join on a thread, can never be created on this platform.
+pub struct JoinHandle<T>(Infallible, PhantomData<T>);
+
+impl<T> JoinHandle<T> {
+    /// Waits for the associated thread to finish.
+    #[inline]
+    pub fn join(self) -> Result<T> {
+        match self.0 {}
+    }
+
+    /// Checks if the associated thread has finished running its main function.
+    #[inline]
+    pub fn is_finished(&self) -> bool {
+        match self.0 {}
+    }
+}
+
+impl<T> fmt::Debug for JoinHandle<T> {
+    fn fmt(&self, _f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {}
+    }
+}
+
+/// A scope to spawn scoped threads in, see [`scope`].
+#[derive(Debug)]
+pub struct Scope<'scope, 'env: 'scope> {
+    scope: PhantomData<&'scope mut &'scope ()>,
+    env: PhantomData<&'env mut &'env ()>,
+}
+
+impl<'scope, 'env> Scope<'scope, 'env> {
+    /// Always returns [`SpawnError::Unsupported`].
+    #[inline]
+    pub fn spawn<F, T>(&self, f: F) -> core::result::Result<ScopedJoinHandle<'scope, T>, SpawnError>
+    where
+        F: FnOnce() -> T + Send + 'scope,
+        T: Send + 'scope,
+    {
+        Builder::new().spawn_scoped(self, f)
+    }
+}
+
+/// An owned permission to join on a scoped thread, can never be created on this platform.
+pub struct ScopedJoinHandle<'scope, T>(Infallible, PhantomData<&'scope T>);
+
+impl<T> ScopedJoinHandle<'_, T> {
+    /// Waits for the associated thread to finish.
+    #[inline]
+    pub fn join(self) -> Result<T> {
+        match self.0 {}
+    }
+
+    /// Checks if the associated thread has finished running its main function.
+    #[inline]
+    pub fn is_finished(&self) -> bool {
+        match self.0 {}
+    }
+}
+
+impl<T> fmt::Debug for ScopedJoinHandle<'_, T> {
+    fn fmt(&self, _f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {}
+    }
+}
+
+/// Creates a scope for spawning scoped threads.
+///
+/// The closure runs on the current thread, and every spawn inside it fails.
+#[inline]
+pub fn scope<'env, F, T>(f: F) -> T
+where
+    F: for<'scope> FnOnce(&Scope<'scope, 'env>) -> T,
+{
+    f(&Scope {
+        scope: PhantomData,
+        env: PhantomData,
+    })
+}
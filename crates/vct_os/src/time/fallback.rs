@@ -0,0 +1,260 @@
+//! Fallback [`Instant`] and [`SystemTime`] for platforms without `std`.
+//!
+//! There is no portable clock without an OS, so the platform must provide one
+//! through [`Instant::set_elapsed`] before the first call to [`Instant::now`],
+//! and through [`SystemTime::set_unix_time`] before the first call to [`SystemTime::now`].
+
+use core::{
+    error::Error,
+    fmt,
+    ops::{Add, AddAssign, Sub, SubAssign},
+    time::Duration,
+};
+
+use crate::sync::OnceLock;
+
+static ELAPSED_GETTER: OnceLock<fn() -> Duration> = OnceLock::new();
+static UNIX_TIME_GETTER: OnceLock<fn() -> Duration> = OnceLock::new();
+
+/// A measurement of a monotonically nondecreasing clock, mirrors `std::time::Instant`.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Instant(Duration);
+
+impl Instant {
+    /// The fixed point that [`Instant::set_elapsed`] measures from, does not read the clock.
+    pub const ZERO: Instant = Instant(Duration::ZERO);
+
+    /// Sets the function that returns the time elapsed since an arbitrary, fixed point.
+    ///
+    /// Can only be set once, returns `false` if a getter was already set.
+    #[inline]
+    pub fn set_elapsed(getter: fn() -> Duration) -> bool {
+        ELAPSED_GETTER.set(getter).is_ok()
+    }
+
+    /// Returns an instant corresponding to "now".
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`Instant::set_elapsed`] has not been called.
+    #[inline]
+    pub fn now() -> Self {
+        let getter = ELAPSED_GETTER
+            .get()
+            .expect("call `Instant::set_elapsed` before using `Instant` without `std`");
+        Self(getter())
+    }
+
+    /// Returns the amount of time elapsed from another instant to this one,
+    /// or zero duration if that instant is later than this one.
+    #[inline]
+    pub fn duration_since(&self, earlier: Self) -> Duration {
+        self.saturating_duration_since(earlier)
+    }
+
+    /// Returns the amount of time elapsed from another instant to this one,
+    /// or `None` if that instant is later than this one.
+    #[inline]
+    pub fn checked_duration_since(&self, earlier: Self) -> Option<Duration> {
+        self.0.checked_sub(earlier.0)
+    }
+
+    /// Returns the amount of time elapsed from another instant to this one,
+    /// or zero duration if that instant is later than this one.
+    #[inline]
+    pub fn saturating_duration_since(&self, earlier: Self) -> Duration {
+        self.0.saturating_sub(earlier.0)
+    }
+
+    /// Returns the amount of time elapsed since this instant.
+    #[inline]
+    pub fn elapsed(&self) -> Duration {
+        Self::now().saturating_duration_since(*self)
+    }
+
+    /// Returns `Some(t)` where `t` is the time `self + duration` if it can be represented.
+    #[inline]
+    pub fn checked_add(&self, duration: Duration) -> Option<Self> {
+        self.0.checked_add(duration).map(Self)
+    }
+
+    /// Returns `Some(t)` where `t` is the time `self - duration` if it can be represented.
+    #[inline]
+    pub fn checked_sub(&self, duration: Duration) -> Option<Self> {
+        self.0.checked_sub(duration).map(Self)
+    }
+}
+
+impl Add<Duration> for Instant {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, other: Duration) -> Self {
+        self.checked_add(other)
+            .expect("overflow when adding duration to instant")
+    }
+}
+
+impl AddAssign<Duration> for Instant {
+    #[inline]
+    fn add_assign(&mut self, other: Duration) {
+        *self = *self + other;
+    }
+}
+
+impl Sub<Duration> for Instant {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, other: Duration) -> Self {
+        self.checked_sub(other)
+            .expect("overflow when subtracting duration from instant")
+    }
+}
+
+impl SubAssign<Duration> for Instant {
+    #[inline]
+    fn sub_assign(&mut self, other: Duration) {
+        *self = *self - other;
+    }
+}
+
+impl Sub<Instant> for Instant {
+    type Output = Duration;
+
+    #[inline]
+    fn sub(self, other: Instant) -> Duration {
+        self.duration_since(other)
+    }
+}
+
+impl fmt::Debug for Instant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// An anchor in time, representing 1970-01-01 00:00:00 UTC.
+pub const UNIX_EPOCH: SystemTime = SystemTime(Duration::ZERO);
+
+/// A measurement of the system clock, mirrors `std::time::SystemTime`.
+///
+/// Unlike the `std` version, times before [`UNIX_EPOCH`] cannot be represented.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SystemTime(Duration);
+
+impl SystemTime {
+    /// An anchor in time, representing 1970-01-01 00:00:00 UTC.
+    pub const UNIX_EPOCH: SystemTime = UNIX_EPOCH;
+
+    /// Sets the function that returns the time elapsed since [`UNIX_EPOCH`].
+    ///
+    /// Can only be set once, returns `false` if a getter was already set.
+    #[inline]
+    pub fn set_unix_time(getter: fn() -> Duration) -> bool {
+        UNIX_TIME_GETTER.set(getter).is_ok()
+    }
+
+    /// Returns the system time corresponding to "now".
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`SystemTime::set_unix_time`] has not been called.
+    #[inline]
+    pub fn now() -> Self {
+        let getter = UNIX_TIME_GETTER
+            .get()
+            .expect("call `SystemTime::set_unix_time` before using `SystemTime` without `std`");
+        Self(getter())
+    }
+
+    /// Returns the amount of time elapsed from an earlier point in time.
+    ///
+    /// Fails with the difference if `earlier` is later than `self`.
+    #[inline]
+    pub fn duration_since(&self, earlier: Self) -> Result<Duration, SystemTimeError> {
+        self.0
+            .checked_sub(earlier.0)
+            .ok_or_else(|| SystemTimeError(earlier.0 - self.0))
+    }
+
+    /// Returns the amount of time elapsed since this system time was created.
+    #[inline]
+    pub fn elapsed(&self) -> Result<Duration, SystemTimeError> {
+        Self::now().duration_since(*self)
+    }
+
+    /// Returns `Some(t)` where `t` is the time `self + duration` if it can be represented.
+    #[inline]
+    pub fn checked_add(&self, duration: Duration) -> Option<Self> {
+        self.0.checked_add(duration).map(Self)
+    }
+
+    /// Returns `Some(t)` where `t` is the time `self - duration` if it can be represented.
+    #[inline]
+    pub fn checked_sub(&self, duration: Duration) -> Option<Self> {
+        self.0.checked_sub(duration).map(Self)
+    }
+}
+
+impl Add<Duration> for SystemTime {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, dur: Duration) -> Self {
+        self.checked_add(dur)
+            .expect("overflow when adding duration to system time")
+    }
+}
+
+impl AddAssign<Duration> for SystemTime {
+    #[inline]
+    fn add_assign(&mut self, other: Duration) {
+        *self = *self + other;
+    }
+}
+
+impl Sub<Duration> for SystemTime {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, dur: Duration) -> Self {
+        self.checked_sub(dur)
+            .expect("overflow when subtracting duration from system time")
+    }
+}
+
+impl SubAssign<Duration> for SystemTime {
+    #[inline]
+    fn sub_assign(&mut self, other: Duration) {
+        *self = *self - other;
+    }
+}
+
+impl fmt::Debug for SystemTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SystemTime")
+            .field("since_unix_epoch", &self.0)
+            .finish()
+    }
+}
+
+/// An error returned from [`SystemTime::duration_since`] when the second time is later than the first.
+#[derive(Clone, Debug)]
+pub struct SystemTimeError(Duration);
+
+impl SystemTimeError {
+    /// Returns how far the second time was after the first.
+    #[inline]
+    pub fn duration(&self) -> Duration {
+        self.0
+    }
+}
+
+impl fmt::Display for SystemTimeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("second time provided was later than self")
+    }
+}
+
+impl Error for SystemTimeError {}
@@ -0,0 +1,46 @@
+//! Thread-local mock clock read by [`now`](super::now).
+
+use alloc::sync::Arc;
+use core::cell::RefCell;
+
+use super::{Instant, InstantSource, MockClock};
+
+std::thread_local! {
+    static MOCK_CLOCK: RefCell<Option<Arc<MockClock>>> = const { RefCell::new(None) };
+}
+
+#[inline]
+pub(super) fn now() -> Option<Instant> {
+    MOCK_CLOCK.with_borrow(|clock| clock.as_ref().map(|clock| clock.now()))
+}
+
+/// Restores the previously installed clock, even if the closure panics.
+struct Restore(Option<Arc<MockClock>>);
+
+impl Drop for Restore {
+    #[inline]
+    fn drop(&mut self) {
+        MOCK_CLOCK.set(self.0.take());
+    }
+}
+
+/// Runs `f` with a [`MockClock`] installed on the current thread, so
+/// [`now`](super::now) returns the mock time until `f` returns.
+///
+/// # Example
+///
+/// ```
+/// use core::time::Duration;
+/// use vct_os::time::{now, with_mock_clock};
+///
+/// with_mock_clock(|clock| {
+///     let start = now();
+///     clock.advance(Duration::from_secs(5));
+///     assert_eq!(now() - start, Duration::from_secs(5));
+/// });
+/// ```
+pub fn with_mock_clock<R>(f: impl FnOnce(&MockClock) -> R) -> R {
+    let clock = Arc::new(MockClock::new());
+    let _restore = Restore(MOCK_CLOCK.replace(Some(clock.clone())));
+    f(&clock)
+}
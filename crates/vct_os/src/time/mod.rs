@@ -1,4 +1,19 @@
-pub use time_impl::Instant;
+//! Time measurement, with a mockable clock for deterministic tests.
+
+mod source;
+
+pub use source::{InstantSource, MockClock, SystemClock, now, unix_timestamp_ms};
+pub use time_impl::{Instant, SystemTime, SystemTimeError, UNIX_EPOCH};
+
+crate::cfg::std! {
+    mod mock;
+    pub use mock::with_mock_clock;
+}
+
+crate::cfg::web! {
+    mod web;
+    pub use web::{next_animation_frame, sleep_async};
+}
 
 crate::cfg::switch! {
     crate::cfg::web => {
@@ -7,6 +22,10 @@ crate::cfg::switch! {
     crate::cfg::std => {
         use std::time as time_impl;
     }
+    crate::cfg::no_std => {
+        mod fallback;
+        use fallback as time_impl;
+    }
     _ => {
         compile_error!("This platform is not supported");
     }
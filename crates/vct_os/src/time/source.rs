@@ -0,0 +1,144 @@
+use core::{
+    sync::atomic::{AtomicU64, Ordering},
+    time::Duration,
+};
+
+use super::{Instant, SystemTime, UNIX_EPOCH};
+
+/// A source of [`Instant`]s.
+///
+/// Code that measures time can take an `InstantSource` instead of calling
+/// [`Instant::now`], so tests can pass a [`MockClock`] and advance it manually.
+pub trait InstantSource {
+    /// Returns the current instant of this source.
+    fn now(&self) -> Instant;
+}
+
+impl<T: InstantSource + ?Sized> InstantSource for &T {
+    #[inline]
+    fn now(&self) -> Instant {
+        (**self).now()
+    }
+}
+
+/// The platform clock, forwards to [`Instant::now`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SystemClock;
+
+impl InstantSource for SystemClock {
+    #[inline]
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// A clock that only moves when it is told to.
+///
+/// # Example
+///
+/// ```
+/// use core::time::Duration;
+/// use vct_os::time::{InstantSource, MockClock};
+///
+/// let clock = MockClock::new();
+/// let start = clock.now();
+/// clock.advance(Duration::from_millis(16));
+/// assert_eq!(clock.now() - start, Duration::from_millis(16));
+/// ```
+#[derive(Debug)]
+pub struct MockClock {
+    start: Instant,
+    elapsed_nanos: AtomicU64,
+}
+
+impl MockClock {
+    /// Creates a clock frozen at a fixed origin.
+    ///
+    /// Without `std` the origin is `Instant::ZERO` and the platform clock is never read.
+    /// The `std` `Instant` has no constant value, so the current platform instant is used there.
+    #[inline]
+    pub fn new() -> Self {
+        crate::cfg::std! {
+            if {
+                Self::starting_at(Instant::now())
+            } else {
+                Self::starting_at(Instant::ZERO)
+            }
+        }
+    }
+
+    /// Creates a clock frozen at `start`.
+    #[inline]
+    pub const fn starting_at(start: Instant) -> Self {
+        Self {
+            start,
+            elapsed_nanos: AtomicU64::new(0),
+        }
+    }
+
+    /// Moves the clock forward by `duration`.
+    #[inline]
+    pub fn advance(&self, duration: Duration) {
+        self.elapsed_nanos
+            .fetch_add(saturating_nanos(duration), Ordering::Relaxed);
+    }
+
+    /// Sets the time elapsed since the clock's start.
+    #[inline]
+    pub fn set_elapsed(&self, elapsed: Duration) {
+        self.elapsed_nanos
+            .store(saturating_nanos(elapsed), Ordering::Relaxed);
+    }
+
+    /// Returns the time elapsed since the clock's start.
+    #[inline]
+    pub fn elapsed(&self) -> Duration {
+        Duration::from_nanos(self.elapsed_nanos.load(Ordering::Relaxed))
+    }
+}
+
+impl Default for MockClock {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InstantSource for MockClock {
+    #[inline]
+    fn now(&self) -> Instant {
+        self.start + self.elapsed()
+    }
+}
+
+#[inline]
+fn saturating_nanos(duration: Duration) -> u64 {
+    u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX)
+}
+
+/// Returns the current instant, read from the mock clock installed by
+/// `with_mock_clock` on this thread if any, otherwise from [`Instant::now`].
+///
+/// Prefer this over [`Instant::now`] in timers and profiling code that tests may want to control.
+#[inline]
+pub fn now() -> Instant {
+    crate::cfg::std! {
+        if let Some(now) = super::mock::now() {
+            return now;
+        }
+    }
+    Instant::now()
+}
+
+/// Returns the milliseconds elapsed since [`UNIX_EPOCH`] according to the system clock,
+/// or zero if the clock is set before it.
+///
+/// Reads `Date.now()` on web.
+#[inline]
+pub fn unix_timestamp_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |since| {
+            u64::try_from(since.as_millis()).unwrap_or(u64::MAX)
+        })
+}
@@ -0,0 +1,39 @@
+//! Async timing helpers for the browser, where blocking the thread is not an option.
+
+use core::time::Duration;
+
+use js_sys::{Function, Promise};
+use wasm_bindgen::prelude::wasm_bindgen;
+use wasm_bindgen_futures::JsFuture;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_name = setTimeout)]
+    fn set_timeout(handler: &Function, timeout: i32);
+
+    #[wasm_bindgen(js_name = requestAnimationFrame)]
+    fn request_animation_frame(callback: &Function);
+}
+
+/// Waits for at least `duration` without blocking the thread, backed by `setTimeout`.
+///
+/// Durations longer than `i32::MAX` milliseconds are clamped.
+pub async fn sleep_async(duration: Duration) {
+    let millis = i32::try_from(duration.as_millis()).unwrap_or(i32::MAX);
+    let promise = Promise::new(&mut |resolve, _reject| set_timeout(&resolve, millis));
+    // The promise never rejects.
+    let _ = JsFuture::from(promise).await;
+}
+
+/// Waits for the browser's next repaint, backed by `requestAnimationFrame`.
+///
+/// Returns the frame timestamp, measured from the page's time origin.
+pub async fn next_animation_frame() -> Duration {
+    let promise = Promise::new(&mut |resolve, _reject| request_animation_frame(&resolve));
+    let millis = JsFuture::from(promise)
+        .await
+        .ok()
+        .and_then(|timestamp| timestamp.as_f64())
+        .unwrap_or_default();
+    Duration::from_secs_f64(millis / 1000.0)
+}
@@ -1,6 +1,8 @@
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::{parse::ParseStream, Expr, Path, Token};
+use syn::{parse::ParseStream, Attribute, Expr, MacroDelimiter, Meta, Path, Token};
+
+use crate::REFLECT_ATTRIBUTE_NAME;
 
 #[derive(Default, Clone)]
 pub(crate) struct CustomAttributes {
@@ -25,6 +27,47 @@ impl CustomAttributes {
         self.push(input.parse()?)
     }
 
+    /// Parse attributes that only accept custom attributes, such as those of generic parameters.
+    ///
+    /// Examples:
+    /// - `#[reflect(@Foo, @Bar::baz("qux"))]`
+    pub fn parse_attrs(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut custom_attributes = CustomAttributes::default();
+
+        for attribute in attrs {
+            match &attribute.meta {
+                Meta::List(meta_list) if meta_list.path.is_ident(REFLECT_ATTRIBUTE_NAME) => {
+                    if !matches!(meta_list.delimiter, MacroDelimiter::Paren(_)) {
+                        return Err(syn::Error::new(
+                            meta_list.delimiter.span().join(),
+                            format_args!(
+                                "`#[{REFLECT_ATTRIBUTE_NAME}(\"...\")]` must use parentheses `(` and `)`"
+                            ),
+                        ));
+                    }
+                    meta_list.parse_args_with(|stream: ParseStream| {
+                        while !stream.is_empty() {
+                            custom_attributes.parse_inner_stream(stream)?;
+                            if stream.is_empty() {
+                                break;
+                            }
+                            stream.parse::<Token![,]>()?;
+                        }
+                        Ok(())
+                    })?;
+                },
+                _ => continue,
+            }
+        }
+
+        Ok(custom_attributes)
+    }
+
+    /// Returns `true` if no custom attributes are specified.
+    pub fn is_empty(&self) -> bool {
+        self.attributes.is_empty()
+    }
+
     /// If `custom_attributes` is empty, this function will return an empty token stream.
     /// 
     /// Otherwise, it will return content similar to this:
@@ -1,14 +1,22 @@
 use proc_macro2::Span;
-use syn::{Attribute, Meta, Token, MacroDelimiter, MetaList, parse::ParseStream, MetaNameValue};
+use syn::{Attribute, Expr, ExprLit, ExprPath, Lit, LitStr, Meta, Token, MacroDelimiter, MetaList, parse::ParseStream, MetaNameValue, spanned::Spanned};
 
 use crate::{
     REFLECT_ATTRIBUTE_NAME,
-    derive_data::{CustomAttributes, ReflectDocs}
+    derive_data::{CustomAttributes, ReflectDocs, RenameRule}
 };
 
 mod kw{
     syn::custom_keyword!(docs);
     syn::custom_keyword!(ignore);
+    syn::custom_keyword!(default);
+    syn::custom_keyword!(clone_with);
+    syn::custom_keyword!(dynamic);
+    syn::custom_keyword!(flatten);
+    syn::custom_keyword!(rename);
+    syn::custom_keyword!(rename_all);
+    syn::custom_keyword!(skip_serializing);
+    syn::custom_keyword!(skip_serializing_if);
 }
 
 
@@ -20,6 +28,24 @@ pub(crate) struct FieldAttributes {
     pub docs: ReflectDocs,
     /// Determines how this field should be ignored if at all.
     pub ignore: Option<Span>,
+    /// `#[reflect(default)]`: use `Default::default()` when the field is missing in `from_reflect`.
+    pub default: Option<Span>,
+    /// `#[reflect(default = "...")]`: use the function instead of `Default::default()`, implies `default`.
+    pub default_fn: Option<ExprPath>,
+    /// `#[reflect(ignore, clone_with = "...")]`: clone the ignored field with the function in `reflect_clone`.
+    pub clone_with: Option<(ExprPath, Span)>,
+    /// `#[reflect(dynamic)]`: a `Box<dyn Reflect>` field, reflected as `dyn Reflect`.
+    pub dynamic: Option<Span>,
+    /// `#[reflect(flatten)]`: the fields of a nested struct are exposed as the fields of the parent.
+    pub flatten: Option<Span>,
+    /// `#[reflect(rename = "...")]`: the name of a named field or a variant in the reflection API and serde.
+    pub rename: Option<(String, Span)>,
+    /// `#[reflect(rename_all = "...")]`: renames the fields of a variant.
+    pub rename_all: Option<(RenameRule, Span)>,
+    /// `#[reflect(skip_serializing)]`: the field is never serialized.
+    pub skip_serializing: Option<Span>,
+    /// `#[reflect(skip_serializing_if = "...")]`: the field is not serialized if the function returns `true`.
+    pub skip_serializing_if: Option<(ExprPath, Span)>,
 }
 
 impl FieldAttributes {
@@ -76,6 +102,22 @@ impl FieldAttributes {
             self.parse_docs(input)
         } else if lookahead.peek(kw::ignore) {
             self.parse_ignore(input)
+        } else if lookahead.peek(kw::default) {
+            self.parse_default(input)
+        } else if lookahead.peek(kw::clone_with) {
+            self.parse_clone_with(input)
+        } else if lookahead.peek(kw::dynamic) {
+            self.parse_dynamic(input)
+        } else if lookahead.peek(kw::flatten) {
+            self.parse_flatten(input)
+        } else if lookahead.peek(kw::rename_all) {
+            self.parse_rename_all(input)
+        } else if lookahead.peek(kw::rename) {
+            self.parse_rename(input)
+        } else if lookahead.peek(kw::skip_serializing_if) {
+            self.parse_skip_serializing_if(input)
+        } else if lookahead.peek(kw::skip_serializing) {
+            self.parse_skip_serializing(input)
         } else {
             Err(lookahead.error())
         }
@@ -98,6 +140,101 @@ impl FieldAttributes {
         self.ignore = Some(s);
         Ok(())
     }
+
+    // #[reflect(default)] or #[reflect(default = "path::to::func")]
+    fn parse_default(&mut self, input: ParseStream) -> syn::Result<()> {
+        let s = input.parse::<kw::default>()?.span;
+        self.default = Some(s);
+
+        if input.peek(Token![=]) {
+            input.parse::<Token![=]>()?;
+            let lit = input.parse::<LitStr>()?;
+            self.default_fn = Some(lit.parse::<ExprPath>()?);
+        }
+
+        Ok(())
+    }
+
+    // #[reflect(clone_with = "path::to::func")]
+    fn parse_clone_with(&mut self, input: ParseStream) -> syn::Result<()> {
+        let pair = input.parse::<MetaNameValue>()?;
+
+        if let Expr::Lit(ExprLit {
+            lit: Lit::Str(lit), ..
+        }) = &pair.value {
+            self.clone_with = Some((lit.parse::<ExprPath>()?, pair.span()));
+        } else {
+            return Err(syn::Error::new(pair.value.span(), "Expected a string liternal value."));
+        }
+
+        Ok(())
+    }
+
+    fn parse_dynamic(&mut self, input: ParseStream) -> syn::Result<()> {
+        let s = input.parse::<kw::dynamic>()?.span;
+        self.dynamic = Some(s);
+        Ok(())
+    }
+
+    fn parse_flatten(&mut self, input: ParseStream) -> syn::Result<()> {
+        let s = input.parse::<kw::flatten>()?.span;
+        self.flatten = Some(s);
+        Ok(())
+    }
+
+    // #[reflect(rename = "...")]
+    fn parse_rename(&mut self, input: ParseStream) -> syn::Result<()> {
+        let pair = input.parse::<MetaNameValue>()?;
+
+        if let Expr::Lit(ExprLit {
+            lit: Lit::Str(lit), ..
+        }) = &pair.value {
+            if lit.value().is_empty() {
+                return Err(syn::Error::new(lit.span(), "`rename` should not be empty."));
+            }
+            self.rename = Some((lit.value(), pair.span()));
+        } else {
+            return Err(syn::Error::new(pair.value.span(), "Expected a string liternal value."));
+        }
+
+        Ok(())
+    }
+
+    fn parse_skip_serializing(&mut self, input: ParseStream) -> syn::Result<()> {
+        let s = input.parse::<kw::skip_serializing>()?.span;
+        self.skip_serializing = Some(s);
+        Ok(())
+    }
+
+    // #[reflect(skip_serializing_if = "path::to::func")]
+    fn parse_skip_serializing_if(&mut self, input: ParseStream) -> syn::Result<()> {
+        let pair = input.parse::<MetaNameValue>()?;
+
+        if let Expr::Lit(ExprLit {
+            lit: Lit::Str(lit), ..
+        }) = &pair.value {
+            self.skip_serializing_if = Some((lit.parse::<ExprPath>()?, pair.span()));
+        } else {
+            return Err(syn::Error::new(pair.value.span(), "Expected a string liternal value."));
+        }
+
+        Ok(())
+    }
+
+    // #[reflect(rename_all = "...")]
+    fn parse_rename_all(&mut self, input: ParseStream) -> syn::Result<()> {
+        let pair = input.parse::<MetaNameValue>()?;
+
+        if let Expr::Lit(ExprLit {
+            lit: Lit::Str(lit), ..
+        }) = &pair.value {
+            self.rename_all = Some((RenameRule::from_lit(lit)?, pair.span()));
+        } else {
+            return Err(syn::Error::new(pair.value.span(), "Expected a string liternal value."));
+        }
+
+        Ok(())
+    }
 }
 
 
@@ -56,6 +56,8 @@ pub(crate) struct TraitAvailableFlags {
     pub debug: Option<Span>,
     pub hash: Option<Span>,
     pub partial_eq: Option<Span>,
+    pub partial_ord: Option<Span>,
+    pub interpolate: Option<Span>,
     pub serialize: Option<Span>,
     pub deserialize: Option<Span>,
 }
@@ -10,6 +10,9 @@ pub(crate) use flags::{
     TraitImplSwitches, TraitAvailableFlags,
 };
 
+mod rename_rule;
+pub(crate) use rename_rule::RenameRule;
+
 mod type_attributes;
 pub(crate) use type_attributes::TypeAttributes;
 
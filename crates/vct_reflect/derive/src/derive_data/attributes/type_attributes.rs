@@ -1,12 +1,13 @@
 use proc_macro2::Span;
 use syn::{
-    Attribute, Expr, ExprLit, Lit, MacroDelimiter, Meta, MetaList, MetaNameValue, Path, Token, parse::ParseStream, spanned::Spanned
+    Attribute, Expr, ExprLit, Lit, MacroDelimiter, Meta, MetaList, MetaNameValue, Path, Token, WherePredicate,
+    parse::ParseStream, punctuated::Punctuated, spanned::Spanned
 };
 
 use crate::{
     REFLECT_ATTRIBUTE_NAME,
     derive_data::{
-        CustomAttributes, TraitAvailableFlags, ReflectDocs, TraitImplSwitches
+        CustomAttributes, TraitAvailableFlags, ReflectDocs, RenameRule, TraitImplSwitches
     }
 };
 
@@ -21,16 +22,24 @@ mod kw {
     syn::custom_keyword!(Tuple);
     syn::custom_keyword!(Enum);
     syn::custom_keyword!(Opaque);
+    syn::custom_keyword!(transparent);
     syn::custom_keyword!(auto_register);
     syn::custom_keyword!(default);
     syn::custom_keyword!(clone);
     syn::custom_keyword!(debug);
     syn::custom_keyword!(hash);
     syn::custom_keyword!(partial_eq);
+    syn::custom_keyword!(partial_ord);
+    syn::custom_keyword!(interpolate);
     syn::custom_keyword!(serialize);
     syn::custom_keyword!(deserialize);
     syn::custom_keyword!(serde);    // serialize + deserialize + auto_register
+    syn::custom_keyword!(derive_serde);
     syn::custom_keyword!(type_path);
+    syn::custom_keyword!(uuid);
+    syn::custom_keyword!(version);
+    syn::custom_keyword!(rename_all);
+    syn::custom_keyword!(no_bounds);
     syn::custom_keyword!(docs);
     syn::custom_keyword!(full);  // serde + clone + debug + hash + partial_eq + default
 }
@@ -45,16 +54,38 @@ pub(crate) struct TypeAttributes {
     pub avail_traits: TraitAvailableFlags,
     /// By default, only types like `struct A;` are `Opaque`, but user can use `#[reflect(opaque)]` to enable it explicitly.
     pub is_opaque: Option<Span>,
+    /// Default is false, use `#[reflect(transparent)]` to delegate the reflection to the single active field.
+    pub is_transparent: Option<Span>,
     /// Default is false, use `#[reflect(auto_register)]` or `#[reflect(auto_register)]` to enable i.
     pub auto_register: Option<Span>,
+    /// Default is None, Use `#[reflect(derive_serde)]` to implement `serde` traits through the reflection serializers.
+    pub derive_serde: Option<Span>,
     /// Default is None, So the macro will be auto generated. Use `#[reflect(type_path = "...")]` to set it.
     pub type_path: Option<Path>,
+    /// Default is None, Use `#[reflect(uuid = "...")]` to set a stable id.
+    pub type_uuid: Option<u128>,
+    /// Default is None, Use `#[reflect(version = 1)]` to serialize a struct with its version, see `migrate`.
+    pub version: Option<(u32, Span)>,
+    /// Default is None, Use `#[reflect(rename_all = "camelCase")]` to rename the fields of a struct or the variants of an enum.
+    pub rename_all: Option<(RenameRule, Span)>,
+    /// Default is Empty, Use `#[reflect(where T: MyTrait)]` to add predicates to the `where` clause of the reflection impls.
+    pub custom_where: Vec<WherePredicate>,
+    /// Default is None, Use `#[reflect(no_bounds)]` to skip the bounds generated for the field types.
+    pub no_bounds: Option<Span>,
+    /// Default is Empty, Use `#[reflect(MyTypeTrait)]` to insert `<MyTypeTrait as FromType<Self>>::from_type()` into the `TypeTraits`.
+    pub type_traits: Vec<Path>,
     /// Default is Empty Docs,  Use `///`, `#[doc = ""]` or `#[reflect(docs = "")]` to set it, Can set multi-lines.
     pub docs: ReflectDocs,
 }
 
 impl TypeAttributes {
     pub fn validity(&self) -> syn::Result<()> {
+        if let Some(span) = self.is_transparent && self.is_opaque.is_some() {
+            return Err(syn::Error::new(
+                span,
+                "#[reflect(transparent)] cannot be used with #[reflect(Opaque)]."
+            ));
+        }
         if let Some(span) = self.is_opaque {
             if self.avail_traits.clone.is_none() {
                 if self.impl_switchs.impl_reflect || self.impl_switchs.impl_from_reflect {
@@ -65,6 +96,26 @@ impl TypeAttributes {
                 }
             }
         }
+        if let Some(path) = self.type_traits.first() && !self.impl_switchs.impl_get_type_traits {
+            return Err(syn::Error::new(
+                path.span(),
+                "custom type traits require the `GetTypeTraits` impl, remove #[reflect(GetTypeTraits = false)] or insert them manually."
+            ));
+        }
+        if let Some(span) = self.derive_serde {
+            if self.avail_traits.serialize.is_some() || self.avail_traits.deserialize.is_some() {
+                return Err(syn::Error::new(
+                    span,
+                    "#[reflect(derive_serde)] cannot be used with `serialize`, `deserialize`, `serde` or `full`, the reflection serializers would call the generated impls."
+                ));
+            }
+            if self.is_opaque.is_some() {
+                return Err(syn::Error::new(
+                    span,
+                    "#[reflect(derive_serde)] cannot be used for Opaque Type, the reflection serializers cannot inspect it."
+                ));
+            }
+        }
         Ok(())
     }
 
@@ -144,8 +195,14 @@ impl TypeAttributes {
             self.parse_hash(input)
         } else if lookahead.peek(kw::partial_eq) {
             self.parse_patrial_eq(input)
+        } else if lookahead.peek(kw::partial_ord) {
+            self.parse_partial_ord(input)
+        } else if lookahead.peek(kw::interpolate) {
+            self.parse_interpolate(input)
         } else if lookahead.peek(kw::debug) {
             self.parse_debug(input)
+        } else if lookahead.peek(kw::derive_serde) {
+            self.parse_derive_serde(input)
         } else if lookahead.peek(kw::serde) {
             self.parse_serde(input)
         } else if lookahead.peek(kw::serialize) {
@@ -154,10 +211,22 @@ impl TypeAttributes {
             self.parse_deserialize(input)
         } else if lookahead.peek(kw::Opaque) {
             self.parse_opaque(input)
+        } else if lookahead.peek(kw::transparent) {
+            self.parse_transparent(input)
         } else if lookahead.peek(kw::auto_register) {
             self.parse_auto_register(input)
         } else if lookahead.peek(kw::type_path) {
             self.parse_type_path(input)
+        } else if lookahead.peek(kw::uuid) {
+            self.parse_uuid(input)
+        } else if lookahead.peek(kw::version) {
+            self.parse_version(input)
+        } else if lookahead.peek(kw::rename_all) {
+            self.parse_rename_all(input)
+        } else if lookahead.peek(Token![where]) {
+            self.parse_custom_where(input)
+        } else if lookahead.peek(kw::no_bounds) {
+            self.parse_no_bounds(input)
         } else if lookahead.peek(kw::TypePath) {
             self.parse_trait_type_path(input)
         } else if lookahead.peek(kw::Typed) {
@@ -176,11 +245,30 @@ impl TypeAttributes {
             self.parse_trait_tuple(input)
         } else if lookahead.peek(kw::Enum) {
             self.parse_trait_enum(input)
+        } else if Self::peek_type_trait(input) {
+            self.parse_type_trait(input)
         } else {
             Err(lookahead.error())
         }
     }
 
+    /// Custom type traits are paths whose last segment starts with an uppercase letter,
+    /// so misspelled keywords still get the list of expected attributes.
+    fn peek_type_trait(input: ParseStream) -> bool {
+        input.fork().parse::<Path>().is_ok_and(|path| {
+            path.segments.last().is_some_and(|segment| {
+                segment.ident.to_string().starts_with(|c: char| c.is_ascii_uppercase())
+            })
+        })
+    }
+
+    // #[reflect(MyTypeTrait)]
+    fn parse_type_trait(&mut self, input: ParseStream) -> syn::Result<()> {
+        let path = input.parse::<Path>()?;
+        self.type_traits.push(path);
+        Ok(())
+    }
+
     // #[reflect(@expr)]
     fn parse_custom_attribute(&mut self, input: ParseStream) -> syn::Result<()> {
         self.custom_attributes.parse_inner_stream(input)
@@ -194,6 +282,22 @@ impl TypeAttributes {
         self.docs.parse_custom_docs(&pair)
     }
 
+    // #[reflect(where T: MyTrait, U: OtherTrait)]
+    // The predicates are separated by commas, so `where` must be the last item.
+    fn parse_custom_where(&mut self, input: ParseStream) -> syn::Result<()> {
+        input.parse::<Token![where]>()?;
+        let predicates = Punctuated::<WherePredicate, Token![,]>::parse_terminated(input)?;
+        self.custom_where.extend(predicates);
+        Ok(())
+    }
+
+    // #[reflect(no_bounds)]
+    fn parse_no_bounds(&mut self, input: ParseStream) -> syn::Result<()> {
+        let s = input.parse::<kw::no_bounds>()?.span;
+        self.no_bounds = Some(s);
+        Ok(())
+    }
+
     // #[reflect(full)]
     fn parse_full(&mut self, input: ParseStream) -> syn::Result<()> {
         let s = input.parse::<kw::full>()?.span;
@@ -236,6 +340,20 @@ impl TypeAttributes {
         Ok(())
     }
 
+    // #[reflect(partial_ord)]
+    fn parse_partial_ord(&mut self, input: ParseStream) -> syn::Result<()> {
+        let s = input.parse::<kw::partial_ord>()?.span;
+        self.avail_traits.partial_ord = Some(s);
+        Ok(())
+    }
+
+    // #[reflect(interpolate)]
+    fn parse_interpolate(&mut self, input: ParseStream) -> syn::Result<()> {
+        let s = input.parse::<kw::interpolate>()?.span;
+        self.avail_traits.interpolate = Some(s);
+        Ok(())
+    }
+
     // #[reflect(debug)]
     fn parse_debug(&mut self, input: ParseStream) -> syn::Result<()> {
         let s = input.parse::<kw::debug>()?.span;
@@ -252,6 +370,13 @@ impl TypeAttributes {
         Ok(())
     }
 
+    // #[reflect(derive_serde)]
+    fn parse_derive_serde(&mut self, input: ParseStream) -> syn::Result<()> {
+        let s = input.parse::<kw::derive_serde>()?.span;
+        self.derive_serde = Some(s);
+        Ok(())
+    }
+
     // #[reflect(serialize)]
     fn parse_serialize(&mut self, input: ParseStream) -> syn::Result<()> {
         let s = input.parse::<kw::serialize>()?.span;
@@ -273,6 +398,13 @@ impl TypeAttributes {
         Ok(())
     }
 
+    // #[reflect(transparent)]
+    fn parse_transparent(&mut self, input: ParseStream) -> syn::Result<()> {
+        let s = input.parse::<kw::transparent>()?.span;
+        self.is_transparent = Some(s);
+        Ok(())
+    }
+
     // #[reflect(auto_register)]
     fn parse_auto_register(&mut self, input: ParseStream) -> syn::Result<()> {
         let s = input.parse::<kw::auto_register>()?.span;
@@ -302,6 +434,54 @@ impl TypeAttributes {
         Ok(())
     }
 
+    // #[reflect(uuid = "...")]
+    fn parse_uuid(&mut self, input: ParseStream) -> syn::Result<()> {
+        let pair = input.parse::<MetaNameValue>()?;
+
+        if let Expr::Lit(ExprLit {
+            lit: Lit::Str(lit), ..
+        }) = &pair.value {
+            match parse_uuid(&lit.value()) {
+                Some(uuid) => self.type_uuid = Some(uuid),
+                None => return Err(syn::Error::new(lit.span(), "Invalid uuid, expected `xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx`.")),
+            }
+        } else {
+            return Err(syn::Error::new(pair.value.span(), "Expected a string liternal value."));
+        }
+
+        Ok(())
+    }
+
+    // #[reflect(version = 1)]
+    fn parse_version(&mut self, input: ParseStream) -> syn::Result<()> {
+        let pair = input.parse::<MetaNameValue>()?;
+
+        if let Expr::Lit(ExprLit {
+            lit: Lit::Int(lit), ..
+        }) = &pair.value {
+            self.version = Some((lit.base10_parse::<u32>()?, pair.span()));
+        } else {
+            return Err(syn::Error::new(pair.value.span(), "Expected a integer liternal value."));
+        }
+
+        Ok(())
+    }
+
+    // #[reflect(rename_all = "...")]
+    fn parse_rename_all(&mut self, input: ParseStream) -> syn::Result<()> {
+        let pair = input.parse::<MetaNameValue>()?;
+
+        if let Expr::Lit(ExprLit {
+            lit: Lit::Str(lit), ..
+        }) = &pair.value {
+            self.rename_all = Some((RenameRule::from_lit(lit)?, pair.span()));
+        } else {
+            return Err(syn::Error::new(pair.value.span(), "Expected a string liternal value."));
+        }
+
+        Ok(())
+    }
+
     fn parse_trait_type_path(&mut self, input: ParseStream) -> syn::Result<()> {
         // #[reflect(TypePath = false)]
         let pair = input.parse::<MetaNameValue>()?;
@@ -480,3 +660,22 @@ impl TypeAttributes {
 
 
 
+
+/// Parses a UUID string, with or without hyphens.
+fn parse_uuid(s: &str) -> Option<u128> {
+    let digits: String = match s.len() {
+        32 => s.to_owned(),
+        36 => {
+            let bytes = s.as_bytes();
+            if [8, 13, 18, 23].iter().any(|&index| bytes[index] != b'-') {
+                return None;
+            }
+            s.replace('-', "")
+        }
+        _ => return None,
+    };
+    if digits.len() != 32 || !digits.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+        return None;
+    }
+    u128::from_str_radix(&digits, 16).ok()
+}
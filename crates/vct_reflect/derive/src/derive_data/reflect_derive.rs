@@ -1,10 +1,10 @@
 use proc_macro2::Span;
-use syn::{DeriveInput, token::Comma, Fields, punctuated::Punctuated, spanned::Spanned, Variant};
+use syn::{DeriveInput, token::Comma, Fields, GenericArgument, GenericParam, Generics, Path, PathArguments, punctuated::Punctuated, spanned::Spanned, Type, TypeParamBound, Variant};
 
 use crate::{
-    ImplSourceKind, 
+    ImplSourceKind, REFLECT_ATTRIBUTE_NAME,
     derive_data::{
-        EnumVariant, EnumVariantFields, FieldAttributes, ReflectEnum, ReflectMeta, ReflectStruct, TypePathParser, StructField, TypeAttributes
+        CustomAttributes, EnumVariant, EnumVariantFields, FieldAttributes, ReflectEnum, ReflectMeta, ReflectStruct, RenameRule, TypePathParser, StructField, TypeAttributes
     }
 };
 
@@ -14,14 +14,45 @@ pub(crate) enum ReflectDerive<'a> {
     UnitStruct(ReflectMeta<'a>),
     Enum(ReflectEnum<'a>),
     Opaque(ReflectMeta<'a>),
+    Transparent(ReflectStruct<'a>),
 }
 
 
 impl<'a> ReflectDerive<'a> {
-    pub fn from_input(input: &'a DeriveInput, source: ImplSourceKind) -> syn::Result<Self> {
+    /// Access the metadata of any kind of type.
+    pub fn meta(&self) -> &ReflectMeta<'a> {
+        match self {
+            Self::Struct(info) | Self::TupleStruct(info) | Self::Transparent(info) => info.meta(),
+            Self::Enum(info) => info.meta(),
+            Self::UnitStruct(meta) | Self::Opaque(meta) => meta,
+        }
+    }
+
+    /// `generic_attributes` are the custom attributes of the generic parameters,
+    /// see [`ReflectDerive::take_generic_attributes`].
+    ///
+    /// `remote` is the wrapped type of `#[reflect_remote(...)]`, `input` mirrors its definition.
+    pub fn from_input(
+        input: &'a DeriveInput,
+        source: ImplSourceKind,
+        generic_attributes: Vec<CustomAttributes>,
+        remote: Option<&'a Path>,
+    ) -> syn::Result<Self> {
+        Self::reject_lifetimes(&input.generics)?;
         let type_attributes = TypeAttributes::parse_attrs(&input.attrs)?;
         type_attributes.validity()?;
 
+        if let Some((_, span)) = type_attributes.version {
+            let is_named_struct = matches!(&input.data, syn::Data::Struct(data) if matches!(data.fields, Fields::Named(..)));
+            if !is_named_struct || type_attributes.is_opaque.is_some() || type_attributes.is_transparent.is_some() {
+                return Err(syn::Error::new(span, "#[reflect(version = ...)] can only be used for structs with named fields."));
+            }
+        }
+
+        if let Some(span) = type_attributes.derive_serde && source == ImplSourceKind::ImplForeignType {
+            return Err(syn::Error::new(span, "#[reflect(derive_serde)] cannot be used for foreign types."));
+        }
+
         // For local types, can use `module_path!()` to get the module path, 
         // but for foreign types, the user needs to explicitly provide it.
         // If automatic implementation is disabled, it can also be ignored.
@@ -44,15 +75,58 @@ impl<'a> ReflectDerive<'a> {
             generics: &input.generics
         };
 
-        let meta = ReflectMeta::new(type_attributes, type_path);
+        let mut meta = ReflectMeta::new(type_attributes, type_path);
+        meta.set_generic_attributes(generic_attributes);
+        meta.set_remote(remote);
 
         if meta.attrs().is_opaque.is_some() {
             return Ok(Self::Opaque(meta));
         }
 
+        if let Some(span) = meta.attrs().is_transparent {
+            if remote.is_some() {
+                return Err(syn::Error::new(span, "#[reflect(transparent)] cannot be used with #[reflect_remote]."));
+            }
+            return match &input.data {
+                syn::Data::Struct(data_struct) => {
+                    let fields = Self::colloct_struct_field(&data_struct.fields, None)?;
+                    if let Some(span) = fields.iter().find_map(|field| field.attrs.dynamic) {
+                        return Err(syn::Error::new(
+                            span,
+                            "#[reflect(dynamic)] cannot be used in #[reflect(transparent)] structs.",
+                        ));
+                    }
+                    if let Some(span) = fields.iter().find_map(|field| field.attrs.flatten) {
+                        return Err(syn::Error::new(
+                            span,
+                            "#[reflect(flatten)] cannot be used in #[reflect(transparent)] structs.",
+                        ));
+                    }
+                    if fields.iter().filter(|field| field.attrs.ignore.is_none()).count() != 1 {
+                        return Err(syn::Error::new(
+                            span,
+                            "#[reflect(transparent)] requires exactly one field that is not ignored.",
+                        ));
+                    }
+                    meta.set_field_types(
+                        fields.iter().filter(|field| field.attrs.ignore.is_none()).map(|field| &field.data.ty)
+                    );
+                    Ok(Self::Transparent(ReflectStruct { meta, fields }))
+                },
+                _ => Err(syn::Error::new(span, "#[reflect(transparent)] can only be used for structs.")),
+            };
+        }
+
         match &input.data {
             syn::Data::Struct(data_struct) => {
-                let fields = Self::colloct_struct_field(&data_struct.fields)?;
+                let rename_all = meta.attrs().rename_all.map(|(rule, _)| rule);
+                let fields = Self::colloct_struct_field(&data_struct.fields, rename_all)?;
+                meta.set_field_types(
+                    fields.iter().filter(|field| field.attrs.ignore.is_none()).map(|field| &field.data.ty)
+                );
+                meta.set_flattened_types(
+                    fields.iter().filter(|field| field.attrs.flatten.is_some()).map(|field| &field.data.ty)
+                );
                 match data_struct.fields {
                     Fields::Named(..) => Ok(Self::Struct(ReflectStruct { meta, fields })),
                     Fields::Unnamed(..) => Ok(Self::TupleStruct(ReflectStruct { meta, fields })),
@@ -60,22 +134,128 @@ impl<'a> ReflectDerive<'a> {
                 }
             },
             syn::Data::Enum(data_enum) => {
-                let variants = Self::collect_enum_variants(&data_enum.variants)?;
-                Ok(Self::Enum(ReflectEnum { meta, variants }))
+                let rename_all = meta.attrs().rename_all.map(|(rule, _)| rule);
+                let variants = Self::collect_enum_variants(&data_enum.variants, rename_all)?;
+                let has_repr = input.attrs.iter().any(|attr| attr.path().is_ident("repr"));
+                meta.set_field_types(
+                    variants.iter().flat_map(EnumVariant::active_fields).map(|field| &field.data.ty)
+                );
+                Ok(Self::Enum(ReflectEnum { meta, variants, has_repr }))
             },
-            syn::Data::Union(_) => {
-                Err(syn::Error::new(input.span(), "reflection macros do not support unions."))
+            syn::Data::Union(data_union) => {
+                // The active field of a union is unknown, so it can only be reflected as a whole.
+                Err(syn::Error::new(
+                    data_union.union_token.span,
+                    "reflection macros cannot inspect the fields of unions, use #[reflect(Opaque, clone)] to reflect it as an `Opaque` type (requires `Clone`).",
+                ))
             },
         }
     }
 
-    fn colloct_struct_field(fields: &'a Fields) -> syn::Result<Vec<StructField<'a>>> {
+    /// Reflected types must be `'static`, so lifetime parameters are rejected at their span.
+    ///
+    /// Fields can still use `'static` lifetimes, such as `Cow<'static, str>` or `&'static str`.
+    pub fn reject_lifetimes(generics: &Generics) -> syn::Result<()> {
+        match generics.lifetimes().next() {
+            Some(param) => Err(syn::Error::new(
+                param.lifetime.span(),
+                "reflected types must be 'static, lifetime parameters are not supported. Use owned data or 'static borrows like `Cow<'static, str>` and `&'static str`.",
+            )),
+            None => Ok(()),
+        }
+    }
+
+    /// Parses and removes `#[reflect(@...)]` from the generic parameters.
+    ///
+    /// The generics are emitted again in the impls, where `#[reflect]` is not an attribute in scope.
+    pub fn take_generic_attributes(generics: &mut Generics) -> syn::Result<Vec<CustomAttributes>> {
+        let mut res = Vec::with_capacity(generics.params.len());
+
+        for param in generics.params.iter_mut() {
+            let attrs = match param {
+                GenericParam::Lifetime(param) => &mut param.attrs,
+                GenericParam::Type(param) => &mut param.attrs,
+                GenericParam::Const(param) => &mut param.attrs,
+            };
+            let custom_attributes = CustomAttributes::parse_attrs(attrs)?;
+            attrs.retain(|attr| !attr.path().is_ident(REFLECT_ATTRIBUTE_NAME));
+
+            if let GenericParam::Lifetime(param) = param && !custom_attributes.is_empty() {
+                return Err(syn::Error::new(
+                    param.span(),
+                    "custom attributes are not supported on lifetime parameters.",
+                ));
+            }
+            res.push(custom_attributes);
+        }
+
+        Ok(res)
+    }
+
+    /// `rename_all` renames the named fields without `#[reflect(rename = "...")]`.
+    fn colloct_struct_field(fields: &'a Fields, rename_all: Option<RenameRule>) -> syn::Result<Vec<StructField<'a>>> {
         let mut active_index = 0;
         
         let mut res: Vec<StructField<'a>> = Vec::with_capacity(fields.len());
 
         for (declaration_index, field) in fields.iter().enumerate() {
-            let attrs = FieldAttributes::parse_attrs(&field.attrs)?;
+            let mut attrs = FieldAttributes::parse_attrs(&field.attrs)?;
+
+            if let Some((_, span)) = attrs.rename_all {
+                return Err(syn::Error::new(span, "#[reflect(rename_all = \"...\")] can only be used for types and enum variants."));
+            }
+            if let Some((_, span)) = attrs.clone_with && attrs.ignore.is_none() {
+                return Err(syn::Error::new(span, "#[reflect(clone_with = \"...\")] can only be used for ignored fields."));
+            }
+
+            if let Some(span) = attrs.flatten {
+                if field.ident.is_none() {
+                    return Err(syn::Error::new(span, "#[reflect(flatten)] can only be used for named fields."));
+                }
+                if attrs.ignore.is_some() || attrs.dynamic.is_some() || attrs.rename.is_some()
+                    || attrs.skip_serializing.is_some() || attrs.skip_serializing_if.is_some()
+                {
+                    return Err(syn::Error::new(span, "#[reflect(flatten)] cannot be used with `ignore`, `dynamic`, `rename` or `skip_serializing`."));
+                }
+            }
+
+            match (&field.ident, &attrs.rename) {
+                (None, Some((_, span))) => {
+                    return Err(syn::Error::new(*span, "#[reflect(rename = \"...\")] can only be used for named fields and enum variants."));
+                },
+                // The fields of a flattened struct keep their own names.
+                (Some(ident), None) => if attrs.flatten.is_none() && let Some(rule) = rename_all {
+                    attrs.rename = Some((rule.apply_to_field(&ident.to_string()), ident.span()));
+                },
+                _ => {},
+            }
+
+            if let Some(span) = attrs.skip_serializing.or(attrs.skip_serializing_if.as_ref().map(|(_, span)| *span)) {
+                if field.ident.is_none() {
+                    return Err(syn::Error::new(span, "#[reflect(skip_serializing)] and #[reflect(skip_serializing_if = \"...\")] can only be used for named fields."));
+                }
+                if attrs.skip_serializing.is_some() && attrs.skip_serializing_if.is_some() {
+                    return Err(syn::Error::new(span, "#[reflect(skip_serializing)] and #[reflect(skip_serializing_if = \"...\")] cannot be used together."));
+                }
+                if attrs.ignore.is_some() {
+                    return Err(syn::Error::new(span, "Ignored fields are never serialized, `skip_serializing` is unnecessary."));
+                }
+                if attrs.skip_serializing_if.is_some() && attrs.dynamic.is_some() {
+                    return Err(syn::Error::new(span, "#[reflect(skip_serializing_if = \"...\")] cannot be used with #[reflect(dynamic)]."));
+                }
+            }
+
+            if let Some(span) = attrs.dynamic {
+                if let Some(inner) = generic_argument(&field.ty, "Option") && is_boxed_dyn_reflect(inner) {
+                    // `Option<Box<dyn Reflect>>` is reflected by itself.
+                    attrs.dynamic = None;
+                } else if !is_boxed_dyn_reflect(&field.ty) {
+                    return Err(syn::Error::new(
+                        span,
+                        "#[reflect(dynamic)] requires a field of type `Box<dyn Reflect>` or `Option<Box<dyn Reflect>>`.",
+                    ));
+                }
+            }
 
             let reflection_index = if attrs.ignore.is_some() {
                 None
@@ -92,10 +272,17 @@ impl<'a> ReflectDerive<'a> {
             });
         }
 
+        // The names of flattened fields are only known at runtime.
+        let names = res.iter()
+            .filter(|field| field.attrs.ignore.is_none() && field.attrs.flatten.is_none())
+            .map(|field| (field.field_name(), field.data.span()));
+        check_duplicate_names(names)?;
+
         Ok(res)
     }
 
-    fn collect_enum_variants(variants: &'a Punctuated<Variant, Comma>) -> syn::Result<Vec<EnumVariant<'a>>> {
+    /// `rename_all` renames the variants without `#[reflect(rename = "...")]`.
+    fn collect_enum_variants(variants: &'a Punctuated<Variant, Comma>, rename_all: Option<RenameRule>) -> syn::Result<Vec<EnumVariant<'a>>> {
         if variants.is_empty() {
             return Err(syn::Error::new(Span::call_site(), "reflection macros do not support empty enum."));
         }
@@ -103,7 +290,15 @@ impl<'a> ReflectDerive<'a> {
         let mut res: Vec<EnumVariant<'a>> = Vec::with_capacity(variants.len());
 
         for variant in variants.iter() {
-            let fields = Self::colloct_struct_field(&variant.fields)?;
+            let mut attrs = FieldAttributes::parse_attrs(&variant.attrs)?;
+            if attrs.rename.is_none() && let Some(rule) = rename_all {
+                attrs.rename = Some((rule.apply_to_variant(&variant.ident.to_string()), variant.ident.span()));
+            }
+
+            let fields = Self::colloct_struct_field(&variant.fields, attrs.rename_all.map(|(rule, _)| rule))?;
+            if let Some(span) = fields.iter().find_map(|field| field.attrs.flatten) {
+                return Err(syn::Error::new(span, "#[reflect(flatten)] cannot be used for enum variant fields."));
+            }
             let variant_fields = match variant.fields {
                 Fields::Named(..) => EnumVariantFields::Named(fields),
                 Fields::Unnamed(..) => EnumVariantFields::Unnamed(fields),
@@ -112,7 +307,7 @@ impl<'a> ReflectDerive<'a> {
             let variant_item = EnumVariant { 
                 data: variant, 
                 fields: variant_fields, 
-                attrs: FieldAttributes::parse_attrs(&variant.attrs)?,
+                attrs,
             };
 
             if let Some(span) = variant_item.attrs.ignore {
@@ -122,7 +317,50 @@ impl<'a> ReflectDerive<'a> {
             res.push(variant_item);
         }
 
+        check_duplicate_names(res.iter().map(|variant| (variant.variant_name(), variant.data.span())))?;
+
         Ok(res)
     }
     
 }
+
+/// Renamed fields and variants must not share a name with each other.
+fn check_duplicate_names(names: impl Iterator<Item = (String, Span)>) -> syn::Result<()> {
+    let mut seen: Vec<String> = Vec::new();
+    for (name, span) in names {
+        if seen.contains(&name) {
+            return Err(syn::Error::new(span, format_args!("Duplicate reflected name `{name}`, check `rename` and `rename_all`.")));
+        }
+        seen.push(name);
+    }
+    Ok(())
+}
+
+/// The single generic argument of `ty` if it is a path ending with `name`, e.g. `Option<T>`.
+fn generic_argument<'t>(ty: &'t Type, name: &str) -> Option<&'t Type> {
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    if segment.ident != name {
+        return None;
+    }
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    match args.args.first() {
+        Some(GenericArgument::Type(inner)) if args.args.len() == 1 => Some(inner),
+        _ => None,
+    }
+}
+
+/// Whether `ty` is `Box<dyn Reflect>`, checked by name.
+fn is_boxed_dyn_reflect(ty: &Type) -> bool {
+    let Some(Type::TraitObject(object)) = generic_argument(ty, "Box") else {
+        return false;
+    };
+    object.bounds.iter().any(|bound| match bound {
+        TypeParamBound::Trait(bound) => bound.path.segments.last().is_some_and(|s| s.ident == "Reflect"),
+        _ => false,
+    })
+}
@@ -6,6 +6,8 @@ use crate::derive_data::{FieldAttributes, ReflectMeta, StructField};
 pub(crate) struct ReflectEnum<'a> {
     pub meta: ReflectMeta<'a>,
     pub variants: Vec<EnumVariant<'a>>,
+    /// Whether the enum has a `#[repr(...)]` attribute.
+    pub has_repr: bool,
 }
 
 /// Represents a variant on an enum.
@@ -25,6 +27,14 @@ pub(crate) enum EnumVariantFields<'a> {
 }
 
 impl<'a> EnumVariant<'a> {
+    /// The name of the variant in the reflection API, `#[reflect(rename = "...")]` if present.
+    pub fn variant_name(&self) -> String {
+        match &self.attrs.rename {
+            Some((name, _)) => name.clone(),
+            None => self.data.ident.to_string(),
+        }
+    }
+
     /// The complete set of fields in this variant.
     pub fn fields(&self) -> &[StructField<'a>] {
         match &self.fields {
@@ -41,7 +51,12 @@ impl<'a> EnumVariant<'a> {
     }
 
     /// Generates a `TokenStream` for `VariantInfo` construction.
-    pub fn to_info_tokens(&self, vct_reflect_path: &syn::Path) -> proc_macro2::TokenStream {
+    /// `discriminant` is the expression of the discriminant value, if it should be recorded.
+    pub fn to_info_tokens(
+        &self,
+        vct_reflect_path: &syn::Path,
+        discriminant: Option<proc_macro2::TokenStream>,
+    ) -> proc_macro2::TokenStream {
         let variant_info_path = crate::path::variant_info_(vct_reflect_path);
 
         let variant_info_kind = match &self.fields {
@@ -60,7 +75,7 @@ impl<'a> EnumVariant<'a> {
             .active_fields()
             .map(|field| field.to_info_tokens(vct_reflect_path));
 
-        let variant_name = &self.data.ident.to_string();
+        let variant_name = self.variant_name();
         let args = match &self.fields {
             EnumVariantFields::Unit => quote!(#variant_name),
             _ => {
@@ -74,9 +89,14 @@ impl<'a> EnumVariant<'a> {
         // If feature is diabled, this function will return a empty TokenStream, so it's safe.
         let with_docs = self.attrs.docs.get_expression_with();
 
+        let with_discriminant = discriminant.map(|expr| quote! {
+            .with_discriminant(#expr)
+        });
+
         quote! {
             #variant_info_path::#variant_info_kind(
                 #info_struct_path::new( #args )
+                    #with_discriminant
                     #with_custom_attributes
                     #with_docs
             )
@@ -101,13 +121,46 @@ impl<'a> ReflectEnum<'a> {
     }
 
     /// Returns the given ident as a qualified unit variant of this enum.
+    ///
+    /// `Self` is used so that prelude variants (such as `Ok`) are not reported as unnecessary qualifications,
+    /// remote enums use the remote path, see [`ReflectMeta::value_path`].
     pub fn variant_path(&self, variant: &Ident) -> proc_macro2::TokenStream {
-        let name = self.meta.type_path_parser().real_ident();
+        let value_path = self.meta.value_path();
         quote! {
-            #name::#variant
+            #value_path::#variant
         }
     }
 
+    /// Returns the discriminant expression of each variant.
+    ///
+    /// Discriminants are only recorded for enums with a `#[repr(...)]` attribute or explicit discriminants,
+    /// implicit values follow the previous explicit one like the compiler does.
+    fn discriminant_exprs(&self) -> Vec<Option<proc_macro2::TokenStream>> {
+        let has_explicit = self.variants.iter().any(|variant| variant.data.discriminant.is_some());
+        if !self.has_repr && !has_explicit {
+            return self.variants.iter().map(|_| None).collect();
+        }
+
+        let mut base: Option<&syn::Expr> = None;
+        let mut offset: i128 = 0;
+        self.variants
+            .iter()
+            .map(|variant| {
+                if let Some((_, expr)) = &variant.data.discriminant {
+                    base = Some(expr);
+                    offset = 0;
+                }
+                let expr = match base {
+                    Some(base) if offset == 0 => quote! { ((#base) as i128) },
+                    Some(base) => quote! { ((#base) as i128 + #offset) },
+                    None => quote! { #offset },
+                };
+                offset += 1;
+                Some(expr)
+            })
+            .collect()
+    }
+
     pub fn to_info_tokens(&self) -> proc_macro2::TokenStream {
         let vct_reflect_path = self.meta.vct_reflect_path();
 
@@ -116,7 +169,8 @@ impl<'a> ReflectEnum<'a> {
         let info_struct_path = crate::path::enum_info_(vct_reflect_path);
 
         let variant_infos = self.variants.iter()
-            .map(|variant| variant.to_info_tokens(vct_reflect_path));
+            .zip(self.discriminant_exprs())
+            .map(|(variant, discriminant)| variant.to_info_tokens(vct_reflect_path, discriminant));
 
         // See [`CustomAttributes::get_expression_with`]
         let with_custom_attributes = self.meta.with_custom_attributes_expression();
@@ -128,7 +182,7 @@ impl<'a> ReflectEnum<'a> {
 
         quote! {
             #type_info_path::Enum(
-                #info_struct_path::::new::<Self>(&[ #(#variant_infos),* ])
+                #info_struct_path::new::<Self>(&[ #(#variant_infos),* ])
                     #with_custom_attributes
                     #with_generics
                     #with_docs
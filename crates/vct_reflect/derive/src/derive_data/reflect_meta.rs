@@ -1,7 +1,9 @@
-use syn::{Path, Token, punctuated::Punctuated};
-use quote::quote;
+use proc_macro2::TokenTree;
+use syn::{Path, Token, Type, punctuated::Punctuated};
+use quote::{ToTokens, quote};
+use crate::path::fp::{SendFP, SyncFP};
 use crate::derive_data::{
-    TypeAttributes, TypePathParser,
+    CustomAttributes, TypeAttributes, TypePathParser,
 };
 
 pub(crate) struct ReflectMeta<'a> {
@@ -11,6 +13,14 @@ pub(crate) struct ReflectMeta<'a> {
     type_path_parser: TypePathParser<'a>,
     /// A cached instance of the path to the `vct_reflect` crate.
     vct_reflect_path: Path,
+    /// The types of the active fields, used to bound generic impls.
+    field_types: Vec<&'a Type>,
+    /// The types of the `#[reflect(flatten)]` fields, which must also be `Struct`.
+    flattened_types: Vec<&'a Type>,
+    /// Custom attributes of the generic parameters, in declaration order.
+    generic_attributes: Vec<CustomAttributes>,
+    /// The wrapped type of `#[reflect_remote(...)]`, whose value is the field `.0` of `Self`.
+    remote: Option<&'a Path>,
 }
 
 impl<'a> ReflectMeta<'a> {
@@ -19,9 +29,68 @@ impl<'a> ReflectMeta<'a> {
             attrs,
             type_path_parser,
             vct_reflect_path: crate::path::vct_reflect(),
+            field_types: Vec::new(),
+            flattened_types: Vec::new(),
+            generic_attributes: Vec::new(),
+            remote: None,
         }
     }
 
+    /// Sets the remote type, see [`ReflectMeta::value_path`].
+    pub fn set_remote(&mut self, remote: Option<&'a Path>) {
+        self.remote = remote;
+    }
+
+    /// The path used to construct and match the reflected value,
+    /// `Self` or the remote type of `#[reflect_remote(...)]`.
+    pub fn value_path(&self) -> proc_macro2::TokenStream {
+        match self.remote {
+            Some(remote) => remote.to_token_stream(),
+            None => quote!(Self),
+        }
+    }
+
+    /// Wraps a value constructed with [`ReflectMeta::value_path`] into `Self`.
+    pub fn wrap_value(&self, value: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        match self.remote {
+            Some(_) => quote!(Self(#value)),
+            None => value,
+        }
+    }
+
+    /// The reflected value of `this: &Self` or `&mut Self`,
+    /// `this` itself or `&this.0` for remote types.
+    pub fn value_ref(&self, this: proc_macro2::TokenStream, mutable: bool) -> proc_macro2::TokenStream {
+        match (self.remote, mutable) {
+            (None, _) => this,
+            (Some(_), false) => quote!(&#this.0),
+            (Some(_), true) => quote!(&mut #this.0),
+        }
+    }
+
+    /// Accesses a field of `this: Self`, `this.field` or `this.0.field` for remote types.
+    pub fn field_access(&self, this: proc_macro2::TokenStream, member: &syn::Member) -> proc_macro2::TokenStream {
+        match self.remote {
+            Some(_) => quote!(#this.0.#member),
+            None => quote!(#this.#member),
+        }
+    }
+
+    /// Sets the types of the active fields, see [`ReflectMeta::where_clause`].
+    pub fn set_field_types(&mut self, field_types: impl Iterator<Item = &'a Type>) {
+        self.field_types = field_types.collect();
+    }
+
+    /// Sets the types of the flattened fields, see [`ReflectMeta::where_clause`].
+    pub fn set_flattened_types(&mut self, flattened_types: impl Iterator<Item = &'a Type>) {
+        self.flattened_types = flattened_types.collect();
+    }
+
+    /// Sets the custom attributes of the generic parameters, see [`ReflectMeta::with_generics_expression`].
+    pub fn set_generic_attributes(&mut self, generic_attributes: Vec<CustomAttributes>) {
+        self.generic_attributes = generic_attributes;
+    }
+
     pub fn vct_reflect_path(&self) -> &Path {
         &self.vct_reflect_path
     }
@@ -34,6 +103,73 @@ impl<'a> ReflectMeta<'a> {
         &self.attrs
     }
 
+    /// The `where` clause of the `TypePath` impl.
+    ///
+    /// Besides the predicates written on the type, every type parameter must be `TypePath`.
+    pub fn type_path_where_clause(&self) -> proc_macro2::TokenStream {
+        let type_path_ = crate::path::type_path_(&self.vct_reflect_path);
+        let generics = self.type_path_parser.generics();
+        let predicates = generics.where_clause.as_ref().map(|clause| &clause.predicates);
+        let params = generics.type_params().map(|param| &param.ident);
+
+        quote! {
+            where #(#params: #type_path_,)* #predicates
+        }
+    }
+
+    /// The `where` clause of the other reflection impls.
+    ///
+    /// Extends [`ReflectMeta::type_path_where_clause`] with `Self: Send + Sync`,
+    /// and bounds the active field types that mention a type parameter by the traits
+    /// the generated code relies on (flattened field types must also be `Struct`). Other field types are left as-is,
+    /// so non-generic types get the same errors as before.
+    ///
+    /// `#[reflect(no_bounds)]` skips the field bounds, and the predicates of
+    /// `#[reflect(where ...)]` are appended.
+    pub fn where_clause(&self) -> proc_macro2::TokenStream {
+        let vct_reflect_path = &self.vct_reflect_path;
+        let type_path_ = crate::path::type_path_(vct_reflect_path);
+        let from_reflect_ = crate::path::from_reflect_(vct_reflect_path);
+        let typed_ = crate::path::typed_(vct_reflect_path);
+        let get_type_traits_ = crate::path::get_type_traits_(vct_reflect_path);
+        let struct_ = crate::path::struct_(vct_reflect_path);
+
+        let generics = self.type_path_parser.generics();
+        let predicates = generics.where_clause.as_ref().map(|clause| &clause.predicates);
+        let params = generics.type_params().map(|param| &param.ident).collect::<Vec<_>>();
+
+        let field_bounds = if self.attrs.impl_switchs.impl_get_type_traits {
+            quote!(#from_reflect_ + #typed_ + #get_type_traits_)
+        } else {
+            quote!(#from_reflect_ + #typed_)
+        };
+        let mut field_types: Vec<&Type> = Vec::new();
+        let mut flattened_types: Vec<&Type> = Vec::new();
+        if self.attrs.no_bounds.is_none() {
+            for ty in &self.field_types {
+                if !field_types.contains(ty) && mentions_any(ty.to_token_stream(), &params) {
+                    field_types.push(ty);
+                }
+            }
+            for ty in &self.flattened_types {
+                if !flattened_types.contains(ty) && mentions_any(ty.to_token_stream(), &params) {
+                    flattened_types.push(ty);
+                }
+            }
+        }
+        let custom_where = &self.attrs.custom_where;
+
+        quote! {
+            where
+                Self: #SendFP + #SyncFP,
+                #(#params: #type_path_,)*
+                #(#field_types: #field_bounds,)*
+                #(#flattened_types: #struct_,)*
+                #(#custom_where,)*
+                #predicates
+        }
+    }
+
     pub fn with_docs_expression(&self) -> proc_macro2::TokenStream {
         self.attrs.docs.get_expression_with()
     }
@@ -50,7 +186,9 @@ impl<'a> ReflectMeta<'a> {
         let const_param_info_ = crate::path::const_param_info_(vct_reflect_path);
         let alloc_utils_ = crate::path::alloc_utils_(vct_reflect_path);
 
-        let generics = self.type_path_parser.generics().params.iter().filter_map(|param| {
+        let generics = self.type_path_parser.generics().params.iter().enumerate().filter_map(|(index, param)| {
+            let with_custom_attributes = self.generic_attributes.get(index)
+                .map(|attributes| attributes.get_expression_with(vct_reflect_path));
             match param {
                 syn::GenericParam::Lifetime(_) => None,
                 syn::GenericParam::Type(type_param) => {
@@ -65,6 +203,7 @@ impl<'a> ReflectMeta<'a> {
                                 #alloc_utils_::Cow::Borrowed( #name ) 
                             ) 
                             #with_default
+                            #with_custom_attributes
                         )
                     })
                 },
@@ -82,6 +221,7 @@ impl<'a> ReflectMeta<'a> {
                                 #alloc_utils_::Cow::Borrowed(#name),
                             )
                             #with_default
+                            #with_custom_attributes
                         )
                     })
                 },
@@ -120,3 +260,12 @@ impl<'a> ReflectMeta<'a> {
     }
 }
 
+
+/// Returns `true` if `tokens` contain one of the `idents`.
+fn mentions_any(tokens: proc_macro2::TokenStream, idents: &[&syn::Ident]) -> bool {
+    tokens.into_iter().any(|tree| match tree {
+        TokenTree::Ident(ident) => idents.contains(&&ident),
+        TokenTree::Group(group) => mentions_any(group.stream(), idents),
+        _ => false,
+    })
+}
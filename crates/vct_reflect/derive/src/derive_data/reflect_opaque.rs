@@ -1,4 +1,4 @@
-use crate::TypeAttributes;
+use crate::{TypeAttributes, derive_data::ReflectDerive};
 use syn::{
     Attribute, Generics, PathSegment, Ident, Path, 
     Token, parenthesized, parse::ParseStream, token::Paren
@@ -27,6 +27,7 @@ impl ReflectOpaqueParser {
 
         let mut generics = input.parse::<Generics>()?;
         generics.where_clause = input.parse()?;
+        ReflectDerive::reject_lifetimes(&generics)?;
 
         let custom_path = if let Some(mut path) = custom_path {
             let name = PathSegment::from(custom_name.unwrap_or_else(|| type_ident.clone()));
@@ -58,23 +59,23 @@ impl ReflectOpaqueParser {
 
     fn parse_custom_path(input: ParseStream) -> syn::Result<(Option<Path>, Option<Ident>)> {
         if input.peek(Paren) {
-            let path;
-            parenthesized!(path in input);
-            input.parse::<Token![in]>()?;
-            if input.peek(Token![::]) {
-                return Err(input.error("did not expect a leading double colon (`::`)"));
+            let content;
+            parenthesized!(content in input);
+            content.parse::<Token![in]>()?;
+            if content.peek(Token![::]) {
+                return Err(content.error("did not expect a leading double colon (`::`)"));
             }
-            let path = Path::parse_mod_style(input)?;
+            let path = Path::parse_mod_style(&content)?;
             if path.segments.is_empty() {
-                return Err(input.error("expected a path"))
+                return Err(content.error("expected a path"))
             }
 
-            if !input.peek(Token![as]) {
+            if !content.peek(Token![as]) {
                 return Ok((Some(path), None));
             }
 
-            input.parse::<Token![as]>()?;
-            let name: Ident = input.parse()?;
+            content.parse::<Token![as]>()?;
+            let name: Ident = content.parse()?;
             Ok((Some(path), Some(name)))
             
         } else {
@@ -30,28 +30,120 @@ impl StructField<'_> {
         };
 
         let name = match &self.data.ident {
-            Some(ident) => ident.to_string().to_token_stream(), // String Literal
+            Some(_) => self.field_name().to_token_stream(), // String Literal
             None => match self.reflection_index {
                 Some(index) => index.to_token_stream(),
                 None => panic!("`StructField::to_info_tokens` is only allowed to be called for active fields."),
             }
         };
 
-        let ty = &self.data.ty;
+        let ty = self.reflected_ty(vct_reflect_path);
+        let new_fn = if self.attrs.dynamic.is_some() {
+            quote!(new_unsized)
+        } else {
+            quote!(new)
+        };
+
+        let mut custom_attributes = self.attrs.custom_attributes.clone();
+        if let Some(skip_serializing) = self.skip_serializing_attribute(vct_reflect_path) {
+            custom_attributes.push(skip_serializing).unwrap();
+        }
 
         // See [`CustomAttributes::get_expression_with`]
-        let with_custom_attributes = self.attrs.custom_attributes.get_expression_with(vct_reflect_path);
+        let with_custom_attributes = custom_attributes.get_expression_with(vct_reflect_path);
         // See [`ReflectDocs::get_expression_with`]
         // If feature is diabled, this function will return a empty TokenStream, so it's safe.
         let with_docs = self.attrs.docs.get_expression_with();
 
         quote! {
-            #field_info::new::<#ty>(#name)
+            #field_info::#new_fn::<#ty>(#name)
                 #with_custom_attributes
                 #with_docs
         }
     }
 
+    /// The `SkipSerializing` custom attribute of `skip_serializing` and `skip_serializing_if`.
+    fn skip_serializing_attribute(&self, vct_reflect_path: &syn::Path) -> Option<syn::Expr> {
+        let skip_serializing_ = crate::path::skip_serializing_(vct_reflect_path);
+
+        if self.attrs.skip_serializing.is_some() {
+            return Some(syn::parse_quote!(#skip_serializing_::always()));
+        }
+
+        let (func, _) = self.attrs.skip_serializing_if.as_ref()?;
+        let reflect_ = crate::path::reflect_(vct_reflect_path);
+        let ty = &self.data.ty;
+        Some(syn::parse_quote! {
+            #skip_serializing_::when(|value: &dyn #reflect_| {
+                // Dynamic values are always serialized.
+                match <dyn #reflect_>::downcast_ref::<#ty>(value) {
+                    Some(value) => #func(value),
+                    None => false,
+                }
+            })
+        })
+    }
+
+    /// The type exposed to the reflection API, `dyn Reflect` for `#[reflect(dynamic)]` fields.
+    pub fn reflected_ty(&self, vct_reflect_path: &syn::Path) -> proc_macro2::TokenStream {
+        if self.attrs.dynamic.is_some() {
+            let reflect_ = crate::path::reflect_(vct_reflect_path);
+            quote!(dyn #reflect_)
+        } else {
+            self.data.ty.to_token_stream()
+        }
+    }
+
+    /// Converts `input: &dyn Reflect` into an `Option` of the field type.
+    ///
+    /// `#[reflect(dynamic)]` fields take a clone of the input, other fields use `FromReflect`.
+    pub fn get_from_reflect_tokens(&self, vct_reflect_path: &syn::Path, input: impl ToTokens) -> proc_macro2::TokenStream {
+        use crate::path::fp::ResultFP;
+
+        let reflect_ = crate::path::reflect_(vct_reflect_path);
+        let from_reflect_ = crate::path::from_reflect_(vct_reflect_path);
+        let ty = &self.data.ty;
+
+        if self.attrs.dynamic.is_some() {
+            quote!(#ResultFP::ok(#reflect_::reflect_clone(#input)))
+        } else {
+            quote!(<#ty as #from_reflect_>::from_reflect(#input))
+        }
+    }
+
+    /// The default value of the field, from `#[reflect(default = "...")]` or `Default::default()`.
+    pub fn get_default_tokens(&self) -> proc_macro2::TokenStream {
+        use crate::path::fp::DefaultFP;
+
+        match &self.attrs.default_fn {
+            Some(func) => quote!(#func()),
+            None => {
+                let ty = &self.data.ty;
+                quote!(<#ty as #DefaultFP>::default())
+            },
+        }
+    }
+
+    /// Clones the field through `field_ref: &FieldType`, returns `Result<FieldType, ReflectCloneError>`.
+    ///
+    /// Ignored fields are only cloneable with `#[reflect(clone_with = "...")]`.
+    pub fn get_reflect_clone_tokens(&self, vct_reflect_path: &syn::Path, field_ref: impl ToTokens) -> proc_macro2::TokenStream {
+        use crate::path::fp::ResultFP;
+
+        let reflect_ = crate::path::reflect_(vct_reflect_path);
+        let macro_exports_ = crate::path::macro_exports_(vct_reflect_path);
+        let ty = &self.data.ty;
+
+        if let Some((func, _)) = &self.attrs.clone_with {
+            let reflect_clone_error_ = crate::path::reflect_clone_error_(vct_reflect_path);
+            quote!(#ResultFP::<#ty, #reflect_clone_error_>::Ok(#func(#field_ref)))
+        } else if self.attrs.dynamic.is_some() {
+            quote!(#reflect_::reflect_clone(&**#field_ref))
+        } else {
+            quote!(#macro_exports_::reflect_clone_field::<#ty>(#field_ref))
+        }
+    }
+
     /// Returns a token stream for generating a `FieldId` for this field.
     pub fn field_id(&self, vct_reflect_path: &syn::Path) -> proc_macro2::TokenStream {
         let field_id_ = crate::path::field_id_(vct_reflect_path);
@@ -88,13 +180,16 @@ impl StructField<'_> {
             panic!("Non-active fields cannot obtain reflect_accessor.");
         }
         match &self.data.ident {
-            Some(ident) => ident.to_string().to_token_stream(),
+            Some(_) => self.field_name().to_token_stream(),
             None => self.reflection_index.to_token_stream(),
         }
     }
 
-    /// Return the actual field string name.
+    /// Return the field string name in the reflection API, `#[reflect(rename = "...")]` if present.
     pub fn field_name(&self) -> String {
+        if let Some((name, _)) = &self.attrs.rename {
+            return name.clone();
+        }
         match &self.data.ident {
             Some(ident) => ident.clone().to_string(),
             None => self.declaration_index.to_string(),
@@ -121,6 +216,18 @@ impl<'a> ReflectStruct<'a> {
             .filter(|field| field.attrs.ignore.is_none())
     }
 
+    /// Get an iterator of active fields which are not `#[reflect(flatten)]`.
+    pub fn direct_fields(&self) -> impl Iterator<Item = &StructField<'a>> {
+        self.active_fields()
+            .filter(|field| field.attrs.flatten.is_none())
+    }
+
+    /// Get an iterator of `#[reflect(flatten)]` fields, whose fields follow the direct fields.
+    pub fn flattened_fields(&self) -> impl Iterator<Item = &StructField<'a>> {
+        self.active_fields()
+            .filter(|field| field.attrs.flatten.is_some())
+    }
+
     pub fn to_info_tokens(&self, is_tuple: bool) -> proc_macro2::TokenStream {
         let vct_reflect_path = self.meta.vct_reflect_path();
 
@@ -138,9 +245,17 @@ impl<'a> ReflectStruct<'a> {
             crate::path::struct_info_(vct_reflect_path)
         };
 
+        // `#[repr(transparent)]` remote wrappers share the layout of the remote type.
+        let value_path = self.meta.value_path();
         let field_infos = self
-            .active_fields()
-            .map(|field| field.to_info_tokens(vct_reflect_path));
+            .direct_fields()
+            .map(|field| {
+                let field_info = field.to_info_tokens(vct_reflect_path);
+                let member = field.to_member();
+                quote! {
+                    #field_info.with_offset(::core::mem::offset_of!(#value_path, #member))
+                }
+            });
 
         // See [`CustomAttributes::get_expression_with`]
         let with_custom_attributes = self.meta.with_custom_attributes_expression();
@@ -150,9 +265,30 @@ impl<'a> ReflectStruct<'a> {
         // See [`ReflectMeta::with_generics_expression`]
         let with_generics = self.meta.with_generics_expression();
 
+        let fields = if self.flattened_fields().next().is_none() {
+            quote!(&[ #(#field_infos),* ])
+        } else {
+            let alloc_utils_ = crate::path::alloc_utils_(vct_reflect_path);
+            let macro_exports_ = crate::path::macro_exports_(vct_reflect_path);
+            let flattened_fields = self.flattened_fields().map(|field| {
+                let member = field.to_member();
+                let ty = &field.data.ty;
+                quote! {
+                    __fields.extend(#macro_exports_::flatten_fields::<#ty>(::core::mem::offset_of!(#value_path, #member)));
+                }
+            });
+            quote! {
+                &{
+                    let mut __fields = #alloc_utils_::Vec::from([ #(#field_infos),* ]);
+                    #(#flattened_fields)*
+                    __fields
+                }
+            }
+        };
+
         quote! {
             #type_info_path::#type_info_kind(
-                #info_struct_path::new::<Self>(&[ #(#field_infos),* ])
+                #info_struct_path::new::<Self>(#fields)
                     #with_generics
                     #with_custom_attributes
                     #with_docs
@@ -177,10 +313,12 @@ pub(crate) struct FieldAccessors {
 impl FieldAccessors {
     pub fn new(info: &ReflectStruct) -> Self {
         let (fields_ref, fields_mut): (Vec<_>, Vec<_>) = info
-            .active_fields()
+            .direct_fields()
             .map(|field| {
-                let member = field.to_member();
-                ( quote!(&self.#member), quote!(&mut self.#member) )
+                let access = info.meta().field_access(quote!(self), &field.to_member());
+                // `#[reflect(dynamic)]` fields are accessed through the box.
+                let deref = field.attrs.dynamic.map(|_| quote!(*));
+                ( quote!(&#deref #access), quote!(&mut #deref #access) )
             })
             .unzip();
 
@@ -18,11 +18,11 @@ pub(crate) fn get_common_try_apply_tokens(meta: &ReflectMeta, input: &syn::Ident
         },
         None => quote! {
             if <dyn #reflect_>::is::<Self>(#input) {
-                if let Ok(__cloned) = #reflect_::reflect_clone(#input)
-                    && Ok(__val) = <dyn #reflect_>::take::<Self>(__cloned)
-                {
-                    *self = __val;
-                    return #ResultFP::Ok(());
+                if let #ResultFP::Ok(__cloned) = #reflect_::reflect_clone(#input) {
+                    if let #ResultFP::Ok(__val) = <dyn #reflect_>::take::<Self>(__cloned) {
+                        *self = __val;
+                        return #ResultFP::Ok(());
+                    }
                 }
             }
         },
@@ -31,7 +31,7 @@ pub(crate) fn get_common_try_apply_tokens(meta: &ReflectMeta, input: &syn::Ident
 
 /// Try `clone` or `reflect_clone`
 pub(crate) fn get_common_from_reflect_tokens(meta: &ReflectMeta, input: &syn::Ident) -> TokenStream {
-    use crate::path::fp::{OptionFP, CloneFP};
+    use crate::path::fp::{OptionFP, ResultFP, CloneFP};
 
     let vct_reflect_path = meta.vct_reflect_path();
     let reflect_ = crate::path::reflect_(vct_reflect_path);
@@ -44,10 +44,10 @@ pub(crate) fn get_common_from_reflect_tokens(meta: &ReflectMeta, input: &syn::Id
         },
         None => quote! {
             if <dyn #reflect_>::is::<Self>(#input) {
-                if let Ok(__cloned) = #reflect_::reflect_clone(#input)
-                    && Ok(__val) = <dyn #reflect_>::take::<Self>(__cloned)
-                {
-                    return #OptionFP::Some(__val);
+                if let #ResultFP::Ok(__cloned) = #reflect_::reflect_clone(#input) {
+                    if let #ResultFP::Ok(__val) = <dyn #reflect_>::take::<Self>(__cloned) {
+                        return #OptionFP::Some(__val);
+                    }
                 }
             }
         },
@@ -74,6 +74,26 @@ pub(crate) fn get_common_partial_eq_impl(meta: &ReflectMeta) -> TokenStream  {
     }
 }
 
+pub(crate) fn get_common_partial_cmp_impl(meta: &ReflectMeta) -> TokenStream  {
+    use crate::path::fp::{OptionFP, PartialOrdFP};
+    let vct_reflect_path = meta.vct_reflect_path();
+    let reflect_ = crate::path::reflect_(vct_reflect_path);
+
+    if let Some(span) = meta.attrs().avail_traits.partial_ord {
+        quote_spanned! { span =>
+            #[inline]
+            fn reflect_partial_cmp(&self, other: &dyn #reflect_) -> #OptionFP<::core::cmp::Ordering> {
+                if let #OptionFP::Some(value) = other.downcast_ref::<Self>() {
+                    return #PartialOrdFP::partial_cmp(self, value);
+                }
+                #OptionFP::None
+            }
+        }
+    } else {
+        crate::utils::empty()
+    }
+}
+
 pub(crate) fn get_common_hash_impl(meta: &ReflectMeta) -> TokenStream {
     use crate::path::fp::{OptionFP, HashFP, HasherFP};
     let vct_reflect_path = meta.vct_reflect_path();
@@ -109,3 +129,32 @@ pub(crate) fn get_common_debug_impl(meta: &ReflectMeta) -> TokenStream {
 }
 
 
+
+/// Types with `Clone` are cloned into the destination with [`Clone::clone_from`].
+pub(crate) fn get_common_clone_into_impl(meta: &ReflectMeta) -> TokenStream {
+    use crate::path::fp::{CloneFP, OptionFP, ResultFP};
+    let vct_reflect_path = meta.vct_reflect_path();
+    let reflect_ = crate::path::reflect_(vct_reflect_path);
+    let alloc_utils_ = crate::path::alloc_utils_(vct_reflect_path);
+    let reflect_clone_error_ = crate::path::reflect_clone_error_(vct_reflect_path);
+    let type_path_ = crate::path::type_path_(vct_reflect_path);
+    let dynamic_type_path_ = crate::path::dynamic_type_path_(vct_reflect_path);
+
+    if let Some(span) = meta.attrs().avail_traits.clone {
+        quote_spanned! { span =>
+            #[inline]
+            fn reflect_clone_into(&self, dst: &mut dyn #reflect_) -> #ResultFP<(), #reflect_clone_error_> {
+                if let #OptionFP::Some(dst) = <dyn #reflect_>::downcast_mut::<Self>(dst) {
+                    <Self as #CloneFP>::clone_from(dst, self);
+                    return #ResultFP::Ok(());
+                }
+                #ResultFP::Err(#reflect_clone_error_::MismatchedTypes {
+                    from_type: #alloc_utils_::Cow::Borrowed(<Self as #type_path_>::type_path()),
+                    to_type: #alloc_utils_::Cow::Owned(#alloc_utils_::ToOwned::to_owned(#dynamic_type_path_::reflect_type_path(dst))),
+                })
+            }
+        }
+    } else {
+        crate::utils::empty()
+    }
+}
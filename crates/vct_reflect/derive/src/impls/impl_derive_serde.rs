@@ -0,0 +1,41 @@
+use proc_macro2::TokenStream;
+use quote::quote_spanned;
+use crate::derive_data::ReflectMeta;
+
+/// serde::Serialize and serde::Deserialize, see `#[reflect(derive_serde)]`.
+///
+/// Both impls are routed through the reflection serializers,
+/// so the output matches the one of `InternalSerializer`.
+pub(crate) fn impl_derive_serde(meta: &ReflectMeta) -> TokenStream {
+    let Some(span) = meta.attrs().derive_serde else {
+        return crate::utils::empty();
+    };
+
+    let vct_reflect_path = meta.vct_reflect_path();
+    let serde_ = crate::path::serde_(vct_reflect_path);
+    let reflect_serialize_ = crate::path::reflect_serialize_(vct_reflect_path);
+    let reflect_deserialize_ = crate::path::reflect_deserialize_(vct_reflect_path);
+
+    let parser = meta.type_path_parser();
+    let real_ident = parser.real_ident();
+    let (impl_generics, ty_generics, _) = parser.generics().split_for_impl();
+    let where_clause = meta.where_clause();
+
+    let mut de_generics = parser.generics().clone();
+    de_generics.params.insert(0, syn::parse_quote!('__de));
+    let (de_impl_generics, _, _) = de_generics.split_for_impl();
+
+    quote_spanned! { span =>
+        impl #impl_generics #serde_::Serialize for #real_ident #ty_generics #where_clause {
+            fn serialize<__S: #serde_::Serializer>(&self, serializer: __S) -> ::core::result::Result<__S::Ok, __S::Error> {
+                #reflect_serialize_(self, serializer)
+            }
+        }
+
+        impl #de_impl_generics #serde_::Deserialize<'__de> for #real_ident #ty_generics #where_clause {
+            fn deserialize<__D: #serde_::Deserializer<'__de>>(deserializer: __D) -> ::core::result::Result<Self, __D::Error> {
+                #reflect_deserialize_(deserializer)
+            }
+        }
+    }
+}
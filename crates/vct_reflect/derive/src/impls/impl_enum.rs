@@ -1,7 +1,7 @@
 use proc_macro2::{TokenStream, Span};
 use quote::{quote, quote_spanned};
 use syn::Ident;
-use crate::{ReflectMeta, derive_data::{EnumVariantFields, ReflectEnum, StructField}, impls::{get_common_debug_impl, get_common_from_reflect_tokens, get_common_hash_impl, get_common_partial_eq_impl, get_common_try_apply_tokens, impl_trait_get_type_traits, impl_trait_reflect, impl_trait_type_path, impl_trait_typed}};
+use crate::{ReflectMeta, derive_data::{EnumVariantFields, ReflectEnum, StructField}, impls::{get_common_debug_impl, get_common_from_reflect_tokens, get_common_hash_impl, get_common_partial_cmp_impl, get_common_partial_eq_impl, get_common_try_apply_tokens, impl_trait_get_type_traits, impl_trait_reflect, impl_trait_type_path, impl_trait_typed}};
 
 
 
@@ -35,6 +35,7 @@ pub(crate) fn impl_enum(info: &ReflectEnum) -> TokenStream {
         let to_dynamic_tokens = get_enum_to_dynamic_impl(meta);
         let reflect_clone_tokens = get_enum_clone_impl(info);
         let reflect_partial_eq_tokens = get_common_partial_eq_impl(meta);
+        let reflect_partial_cmp_tokens = get_common_partial_cmp_impl(meta);
         let reflect_hash_tokens = get_common_hash_impl(meta);
         let reflect_debug_tokens = get_common_debug_impl(meta);
 
@@ -45,6 +46,7 @@ pub(crate) fn impl_enum(info: &ReflectEnum) -> TokenStream {
             to_dynamic_tokens,
             reflect_clone_tokens,
             reflect_partial_eq_tokens,
+            reflect_partial_cmp_tokens,
             reflect_hash_tokens,
             reflect_debug_tokens,
         )
@@ -54,7 +56,7 @@ pub(crate) fn impl_enum(info: &ReflectEnum) -> TokenStream {
 
     // trait: GetTypeTraits
     let get_type_traits_tokens = if meta.attrs().impl_switchs.impl_get_type_traits {
-        impl_trait_get_type_traits(meta, get_registry_dependencies(info))
+        impl_trait_get_type_traits(meta, get_registry_dependencies(info), &[])
     } else {
         crate::utils::empty()
     };
@@ -96,7 +98,8 @@ fn impl_trait_enum(info: &ReflectEnum) -> TokenStream {
 
     let parser = meta.type_path_parser();
     let real_ident = parser.real_ident();
-    let (impl_generics, ty_generics, where_clause) = parser.generics().split_for_impl();
+    let (impl_generics, ty_generics, _) = parser.generics().split_for_impl();
+    let where_clause = meta.where_clause();
 
     let mut enum_field = Vec::new();
     let mut enum_field_mut = Vec::new();
@@ -109,10 +112,13 @@ fn impl_trait_enum(info: &ReflectEnum) -> TokenStream {
     let mut enum_variant_index = Vec::new();
     let mut enum_variant_kind = Vec::new();
 
+    let this = meta.value_ref(quote!(self), false);
+    let this_mut = meta.value_ref(quote!(self), true);
+
 
     for (variant_index, variant) in info.variants().iter().enumerate() {
         let ident = &variant.data.ident;
-        let name = ident.to_string();
+        let name = variant.variant_name();
         let variant_path_ = info.variant_path(ident);
 
         let variant_type_ident = match variant.data.fields {
@@ -157,12 +163,13 @@ fn impl_trait_enum(info: &ReflectEnum) -> TokenStream {
                     let reflection_index = field.reflection_index.unwrap();
 
                     let declare_field = syn::Index::from(field.declaration_index);
+                    let (value, value_mut) = field_value_tokens(field);
 
                     enum_field_at.push(quote! {
-                        #variant_path_ { #declare_field : __value, .. } if #ref_index == #reflection_index => #OptionFP::Some(__value)
+                        #variant_path_ { #declare_field : __value, .. } if #ref_index == #reflection_index => #OptionFP::Some(#value)
                     });
                     enum_field_at_mut.push(quote! {
-                        #variant_path_ { #declare_field : __value, .. } if #ref_index == #reflection_index => #OptionFP::Some(__value)
+                        #variant_path_ { #declare_field : __value, .. } if #ref_index == #reflection_index => #OptionFP::Some(#value_mut)
                     });
                 });
 
@@ -173,20 +180,21 @@ fn impl_trait_enum(info: &ReflectEnum) -> TokenStream {
             EnumVariantFields::Named(fields) => {
                 let field_len = process_fields(fields, |field: &StructField| {
                     let field_ident = field.data.ident.as_ref().unwrap();
-                    let field_name = field_ident.to_string();
+                    let field_name = field.field_name();
                     let reflection_index = field.reflection_index.unwrap();
+                    let (value, value_mut) = field_value_tokens(field);
 
                     enum_field.push(quote! {
-                        #variant_path_{ #field_ident: __value, .. } if #ref_name == #field_name => #OptionFP::Some(__value)
+                        #variant_path_{ #field_ident: __value, .. } if #ref_name == #field_name => #OptionFP::Some(#value)
                     });
                     enum_field_mut.push(quote! {
-                        #variant_path_{ #field_ident: __value, .. } if #ref_name == #field_name => #OptionFP::Some(__value)
+                        #variant_path_{ #field_ident: __value, .. } if #ref_name == #field_name => #OptionFP::Some(#value_mut)
                     });
                     enum_field_at.push(quote! {
-                        #variant_path_{ #field_ident: __value, .. } if #ref_index == #reflection_index => #OptionFP::Some(__value)
+                        #variant_path_{ #field_ident: __value, .. } if #ref_index == #reflection_index => #OptionFP::Some(#value)
                     });
                     enum_field_at_mut.push(quote! {
-                        #variant_path_{ #field_ident: __value, .. } if #ref_index == #reflection_index => #OptionFP::Some(__value)
+                        #variant_path_{ #field_ident: __value, .. } if #ref_index == #reflection_index => #OptionFP::Some(#value_mut)
                     });
                     enum_index_of.push(quote! {
                         #variant_path_{ .. } if #ref_name == #field_name => #OptionFP::Some(#reflection_index)
@@ -206,42 +214,42 @@ fn impl_trait_enum(info: &ReflectEnum) -> TokenStream {
     quote! {
         impl #impl_generics #enum_ for #real_ident #ty_generics #where_clause {
             fn field(&self, #ref_name: &str) -> #OptionFP<&dyn #reflect_> {
-                    match self {
+                    match #this {
                     #(#enum_field,)*
                     _ => #OptionFP::None,
                 }
             }
 
             fn field_at(&self, #ref_index: usize) -> #OptionFP<&dyn #reflect_> {
-                match self {
+                match #this {
                     #(#enum_field_at,)*
                     _ => #OptionFP::None,
                 }
             }
 
             fn field_mut(&mut self, #ref_name: &str) -> #OptionFP<&mut dyn #reflect_> {
-                    match self {
+                    match #this_mut {
                     #(#enum_field_mut,)*
                     _ => #OptionFP::None,
                 }
             }
 
             fn field_at_mut(&mut self, #ref_index: usize) -> #OptionFP<&mut dyn #reflect_> {
-                match self {
+                match #this_mut {
                     #(#enum_field_at_mut,)*
                     _ => #OptionFP::None,
                 }
             }
 
             fn index_of(&self, #ref_name: &str) -> #OptionFP<usize> {
-                    match self {
+                    match #this {
                     #(#enum_index_of,)*
                     _ => #OptionFP::None,
                 }
             }
 
             fn name_at(&self, #ref_index: usize) -> #OptionFP<&str> {
-                    match self {
+                    match #this {
                     #(#enum_name_at,)*
                     _ => #OptionFP::None,
                 }
@@ -254,7 +262,7 @@ fn impl_trait_enum(info: &ReflectEnum) -> TokenStream {
 
             #[inline]
             fn field_len(&self) -> usize {
-                    match self {
+                    match #this {
                     #(#enum_field_len,)*
                     _ => 0,
                 }
@@ -262,7 +270,7 @@ fn impl_trait_enum(info: &ReflectEnum) -> TokenStream {
 
             #[inline]
             fn variant_name(&self) -> &str {
-                    match self {
+                    match #this {
                     #(#enum_variant_name,)*
                     _ => unreachable!(),
                 }
@@ -270,7 +278,7 @@ fn impl_trait_enum(info: &ReflectEnum) -> TokenStream {
 
             #[inline]
             fn variant_index(&self) -> usize {
-                    match self {
+                    match #this {
                     #(#enum_variant_index,)*
                     _ => unreachable!(),
                 }
@@ -278,7 +286,7 @@ fn impl_trait_enum(info: &ReflectEnum) -> TokenStream {
 
             #[inline]
             fn variant_kind(&self) -> #variant_kind_ {
-                    match self {
+                    match #this {
                     #(#enum_variant_kind,)*
                     _ => unreachable!(),
                 }
@@ -287,6 +295,17 @@ fn impl_trait_enum(info: &ReflectEnum) -> TokenStream {
     }
 }
 
+/// The values returned by field accessors, from the binding `__value`.
+///
+/// `#[reflect(dynamic)]` fields are accessed through the box.
+fn field_value_tokens(field: &StructField) -> (TokenStream, TokenStream) {
+    if field.attrs.dynamic.is_some() {
+        (quote!(&**__value), quote!(&mut **__value))
+    } else {
+        (quote!(__value), quote!(__value))
+    }
+}
+
 fn get_enum_try_apply_impl(info: &ReflectEnum) -> TokenStream {
     use crate::path::fp::{ResultFP, OptionFP};
 
@@ -300,7 +319,7 @@ fn get_enum_try_apply_impl(info: &ReflectEnum) -> TokenStream {
     let apply_error_ = crate::path::apply_error_(vct_reflect_path);
     let alloc_utils_ = crate::path::alloc_utils_(vct_reflect_path);
     let type_path_ = crate::path::type_path_(vct_reflect_path);
-    let from_reflect_ = crate::path::from_reflect_(vct_reflect_path);
+    let enum_try_apply_owned_ = crate::path::enum_try_apply_owned_(vct_reflect_path);
 
     let input_ = Ident::new("__input", Span::call_site());
 
@@ -311,13 +330,14 @@ fn get_enum_try_apply_impl(info: &ReflectEnum) -> TokenStream {
     for variant in info.variants.iter() {
         let ident = &variant.data.ident;
         let variant_path_ = info.variant_path(ident);
-        let variant_name_ = ident.to_string();
+        let variant_name_ = variant.variant_name();
+        let unit_value = meta.wrap_value(variant_path_.clone());
 
         match variant.data.fields {
             syn::Fields::Unit => {
                 match_tokens.extend(quote! {
                     #variant_name_ => {
-                        *self = #variant_path_;
+                        *self = #unit_value;
                         return #ResultFP::Ok(());
                     },
                 });
@@ -326,7 +346,7 @@ fn get_enum_try_apply_impl(info: &ReflectEnum) -> TokenStream {
                 if let Some(field) = variant.fields().iter().find(|f|f.attrs.ignore.is_some()) {
                     let field_name = field.field_name();
                     
-                    match_tokens.extend(quote! { span =>
+                    match_tokens.extend(quote! {
                         #variant_name_ => {
                            return #ResultFP::Err(
                                 #apply_error_::MissingEnumField {
@@ -341,13 +361,13 @@ fn get_enum_try_apply_impl(info: &ReflectEnum) -> TokenStream {
                 let mut clone_tokens = TokenStream::new();
 
                 for field in variant.fields().iter() {
-                    let field_ty = &field.data.ty;
                     let member = field.to_member();
                     let field_name = field.field_name();
+                    let from_other = field.get_from_reflect_tokens(vct_reflect_path, quote!(__other));
 
                     let accessor = match &field.data.ident {
-                        Some(id) => {
-                            let name = id.to_string();
+                        Some(_) => {
+                            let name = field.field_name();
                             quote! { #enum_::field(#input_, #name) }
                         },
                         None => {
@@ -367,7 +387,7 @@ fn get_enum_try_apply_impl(info: &ReflectEnum) -> TokenStream {
                                     }
                                 ),
                             };
-                            match <#field_ty as #from_reflect_>::from_reflect(__other) {
+                            match #from_other {
                                 #OptionFP::Some(__val) => __val,
                                 #OptionFP::None => return #ResultFP::Err(
                                     #apply_error_::MissingEnumField {
@@ -379,9 +399,10 @@ fn get_enum_try_apply_impl(info: &ReflectEnum) -> TokenStream {
                         },
                     });
                 }
+                let value = meta.wrap_value(quote!(#variant_path_{ #clone_tokens }));
                 match_tokens.extend(quote! {
                     #variant_name_ => {
-                        *self = variant_path_{ #clone_tokens };
+                        *self = #value;
                     },
                 });
                 
@@ -401,14 +422,14 @@ fn get_enum_try_apply_impl(info: &ReflectEnum) -> TokenStream {
                             for field in #enum_::iter_fields(#input_) {
                                 let name = field.name().unwrap();
                                 if let #OptionFP::Some(v) = #enum_::field_mut(self, name) {
-                                    #reflect_::try_apply(v, field.value())?;
+                                    #reflect_::try_apply(v, field.value()).map_err(|e| #apply_error_::with_field_name(e, name))?;
                                 }
                             }
                         },
                         #variant_kind_::Tuple => {
                             for (index, field) in ::core::iter::Iterator::enumerate(#enum_::iter_fields(#input_)) {
                                 if let #OptionFP::Some(v) = #enum_::field_at_mut(self, index) {
-                                    #reflect_::try_apply(v, field.value())?;
+                                    #reflect_::try_apply(v, field.value()).map_err(|e| #apply_error_::with_tuple_index(e, index))?;
                                 }
                             }
                         },
@@ -437,6 +458,11 @@ fn get_enum_try_apply_impl(info: &ReflectEnum) -> TokenStream {
             }
             #ResultFP::Ok(())
         }
+
+        #[inline]
+        fn try_apply_owned(&mut self, #input_: #alloc_utils_::Box<dyn #reflect_>) -> #ResultFP<(), #apply_error_> {
+            #enum_try_apply_owned_(self, #input_)
+        }
     }
 }
 
@@ -462,7 +488,6 @@ fn get_enum_clone_impl(info: &ReflectEnum) -> TokenStream {
     let alloc_utils_ = crate::path::alloc_utils_(vct_reflect_path);
     let reflect_ = crate::path::reflect_(vct_reflect_path);
     let reflect_clone_error_ = crate::path::reflect_clone_error_(vct_reflect_path);
-    let macro_exports_ = crate::path::macro_exports_(vct_reflect_path);
     let type_path_ = crate::path::type_path_(vct_reflect_path);
 
     if let Some(span) = meta.attrs().avail_traits.clone {
@@ -477,22 +502,23 @@ fn get_enum_clone_impl(info: &ReflectEnum) -> TokenStream {
 
         for variant in info.variants.iter() {
             let ident = &variant.data.ident;
-            let variant_name = ident.to_string();
+            let variant_name = variant.variant_name();
             let variant_path_ = info.variant_path(ident);
 
             match variant.data.fields {
                 syn::Fields::Unit => {
+                    let value = meta.wrap_value(variant_path_.clone());
                     match_tokens.extend(quote! {
-                        #variant_path_ => #ResultFP::Ok(#alloc_utils_::Box::new(#variant_path_) as #alloc_utils_::Box<dyn #reflect_>),
+                        #variant_path_ => #ResultFP::Ok(#alloc_utils_::Box::new(#value) as #alloc_utils_::Box<dyn #reflect_>),
                     });
                 },
                 syn::Fields::Named(..) | syn::Fields::Unnamed(..) => {
-                    if let Some(ignored_field) = variant.fields().iter().find(|f|f.attrs.ignore.is_some()) {
+                    if let Some(ignored_field) = variant.fields().iter().find(|f|f.attrs.ignore.is_some() && f.attrs.clone_with.is_none()) {
                         let span = ignored_field.attrs.ignore.unwrap();
                         let field_id = ignored_field.field_id(vct_reflect_path);
                         match_tokens.extend(quote_spanned! { span =>
-                            #variant_path_ => #ResultFP::Err(#reflect_clone_error_::FieldNotCloneable {
-                                type_path:  #alloc_utils_::Cow::Borrowed(<Self as #type_path_>::type_path())
+                            #variant_path_ { .. } => #ResultFP::Err(#reflect_clone_error_::FieldNotCloneable {
+                                type_path:  #alloc_utils_::Cow::Borrowed(<Self as #type_path_>::type_path()),
                                 field: #field_id,
                                 variant: #OptionFP::Some(#alloc_utils_::Cow::Borrowed(#variant_name)),
                             }),
@@ -502,27 +528,32 @@ fn get_enum_clone_impl(info: &ReflectEnum) -> TokenStream {
                     let mut member_tokens = TokenStream::new();
                     let mut clone_tokens = TokenStream::new();
                     for (index, field) in variant.fields().iter().enumerate() {
-                        let field_ty = &field.data.ty;
                         let member = field.to_member();
                         let accessor = Ident::new(&format!("__mem_{index}"), Span::call_site());
+                        let clone_field = field.get_reflect_clone_tokens(vct_reflect_path, &accessor);
 
                         member_tokens.extend(quote! {
                             #member: #accessor,
                         });
                         clone_tokens.extend(quote! {
-                            #member: #macro_exports_::reflect_clone_field::<#field_ty>(#accessor)?,
+                            #member: #clone_field?,
                         });
                     }
+                    let value = meta.wrap_value(quote!(#variant_path_ { #clone_tokens }));
                     match_tokens.extend(quote! {
-                        #variant_path_{ #member_tokens } => #variant_path_ { #clone_tokens },
+                        #variant_path_{ #member_tokens } => #ResultFP::Ok(
+                            #alloc_utils_::Box::new(#value) as #alloc_utils_::Box<dyn #reflect_>
+                        ),
                     });
                 },
             }
         }
 
+        let this = meta.value_ref(quote!(self), false);
+
         quote! {
             fn reflect_clone(&self) -> #ResultFP<#alloc_utils_::Box<dyn #reflect_>, #reflect_clone_error_> {
-                match self {
+                match #this {
                     #match_tokens
                 }
             }
@@ -534,7 +565,7 @@ fn get_registry_dependencies(info: &ReflectEnum) -> TokenStream {
     let vct_reflect_path = info.meta().vct_reflect_path();
     let type_registry_ = crate::path::type_registry_(vct_reflect_path);
 
-    let field_types =  info.active_fields().map(|x|&x.data.ty);
+    let field_types =  info.active_fields().map(|x| x.reflected_ty(vct_reflect_path));
 
     quote! {
         fn register_dependencies(__registry: &mut #type_registry_) {
@@ -563,17 +594,18 @@ fn impl_enum_from_reflect(info: &ReflectEnum) -> TokenStream {
     for variant in info.variants.iter() {
         let ident = &variant.data.ident;
         let variant_path_ = info.variant_path(ident);
-        let variant_name_ = ident.to_string();
+        let variant_name_ = variant.variant_name();
 
         match variant.data.fields {
             syn::Fields::Unit => {
+                let value = meta.wrap_value(variant_path_);
                 match_tokens.extend(quote! {
-                    #variant_name_ => { return #OptionFP::Some(#variant_path_); },
+                    #variant_name_ => { return #OptionFP::Some(#value); },
                 });
             },
             syn::Fields::Named(..) | syn::Fields::Unnamed(..) => {
-                if variant.fields().iter().any(|f|f.attrs.ignore.is_some()) {
-                    // Cannot construct if ignored fields exist.
+                if variant.fields().iter().any(|f|f.attrs.ignore.is_some() && f.attrs.default.is_none()) {
+                    // Cannot construct if ignored fields without `default` exist.
                     match_tokens.extend(quote! {
                         #variant_name_ => { return #OptionFP::None; },
                     });
@@ -582,28 +614,47 @@ fn impl_enum_from_reflect(info: &ReflectEnum) -> TokenStream {
                 let mut clone_tokens = TokenStream::new();
 
                 for field in variant.fields().iter() {
-                    let field_ty = &field.data.ty;
                     let member = field.to_member();
+                    let default_value = field.get_default_tokens();
+
+                    if field.attrs.ignore.is_some() {
+                        clone_tokens.extend(quote! {
+                            #member: #default_value,
+                        });
+                        continue;
+                    }
 
                     let getter = match &field.data.ident {
-                        Some(id) => {
-                            let name = id.to_string();
-                            quote! { #enum_::field(#input_, #name)? }
+                        Some(_) => {
+                            let name = field.field_name();
+                            quote! { #enum_::field(#input_, #name) }
                         },
                         None => {
-                            let index = field.declaration_index; 
-                            quote! { #enum_::field_at(#input_, #index)? }
+                            let index = field.reflection_index; 
+                            quote! { #enum_::field_at(#input_, #index) }
                         },
                     };
 
-                    clone_tokens.extend(quote! {
-                        #member: <#field_ty as #from_reflect_>::from_reflect(#getter)?,
-                    });
+                    if field.attrs.default.is_some() {
+                        let from_field = field.get_from_reflect_tokens(vct_reflect_path, quote!(__field));
+                        clone_tokens.extend(quote! {
+                            #member: match #getter {
+                                #OptionFP::Some(__field) => #from_field?,
+                                #OptionFP::None => #default_value,
+                            },
+                        });
+                    } else {
+                        let from_field = field.get_from_reflect_tokens(vct_reflect_path, quote!(#getter?));
+                        clone_tokens.extend(quote! {
+                            #member: #from_field?,
+                        });
+                    }
                 }
 
+                let value = meta.wrap_value(quote!(#variant_path_{ #clone_tokens }));
                 match_tokens.extend(quote! {
                     #variant_name_ => {
-                        let __result = #variant_path_{ #clone_tokens };
+                        let __result = #value;
                         return #OptionFP::Some(__result);
                     },
                 });
@@ -613,7 +664,8 @@ fn impl_enum_from_reflect(info: &ReflectEnum) -> TokenStream {
 
     let parser = meta.type_path_parser();
     let real_ident = parser.real_ident();
-    let (impl_generics, ty_generics, where_clause) = parser.generics().split_for_impl();
+    let (impl_generics, ty_generics, _) = parser.generics().split_for_impl();
+    let where_clause = meta.where_clause();
 
     quote! {
         impl #impl_generics #from_reflect_ for #real_ident #ty_generics #where_clause  {
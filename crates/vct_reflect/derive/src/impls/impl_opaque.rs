@@ -2,7 +2,7 @@ use proc_macro2::Span;
 use quote::{quote, quote_spanned};
 use syn::Ident;
 
-use crate::{derive_data::ReflectMeta, impls::{get_common_debug_impl, get_common_from_reflect_tokens, get_common_hash_impl, get_common_partial_eq_impl, impl_trait_get_type_traits, impl_trait_reflect, impl_trait_type_path, impl_trait_typed}};
+use crate::{derive_data::ReflectMeta, impls::{get_common_debug_impl, get_common_from_reflect_tokens, get_common_hash_impl, get_common_partial_cmp_impl, get_common_partial_eq_impl, impl_trait_get_type_traits, impl_trait_reflect, impl_trait_type_path, impl_trait_typed}};
 
 pub(crate) fn impl_opaque(meta: &ReflectMeta) -> proc_macro2::TokenStream {
     // trait: TypePath
@@ -25,6 +25,7 @@ pub(crate) fn impl_opaque(meta: &ReflectMeta) -> proc_macro2::TokenStream {
         let to_dynamic_tokens = get_opaque_to_dynamic_impl(meta);
         let reflect_clone_tokens = get_opaque_clone_impl(meta);
         let reflect_partial_eq_tokens = get_common_partial_eq_impl(meta);
+        let reflect_partial_cmp_tokens = get_common_partial_cmp_impl(meta);
         let reflect_hash_tokens = get_common_hash_impl(meta);
         let reflect_debug_tokens = get_common_debug_impl(meta);
 
@@ -35,6 +36,7 @@ pub(crate) fn impl_opaque(meta: &ReflectMeta) -> proc_macro2::TokenStream {
             to_dynamic_tokens,
             reflect_clone_tokens,
             reflect_partial_eq_tokens,
+            reflect_partial_cmp_tokens,
             reflect_hash_tokens,
             reflect_debug_tokens,
         )
@@ -44,7 +46,7 @@ pub(crate) fn impl_opaque(meta: &ReflectMeta) -> proc_macro2::TokenStream {
 
     // trait: GetTypeTraits
     let get_type_traits_tokens = if meta.attrs().impl_switchs.impl_get_type_traits {
-        impl_trait_get_type_traits(meta, crate::utils::empty())
+        impl_trait_get_type_traits(meta, crate::utils::empty(), &[])
     } else {
         crate::utils::empty()
     };
@@ -94,6 +96,13 @@ fn get_opaque_try_apply_impl(meta: &ReflectMeta) -> proc_macro2::TokenStream {
                     }
                 )
             }
+
+            fn try_apply_owned(&mut self, __input: #alloc_utils_::Box<dyn #reflect_>) -> #ResultFP<(), #apply_error_> {
+                match #reflect_::set(self, __input) {
+                    #ResultFP::Ok(()) => #ResultFP::Ok(()),
+                    #ResultFP::Err(__input) => #reflect_::try_apply(self, &*__input),
+                }
+            }
         }
     } else {
         unreachable!("#[reflect(clone)] must be specified when auto impl `Reflect` for Opaque Type.")
@@ -152,7 +161,8 @@ fn impl_opaque_from_reflect(meta: &ReflectMeta) -> proc_macro2::TokenStream {
 
     let parser = meta.type_path_parser();
     let real_ident = parser.real_ident();
-    let (impl_generics, ty_generics, where_clause) = parser.generics().split_for_impl();
+    let (impl_generics, ty_generics, _) = parser.generics().split_for_impl();
+    let where_clause = meta.where_clause();
 
     quote! {
         impl #impl_generics #from_reflect_ for #real_ident #ty_generics #where_clause  {
@@ -1,8 +1,8 @@
 use proc_macro2::{Span, TokenStream};
-use quote::{ToTokens, quote};
+use quote::{ToTokens, quote, quote_spanned};
 use syn::Ident;
 
-use crate::{derive_data::{FieldAccessors, ReflectMeta, ReflectStruct}, impls::{get_common_debug_impl, get_common_hash_impl, get_common_partial_eq_impl, get_struct_clone_impl, impl_struct_from_reflect, impl_trait_get_type_traits, impl_trait_reflect, impl_trait_type_path, impl_trait_typed}};
+use crate::{derive_data::{FieldAccessors, ReflectMeta, ReflectStruct, StructField}, impls::{get_common_debug_impl, get_common_hash_impl, get_common_partial_cmp_impl, get_common_partial_eq_impl, get_struct_clone_impl, impl_struct_from_reflect, impl_struct_interpolate, impl_trait_get_type_traits, impl_trait_reflect, impl_trait_type_path, impl_trait_typed}};
 
 
 
@@ -36,6 +36,7 @@ pub(crate) fn impl_struct(info: &ReflectStruct) -> TokenStream {
         let to_dynamic_tokens = get_struct_to_dynamic_impl(meta);
         let reflect_clone_tokens = get_struct_clone_impl(info);
         let reflect_partial_eq_tokens = get_common_partial_eq_impl(meta);
+        let reflect_partial_cmp_tokens = get_common_partial_cmp_impl(meta);
         let reflect_hash_tokens = get_common_hash_impl(meta);
         let reflect_debug_tokens = get_common_debug_impl(meta);
 
@@ -46,6 +47,7 @@ pub(crate) fn impl_struct(info: &ReflectStruct) -> TokenStream {
             to_dynamic_tokens,
             reflect_clone_tokens,
             reflect_partial_eq_tokens,
+            reflect_partial_cmp_tokens,
             reflect_hash_tokens,
             reflect_debug_tokens,
         )
@@ -55,7 +57,7 @@ pub(crate) fn impl_struct(info: &ReflectStruct) -> TokenStream {
 
     // trait: GetTypeTraits
     let get_type_traits_tokens = if meta.attrs().impl_switchs.impl_get_type_traits {
-        impl_trait_get_type_traits(meta, get_registry_dependencies(info))
+        impl_trait_get_type_traits(meta, get_registry_dependencies(info), get_version_type_trait(meta).as_slice())
     } else {
         crate::utils::empty()
     };
@@ -67,6 +69,9 @@ pub(crate) fn impl_struct(info: &ReflectStruct) -> TokenStream {
         crate::utils::empty()
     };
 
+    // trait: Interpolate
+    let interpolate_trait_tokens = impl_struct_interpolate(info);
+
     quote! {
         #type_path_trait_tokens
 
@@ -79,6 +84,8 @@ pub(crate) fn impl_struct(info: &ReflectStruct) -> TokenStream {
         #get_type_traits_tokens
 
         #get_from_reflect_tokens
+
+        #interpolate_trait_tokens
     }
 }
 
@@ -91,18 +98,12 @@ pub fn impl_trait_struct(info: &ReflectStruct) -> TokenStream {
     let reflect_ = crate::path::reflect_(vct_reflect_path);
     let struct_field_iter_ = crate::path::struct_field_iter_(vct_reflect_path);
     let dynamic_struct_ = crate::path::dynamic_struct_(vct_reflect_path);
+    let alloc_utils_ = crate::path::alloc_utils_(vct_reflect_path);
     let option_ = OptionFP.to_token_stream();
 
     let field_names = info
-        .active_fields()
-        .map(|field| {
-            field
-                .data
-                .ident
-                .as_ref()
-                .map(ToString::to_string)
-                .expect("Struct should not have unnamed fields.")
-        })
+        .direct_fields()
+        .map(StructField::field_name)
         .collect::<Vec<String>>();
 
     let FieldAccessors {
@@ -112,50 +113,93 @@ pub fn impl_trait_struct(info: &ReflectStruct) -> TokenStream {
         field_count,
     } = FieldAccessors::new(info);
 
+    // The fields of `#[reflect(flatten)]` fields follow the direct fields.
+    let flattened = info
+        .flattened_fields()
+        .map(|field| meta.field_access(quote!(self), &field.to_member()))
+        .collect::<Vec<_>>();
+    let field_len = quote! {
+        #field_count #(+ #struct_::field_len(&#flattened))*
+    };
+    let (field_fallback, field_mut_fallback, field_at_fallback, field_at_mut_fallback, name_at_fallback) =
+        if flattened.is_empty() {
+            let none = quote!(#OptionFP::None);
+            (none.clone(), none.clone(), none.clone(), none.clone(), none)
+        } else {
+            let by_name = |method: TokenStream, ref_: TokenStream| quote! {{
+                #(
+                    if let #option_::Some(__field) = #struct_::#method(#ref_ #flattened, name) {
+                        return #option_::Some(__field);
+                    }
+                )*
+                #OptionFP::None
+            }};
+            let by_index = |method: TokenStream, ref_: TokenStream| quote! {{
+                let mut __index = index - #field_count;
+                #(
+                    let __len = #struct_::field_len(&#flattened);
+                    if __index < __len {
+                        return #struct_::#method(#ref_ #flattened, __index);
+                    }
+                    __index -= __len;
+                )*
+                let _ = __index;
+                #OptionFP::None
+            }};
+            (
+                by_name(quote!(field), quote!(&)),
+                by_name(quote!(field_mut), quote!(&mut)),
+                by_index(quote!(field_at), quote!(&)),
+                by_index(quote!(field_at_mut), quote!(&mut)),
+                by_index(quote!(name_at), quote!(&)),
+            )
+        };
+
     let parser = meta.type_path_parser();
     let real_ident = parser.real_ident();
-    let (impl_generics, ty_generics, where_clause) = parser.generics().split_for_impl();
+    let (impl_generics, ty_generics, _) = parser.generics().split_for_impl();
+    let where_clause = meta.where_clause();
 
     quote! {
         impl #impl_generics #struct_ for #real_ident #ty_generics #where_clause {
             fn field(&self, name: &str) -> #OptionFP<&dyn #reflect_> {
                 match name {
                     #(#field_names => #option_::Some(#fields_ref),)*
-                    _ => #OptionFP::None,
+                    _ => #field_fallback,
                 }
             }
 
             fn field_mut(&mut self, name: &str) -> #OptionFP<&mut dyn #reflect_> {
                 match name {
                     #(#field_names => #option_::Some(#fields_mut),)*
-                    _ => #OptionFP::None,
+                    _ => #field_mut_fallback,
                 }
             }
 
             fn field_at(&self, index: usize) -> #OptionFP<&dyn #reflect_> {
                 match index {
                     #(#field_indices => #option_::Some(#fields_ref),)*
-                    _ => #OptionFP::None,
+                    _ => #field_at_fallback,
                 }
             }
 
             fn field_at_mut(&mut self, index: usize) -> #OptionFP<&mut dyn #reflect_> {
                 match index {
                     #(#field_indices => #option_::Some(#fields_mut),)*
-                    _ => #OptionFP::None,
+                    _ => #field_at_mut_fallback,
                 }
             }
 
             fn name_at(&self, index: usize) -> #OptionFP<&str> {
                 match index {
                     #(#field_indices => #option_::Some(#field_names),)*
-                    _ => #OptionFP::None,
+                    _ => #name_at_fallback,
                 }
             }
 
             #[inline]
             fn field_len(&self) -> usize {
-                #field_count
+                #field_len
             }
 
             #[inline]
@@ -168,6 +212,12 @@ pub fn impl_trait_struct(info: &ReflectStruct) -> TokenStream {
                 let mut dynamic = #dynamic_struct_::with_capacity(#struct_::field_len(self));
                 dynamic.set_type_info(#reflect_::represented_type_info(self));
                 #(dynamic.insert_boxed(#field_names, #reflect_::to_dynamic(#fields_ref));)*
+                #(
+                    for (__index, __field) in ::core::iter::Iterator::enumerate(#struct_::iter_fields(&#flattened)) {
+                        let __name = #struct_::name_at(&#flattened, __index).unwrap();
+                        dynamic.insert_boxed(#alloc_utils_::ToString::to_string(__name), #reflect_::to_dynamic(__field));
+                    }
+                )*
                 dynamic
             }
         }
@@ -183,6 +233,8 @@ pub fn get_struct_try_apply_impl(meta: &ReflectMeta) -> TokenStream {
     let struct_ = crate::path::struct_(vct_reflect_path);
     let reflect_kind_ = crate::path::reflect_kind_(vct_reflect_path);
     let apply_error_ = crate::path::apply_error_(vct_reflect_path);
+    let alloc_utils_ = crate::path::alloc_utils_(vct_reflect_path);
+    let struct_try_apply_owned_ = crate::path::struct_try_apply_owned_(vct_reflect_path);
 
     let input_ = Ident::new("__ident", Span::call_site());
 
@@ -199,9 +251,9 @@ pub fn get_struct_try_apply_impl(meta: &ReflectMeta) -> TokenStream {
 
             if let #reflect_ref::Struct(struct_value) = #reflect_::reflect_ref(#input_) {
                 for (i, value) in ::core::iter::Iterator::enumerate(#struct_::iter_fields(struct_value)) {
-                    let name = #struct_::name_at(#input_, i).unwrap();
+                    let name = #struct_::name_at(struct_value, i).unwrap();
                     if let #OptionFP::Some(v) = #struct_::field_mut(self, name) {
-                        #reflect_::try_apply(v, value)?;
+                        #reflect_::try_apply(v, value).map_err(|e| #apply_error_::with_field_name(e, name))?;
                     }
                 }
 
@@ -215,6 +267,11 @@ pub fn get_struct_try_apply_impl(meta: &ReflectMeta) -> TokenStream {
                 )
             }
         }
+
+        #[inline]
+        fn try_apply_owned(&mut self, #input_: #alloc_utils_::Box<dyn #reflect_>) -> #ResultFP<(), #apply_error_> {
+            #struct_try_apply_owned_(self, #input_)
+        }
     }
 }
 
@@ -232,11 +289,20 @@ fn get_struct_to_dynamic_impl(meta: &ReflectMeta) -> TokenStream {
     }
 }
 
+/// `TypeTraitMigrations` for `#[reflect(version = ...)]`.
+fn get_version_type_trait(meta: &ReflectMeta) -> Option<TokenStream> {
+    let (version, span) = meta.attrs().version?;
+    let type_trait_migrations_ = crate::path::type_trait_migrations_(meta.vct_reflect_path());
+    Some(quote_spanned! { span =>
+        #type_trait_migrations_::new(#version)
+    })
+}
+
 fn get_registry_dependencies(info: &ReflectStruct) -> TokenStream {
     let vct_reflect_path = info.meta().vct_reflect_path();
     let type_registry_ = crate::path::type_registry_(vct_reflect_path);
 
-    let field_types =  info.active_fields().map(|x|&x.data.ty);
+    let field_types =  info.active_fields().map(|x| x.reflected_ty(vct_reflect_path));
 
     quote! {
         fn register_dependencies(__registry: &mut #type_registry_) {
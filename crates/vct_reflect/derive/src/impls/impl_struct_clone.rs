@@ -9,7 +9,6 @@ pub(crate) fn get_struct_clone_impl(info: &ReflectStruct) -> TokenStream {
     let meta = info.meta();
     let vct_reflect_path = meta.vct_reflect_path();
     let alloc_utils_ = crate::path::alloc_utils_(vct_reflect_path);
-    let macro_exports_ = crate::path::macro_exports_(vct_reflect_path);
     let reflect_ = crate::path::reflect_(vct_reflect_path);
     let reflect_clone_error_ = crate::path::reflect_clone_error_(vct_reflect_path);
     let type_path_ = crate::path::type_path_(vct_reflect_path);
@@ -25,12 +24,15 @@ pub(crate) fn get_struct_clone_impl(info: &ReflectStruct) -> TokenStream {
     } else if let Some(span) = meta.attrs().avail_traits.default {
         let mut tokens = TokenStream::new();
 
-        for field in info.active_fields() {
-            let field_ty = &field.data.ty;
+        // Other ignored fields keep their default values.
+        for field in info.fields().iter().filter(|f| f.attrs.ignore.is_none() || f.attrs.clone_with.is_some()) {
             let member = field.to_member();
+            let source = meta.field_access(quote!(self), &member);
+            let target = meta.field_access(quote!(__new_value), &member);
+            let clone_field = field.get_reflect_clone_tokens(vct_reflect_path, quote!(&#source));
 
             tokens.extend(quote! {
-                __new_value.#member = #macro_exports_::reflect_clone_field::<#field_ty>(&self.#member)?;
+                #target = #clone_field?;
             });
         }
 
@@ -46,13 +48,13 @@ pub(crate) fn get_struct_clone_impl(info: &ReflectStruct) -> TokenStream {
 
     } else {
         for field in info.fields().iter() {
-            if let Some(span) = field.attrs.ignore {
+            if let Some(span) = field.attrs.ignore && field.attrs.clone_with.is_none() {
                 let field_id = field.field_id(vct_reflect_path);
                 return quote_spanned! { span =>
                     #[inline]
                     fn reflect_clone(&self) -> #ResultFP<#alloc_utils_::Box<dyn #reflect_>, #reflect_clone_error_> {
                         #ResultFP::Err(#reflect_clone_error_::FieldNotCloneable {
-                            type_path:  #alloc_utils_::Cow::Borrowed(<Self as #type_path_>::type_path())
+                            type_path:  #alloc_utils_::Cow::Borrowed(<Self as #type_path_>::type_path()),
                             field: #field_id,
                             variant: #OptionFP::None,
                         })
@@ -64,20 +66,26 @@ pub(crate) fn get_struct_clone_impl(info: &ReflectStruct) -> TokenStream {
         let mut tokens = TokenStream::new();
 
         for field in info.fields().iter() {
-            let field_ty = &field.data.ty;
             let member = field.to_member();
+            let source = meta.field_access(quote!(self), &member);
+            let clone_field = field.get_reflect_clone_tokens(vct_reflect_path, quote!(&#source));
 
             tokens.extend(quote! {
-                #member: #macro_exports_::reflect_clone_field::<#field_ty>(&self.#member)?,
+                #member: #clone_field?,
             });
         }
 
+        let value_path = meta.value_path();
+        let new_value = meta.wrap_value(quote! {
+            #value_path {
+                #tokens
+            }
+        });
+
         quote! {
             fn reflect_clone(&self) -> #ResultFP<#alloc_utils_::Box<dyn #reflect_>, #reflect_clone_error_> {
                 #ResultFP::Ok(#alloc_utils_::Box::new(
-                    Self {
-                        #tokens
-                    }
+                    #new_value
                 ) as #alloc_utils_::Box<dyn #reflect_>)
             }
         }
@@ -33,12 +33,20 @@ pub(crate) fn impl_struct_from_reflect(info: &ReflectStruct, is_tuple: bool) ->
     let (active_members, active_values): (Vec<_>, Vec<_>) = info
         .active_fields()
         .map(|field| {
-            let member = field.to_member();
-            let field_ty = field.data.ty.clone();
+            let member = meta.field_access(quote!(__this), &field.to_member());
+            if field.attrs.flatten.is_some() {
+                // The flattened struct reads its fields from the parent.
+                let ty = &field.data.ty;
+                let value = quote! {
+                    <#ty as #from_reflect_>::from_reflect(#reflect_::as_reflect(#input_))
+                };
+                return (member, value);
+            }
             let accessor = field.reflect_accessor();
+            let from_field = field.get_from_reflect_tokens(vct_reflect_path, quote!(__field));
             let value = quote! {
                 match #struct_trait_path_::field(#input_, #accessor) {
-                    #OptionFP::Some(__field) => <#field_ty as #from_reflect_>::from_reflect(__field),
+                    #OptionFP::Some(__field) => #from_field,
                     #OptionFP::None => #OptionFP::None,
                 }
             };
@@ -52,28 +60,76 @@ pub(crate) fn impl_struct_from_reflect(info: &ReflectStruct, is_tuple: bool) ->
                 let mut __this = <Self as #DefaultFP>::default();
                 #(
                     if let #option_::Some(__field_val) = #active_values {
-                        __this.#active_members = __field_val;
+                        #active_members = __field_val;
                     }
                 )*
-                #OptionFP::Some(__this)
+                return #OptionFP::Some(__this);
             }
         }
-    } else if info.fields().iter().any(|f| f.attrs.ignore.is_some()) {
+    } else if info.fields().iter().any(|f| f.attrs.ignore.is_some() && f.attrs.default.is_none()) {
         crate::utils::empty()
     } else {
+        let field_values = info.fields().iter().map(|field| {
+            let member = field.to_member();
+            let default_value = field.get_default_tokens();
+            if field.attrs.ignore.is_some() {
+                return quote! { #member: #default_value, };
+            }
+            if field.attrs.flatten.is_some() {
+                let ty = &field.data.ty;
+                let from_field = quote! {
+                    <#ty as #from_reflect_>::from_reflect(#reflect_::as_reflect(#input_))
+                };
+                return if field.attrs.default.is_some() {
+                    quote! {
+                        #member: match #from_field {
+                            #OptionFP::Some(__field) => __field,
+                            #OptionFP::None => #default_value,
+                        },
+                    }
+                } else {
+                    quote! { #member: #from_field?, }
+                };
+            }
+            let accessor = field.reflect_accessor();
+            if field.attrs.default.is_some() {
+                let from_field = field.get_from_reflect_tokens(vct_reflect_path, quote!(__field));
+                quote! {
+                    #member: match #struct_trait_path_::field(#input_, #accessor) {
+                        #OptionFP::Some(__field) => #from_field?,
+                        #OptionFP::None => #default_value,
+                    },
+                }
+            } else {
+                let from_field = field.get_from_reflect_tokens(
+                    vct_reflect_path,
+                    quote!(#struct_trait_path_::field(#input_, #accessor)?),
+                );
+                quote! {
+                    #member: #from_field?,
+                }
+            }
+        });
+
+        let value_path = meta.value_path();
+        let this = meta.wrap_value(quote! {
+            #value_path {
+                #(#field_values)*
+            }
+        });
+
         quote! {
             if let #reflect_ref_::#struct_kind_(#input_) = #reflect_::reflect_ref(#input_) {
-                let __this = Self {
-                    #(#active_members: #active_values?,)*
-                };
-                #OptionFP::Some(__this)
+                let __this = #this;
+                return #OptionFP::Some(__this);
             }
         }
     };  
 
     let parser = meta.type_path_parser();
     let real_ident = parser.real_ident();
-    let (impl_generics, ty_generics, where_clause) = parser.generics().split_for_impl();
+    let (impl_generics, ty_generics, _) = parser.generics().split_for_impl();
+    let where_clause = meta.where_clause();
 
     quote! {
         impl #impl_generics #from_reflect_ for #real_ident #ty_generics #where_clause  {
@@ -0,0 +1,46 @@
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+
+use crate::derive_data::ReflectStruct;
+
+/// Implements `Interpolate` field by field if `#[reflect(interpolate)]` is specified.
+///
+/// Every field (including ignored fields) must implement `Interpolate`.
+pub(crate) fn impl_struct_interpolate(info: &ReflectStruct) -> TokenStream {
+    let meta = info.meta();
+
+    let Some(span) = meta.attrs().avail_traits.interpolate else {
+        return crate::utils::empty();
+    };
+
+    let vct_reflect_path = meta.vct_reflect_path();
+    let interpolate_ = crate::path::interpolate_(vct_reflect_path);
+
+    let fields = info.fields().iter().map(|field| {
+        let member = field.to_member();
+        let this = meta.field_access(quote!(self), &member);
+        let other = meta.field_access(quote!(other), &member);
+        quote_spanned! { span =>
+            #member: #interpolate_::interpolate(&#this, &#other, t)
+        }
+    });
+
+    let value_path = meta.value_path();
+    let value = meta.wrap_value(quote_spanned! { span =>
+        #value_path {
+            #(#fields,)*
+        }
+    });
+
+    let parser = meta.type_path_parser();
+    let real_ident = parser.real_ident();
+    let (impl_generics, ty_generics, where_clause) = parser.generics().split_for_impl();
+
+    quote_spanned! { span =>
+        impl #impl_generics #interpolate_ for #real_ident #ty_generics #where_clause {
+            fn interpolate(&self, other: &Self, t: f32) -> Self {
+                #value
+            }
+        }
+    }
+}
@@ -1,10 +1,12 @@
 use proc_macro2::{Span, TokenStream};
 use quote::{quote, quote_spanned};
-use syn::Ident;
+use syn::{Ident, spanned::Spanned};
 use crate::derive_data::ReflectMeta;
 
 /// vec_reflect::registry::GetTypeTraits
-pub(crate) fn impl_trait_get_type_traits<'a>(meta: &ReflectMeta, register_deps_tokens: TokenStream) -> TokenStream {
+/// 
+/// `extra_type_traits` are expressions of type traits inserted besides the default ones.
+pub(crate) fn impl_trait_get_type_traits<'a>(meta: &ReflectMeta, register_deps_tokens: TokenStream, extra_type_traits: &[TokenStream]) -> TokenStream {
     debug_assert!(meta.attrs().impl_switchs.impl_get_type_traits);
 
     let vct_reflect_path = meta.vct_reflect_path();
@@ -26,6 +28,16 @@ pub(crate) fn impl_trait_get_type_traits<'a>(meta: &ReflectMeta, register_deps_t
         None => crate::utils::empty(),
     };
 
+    let insert_interpolate = match meta.attrs().avail_traits.interpolate {
+        Some(span) => {
+            let type_trait_interpolate_ = crate::path::type_trait_interpolate_(vct_reflect_path);
+            quote_spanned! { span =>
+                #type_traits_::insert::<#type_trait_interpolate_>(&mut #outer_, #from_type_::<Self>::from_type());
+            }
+        },
+        None => crate::utils::empty(),
+    };
+
     let insert_serialize = match meta.attrs().avail_traits.serialize {
         Some(span) => {
             let type_trait_serialize_ = crate::path::type_trait_serialize_(vct_reflect_path);
@@ -46,9 +58,18 @@ pub(crate) fn impl_trait_get_type_traits<'a>(meta: &ReflectMeta, register_deps_t
         None => crate::utils::empty(),
     };
 
+    let insert_custom = meta.attrs().type_traits.iter().map(|path| {
+        quote_spanned! { path.span() =>
+            #type_traits_::insert::<#path>(&mut #outer_, #from_type_::<Self>::from_type());
+        }
+    });
+
     let parser = meta.type_path_parser();
     let real_ident = parser.real_ident();
-    let (impl_generics, ty_generics, where_clause) = parser.generics().split_for_impl();
+    let (impl_generics, ty_generics, _) = parser.generics().split_for_impl();
+    let where_clause = meta.where_clause();
+
+    let auto_register = impl_auto_register(meta);
 
     quote! {
         impl #impl_generics #get_type_traits_ for #real_ident #ty_generics #where_clause {
@@ -57,12 +78,50 @@ pub(crate) fn impl_trait_get_type_traits<'a>(meta: &ReflectMeta, register_deps_t
                 #type_traits_::insert::<#type_trait_from_ptr>(&mut #outer_, #from_type_::<Self>::from_type());
                 #type_traits_::insert::<#type_trait_from_reflect>(&mut #outer_, #from_type_::<Self>::from_type());
                 #insert_default
+                #insert_interpolate
                 #insert_serialize
                 #insert_deserialize
+                #(#type_traits_::insert(&mut #outer_, #extra_type_traits);)*
+                #(#insert_custom)*
                 #outer_
             }
 
             #register_deps_tokens
         }
+
+        #auto_register
+    }
+}
+
+/// Submits a function registering the type to `inventory`, see `#[reflect(auto_register)]`.
+///
+/// Generic types are skipped, there is no concrete type to register.
+#[cfg(feature = "auto_register_inventory")]
+fn impl_auto_register(meta: &ReflectMeta) -> TokenStream {
+    let Some(span) = meta.attrs().auto_register else {
+        return crate::utils::empty();
+    };
+    let parser = meta.type_path_parser();
+    if !parser.generics().params.is_empty() {
+        return crate::utils::empty();
+    }
+
+    let vct_reflect_path = meta.vct_reflect_path();
+    let auto_register_ = crate::path::auto_register_(vct_reflect_path);
+    let type_registry_ = crate::path::type_registry_(vct_reflect_path);
+    let real_ident = parser.real_ident();
+
+    quote_spanned! { span =>
+        #auto_register_::inventory::submit! {
+            #auto_register_::AutoReflectRegisterFn(
+                |registry: &mut #type_registry_| #type_registry_::register::<#real_ident>(registry)
+            )
+        }
     }
 }
+
+/// Without `inventory`, types are registered with `push_registration_fn` by hand.
+#[cfg(not(feature = "auto_register_inventory"))]
+fn impl_auto_register(_meta: &ReflectMeta) -> TokenStream {
+    crate::utils::empty()
+}
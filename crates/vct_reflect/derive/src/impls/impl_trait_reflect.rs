@@ -10,6 +10,7 @@ pub(crate) fn impl_trait_reflect(
     to_dynamic_tokens: TokenStream,
     reflect_clone_tokens: TokenStream,
     reflect_partial_eq_tokens: TokenStream,
+    reflect_partial_cmp_tokens: TokenStream,
     reflect_hash_tokens: TokenStream,
     reflect_debug_tokens: TokenStream,
 ) -> TokenStream {
@@ -22,9 +23,12 @@ pub(crate) fn impl_trait_reflect(
     let reflect_mut_ = crate::path::reflect_mut_(vct_reflect_path);
     let reflect_owned_ = crate::path::reflect_owned_(vct_reflect_path);
 
+    let reflect_clone_into_tokens = crate::impls::get_common_clone_into_impl(meta);
+
     let parser = meta.type_path_parser();
     let real_ident = parser.real_ident();
-    let (impl_generics, ty_generics, where_clause) = parser.generics().split_for_impl();
+    let (impl_generics, ty_generics, _) = parser.generics().split_for_impl();
+    let where_clause = meta.where_clause();
 
     quote! {
         impl #impl_generics #reflect_ for #real_ident #ty_generics #where_clause {
@@ -75,8 +79,12 @@ pub(crate) fn impl_trait_reflect(
 
             #reflect_clone_tokens
 
+            #reflect_clone_into_tokens
+
             #reflect_partial_eq_tokens
 
+            #reflect_partial_cmp_tokens
+
             #reflect_hash_tokens
 
             #reflect_debug_tokens
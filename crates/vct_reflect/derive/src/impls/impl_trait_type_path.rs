@@ -9,7 +9,7 @@ fn static_path_cell(vct_reflect_path: &syn::Path, generator: TokenStream) -> Tok
     let cell_path = crate::path::generic_type_path_cell_(vct_reflect_path);
 
     quote! {
-        static CELL: #cell_path = #cell_path::new()
+        static CELL: #cell_path = #cell_path::new();
         CELL.get_or_insert::<Self, _>(|| {
             #generator
         })
@@ -46,7 +46,21 @@ pub(crate) fn impl_trait_type_path(meta: &ReflectMeta) -> TokenStream {
     let module_path = wrap_in_option(parser.module_path().map(StringExpr::into_borrowed));
     let crate_name = wrap_in_option(parser.crate_name().map(StringExpr::into_borrowed));
 
-    let (impl_generics, ty_generics, where_clause) = parser.generics().split_for_impl();
+    let type_uuid = match meta.attrs().type_uuid {
+        Some(uuid) => {
+            let type_uuid_ = crate::path::type_uuid_(vct_reflect_path);
+            quote! {
+                #[inline]
+                fn type_uuid() -> #OptionFP<#type_uuid_> {
+                    #OptionFP::Some(#type_uuid_::from_u128(#uuid))
+                }
+            }
+        },
+        None => crate::utils::empty(),
+    };
+
+    let (impl_generics, ty_generics, _) = parser.generics().split_for_impl();
+    let where_clause = meta.type_path_where_clause();
 
     quote! {
         impl #impl_generics #trait_type_path_ for #real_ident #ty_generics #where_clause {
@@ -74,6 +88,8 @@ pub(crate) fn impl_trait_type_path(meta: &ReflectMeta) -> TokenStream {
             fn module_path() -> #OptionFP<&'static str> {
                 #module_path
             }
+
+            #type_uuid
         }
     }
 }
@@ -28,7 +28,8 @@ pub(crate) fn impl_trait_typed(meta: &ReflectMeta, type_info_tokens: TokenStream
         }
     };
 
-    let (impl_generics, ty_generics, where_clause) = parser.generics().split_for_impl();
+    let (impl_generics, ty_generics, _) = parser.generics().split_for_impl();
+    let where_clause = meta.where_clause();
 
     quote! {
         impl #impl_generics #trait_typed_ for #real_ident #ty_generics #where_clause {
@@ -0,0 +1,289 @@
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::Ident;
+
+use crate::{derive_data::{ReflectStruct, StructField}, impls::{get_common_from_reflect_tokens, get_struct_clone_impl, impl_trait_get_type_traits, impl_trait_type_path, impl_trait_typed}};
+
+/// `#[reflect(transparent)]`: the type is `Opaque`, but the kind, `ReflectRef`,
+/// comparison, hashing, debugging and serialization are those of its single active field.
+pub(crate) fn impl_transparent(info: &ReflectStruct) -> TokenStream {
+    let meta = info.meta();
+
+    // trait: TypePath
+    let type_path_trait_tokens = if meta.attrs().impl_switchs.impl_type_path {
+        impl_trait_type_path(meta)
+    } else {
+        crate::utils::empty()
+    };
+
+    // trait: Typed
+    let typed_trait_tokens = if meta.attrs().impl_switchs.impl_typed {
+        impl_trait_typed(meta, meta.to_info_tokens())
+    } else {
+        crate::utils::empty()
+    };
+
+    // trait: Reflect
+    let reflect_trait_tokens = if meta.attrs().impl_switchs.impl_reflect {
+        impl_transparent_reflect(info)
+    } else {
+        crate::utils::empty()
+    };
+
+    // trait: GetTypeTraits
+    let get_type_traits_tokens = if meta.attrs().impl_switchs.impl_get_type_traits {
+        impl_trait_get_type_traits(
+            meta,
+            get_registry_dependencies(info),
+            &[get_transparent_type_trait(info)],
+        )
+    } else {
+        crate::utils::empty()
+    };
+
+    // trait: FromReflect
+    let from_reflect_tokens = if meta.attrs().impl_switchs.impl_from_reflect {
+        impl_transparent_from_reflect(info)
+    } else {
+        crate::utils::empty()
+    };
+
+    quote! {
+        #type_path_trait_tokens
+
+        #typed_trait_tokens
+
+        #reflect_trait_tokens
+
+        #get_type_traits_tokens
+
+        #from_reflect_tokens
+    }
+}
+
+/// The single active field, checked when parsing.
+fn transparent_field<'a, 'b>(info: &'b ReflectStruct<'a>) -> &'b StructField<'a> {
+    info.active_fields().next().expect("transparent type should have one active field")
+}
+
+/// Replaces `input` with its field if it is a `Self`.
+fn get_field_or_self_tokens(info: &ReflectStruct, input: &Ident) -> TokenStream {
+    use crate::path::fp::OptionFP;
+
+    let reflect_ = crate::path::reflect_(info.meta().vct_reflect_path());
+    let member = transparent_field(info).to_member();
+
+    quote! {
+        let #input = match <dyn #reflect_>::downcast_ref::<Self>(#input) {
+            #OptionFP::Some(__this) => #reflect_::as_reflect(&__this.#member),
+            #OptionFP::None => #input,
+        };
+    }
+}
+
+fn impl_transparent_reflect(info: &ReflectStruct) -> TokenStream {
+    use crate::path::fp::{OptionFP, ResultFP};
+
+    let meta = info.meta();
+    let vct_reflect_path = meta.vct_reflect_path();
+    let reflect_ = crate::path::reflect_(vct_reflect_path);
+    let alloc_utils_ = crate::path::alloc_utils_(vct_reflect_path);
+    let type_info_ = crate::path::type_info_(vct_reflect_path);
+    let reflect_kind_ = crate::path::reflect_kind_(vct_reflect_path);
+    let reflect_ref_ = crate::path::reflect_ref_(vct_reflect_path);
+    let reflect_mut_ = crate::path::reflect_mut_(vct_reflect_path);
+    let reflect_owned_ = crate::path::reflect_owned_(vct_reflect_path);
+    let apply_error_ = crate::path::apply_error_(vct_reflect_path);
+
+    let member = transparent_field(info).to_member();
+    let input_ = Ident::new("__input", Span::call_site());
+    let field_or_self = get_field_or_self_tokens(info, &input_);
+    let reflect_clone_tokens = get_struct_clone_impl(info);
+
+    let parser = meta.type_path_parser();
+    let real_ident = parser.real_ident();
+    let (impl_generics, ty_generics, _) = parser.generics().split_for_impl();
+    let where_clause = meta.where_clause();
+
+    quote! {
+        impl #impl_generics #reflect_ for #real_ident #ty_generics #where_clause {
+            #[inline]
+            fn as_reflect(&self) -> &dyn #reflect_ {
+                self
+            }
+
+            #[inline]
+            fn as_reflect_mut(&mut self) -> &mut dyn #reflect_ {
+                self
+            }
+
+            #[inline]
+            fn into_reflect(self: #alloc_utils_::Box<Self>) -> #alloc_utils_::Box<dyn #reflect_> {
+                self
+            }
+
+            fn set(&mut self, #input_: #alloc_utils_::Box<dyn #reflect_>) -> #ResultFP<(), #alloc_utils_::Box<dyn #reflect_>> {
+                match <dyn #reflect_>::take::<Self>(#input_) {
+                    #ResultFP::Ok(__val) => {
+                        *self = __val;
+                        #ResultFP::Ok(())
+                    }
+                    #ResultFP::Err(#input_) => #reflect_::set(&mut self.#member, #input_),
+                }
+            }
+
+            #[inline]
+            fn represented_type_info(&self) -> #OptionFP<&'static #type_info_> {
+                #reflect_::represented_type_info(&self.#member)
+            }
+
+            #[inline]
+            fn reflect_kind(&self) -> #reflect_kind_ {
+                #reflect_::reflect_kind(&self.#member)
+            }
+
+            #[inline]
+            fn reflect_ref(&self) -> #reflect_ref_<'_> {
+                #reflect_::reflect_ref(&self.#member)
+            }
+
+            #[inline]
+            fn reflect_mut(&mut self) -> #reflect_mut_<'_> {
+                #reflect_::reflect_mut(&mut self.#member)
+            }
+
+            #[inline]
+            fn reflect_owned(self: #alloc_utils_::Box<Self>) -> #reflect_owned_ {
+                #reflect_::reflect_owned(#alloc_utils_::Box::new(self.#member))
+            }
+
+            #[inline]
+            fn to_dynamic(&self) -> #alloc_utils_::Box<dyn #reflect_> {
+                #reflect_::to_dynamic(&self.#member)
+            }
+
+            fn try_apply(&mut self, #input_: &dyn #reflect_) -> #ResultFP<(), #apply_error_> {
+                #field_or_self
+                #reflect_::try_apply(&mut self.#member, #input_)
+            }
+
+            fn try_apply_owned(&mut self, #input_: #alloc_utils_::Box<dyn #reflect_>) -> #ResultFP<(), #apply_error_> {
+                match <dyn #reflect_>::take::<Self>(#input_) {
+                    #ResultFP::Ok(__val) => {
+                        *self = __val;
+                        #ResultFP::Ok(())
+                    }
+                    #ResultFP::Err(#input_) => #reflect_::try_apply_owned(&mut self.#member, #input_),
+                }
+            }
+
+            #reflect_clone_tokens
+
+            fn reflect_partial_eq(&self, #input_: &dyn #reflect_) -> #OptionFP<bool> {
+                #field_or_self
+                #reflect_::reflect_partial_eq(&self.#member, #input_)
+            }
+
+            fn reflect_partial_cmp(&self, #input_: &dyn #reflect_) -> #OptionFP<::core::cmp::Ordering> {
+                #field_or_self
+                #reflect_::reflect_partial_cmp(&self.#member, #input_)
+            }
+
+            #[inline]
+            fn reflect_hash(&self) -> #OptionFP<u64> {
+                #reflect_::reflect_hash(&self.#member)
+            }
+
+            #[inline]
+            fn reflect_debug(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                #reflect_::reflect_debug(&self.#member, f)
+            }
+        }
+    }
+}
+
+fn get_transparent_type_trait(info: &ReflectStruct) -> TokenStream {
+    use crate::path::fp::OptionFP;
+
+    let vct_reflect_path = info.meta().vct_reflect_path();
+    let reflect_ = crate::path::reflect_(vct_reflect_path);
+    let type_path_ = crate::path::type_path_(vct_reflect_path);
+    let dynamic_type_path_ = crate::path::dynamic_type_path_(vct_reflect_path);
+    let type_trait_transparent_ = crate::path::type_trait_transparent_(vct_reflect_path);
+
+    let field = transparent_field(info);
+    let field_ty = &field.data.ty;
+    let member = field.to_member();
+
+    quote! {
+        #type_trait_transparent_::new::<#field_ty>(|__value| {
+            match <dyn #reflect_>::downcast_ref::<Self>(__value) {
+                #OptionFP::Some(__this) => #reflect_::as_reflect(&__this.#member),
+                #OptionFP::None => ::core::panic!(
+                    "Transparent type mismatched, Transparent Type `{}` with Value Type: {}",
+                    <Self as #type_path_>::type_path(),
+                    #dynamic_type_path_::reflect_type_path(__value),
+                ),
+            }
+        })
+    }
+}
+
+fn get_registry_dependencies(info: &ReflectStruct) -> TokenStream {
+    let vct_reflect_path = info.meta().vct_reflect_path();
+    let type_registry_ = crate::path::type_registry_(vct_reflect_path);
+
+    let field_ty = &transparent_field(info).data.ty;
+
+    quote! {
+        fn register_dependencies(__registry: &mut #type_registry_) {
+            #type_registry_::register::<#field_ty>(__registry);
+        }
+    }
+}
+
+/// Accepts a value of the type itself or of its field,
+/// ignored fields are set to `Default::default()`.
+fn impl_transparent_from_reflect(info: &ReflectStruct) -> TokenStream {
+    use crate::path::fp::OptionFP;
+
+    let meta = info.meta();
+    let vct_reflect_path = meta.vct_reflect_path();
+    let reflect_ = crate::path::reflect_(vct_reflect_path);
+    let from_reflect_ = crate::path::from_reflect_(vct_reflect_path);
+
+    let input_ = Ident::new("__input", Span::call_site());
+
+    let clone_tokens = get_common_from_reflect_tokens(meta, &input_);
+    let field_or_self = get_field_or_self_tokens(info, &input_);
+
+    let field_values = info.fields().iter().map(|field| {
+        let member = field.to_member();
+        let field_ty = &field.data.ty;
+        if field.attrs.ignore.is_some() {
+            let default_value = field.get_default_tokens();
+            quote! { #member: #default_value, }
+        } else {
+            quote! { #member: <#field_ty as #from_reflect_>::from_reflect(#input_)?, }
+        }
+    });
+
+    let parser = meta.type_path_parser();
+    let real_ident = parser.real_ident();
+    let (impl_generics, ty_generics, _) = parser.generics().split_for_impl();
+    let where_clause = meta.where_clause();
+
+    quote! {
+        impl #impl_generics #from_reflect_ for #real_ident #ty_generics #where_clause  {
+            fn from_reflect(#input_: &dyn #reflect_) -> #OptionFP<Self> {
+                #clone_tokens
+
+                #field_or_self
+
+                #OptionFP::Some(Self {
+                    #(#field_values)*
+                })
+            }
+        }
+    }
+}
@@ -2,7 +2,7 @@ use proc_macro2::{Span, TokenStream};
 use quote::{ToTokens, quote};
 use syn::Ident;
 
-use crate::{ReflectMeta, derive_data::{FieldAccessors, ReflectStruct}, impls::{get_common_debug_impl, get_common_hash_impl, get_common_partial_eq_impl, get_struct_clone_impl, impl_struct_from_reflect, impl_trait_get_type_traits, impl_trait_reflect, impl_trait_type_path, impl_trait_typed}};
+use crate::{ReflectMeta, derive_data::{FieldAccessors, ReflectStruct}, impls::{get_common_debug_impl, get_common_hash_impl, get_common_partial_cmp_impl, get_common_partial_eq_impl, get_struct_clone_impl, impl_struct_from_reflect, impl_struct_interpolate, impl_trait_get_type_traits, impl_trait_reflect, impl_trait_type_path, impl_trait_typed}};
 
 
 
@@ -36,6 +36,7 @@ pub(crate) fn impl_tuple_struct(info: &ReflectStruct) -> TokenStream {
         let to_dynamic_tokens = get_tuple_struct_to_dynamic_impl(meta);
         let reflect_clone_tokens = get_struct_clone_impl(info);
         let reflect_partial_eq_tokens = get_common_partial_eq_impl(meta);
+        let reflect_partial_cmp_tokens = get_common_partial_cmp_impl(meta);
         let reflect_hash_tokens = get_common_hash_impl(meta);
         let reflect_debug_tokens = get_common_debug_impl(meta);
 
@@ -46,6 +47,7 @@ pub(crate) fn impl_tuple_struct(info: &ReflectStruct) -> TokenStream {
             to_dynamic_tokens,
             reflect_clone_tokens,
             reflect_partial_eq_tokens,
+            reflect_partial_cmp_tokens,
             reflect_hash_tokens,
             reflect_debug_tokens,
         )
@@ -56,7 +58,7 @@ pub(crate) fn impl_tuple_struct(info: &ReflectStruct) -> TokenStream {
 
     // trait: GetTypeTraits
     let get_type_traits_tokens = if meta.attrs().impl_switchs.impl_get_type_traits {
-        impl_trait_get_type_traits(meta, get_registry_dependencies(info))
+        impl_trait_get_type_traits(meta, get_registry_dependencies(info), &[])
     } else {
         crate::utils::empty()
     };
@@ -68,6 +70,9 @@ pub(crate) fn impl_tuple_struct(info: &ReflectStruct) -> TokenStream {
         crate::utils::empty()
     };
 
+    // trait: Interpolate
+    let interpolate_trait_tokens = impl_struct_interpolate(info);
+
     quote! {
         #type_path_trait_tokens
 
@@ -80,6 +85,8 @@ pub(crate) fn impl_tuple_struct(info: &ReflectStruct) -> TokenStream {
         #get_type_traits_tokens
 
         #get_from_reflect_tokens
+
+        #interpolate_trait_tokens
     }
 }
 
@@ -103,7 +110,8 @@ pub fn impl_trait_tuple_struct(info: &ReflectStruct) -> TokenStream {
 
     let parser = meta.type_path_parser();
     let real_ident = parser.real_ident();
-    let (impl_generics, ty_generics, where_clause) = parser.generics().split_for_impl();
+    let (impl_generics, ty_generics, _) = parser.generics().split_for_impl();
+    let where_clause = meta.where_clause();
 
     quote! {
         impl #impl_generics #tuple_struct_ for #real_ident #ty_generics #where_clause {
@@ -148,6 +156,8 @@ pub fn get_tuple_struct_try_apply_impl(meta: &ReflectMeta) -> TokenStream {
     let tuple_struct_ = crate::path::tuple_struct_(vct_reflect_path);
     let reflect_kind_ = crate::path::reflect_kind_(vct_reflect_path);
     let apply_error_ = crate::path::apply_error_(vct_reflect_path);
+    let alloc_utils_ = crate::path::alloc_utils_(vct_reflect_path);
+    let tuple_struct_try_apply_owned_ = crate::path::tuple_struct_try_apply_owned_(vct_reflect_path);
 
     let input_ = Ident::new("__ident", Span::call_site());
 
@@ -165,7 +175,7 @@ pub fn get_tuple_struct_try_apply_impl(meta: &ReflectMeta) -> TokenStream {
             if let #reflect_ref::TupleStruct(struct_value) = #reflect_::reflect_ref(#input_) {
                 for (i, value) in ::core::iter::Iterator::enumerate(#tuple_struct_::iter_fields(struct_value)) {
                     if let #OptionFP::Some(v) = #tuple_struct_::field_mut(self, i) {
-                        #reflect_::try_apply(v, value)?;
+                        #reflect_::try_apply(v, value).map_err(|e| #apply_error_::with_tuple_index(e, i))?;
                     }
                 }
 
@@ -179,6 +189,11 @@ pub fn get_tuple_struct_try_apply_impl(meta: &ReflectMeta) -> TokenStream {
                 )
             }
         }
+
+        #[inline]
+        fn try_apply_owned(&mut self, #input_: #alloc_utils_::Box<dyn #reflect_>) -> #ResultFP<(), #apply_error_> {
+            #tuple_struct_try_apply_owned_(self, #input_)
+        }
     }
 }
 
@@ -200,7 +215,7 @@ fn get_registry_dependencies(info: &ReflectStruct) -> TokenStream {
     let vct_reflect_path = info.meta().vct_reflect_path();
     let type_registry_ = crate::path::type_registry_(vct_reflect_path);
 
-    let field_types =  info.active_fields().map(|x|&x.data.ty);
+    let field_types =  info.active_fields().map(|x| x.reflected_ty(vct_reflect_path));
 
     quote! {
         fn register_dependencies(__registry: &mut #type_registry_) {
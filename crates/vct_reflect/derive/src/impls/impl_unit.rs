@@ -23,6 +23,7 @@ pub(crate) fn impl_unit(meta: &ReflectMeta) -> proc_macro2::TokenStream {
         let to_dynamic_tokens = get_unit_to_dynamic_impl(meta);
         let reflect_clone_tokens = get_unit_clone_impl(meta);
         let reflect_partial_eq_tokens = get_unit_partial_eq_impl(meta);
+        let reflect_partial_cmp_tokens = get_unit_partial_cmp_impl(meta);
         let reflect_hash_tokens = get_unit_hash_impl(meta);
         let reflect_debug_tokens = get_unit_debug_impl(meta);
 
@@ -33,6 +34,7 @@ pub(crate) fn impl_unit(meta: &ReflectMeta) -> proc_macro2::TokenStream {
             to_dynamic_tokens,
             reflect_clone_tokens,
             reflect_partial_eq_tokens,
+            reflect_partial_cmp_tokens,
             reflect_hash_tokens,
             reflect_debug_tokens,
         )
@@ -42,7 +44,7 @@ pub(crate) fn impl_unit(meta: &ReflectMeta) -> proc_macro2::TokenStream {
 
     // trait: GetTypeTraits
     let get_type_traits_tokens = if meta.attrs().impl_switchs.impl_get_type_traits {
-        impl_trait_get_type_traits(meta, crate::utils::empty())
+        impl_trait_get_type_traits(meta, crate::utils::empty(), &[])
     } else {
         crate::utils::empty()
     };
@@ -124,10 +126,11 @@ fn get_unit_to_dynamic_impl(meta: &ReflectMeta) -> proc_macro2::TokenStream {
             }
         }
     } else {
+        let value = meta.wrap_value(meta.value_path());
         quote! {
             #[inline]
             fn to_dynamic(&self) -> #alloc_utils_::Box<dyn #reflect_> {
-                #alloc_utils_::Box::new(Self)
+                #alloc_utils_::Box::new(#value)
             }
         }
     }
@@ -150,10 +153,11 @@ fn get_unit_clone_impl(meta: &ReflectMeta) -> proc_macro2::TokenStream {
             }
         }
     } else {
+        let value = meta.wrap_value(meta.value_path());
         quote! {
             #[inline]
             fn reflect_clone(&self) -> #ResultFP<#alloc_utils_::Box<dyn #reflect_>, #reflect_clone_error_> {
-                #ResultFP::Ok(#alloc_utils_::Box::new(Self) as #alloc_utils_::Box<dyn #reflect_>)
+                #ResultFP::Ok(#alloc_utils_::Box::new(#value) as #alloc_utils_::Box<dyn #reflect_>)
             }
         }
     }
@@ -189,6 +193,35 @@ fn get_unit_partial_eq_impl(meta: &ReflectMeta) -> proc_macro2::TokenStream  {
     }
 }
 
+fn get_unit_partial_cmp_impl(meta: &ReflectMeta) -> proc_macro2::TokenStream  {
+    use crate::path::fp::{OptionFP, PartialOrdFP};
+    let vct_reflect_path = meta.vct_reflect_path();
+    let reflect_ = crate::path::reflect_(vct_reflect_path);
+
+    if let Some(span) = meta.attrs().avail_traits.partial_ord {
+        quote_spanned! { span =>
+            #[inline]
+            fn reflect_partial_cmp(&self, other: &dyn #reflect_) -> #OptionFP<::core::cmp::Ordering> {
+                if let #OptionFP::Some(value) = other.downcast_ref::<Self>() {
+                    return #PartialOrdFP::partial_cmp(self, value);
+                }
+                #OptionFP::None
+            }
+        }
+    } else {
+        quote! {
+            #[inline]
+            fn reflect_partial_cmp(&self, other: &dyn #reflect_) -> #OptionFP<::core::cmp::Ordering> {
+                if other.is::<Self>() {
+                    #OptionFP::Some(::core::cmp::Ordering::Equal)
+                } else {
+                    #OptionFP::None
+                }
+            }
+        }
+    }
+}
+
 fn get_unit_hash_impl(meta: &ReflectMeta) -> proc_macro2::TokenStream {
     use crate::path::fp::{OptionFP, HashFP, HasherFP, TypeIdFP, AnyFP};
 
@@ -244,16 +277,18 @@ fn impl_unit_from_reflect(meta: &ReflectMeta) -> proc_macro2::TokenStream {
     let vct_reflect_path = meta.vct_reflect_path();
     let reflect_ = crate::path::reflect_(vct_reflect_path);
     let from_reflect_ = crate::path::from_reflect_(vct_reflect_path);
+    let value = meta.wrap_value(meta.value_path());
 
     let parser = meta.type_path_parser();
     let real_ident = parser.real_ident();
-    let (impl_generics, ty_generics, where_clause) = parser.generics().split_for_impl();
+    let (impl_generics, ty_generics, _) = parser.generics().split_for_impl();
+    let where_clause = meta.where_clause();
 
     quote! {
         impl #impl_generics #from_reflect_ for #real_ident #ty_generics #where_clause  {
             fn from_reflect(__input: &dyn #reflect_) -> #OptionFP<Self> {
                 if <dyn #reflect_>::is::<Self>(__input) {
-                    #OptionFP::Some(Self)
+                    #OptionFP::Some(#value)
                 } else {
                     #OptionFP::None
                 }
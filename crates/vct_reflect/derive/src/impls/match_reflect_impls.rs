@@ -1,25 +1,35 @@
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::DeriveInput;
+use syn::{DeriveInput, Path};
 use crate::{ImplSourceKind, derive_data::ReflectDerive};
 
-pub(crate) fn match_reflect_impls(ast: DeriveInput, source: ImplSourceKind) -> TokenStream {
-    let reflect_derive = match ReflectDerive::from_input(&ast, source) {
+/// `remote` is the wrapped type of `#[reflect_remote(...)]`, see [`ReflectDerive::from_input`].
+pub(crate) fn match_reflect_impls(mut ast: DeriveInput, source: ImplSourceKind, remote: Option<&Path>) -> TokenStream {
+    let generic_attributes = match ReflectDerive::take_generic_attributes(&mut ast.generics) {
         Ok(val) => val,
         Err(err) => return err.into_compile_error().into(),
     };
 
+    let reflect_derive = match ReflectDerive::from_input(&ast, source, generic_attributes, remote) {
+        Ok(val) => val,
+        Err(err) => return err.into_compile_error().into(),
+    };
+
+    let derive_serde = crate::impls::impl_derive_serde(reflect_derive.meta());
+
     let reflect_impls: proc_macro2::TokenStream = match reflect_derive {
         ReflectDerive::Struct(info) => crate::impls::impl_struct(&info),
         ReflectDerive::TupleStruct(info) => crate::impls::impl_tuple_struct(&info),
         ReflectDerive::Enum(info) => crate::impls::impl_enum(&info),
         ReflectDerive::UnitStruct(meta) => crate::impls::impl_unit(&meta),
         ReflectDerive::Opaque(meta) => crate::impls::impl_opaque(&meta),
+        ReflectDerive::Transparent(info) => crate::impls::impl_transparent(&info),
     };
 
     let res = quote! {
         const _: () = {
             #reflect_impls
+            #derive_serde
         };
     };
 
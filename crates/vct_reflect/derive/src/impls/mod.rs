@@ -16,6 +16,12 @@ pub(crate) use impl_struct_from_reflect::impl_struct_from_reflect;
 mod impl_struct_clone;
 pub(crate) use impl_struct_clone::get_struct_clone_impl;
 
+mod impl_struct_interpolate;
+pub(crate) use impl_struct_interpolate::impl_struct_interpolate;
+
+mod impl_derive_serde;
+pub(crate) use impl_derive_serde::impl_derive_serde;
+
 // mod enum_utils;
 // pub(crate) use enum_utils::*;
 mod common_imps;
@@ -36,5 +42,8 @@ pub(crate) use impl_opaque::impl_opaque;
 mod impl_unit;
 pub(crate) use impl_unit::impl_unit;
 
+mod impl_transparent;
+pub(crate) use impl_transparent::impl_transparent;
+
 mod match_reflect_impls;
 pub(crate) use match_reflect_impls::match_reflect_impls;
@@ -2,7 +2,7 @@
 
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::{DeriveInput, parse_macro_input};
+use syn::{DeriveInput, ItemTrait, Path, parse_macro_input};
 
 use crate::derive_data::{ReflectMeta, ReflectOpaqueParser, TypeAttributes, TypePathParser};
 
@@ -13,6 +13,8 @@ mod path;
 mod utils;
 mod derive_data;
 mod impls;
+mod reflect_trait;
+mod reflect_remote;
 
 
 /// # Example
@@ -55,6 +57,24 @@ mod impls;
 /// struct Foo { /* ... */ }
 /// ```
 /// 
+/// For long-lived serialized data, a stable UUID can be attached, it is available through `TypePath::type_uuid` and `TypeRegistry::get_with_type_uuid`:
+/// 
+/// ```rust, ignore
+/// #[derive(Reflect)]
+/// #[reflect(uuid = "67e55044-10b1-426f-9247-bb680e5fe0c8")]
+/// struct Foo { /* ... */ }
+/// ```
+/// 
+/// Structs with named fields can declare a version. They are serialized as `(version, struct)`,
+/// and data of older versions is migrated with the migrations registered by `TypeRegistry::register_migration`,
+/// see `vct_reflect::migrate`:
+///
+/// ```rust, ignore
+/// #[derive(Reflect)]
+/// #[reflect(version = 2)]
+/// struct Foo { /* ... */ }
+/// ```
+///
 /// `Opaque` is a special attribute that forces the type to be treated as `Opaque` instead of `Struct`, etc.
 /// 
 /// ```rust, ignore
@@ -70,7 +90,49 @@ mod impls;
 /// #[reflect(Opaque, clone)] // error
 /// struct Foo { /* ... */ }
 /// ```
-/// 
+///
+/// Unions can only be reflected as `Opaque` types, so they require `#[reflect(Opaque, clone)]`.
+///
+/// ```rust, ignore
+/// #[derive(Reflect, Clone, Copy)]
+/// #[reflect(Opaque, clone)]
+/// union Bits { int: u32, float: f32 }
+/// ```
+///
+/// Reflected types must be `'static`, so lifetime parameters are rejected. Fields can still borrow
+/// `'static` data, like `Cow<'static, str>` or `&'static str` (which cannot be deserialized).
+///
+/// ```rust, ignore
+/// #[derive(Reflect)]
+/// struct Label { text: Cow<'static, str>, tag: &'static str }
+///
+/// #[derive(Reflect)]
+/// struct View<'a> { text: &'a str } // error
+/// ```
+///
+/// `transparent` makes a struct with a single reflected field delegate its reflection to that field,
+/// like `serde(transparent)`. The type is `Opaque` in its own `TypeInfo`, but its kind, `ReflectRef`,
+/// represented type info, comparison, hashing, debugging and serialization are those of the field,
+/// and `FromReflect` accepts values of the field type. Other fields must be `ignore` and implement `Default`.
+///
+/// ```rust
+/// use vct_reflect::{FromReflect, Reflect, registry::TypeRegistry, serde::InternalSerializer};
+/// use vct_reflect_derive::Reflect;
+///
+/// #[derive(Reflect)]
+/// #[reflect(transparent)]
+/// struct Meters(u32);
+///
+/// assert_eq!(Meters(3).reflect_kind(), 3u32.reflect_kind());
+///
+/// let mut registry = TypeRegistry::new();
+/// registry.register::<Meters>();
+/// let json = serde_json::to_string(&InternalSerializer::new(&Meters(3), &registry)).unwrap();
+/// assert_eq!(json, "3");
+///
+/// assert_eq!(Meters::from_reflect(&3u32).map(|meters| meters.0), Some(3));
+/// ```
+///
 /// # Using standard traits
 /// 
 /// If the type implements traits like `Hash` or `Clone`, the reflection impls can be simplified (often much faster). The macro cannot know this, so it does not assume them by default. Use attributes to declare availability so the macro can optimize. As noted, `Opaque` types must support `Clone`, so implement it and mark with `clone`.
@@ -88,27 +150,159 @@ mod impls;
 /// - `default`: std::Default
 /// - `hash`: std::Hash
 /// - `partial_eq`: std::PartialEq
+/// - `partial_ord`: std::PartialOrd, not included in `full`
+/// - `interpolate`: vct_reflect::registry::Interpolate, implemented field by field for structs, not included in `full`
 /// - `serialize`: serde::Serialize
 /// - `deserialize`: serde::Deserialize
 /// 
-/// `auto_register` is special: with the feature enabled, marked types auto-register.
+/// `auto_register` is special: with the `auto_register_inventory` feature enabled, marked types
+/// are collected at link time and registered by `TypeRegistry::register_auto_types`.
+/// Generic types are not collected, register each instantiation manually.
 /// 
 /// Two convenience bundles enable multiple flags at once:
 /// 
 /// - `serde`: `serialize` + `deserialize` + `auto_register`
 /// - `full`: all seven above (including `auto_register`)
-/// 
+///
+/// `derive_serde` goes the other way: the macro implements `serde::Serialize` and `serde::Deserialize`
+/// for the type through `vct_reflect::serde::{reflect_serialize, reflect_deserialize}`, so it can be
+/// used with any serde format without a hand-written impl. The output matches `InternalSerializer`.
+/// It cannot be combined with `serialize`, `deserialize`, `serde` or `full` (the reflection serializers
+/// would call back into the generated impls), and cannot be used for Opaque or foreign types.
+///
+/// ```rust, ignore
+/// #[derive(Reflect)]
+/// #[reflect(derive_serde)]
+/// struct Config { name: String, level: u32 }
+///
+/// let json = serde_json::to_string(&config)?;
+/// ```
+///
+/// # Custom type traits
+///
+/// Any other path whose last segment starts with an uppercase letter is a custom type trait.
+/// It must be a `TypeTrait` (any `Clone + Send + Sync` type) implementing `FromType<Self>`.
+/// `GetTypeTraits::get_type_traits` inserts it next to the built-in ones, so it can be fetched
+/// from the `TypeRegistry`.
+///
+/// ```rust, ignore
+/// #[derive(Clone)]
+/// struct ReflectDraw { draw: fn(&dyn Reflect) }
+/// // impl<T: Draw + Typed> FromType<T> for ReflectDraw ...
+///
+/// #[derive(Reflect)]
+/// #[reflect(ReflectDraw, my_crate::ReflectSave)]
+/// struct Sprite { /* ... */ }
+/// ```
+///
 /// # Docs reflection
 /// 
 /// Enable the `reflect_docs` feature to include docs in type info. By default the macro collects `#[doc = "..."]` (including `///` comments).
 /// 
+/// Docs on fields and enum variants are collected as well, and are available through `docs()`
+/// of `NamedField`, `UnnamedField` and `VariantInfo`. `#[reflect(docs = ...)]` also works on them.
+/// 
 /// Use `#[reflect(docs = false)]` to disable doc collection for a type.
 /// 
 /// Use `#[reflect(docs = "...")]` to override with custom docs; when present, the macro ignores `#[doc = "..."]`.
+/// 
+/// # Field attributes
+/// 
+/// - `ignore`: the field is not reflected.
+/// - `default`: `FromReflect` uses `Default::default()` when the field is missing from the input,
+///   so data saved before the field was added can still be converted. Ignored fields marked
+///   `default` no longer prevent `FromReflect` from constructing the type.
+/// - `default = "path"`: like `default`, but calls `path()` instead of `Default::default()`,
+///   so the field type does not need to implement `Default`.
+/// - `clone_with = "path"`: for ignored fields, `reflect_clone` calls `path(&field)` to clone them
+///   instead of failing, so types holding non-reflected values (channels, handles, ...) stay cloneable.
+/// - `dynamic`: the field is a `Box<dyn Reflect>` (or `Option<Box<dyn Reflect>>`) holding a value of any type.
+///   It is reflected as `dyn Reflect`, and serialized with its type path like `ReflectSerializer`,
+///   so the actual type must be registered to deserialize it.
+/// - `flatten`: the fields of a nested struct are exposed as the fields of the parent in `Struct`,
+///   `TypeInfo` and serde, like `serde(flatten)`. They follow the other fields, in declaration order.
+///   The field type must be a struct, and the flattened names must not collide with the other fields.
+/// - `skip_serializing`: the named field is reflected as usual but never serialized.
+///   It is stored as the `SkipSerializing` custom attribute of the field.
+/// - `skip_serializing_if = "path"`: like `skip_serializing`, but only if `path(&field)` returns `true`.
+///   Only self-describing formats can deserialize such fields.
+///   Skipped fields are missing when deserializing, so combine them with `default` for `FromReflect`.
+///
+/// ```rust, ignore
+/// #[derive(Reflect)]
+/// struct Foo {
+///     a: u32,
+///     #[reflect(default)]
+///     b: u32,
+///     #[reflect(default = "default_retries")]
+///     retries: u32,
+///     #[reflect(skip_serializing, default)]
+///     token: u64,
+///     #[reflect(skip_serializing_if = "VecDeque::is_empty", default)]
+///     tags: VecDeque<String>,
+///     #[reflect(ignore, default)]
+///     cache: VecDeque<u32>,
+///     #[reflect(ignore, clone_with = "Sender::clone")]
+///     sender: Sender<Event>,
+///     #[reflect(dynamic)]
+///     plugin: Box<dyn Reflect>,
+///     #[reflect(dynamic)]
+///     extra: Option<Box<dyn Reflect>>,
+/// }
+/// ```
+///
+/// # Renaming
+///
+/// `rename` changes the name of a named field or an enum variant, `rename_all` renames
+/// the fields of a struct, the variants of an enum, or the fields of an enum variant,
+/// like `serde(rename_all)`. The names are used by `TypeInfo`, field access and serialization.
+///
+/// Available rules: `lowercase`, `UPPERCASE`, `PascalCase`, `camelCase`, `snake_case`,
+/// `SCREAMING_SNAKE_CASE`, `kebab-case` and `SCREAMING-KEBAB-CASE`.
+///
+/// ```rust, ignore
+/// #[derive(Reflect)]
+/// #[reflect(rename_all = "camelCase")]
+/// struct Foo {
+///     max_health: u32,       // "maxHealth"
+///     #[reflect(rename = "id")]
+///     entity_id: u64,        // "id"
+/// }
+/// ```
+///
+/// # Generic bounds
+///
+/// For generic types, every type parameter must be `TypePath`, and the field types that mention
+/// a type parameter must be `FromReflect + Typed + GetTypeTraits`. `no_bounds` skips the field bounds,
+/// and `where` appends custom predicates. The predicates are separated by commas,
+/// so `where` must be the last item of the attribute.
+///
+/// ```rust, ignore
+/// #[derive(Reflect)]
+/// #[reflect(no_bounds, where T: Asset)]
+/// struct Foo<T> {
+///     handle: Handle<T>, // `Handle<T>` implements reflection traits for all `T: Asset`
+/// }
+/// ```
+///
+/// # Custom attributes
+///
+/// `#[reflect(@expr)]` stores the value of `expr` in the `CustomAttributes` of the type,
+/// a field, an enum variant or a generic parameter. The value must implement `Reflect`.
+///
+/// ```rust, ignore
+/// #[derive(Reflect)]
+/// #[reflect(@DisplayName("Foo"))]
+/// enum Foo<#[reflect(@DisplayName("Item"))] T> {
+///     #[reflect(@DisplayName("Some value"))]
+///     Some(#[reflect(@0..256u32)] T),
+///     None,
+/// }
+/// ```
 #[proc_macro_derive(Reflect, attributes(reflect))]
 pub fn derive_full_reflect(input: TokenStream) -> TokenStream {
     let ast = parse_macro_input!(input as DeriveInput);
-    impls::match_reflect_impls(ast, ImplSourceKind::DeriveLocalType)
+    impls::match_reflect_impls(ast, ImplSourceKind::DeriveLocalType, None)
 }
 
 /// Implements reflection for foreign types, requiring full type info and field access. 
@@ -128,7 +322,7 @@ pub fn derive_full_reflect(input: TokenStream) -> TokenStream {
 #[proc_macro]
 pub fn impl_reflect(input: TokenStream) -> TokenStream {
     let ast = parse_macro_input!(input as DeriveInput);
-    impls::match_reflect_impls(ast, ImplSourceKind::ImplForeignType)
+    impls::match_reflect_impls(ast, ImplSourceKind::ImplForeignType, None)
 }
 
 /// How the macro was invoked.
@@ -149,8 +343,14 @@ pub(crate) enum ImplSourceKind {
 /// impl_reflect_opaque!(String (clone, debug, TypePath = false, docs = "hello"));
 /// impl_reflect_opaque!((in utils::time) Instant (clone));
 /// impl_reflect_opaque!((in utils::time as Ins) Instant (clone));
+/// impl_reflect_opaque!(my::Wrapper<T: Clone + Send + Sync> (clone));
 /// ```
 /// 
+/// A single ident is treated as a primitive, its type path is the ident itself.
+/// A path with several segments (or with `in`) is a foreign type, whose type path is
+/// the path written; generic types must use one of these forms.
+/// Type parameters are required to be `TypePath`, other bounds are written inline or in a `where` clause.
+/// 
 /// This macro always implies `Opaque`, so `clone` is required.
 /// 
 /// See: [`derive Reflect`](derive_full_reflect)
@@ -160,8 +360,14 @@ pub fn impl_reflect_opaque(input: TokenStream) -> TokenStream {
 
     // let default_name = &def.type_path.segments.last().unwrap().ident;
     let parser = {
-        if op.type_path.leading_colon.is_none() && op.custom_path.is_none() {
+        let is_single_ident = op.type_path.leading_colon.is_none() && op.type_path.segments.len() == 1;
+        if is_single_ident && op.custom_path.is_none() && op.generics.params.is_empty() {
             TypePathParser::Primitive(&op.type_ident)
+        } else if is_single_ident && op.custom_path.is_none() {
+            return syn::Error::new(
+                op.type_ident.span(),
+                "generic opaque types need a module path, use `(in module::path) Ident<...>` or `module::path::Ident<...>`.",
+            ).into_compile_error().into();
         } else {
             TypePathParser::Foreign {
                 path: &op.type_path,
@@ -195,3 +401,86 @@ pub fn impl_reflect_opaque(input: TokenStream) -> TokenStream {
         };
     }.into()
 }
+
+/// Generates a `Reflect{TraitName}` type trait, used to cast a `&dyn Reflect`
+/// back to the trait object through the `TypeRegistry`.
+/// 
+/// ```rust, ignore
+/// #[reflect_trait]
+/// pub trait Greet {
+///     fn greet(&self) -> String;
+/// }
+/// 
+/// #[derive(Reflect)]
+/// struct Foo;
+/// 
+/// impl Greet for Foo { /* ... */ }
+/// 
+/// let mut registry = TypeRegistry::new();
+/// registry.register::<Foo>();
+/// registry.register_type_trait::<Foo, ReflectGreet>();
+/// 
+/// let value: &dyn Reflect = &Foo;
+/// let reflect_greet = registry
+///     .get_type_trait::<ReflectGreet>(value.type_id())
+///     .unwrap();
+/// let greet: &dyn Greet = reflect_greet.get(value).unwrap();
+/// ```
+/// 
+/// The generated struct provides `get`, `get_mut` and `get_boxed`, and implements `FromType<T>`
+/// for every `T: Greet + Reflect + Typed`. Generic traits are not supported.
+#[proc_macro_attribute]
+pub fn reflect_trait(_args: TokenStream, input: TokenStream) -> TokenStream {
+    let item_trait = parse_macro_input!(input as ItemTrait);
+    reflect_trait::reflect_trait(item_trait).into()
+}
+
+/// Reflects a foreign type through a local wrapper.
+/// 
+/// ```rust, ignore
+/// #[reflect_remote(foreign::Point)]
+/// #[derive(Clone)]
+/// #[reflect(clone)]
+/// struct Point {
+///     x: u32,
+///     y: u32,
+/// }
+/// 
+/// // `Point` is now `#[repr(transparent)] struct Point(pub foreign::Point);`
+/// let point: Point = foreign::Point { x: 1, y: 2 }.into();
+/// let remote: foreign::Point = point.into();
+///
+/// // A foreign value can be viewed as the wrapper in place.
+/// let value: &dyn Reflect = Point::as_wrapper(&remote);
+/// ```
+/// 
+/// The input mirrors the definition of the foreign type and accepts the same attributes
+/// as [`derive Reflect`](derive_full_reflect). It is replaced by a `#[repr(transparent)]` wrapper
+/// of the foreign value, which implements `From` in both directions and provides
+/// `as_wrapper`/`as_wrapper_mut` to view `&foreign::Type` as `&Wrapper`, so foreign values
+/// can be reflected without moving them. Other attributes (like `derive`) apply to the wrapper.
+///
+/// The mirrored fields (or variants) must match the foreign type exactly, this is checked at compile time.
+///
+/// # Restrictions
+///
+/// The reflection impls read and build the foreign value field by field,
+/// so every field of the foreign type must be visible to the wrapper.
+/// Foreign types with private fields or `#[non_exhaustive]` types of other crates
+/// cannot be reflected field by field, they can only be wrapped as `Opaque` (requires `Clone`),
+/// the fields are not mirrored then:
+///
+/// ```rust, ignore
+/// #[reflect_remote(foreign::Secret)]
+/// #[derive(Clone)]
+/// #[reflect(Opaque, clone)]
+/// struct Secret;
+/// ```
+///
+/// `transparent` and generic types are not supported.
+#[proc_macro_attribute]
+pub fn reflect_remote(args: TokenStream, input: TokenStream) -> TokenStream {
+    let remote = parse_macro_input!(args as Path);
+    let ast = parse_macro_input!(input as DeriveInput);
+    reflect_remote::reflect_remote(remote, ast).into()
+}
@@ -12,11 +12,13 @@ pub(crate) struct OptionFP;
 /// Full Path (FP) for [`Result`]
 pub(crate) struct ResultFP;
 /// Full Path (FP) for [`Send`]
-// pub(crate) struct SendFP;
-// /// Full Path (FP) for [`Sync`]
-// pub(crate) struct SyncFP;
-// /// Full Path (FP) for [`PartialEq`]
+pub(crate) struct SendFP;
+/// Full Path (FP) for [`Sync`]
+pub(crate) struct SyncFP;
+/// Full Path (FP) for [`PartialEq`]
 pub(crate) struct PartialEqFP;
+/// Full Path (FP) for [`PartialOrd`](core::cmp::PartialOrd)
+pub(crate) struct PartialOrdFP;
 /// Full Path (FP) for [`Hash`](core::hash::Hash)
 pub(crate) struct HashFP;
 /// Full Path (FP) for [`Hasher`](core::hash::Hasher)
@@ -56,17 +58,17 @@ impl ToTokens for ResultFP {
     }
 }
 
-// impl ToTokens for SendFP {
-//     fn to_tokens(&self, tokens: &mut TokenStream) {
-//         quote!(::core::marker::Send).to_tokens(tokens);
-//     }
-// }
+impl ToTokens for SendFP {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        quote!(::core::marker::Send).to_tokens(tokens);
+    }
+}
 
-// impl ToTokens for SyncFP {
-//     fn to_tokens(&self, tokens: &mut TokenStream) {
-//         quote!(::core::marker::Sync).to_tokens(tokens);
-//     }
-// }
+impl ToTokens for SyncFP {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        quote!(::core::marker::Sync).to_tokens(tokens);
+    }
+}
 
 impl ToTokens for PartialEqFP {
     fn to_tokens(&self, tokens: &mut TokenStream) {
@@ -74,6 +76,12 @@ impl ToTokens for PartialEqFP {
     }
 }
 
+impl ToTokens for PartialOrdFP {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        quote!(::core::cmp::PartialOrd).to_tokens(tokens);
+    }
+}
+
 impl ToTokens for HashFP {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         quote!(::core::hash::Hash).to_tokens(tokens);
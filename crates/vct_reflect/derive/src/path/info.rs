@@ -8,6 +8,13 @@ pub(crate) fn type_path_(vct_reflect_path: &syn::Path) -> TokenStream {
     }
 }
 
+#[inline]
+pub(crate) fn type_uuid_(vct_reflect_path: &syn::Path) -> TokenStream {
+    quote! {
+        #vct_reflect_path::info::TypeUuid
+    }
+}
+
 // #[inline(always)]
 // pub(crate) fn type_path_table_(vct_reflect_path: &syn::Path) -> TokenStream {
 //     quote! {
@@ -29,11 +29,13 @@ mod cell;
 mod info;
 mod ops;
 mod registry;
+mod serde;
 
 pub(crate) use cell::*;
 pub(crate) use info::*;
 pub(crate) use ops::*;
 pub(crate) use registry::*;
+pub(crate) use serde::*;
 
 // mod access;
 // `vct_reflect::access` does not require additional content.
@@ -45,6 +47,14 @@ pub(crate) fn macro_exports_(vct_reflect_path: &syn::Path) -> TokenStream {
     }
 }
 
+#[cfg(feature = "auto_register_inventory")]
+#[inline(always)]
+pub(crate) fn auto_register_(vct_reflect_path: &syn::Path) -> TokenStream {
+    quote! {
+        #vct_reflect_path::__macro_exports::auto_register
+    }
+}
+
 #[inline(always)]
 pub(crate) fn alloc_utils_(vct_reflect_path: &syn::Path) -> TokenStream {
     quote! {
@@ -64,6 +64,13 @@ pub(crate) fn struct_field_iter_(vct_reflect_path: &syn::Path) -> TokenStream {
     }
 }
 
+#[inline]
+pub(crate) fn struct_try_apply_owned_(vct_reflect_path: &syn::Path) -> TokenStream {
+    quote! {
+        #vct_reflect_path::ops::struct_try_apply_owned
+    }
+}
+
 // #[inline]
 // pub(crate) fn struct_debug_(vct_reflect_path: &syn::Path) -> TokenStream {
 //     quote! {
@@ -106,6 +113,13 @@ pub(crate) fn tuple_struct_field_iter_(vct_reflect_path: &syn::Path) -> TokenStr
     }
 }
 
+#[inline]
+pub(crate) fn tuple_struct_try_apply_owned_(vct_reflect_path: &syn::Path) -> TokenStream {
+    quote! {
+        #vct_reflect_path::ops::tuple_struct_try_apply_owned
+    }
+}
+
 // #[inline]
 // pub(crate) fn tuple_struct_debug_(vct_reflect_path: &syn::Path) -> TokenStream {
 //     quote! {
@@ -329,6 +343,13 @@ pub(crate) fn enum_(vct_reflect_path: &syn::Path) -> TokenStream {
         #vct_reflect_path::ops::Enum
     }
 }
+
+#[inline]
+pub(crate) fn enum_try_apply_owned_(vct_reflect_path: &syn::Path) -> TokenStream {
+    quote! {
+        #vct_reflect_path::ops::enum_try_apply_owned
+    }
+}
     
 // #[inline]
 // pub(crate) fn enum_debug_(vct_reflect_path: &syn::Path) -> TokenStream {
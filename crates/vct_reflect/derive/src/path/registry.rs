@@ -64,6 +64,20 @@ pub(crate) fn type_trait_from_reflect_(vct_reflect_path: &syn::Path) -> TokenStr
     }
 }
 
+#[inline]
+pub(crate) fn type_trait_interpolate_(vct_reflect_path: &syn::Path) -> TokenStream {
+    quote! {
+        #vct_reflect_path::registry::TypeTraitInterpolate
+    }
+}
+
+#[inline]
+pub(crate) fn interpolate_(vct_reflect_path: &syn::Path) -> TokenStream {
+    quote! {
+        #vct_reflect_path::registry::Interpolate
+    }
+}
+
 #[inline]
 pub(crate) fn type_trait_serialize_(vct_reflect_path: &syn::Path) -> TokenStream {
     quote! {
@@ -77,3 +91,17 @@ pub(crate) fn type_trait_deserialize_(vct_reflect_path: &syn::Path) -> TokenStre
         #vct_reflect_path::registry::TypeTraitDeserialize
     }
 }
+
+#[inline]
+pub(crate) fn type_trait_transparent_(vct_reflect_path: &syn::Path) -> TokenStream {
+    quote! {
+        #vct_reflect_path::registry::TypeTraitTransparent
+    }
+}
+
+#[inline]
+pub(crate) fn type_trait_migrations_(vct_reflect_path: &syn::Path) -> TokenStream {
+    quote! {
+        #vct_reflect_path::migrate::TypeTraitMigrations
+    }
+}
@@ -0,0 +1,30 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+#[inline]
+pub(crate) fn skip_serializing_(vct_reflect_path: &syn::Path) -> TokenStream {
+    quote! {
+        #vct_reflect_path::serde::SkipSerializing
+    }
+}
+
+#[inline]
+pub(crate) fn serde_(vct_reflect_path: &syn::Path) -> TokenStream {
+    quote! {
+        #vct_reflect_path::__macro_exports::serde
+    }
+}
+
+#[inline]
+pub(crate) fn reflect_serialize_(vct_reflect_path: &syn::Path) -> TokenStream {
+    quote! {
+        #vct_reflect_path::serde::reflect_serialize
+    }
+}
+
+#[inline]
+pub(crate) fn reflect_deserialize_(vct_reflect_path: &syn::Path) -> TokenStream {
+    quote! {
+        #vct_reflect_path::serde::reflect_deserialize
+    }
+}
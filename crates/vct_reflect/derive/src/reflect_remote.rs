@@ -0,0 +1,151 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Fields, Ident, Path, Type, spanned::Spanned};
+
+use crate::{ImplSourceKind, REFLECT_ATTRIBUTE_NAME, derive_data::TypeAttributes};
+
+/// Generates a local wrapper for `#[reflect_remote(foreign::Type)]`.
+///
+/// The input type mirrors the definition of the remote type, it is replaced by
+/// a `#[repr(transparent)]` tuple struct wrapping the remote value (keeping the other attributes),
+/// with reflection impls that access the mirrored fields of the remote value,
+/// `From` conversions, and `as_wrapper`/`as_wrapper_mut` to view a remote value as the wrapper.
+pub(crate) fn reflect_remote(remote: Path, mut ast: DeriveInput) -> TokenStream {
+    if !ast.generics.params.is_empty() {
+        return syn::Error::new(
+            ast.generics.span(),
+            "`#[reflect_remote]` does not support generic types.",
+        )
+        .to_compile_error();
+    }
+
+    // `Opaque` wrappers do not mirror the fields, the remote value is reflected as a whole.
+    let is_opaque = TypeAttributes::parse_attrs(&ast.attrs).is_ok_and(|attrs| attrs.is_opaque.is_some());
+    let mirror_check = if is_opaque {
+        crate::utils::empty()
+    } else {
+        match impl_mirror_check(&remote, &ast) {
+            Ok(val) => val,
+            Err(err) => return err.into_compile_error(),
+        }
+    };
+
+    let reflect_impls: TokenStream = crate::impls::match_reflect_impls(
+        ast.clone(),
+        ImplSourceKind::DeriveLocalType,
+        Some(&remote),
+    )
+    .into();
+
+    strip_reflect_attrs(&mut ast);
+
+    let DeriveInput { attrs, vis, ident, .. } = &ast;
+    let conversions = impl_conversions(&remote, ident);
+
+    quote! {
+        #(#attrs)*
+        #[repr(transparent)]
+        #vis struct #ident(pub #remote);
+
+        #reflect_impls
+
+        #mirror_check
+
+        #conversions
+    }
+}
+
+/// `#[reflect]` is only registered as a helper attribute of `derive(Reflect)`.
+fn strip_reflect_attrs(ast: &mut DeriveInput) {
+    ast.attrs.retain(|attr| !attr.path().is_ident(REFLECT_ATTRIBUTE_NAME));
+}
+
+/// Returns the pattern that binds every field of `path` to `__{index}`,
+/// and the bound idents with their mirrored types.
+fn bind_fields<'a>(path: &TokenStream, fields: &'a Fields) -> (TokenStream, Vec<(Ident, &'a Type)>) {
+    let bindings: Vec<_> = fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| (format_ident!("__{}", index), &field.ty))
+        .collect();
+    let idents = bindings.iter().map(|(ident, _)| ident);
+    let pattern = match fields {
+        Fields::Named(fields) => {
+            let members = fields.named.iter().map(|field| &field.ident);
+            quote! { #path { #(#members: #idents),* } }
+        }
+        Fields::Unnamed(_) => quote! { #path ( #(#idents),* ) },
+        Fields::Unit => quote! { #path },
+    };
+    (pattern, bindings)
+}
+
+/// Checks at compile time that the input mirrors every field (and variant) of the remote type
+/// with the same types, the reflection impls rely on the mirrored definition.
+fn impl_mirror_check(remote: &Path, ast: &DeriveInput) -> Result<TokenStream, syn::Error> {
+    let arm = |path: TokenStream, fields: &Fields| {
+        let (pattern, bindings) = bind_fields(&path, fields);
+        let checks = bindings.iter().map(|(ident, ty)| quote! { let _: &#ty = #ident; });
+        quote! { #pattern => { #(#checks)* } }
+    };
+
+    let arms = match &ast.data {
+        Data::Struct(data) => vec![arm(quote! { #remote }, &data.fields)],
+        Data::Enum(data) => data
+            .variants
+            .iter()
+            .map(|variant| {
+                let variant_ident = &variant.ident;
+                arm(quote! { #remote::#variant_ident }, &variant.fields)
+            })
+            .collect(),
+        Data::Union(_) => {
+            return Err(syn::Error::new(
+                ast.span(),
+                "reflection macros do not support unions.",
+            ));
+        }
+    };
+
+    Ok(quote! {
+        const _: fn(&#remote) = |__remote| match __remote {
+            #(#arms)*
+        };
+    })
+}
+
+fn impl_conversions(remote: &Path, ident: &Ident) -> TokenStream {
+    quote! {
+        impl ::core::convert::From<#remote> for #ident {
+            #[inline]
+            fn from(value: #remote) -> Self {
+                Self(value)
+            }
+        }
+
+        impl ::core::convert::From<#ident> for #remote {
+            #[inline]
+            fn from(value: #ident) -> Self {
+                value.0
+            }
+        }
+
+        impl #ident {
+            /// Views a reference to the remote value as the reflected wrapper.
+            #[inline]
+            #[allow(unsafe_code)]
+            pub fn as_wrapper(value: &#remote) -> &Self {
+                // SAFETY: `Self` is a `#[repr(transparent)]` wrapper of the remote type.
+                unsafe { &*(value as *const #remote as *const Self) }
+            }
+
+            /// Views a mutable reference to the remote value as the reflected wrapper.
+            #[inline]
+            #[allow(unsafe_code)]
+            pub fn as_wrapper_mut(value: &mut #remote) -> &mut Self {
+                // SAFETY: `Self` is a `#[repr(transparent)]` wrapper of the remote type.
+                unsafe { &mut *(value as *mut #remote as *mut Self) }
+            }
+        }
+    }
+}
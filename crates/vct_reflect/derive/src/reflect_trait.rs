@@ -0,0 +1,89 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{ItemTrait, spanned::Spanned};
+
+/// Generates `Reflect{TraitName}` type trait for `#[reflect_trait]`.
+pub(crate) fn reflect_trait(item_trait: ItemTrait) -> TokenStream {
+    if !item_trait.generics.params.is_empty() {
+        return syn::Error::new(
+            item_trait.generics.span(),
+            "`#[reflect_trait]` does not support generic traits.",
+        )
+        .to_compile_error();
+    }
+
+    let vct_reflect_path = crate::path::vct_reflect();
+    let reflect_ = crate::path::reflect_(&vct_reflect_path);
+    let typed_ = crate::path::typed_(&vct_reflect_path);
+    let from_type_ = crate::path::from_type_(&vct_reflect_path);
+    let alloc_utils_ = crate::path::alloc_utils_(&vct_reflect_path);
+
+    let vis = &item_trait.vis;
+    let trait_ident = &item_trait.ident;
+    let reflect_trait_ident = format_ident!("Reflect{}", trait_ident);
+    let struct_doc = format!(
+        " Type trait used to cast a `dyn Reflect` to `dyn {trait_ident}`, generated by `#[reflect_trait]`."
+    );
+
+    quote! {
+        #item_trait
+
+        #[doc = #struct_doc]
+        #[derive(Clone)]
+        #vis struct #reflect_trait_ident {
+            get_func: fn(&dyn #reflect_) -> ::core::option::Option<&dyn #trait_ident>,
+            get_mut_func: fn(&mut dyn #reflect_) -> ::core::option::Option<&mut dyn #trait_ident>,
+            get_boxed_func: fn(
+                #alloc_utils_::Box<dyn #reflect_>,
+            ) -> ::core::result::Result<#alloc_utils_::Box<dyn #trait_ident>, #alloc_utils_::Box<dyn #reflect_>>,
+        }
+
+        impl #reflect_trait_ident {
+            /// Downcasts a `&dyn Reflect` to `&dyn Trait`.
+            ///
+            /// Returns `None` if the underlying type is not the registered one.
+            #[inline]
+            pub fn get<'a>(&self, value: &'a dyn #reflect_) -> ::core::option::Option<&'a dyn #trait_ident> {
+                (self.get_func)(value)
+            }
+
+            /// Downcasts a `&mut dyn Reflect` to `&mut dyn Trait`.
+            ///
+            /// Returns `None` if the underlying type is not the registered one.
+            #[inline]
+            pub fn get_mut<'a>(&self, value: &'a mut dyn #reflect_) -> ::core::option::Option<&'a mut dyn #trait_ident> {
+                (self.get_mut_func)(value)
+            }
+
+            /// Downcasts a `Box<dyn Reflect>` to `Box<dyn Trait>`.
+            ///
+            /// Returns the original box if the underlying type is not the registered one.
+            #[inline]
+            pub fn get_boxed(
+                &self,
+                value: #alloc_utils_::Box<dyn #reflect_>,
+            ) -> ::core::result::Result<#alloc_utils_::Box<dyn #trait_ident>, #alloc_utils_::Box<dyn #reflect_>> {
+                (self.get_boxed_func)(value)
+            }
+        }
+
+        impl<T: #trait_ident + #reflect_ + #typed_> #from_type_<T> for #reflect_trait_ident {
+            fn from_type() -> Self {
+                Self {
+                    get_func: |value| {
+                        <dyn #reflect_>::downcast_ref::<T>(value)
+                            .map(|value| value as &dyn #trait_ident)
+                    },
+                    get_mut_func: |value| {
+                        <dyn #reflect_>::downcast_mut::<T>(value)
+                            .map(|value| value as &mut dyn #trait_ident)
+                    },
+                    get_boxed_func: |value| {
+                        <dyn #reflect_>::downcast::<T>(value)
+                            .map(|value| value as #alloc_utils_::Box<dyn #trait_ident>)
+                    },
+                }
+            }
+        }
+    }
+}
@@ -2,7 +2,15 @@
 //!
 //! Users should not use any content here.
 
-use crate::{Reflect, info::TypePath, ops::ReflectCloneError};
+use crate::{
+    Reflect,
+    info::{NamedField, TypeInfo, TypePath, Typed},
+    ops::ReflectCloneError,
+};
+
+// `#[reflect(derive_serde)]` implements the `serde` traits,
+// the user crate may not depend on `serde` directly.
+pub use serde;
 
 pub mod alloc_utils {
     // When generating code, using `std` or `alloc` directly is unsafe.
@@ -13,6 +21,7 @@ pub mod alloc_utils {
         borrow::{Cow, ToOwned},
         boxed::Box,
         string::ToString,
+        vec::Vec,
     };
 
     // Efficiently connect strings.
@@ -42,6 +51,24 @@ pub fn reflect_clone_field<T: Reflect + TypePath>(source: &T) -> Result<T, Refle
     }
 }
 
+/// The fields of a `#[reflect(flatten)]` field of type `T` at `offset` in the parent struct.
+///
+/// # Panic
+/// - `T` is not a struct.
+pub fn flatten_fields<T: Typed>(offset: usize) -> impl Iterator<Item = NamedField> {
+    let TypeInfo::Struct(struct_info) = T::type_info() else {
+        panic!(
+            "`#[reflect(flatten)]` requires a struct, but `{}` is not.",
+            T::type_path()
+        );
+    };
+
+    struct_info.iter().map(move |field| match field.offset() {
+        Some(field_offset) => field.clone().with_offset(offset + field_offset),
+        None => field.clone(),
+    })
+}
+
 // pub trait RegisterForReflection {
 //     fn __register(_registry: &mut TypeRegistry) {}
 // }
@@ -125,18 +152,18 @@ pub mod auto_register {
     mod __auto_register_types_impl {
         use super::*;
         use alloc::vec::Vec;
-        use vct_os::sync::Mutex;
+        use vct_os::sync::poison_free::Mutex;
 
         static REGISTRATION_FNS: Mutex<Vec<fn(&mut TypeRegistry)>> = Mutex::new(Vec::new());
 
         /// Adds a new registration function for [`TypeRegistry`]
         pub fn push_registration_fn(registration_fn: fn(&mut TypeRegistry)) {
-            REGISTRATION_FNS.lock().unwrap().push(registration_fn);
+            REGISTRATION_FNS.lock().push(registration_fn);
         }
 
         /// Registers all collected types.
         pub fn register_types(registry: &mut TypeRegistry) {
-            for func in REGISTRATION_FNS.lock().unwrap().iter() {
+            for func in REGISTRATION_FNS.lock().iter() {
                 (func)(registry);
             }
         }
@@ -13,3 +13,7 @@ mod string_parser;
 // Provide complete path access API
 mod path_access;
 pub use path_access::{PathAccessError, PathAccessor, ReflectPathAccess};
+
+// Set values parsed from strings by path
+mod set_by_path;
+pub use set_by_path::{SetByPathError, set_by_path};
@@ -106,6 +106,12 @@ impl PathAccessor {
         Ok(Self(vc))
     }
 
+    /// Inserts an [`OffsetAccessor`] at the beginning of the path.
+    #[inline]
+    pub(crate) fn push_front(&mut self, accessor: OffsetAccessor<'static>) {
+        self.0.insert(0, accessor);
+    }
+
     /// Returns the length of the internal [`Vec`] (number of [`OffsetAccessor`]s).
     #[inline]
     pub fn len(&self) -> usize {
@@ -0,0 +1,167 @@
+use alloc::{
+    borrow::Cow,
+    string::{String, ToString},
+};
+use serde::{
+    Deserializer,
+    de::{Error, IntoDeserializer, Unexpected, Visitor, value::Error as ValueError},
+    forward_to_deserialize_any,
+};
+use vct_utils::error::define_error;
+
+use crate::{
+    Reflect,
+    access::{AccessPath, PathAccessError, ReflectPathAccess},
+    ops::ApplyError,
+    registry::{TypeRegistry, TypeTraitDeserialize, TypeTraitFromStr},
+};
+
+define_error! {
+    /// A enumeration of all error outcomes that might happen when running [`set_by_path`].
+    #[derive(Debug)]
+    pub enum SetByPathError<'a> {
+        /// The path could not be parsed or accessed.
+        Access { error: PathAccessError<'a> } => "{error}",
+        /// The target type has no way to be parsed from a string.
+        MissingParser { type_path: Cow<'static, str> } =>
+            "type `{type_path}` has no `TypeTraitFromStr` or `TypeTraitDeserialize` in the registry",
+        /// The string could not be parsed as the target type.
+        InvalidValue { type_path: Cow<'static, str>, value: String, reason: String } =>
+            "cannot parse `{value}` as `{type_path}`: {reason}",
+        /// The parsed value could not be applied to the target.
+        Apply { error: ApplyError } => "{error}",
+    }
+}
+
+impl<'a> From<PathAccessError<'a>> for SetByPathError<'a> {
+    #[inline]
+    fn from(error: PathAccessError<'a>) -> Self {
+        Self::Access { error }
+    }
+}
+
+/// Parses `value` as the type of the field at `path` in `root`, then sets the field to it.
+///
+/// The type is parsed with its [`TypeTraitFromStr`] if registered, otherwise with its
+/// [`TypeTraitDeserialize`]: strings are given as is, numbers, booleans and chars are parsed
+/// from the string, and unit enum variants are selected by name.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// set_by_path(&mut player, ".stats.health", "100", &registry)?;
+/// set_by_path(&mut player, ".items[2].name", "Sword", &registry)?;
+/// ```
+pub fn set_by_path<'a>(
+    root: &mut dyn Reflect,
+    path: impl AccessPath<'a>,
+    value: &str,
+    registry: &TypeRegistry,
+) -> Result<(), SetByPathError<'a>> {
+    let target = root.access_mut(path)?;
+
+    let Some(type_info) = target.represented_type_info() else {
+        return Err(SetByPathError::MissingParser {
+            type_path: Cow::Owned(target.reflect_type_path().into()),
+        });
+    };
+    let type_path = || Cow::Borrowed(type_info.type_path());
+    let type_id = type_info.type_id();
+
+    let parsed = if let Some(from_str) = registry.get_type_trait::<TypeTraitFromStr>(type_id) {
+        from_str
+            .from_str(value)
+            .ok_or_else(|| SetByPathError::InvalidValue {
+                type_path: type_path(),
+                value: value.into(),
+                reason: "`FromStr` failed".into(),
+            })?
+    } else if let Some(deserialize) = registry.get_type_trait::<TypeTraitDeserialize>(type_id) {
+        deserialize
+            .deserialize(StrValueDeserializer(value))
+            .map_err(|error: ValueError| SetByPathError::InvalidValue {
+                type_path: type_path(),
+                value: value.into(),
+                reason: error.to_string(),
+            })?
+    } else {
+        return Err(SetByPathError::MissingParser {
+            type_path: type_path(),
+        });
+    };
+
+    target
+        .try_apply_owned(parsed)
+        .map_err(|error| SetByPathError::Apply { error })
+}
+
+/// A deserializer parsing primitive values from a string.
+struct StrValueDeserializer<'a>(&'a str);
+
+macro_rules! deserialize_parsed {
+    ($($method:ident => $visit:ident,)*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+                match self.0.trim().parse() {
+                    Ok(value) => visitor.$visit(value),
+                    Err(_) => Err(Error::invalid_value(Unexpected::Str(self.0), &visitor)),
+                }
+            }
+        )*
+    };
+}
+
+impl<'de> Deserializer<'de> for StrValueDeserializer<'de> {
+    type Error = ValueError;
+
+    deserialize_parsed! {
+        deserialize_bool => visit_bool,
+        deserialize_i8 => visit_i8,
+        deserialize_i16 => visit_i16,
+        deserialize_i32 => visit_i32,
+        deserialize_i64 => visit_i64,
+        deserialize_i128 => visit_i128,
+        deserialize_u8 => visit_u8,
+        deserialize_u16 => visit_u16,
+        deserialize_u32 => visit_u32,
+        deserialize_u64 => visit_u64,
+        deserialize_u128 => visit_u128,
+        deserialize_f32 => visit_f32,
+        deserialize_f64 => visit_f64,
+        deserialize_char => visit_char,
+    }
+
+    #[inline]
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_borrowed_str(self.0)
+    }
+
+    #[inline]
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_some(self)
+    }
+
+    #[inline]
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    #[inline]
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_enum(self.0.trim().into_deserializer())
+    }
+
+    forward_to_deserialize_any! {
+        str string bytes byte_buf unit unit_struct seq tuple
+        tuple_struct map struct identifier ignored_any
+    }
+}
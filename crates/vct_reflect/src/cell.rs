@@ -52,11 +52,13 @@ use vct_utils::collections::TypeIdMap;
 
 mod sealed {
     use super::TypeInfo;
+    use crate::registry::TypeRegistry;
     use alloc::string::String;
     pub trait TypedProperty: 'static {}
 
     impl TypedProperty for String {}
     impl TypedProperty for TypeInfo {}
+    impl TypedProperty for TypeRegistry {}
 }
 
 use sealed::TypedProperty;
@@ -0,0 +1,181 @@
+use alloc::{borrow::Cow, boxed::Box, vec::Vec};
+use core::fmt;
+use vct_os::sync::Arc;
+
+use crate::{
+    Reflect,
+    cell::NonGenericTypeInfoCell,
+    func::{FunctionError, FunctionInfo, IntoFunction},
+    info::{OpaqueInfo, ReflectKind, TypeInfo, TypePath, Typed},
+    ops::{ApplyError, ReflectCloneError, ReflectMut, ReflectOwned, ReflectRef},
+    reflect::impl_cast_reflect_fn,
+};
+
+/// The result of calling a [`DynamicFunction`].
+pub type FunctionResult = Result<Box<dyn Reflect>, FunctionError>;
+
+/// A type-erased function that can be called with reflected arguments.
+///
+/// Usually created from closures and fn pointers by [`IntoFunction`].
+/// Its `TypeInfo` is [`OpaqueInfo`], the signature is available through [`DynamicFunction::info`].
+///
+/// # Example
+///
+/// ```ignore
+/// use vct_reflect::{Reflect, func::IntoFunction};
+///
+/// let add = (|a: i32, b: i32| a + b).into_function();
+/// assert_eq!(add.info().arg_count(), 2);
+///
+/// let args: Vec<Box<dyn Reflect>> = vec![Box::new(1_i32), Box::new(2_i32)];
+/// let sum = add.call(args).unwrap();
+/// assert_eq!(sum.take::<i32>().unwrap(), 3);
+/// ```
+#[derive(Clone)]
+pub struct DynamicFunction {
+    info: FunctionInfo,
+    func: Arc<dyn Fn(Vec<Box<dyn Reflect>>) -> FunctionResult + Send + Sync>,
+}
+
+impl TypePath for DynamicFunction {
+    #[inline]
+    fn type_path() -> &'static str {
+        "vct_reflect::func::DynamicFunction"
+    }
+
+    #[inline]
+    fn type_name() -> &'static str {
+        "DynamicFunction"
+    }
+
+    #[inline]
+    fn type_ident() -> &'static str {
+        "DynamicFunction"
+    }
+
+    #[inline]
+    fn crate_name() -> Option<&'static str> {
+        Some("vct_reflect")
+    }
+
+    #[inline]
+    fn module_path() -> Option<&'static str> {
+        Some("vct_reflect::func")
+    }
+}
+
+impl Typed for DynamicFunction {
+    fn type_info() -> &'static TypeInfo {
+        static CELL: NonGenericTypeInfoCell = NonGenericTypeInfoCell::new();
+        CELL.get_or_init(|| TypeInfo::Opaque(OpaqueInfo::new::<Self>()))
+    }
+}
+
+impl DynamicFunction {
+    /// Creates a new [`DynamicFunction`] from a type-erased function and its signature.
+    ///
+    /// The argument count is checked against `info` before `func` is called,
+    /// `func` is responsible for converting each argument.
+    pub fn new<F>(info: FunctionInfo, func: F) -> Self
+    where
+        F: Fn(Vec<Box<dyn Reflect>>) -> FunctionResult + Send + Sync + 'static,
+    {
+        Self {
+            info,
+            func: Arc::new(func),
+        }
+    }
+
+    /// Sets the function name.
+    #[inline]
+    pub fn with_name(self, name: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            info: self.info.with_name(name),
+            ..self
+        }
+    }
+
+    /// Returns the [`FunctionInfo`].
+    #[inline]
+    pub fn info(&self) -> &FunctionInfo {
+        &self.info
+    }
+
+    /// Returns the function name, if any.
+    #[inline]
+    pub fn name(&self) -> Option<&str> {
+        self.info.name()
+    }
+
+    /// Calls the function with the given arguments.
+    pub fn call(&self, args: Vec<Box<dyn Reflect>>) -> FunctionResult {
+        let expected = self.info.arg_count();
+        if args.len() != expected {
+            return Err(FunctionError::ArgCountMismatch {
+                expected,
+                received: args.len(),
+            });
+        }
+        (self.func)(args)
+    }
+}
+
+impl IntoFunction<()> for DynamicFunction {
+    #[inline]
+    fn into_function(self) -> DynamicFunction {
+        self
+    }
+}
+
+impl Reflect for DynamicFunction {
+    impl_cast_reflect_fn!();
+
+    #[inline]
+    fn reflect_kind(&self) -> ReflectKind {
+        ReflectKind::Opaque
+    }
+
+    #[inline]
+    fn reflect_ref(&self) -> ReflectRef<'_> {
+        ReflectRef::Opaque(self)
+    }
+
+    #[inline]
+    fn reflect_mut(&mut self) -> ReflectMut<'_> {
+        ReflectMut::Opaque(self)
+    }
+
+    #[inline]
+    fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+        ReflectOwned::Opaque(self)
+    }
+
+    fn try_apply(&mut self, value: &dyn Reflect) -> Result<(), ApplyError> {
+        match value.downcast_ref::<Self>() {
+            Some(value) => {
+                *self = value.clone();
+                Ok(())
+            }
+            None => Err(ApplyError::MismatchedTypes {
+                from_type: Cow::Owned(value.reflect_type_path().into()),
+                to_type: Cow::Borrowed(Self::type_path()),
+            }),
+        }
+    }
+
+    #[inline]
+    fn reflect_clone(&self) -> Result<Box<dyn Reflect>, ReflectCloneError> {
+        Ok(Box::new(self.clone()))
+    }
+
+    fn reflect_debug(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "DynamicFunction({})", self.name().unwrap_or("_"))
+    }
+}
+
+impl fmt::Debug for DynamicFunction {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.reflect_debug(f)
+    }
+}
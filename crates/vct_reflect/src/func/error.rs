@@ -0,0 +1,21 @@
+use alloc::borrow::Cow;
+use vct_utils::error::define_error;
+
+define_error! {
+    /// A enumeration of all error outcomes
+    /// that might happen when running [`DynamicFunction::call`](crate::func::DynamicFunction::call).
+    #[derive(Debug)]
+    pub enum FunctionError {
+        /// The number of arguments does not match the function signature.
+        ArgCountMismatch {
+            expected: usize,
+            received: usize,
+        } => "expected {expected} arguments but received {received}",
+        /// An argument could not be converted to the type of the parameter.
+        MismatchedArgType {
+            index: usize,
+            expected: Cow<'static, str>,
+            received: Cow<'static, str>,
+        } => "argument {index} expected type `{expected}` but received `{received}`",
+    }
+}
@@ -0,0 +1,142 @@
+use alloc::{borrow::Cow, boxed::Box};
+
+use crate::info::{Type, TypeInfo, Typed, type_struct::impl_type_fn};
+
+/// Information about an argument of a reflected function.
+#[derive(Clone, Debug)]
+pub struct ArgInfo {
+    ty: Type,
+    index: usize,
+    name: Option<Cow<'static, str>>,
+    // `TypeInfo` is created on the first visit, use function pointers to delay it.
+    type_info: fn() -> &'static TypeInfo,
+}
+
+impl ArgInfo {
+    impl_type_fn!(ty);
+
+    /// Creates a new [`ArgInfo`] for the argument at `index`.
+    #[inline]
+    pub fn new<T: Typed>(index: usize) -> Self {
+        Self {
+            ty: Type::of::<T>(),
+            index,
+            name: None,
+            type_info: T::type_info,
+        }
+    }
+
+    /// Sets the argument name.
+    #[inline]
+    pub fn with_name(self, name: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            name: Some(name.into()),
+            ..self
+        }
+    }
+
+    /// Returns the argument index.
+    #[inline]
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the argument name, if any.
+    #[inline]
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Returns the argument's [`TypeInfo`].
+    #[inline]
+    pub fn type_info(&self) -> &'static TypeInfo {
+        (self.type_info)()
+    }
+}
+
+/// Information about the return value of a reflected function.
+#[derive(Clone, Debug)]
+pub struct ReturnInfo {
+    ty: Type,
+    // `TypeInfo` is created on the first visit, use function pointers to delay it.
+    type_info: fn() -> &'static TypeInfo,
+}
+
+impl ReturnInfo {
+    impl_type_fn!(ty);
+
+    /// Creates a new [`ReturnInfo`].
+    #[inline]
+    pub fn new<T: Typed>() -> Self {
+        Self {
+            ty: Type::of::<T>(),
+            type_info: T::type_info,
+        }
+    }
+
+    /// Returns the [`TypeInfo`] of the return value.
+    #[inline]
+    pub fn type_info(&self) -> &'static TypeInfo {
+        (self.type_info)()
+    }
+}
+
+/// Container for storing the signature of a reflected function.
+#[derive(Clone, Debug)]
+pub struct FunctionInfo {
+    name: Option<Cow<'static, str>>,
+    args: Box<[ArgInfo]>,
+    return_info: ReturnInfo,
+}
+
+impl FunctionInfo {
+    /// Creates a new [`FunctionInfo`].
+    ///
+    /// The order of arguments inside the container is fixed.
+    pub fn new(args: &[ArgInfo], return_info: ReturnInfo) -> Self {
+        Self {
+            name: None,
+            args: args.to_vec().into_boxed_slice(),
+            return_info,
+        }
+    }
+
+    /// Sets the function name.
+    #[inline]
+    pub fn with_name(self, name: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            name: Some(name.into()),
+            ..self
+        }
+    }
+
+    /// Returns the function name, if any.
+    #[inline]
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Returns the [`ArgInfo`] by index, if it exists.
+    #[inline]
+    pub fn arg_at(&self, index: usize) -> Option<&ArgInfo> {
+        self.args.get(index)
+    }
+
+    /// Returns the list of arguments.
+    #[inline]
+    pub fn args(&self) -> &[ArgInfo] {
+        &self.args
+    }
+
+    /// Returns the number of arguments.
+    #[inline]
+    pub fn arg_count(&self) -> usize {
+        self.args.len()
+    }
+
+    /// Returns the [`ReturnInfo`].
+    #[inline]
+    pub fn return_info(&self) -> &ReturnInfo {
+        &self.return_info
+    }
+}
@@ -0,0 +1,61 @@
+use alloc::{borrow::Cow, boxed::Box, vec::IntoIter};
+use vct_utils::range_invoke;
+
+use crate::{
+    FromReflect, Reflect,
+    func::{ArgInfo, DynamicFunction, FunctionError, FunctionInfo, ReturnInfo},
+    info::{TypePath, Typed},
+};
+
+/// A trait for types that can be converted into a [`DynamicFunction`].
+///
+/// Implemented for closures and fn pointers with up to 12 arguments,
+/// where every argument implements [`FromReflect`] and the return value implements [`Reflect`].
+/// Arguments are taken by value, references are not supported.
+///
+/// The `Marker` only exists to distinguish the implementations of different signatures.
+pub trait IntoFunction<Marker> {
+    /// Converts `self` into a [`DynamicFunction`].
+    fn into_function(self) -> DynamicFunction;
+}
+
+/// Takes the next argument and converts it to `T`.
+fn take_arg<T: FromReflect + TypePath>(
+    args: &mut IntoIter<Box<dyn Reflect>>,
+    index: usize,
+) -> Result<T, FunctionError> {
+    // The argument count is checked by `DynamicFunction::call`.
+    let arg = args.next().expect("argument count has been checked");
+    T::take_from_reflect(arg).map_err(|arg| FunctionError::MismatchedArgType {
+        index,
+        expected: Cow::Borrowed(T::type_path()),
+        received: Cow::Owned(arg.reflect_type_path().into()),
+    })
+}
+
+macro_rules! impl_into_function {
+    ($num:literal: [$($index:tt: $arg:ident),*]) => {
+        impl<F, R, $($arg),*> IntoFunction<fn($($arg),*) -> R> for F
+        where
+            F: Fn($($arg),*) -> R + Send + Sync + 'static,
+            R: Reflect + Typed,
+            $($arg: FromReflect + Typed,)*
+        {
+            fn into_function(self) -> DynamicFunction {
+                let info = FunctionInfo::new(
+                    &[$(ArgInfo::new::<$arg>($index)),*],
+                    ReturnInfo::new::<R>(),
+                )
+                .with_name(core::any::type_name::<F>());
+
+                DynamicFunction::new(info, move |args| {
+                    // Unused when the function has no arguments.
+                    let _args = &mut args.into_iter();
+                    Ok(Box::new((self)($(take_arg::<$arg>(_args, $index)?),*)))
+                })
+            }
+        }
+    };
+}
+
+range_invoke!(impl_into_function, 12: P);
@@ -0,0 +1,11 @@
+mod error;
+pub use error::FunctionError;
+
+mod info;
+pub use info::{ArgInfo, FunctionInfo, ReturnInfo};
+
+mod dynamic_function;
+pub use dynamic_function::{DynamicFunction, FunctionResult};
+
+mod into_function;
+pub use into_function::IntoFunction;
@@ -0,0 +1,71 @@
+use crate::registry::Interpolate;
+use ::glam::{
+    Affine2, Affine3A, BVec2, BVec3, BVec4, DAffine2, DAffine3, DMat2, DMat3, DMat4, DQuat, DVec2,
+    DVec3, DVec4, I64Vec2, I64Vec3, I64Vec4, IVec2, IVec3, IVec4, Mat2, Mat3, Mat3A, Mat4, Quat,
+    U64Vec2, U64Vec3, U64Vec4, UVec2, UVec3, UVec4, Vec2, Vec3, Vec3A, Vec4,
+};
+use vct_reflect_derive::impl_reflect_opaque;
+
+macro_rules! impl_interpolate_lerp {
+    ($($ty:ident: $method:ident as $t:ty),*) => {
+        $(
+            impl Interpolate for $ty {
+                #[inline]
+                fn interpolate(&self, other: &Self, t: f32) -> Self {
+                    self.$method(*other, t as $t)
+                }
+            }
+        )*
+    };
+}
+
+impl_interpolate_lerp!(
+    Vec2: lerp as f32,
+    Vec3: lerp as f32,
+    Vec3A: lerp as f32,
+    Vec4: lerp as f32,
+    DVec2: lerp as f64,
+    DVec3: lerp as f64,
+    DVec4: lerp as f64,
+    Quat: slerp as f32,
+    DQuat: slerp as f64
+);
+
+// Floating point types.
+impl_reflect_opaque!((in glam) Vec2 (clone, debug, default, partial_eq, serde, interpolate));
+impl_reflect_opaque!((in glam) Vec3 (clone, debug, default, partial_eq, serde, interpolate));
+impl_reflect_opaque!((in glam) Vec3A (clone, debug, default, partial_eq, serde, interpolate));
+impl_reflect_opaque!((in glam) Vec4 (clone, debug, default, partial_eq, serde, interpolate));
+impl_reflect_opaque!((in glam) DVec2 (clone, debug, default, partial_eq, serde, interpolate));
+impl_reflect_opaque!((in glam) DVec3 (clone, debug, default, partial_eq, serde, interpolate));
+impl_reflect_opaque!((in glam) DVec4 (clone, debug, default, partial_eq, serde, interpolate));
+impl_reflect_opaque!((in glam) Quat (clone, debug, default, partial_eq, serde, interpolate));
+impl_reflect_opaque!((in glam) DQuat (clone, debug, default, partial_eq, serde, interpolate));
+impl_reflect_opaque!((in glam) Mat2 (clone, debug, default, partial_eq, serde));
+impl_reflect_opaque!((in glam) Mat3 (clone, debug, default, partial_eq, serde));
+impl_reflect_opaque!((in glam) Mat3A (clone, debug, default, partial_eq, serde));
+impl_reflect_opaque!((in glam) Mat4 (clone, debug, default, partial_eq, serde));
+impl_reflect_opaque!((in glam) DMat2 (clone, debug, default, partial_eq, serde));
+impl_reflect_opaque!((in glam) DMat3 (clone, debug, default, partial_eq, serde));
+impl_reflect_opaque!((in glam) DMat4 (clone, debug, default, partial_eq, serde));
+impl_reflect_opaque!((in glam) Affine2 (clone, debug, default, partial_eq, serde));
+impl_reflect_opaque!((in glam) Affine3A (clone, debug, default, partial_eq, serde));
+impl_reflect_opaque!((in glam) DAffine2 (clone, debug, default, partial_eq, serde));
+impl_reflect_opaque!((in glam) DAffine3 (clone, debug, default, partial_eq, serde));
+
+// Integer and boolean types.
+impl_reflect_opaque!((in glam) IVec2 (full));
+impl_reflect_opaque!((in glam) IVec3 (full));
+impl_reflect_opaque!((in glam) IVec4 (full));
+impl_reflect_opaque!((in glam) UVec2 (full));
+impl_reflect_opaque!((in glam) UVec3 (full));
+impl_reflect_opaque!((in glam) UVec4 (full));
+impl_reflect_opaque!((in glam) I64Vec2 (full));
+impl_reflect_opaque!((in glam) I64Vec3 (full));
+impl_reflect_opaque!((in glam) I64Vec4 (full));
+impl_reflect_opaque!((in glam) U64Vec2 (full));
+impl_reflect_opaque!((in glam) U64Vec3 (full));
+impl_reflect_opaque!((in glam) U64Vec4 (full));
+impl_reflect_opaque!((in glam) BVec2 (full));
+impl_reflect_opaque!((in glam) BVec3 (full));
+impl_reflect_opaque!((in glam) BVec4 (full));
@@ -0,0 +1,8 @@
+//! Implement reflection traits for [`HashMap`] as a [`Map`](crate::ops::Map)
+//! and [`HashSet`] as a [`Set`](crate::ops::Set), for any hasher.
+
+use super::super::native::{impl_reflect_hash_map, impl_reflect_hash_set};
+use ::hashbrown::{HashMap, HashSet};
+
+impl_reflect_hash_map!(HashMap, "hashbrown::hash_map", "hashbrown");
+impl_reflect_hash_set!(HashSet, "hashbrown::hash_set", "hashbrown");
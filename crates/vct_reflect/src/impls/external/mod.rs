@@ -0,0 +1,12 @@
+//! Reflection for types of external crates, each behind the cargo feature of the same name.
+
+#[cfg(feature = "glam")]
+mod glam; // Vec2 - DVec4, Quat, Mat2 - DMat4, Affine2, Affine3A ...
+#[cfg(feature = "hashbrown")]
+mod hashbrown; // HashMap<K, V, S>, HashSet<T, S>
+#[cfg(feature = "smallvec")]
+mod smallvec; // SmallVec<[T; N]>
+#[cfg(feature = "smol_str")]
+mod smol_str; // SmolStr
+#[cfg(feature = "uuid")]
+mod uuid; // Uuid
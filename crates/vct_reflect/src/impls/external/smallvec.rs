@@ -0,0 +1,242 @@
+//! Implement reflection traits for [`SmallVec`] as a [`List`].
+//!
+//! - [`TypePath`] -> [`DynamicTypePath`]
+//! - [`Typed`] -> [`DynamicTyped`]
+//! - [`List`]
+//! - [`Reflect`]
+//! - [`GetTypeTraits`]
+//! - [`FromReflect`]
+//!
+//! [`DynamicTypePath`]: crate::info::DynamicTypePath
+//! [`DynamicTyped`]: crate::info::DynamicTyped
+
+use crate::{
+    FromReflect, Reflect,
+    cell::{GenericTypeInfoCell, GenericTypePathCell},
+    info::{Generics, ListInfo, ReflectKind, TypeInfo, TypeParamInfo, TypePath, Typed},
+    ops::{
+        ApplyError, List, ListItemIter, ReflectCloneError, ReflectMut, ReflectOwned, ReflectRef,
+        list_debug, list_hash, list_partial_cmp, list_partial_eq, list_try_apply,
+        list_try_apply_owned,
+    },
+    registry::{
+        FromType, GetTypeTraits, TypeRegistry, TypeTraitDefault, TypeTraitFromPtr,
+        TypeTraitFromReflect, TypeTraits,
+    },
+};
+use ::smallvec::SmallVec;
+use alloc::{boxed::Box, vec::Vec};
+use core::{cmp::Ordering, fmt};
+
+impl<T: TypePath, const N: usize> TypePath for SmallVec<[T; N]> {
+    fn type_path() -> &'static str {
+        static CELL: GenericTypePathCell = GenericTypePathCell::new();
+        CELL.get_or_insert::<Self, _>(|| {
+            crate::impls::concat(&["smallvec::SmallVec<", <[T; N]>::type_path(), ">"])
+        })
+    }
+
+    fn type_name() -> &'static str {
+        static CELL: GenericTypePathCell = GenericTypePathCell::new();
+        CELL.get_or_insert::<Self, _>(|| {
+            crate::impls::concat(&["SmallVec<", <[T; N]>::type_name(), ">"])
+        })
+    }
+
+    #[inline]
+    fn type_ident() -> &'static str {
+        "SmallVec"
+    }
+
+    #[inline]
+    fn crate_name() -> Option<&'static str> {
+        Some("smallvec")
+    }
+
+    #[inline]
+    fn module_path() -> Option<&'static str> {
+        Some("smallvec")
+    }
+}
+
+impl<T: FromReflect + Typed, const N: usize> Typed for SmallVec<[T; N]> {
+    fn type_info() -> &'static TypeInfo {
+        static CELL: GenericTypeInfoCell = GenericTypeInfoCell::new();
+        CELL.get_or_insert::<Self, _>(|| {
+            let generics = Generics::new().with(TypeParamInfo::new::<[T; N]>("A"));
+            TypeInfo::List(ListInfo::new::<Self, T>().with_generics(generics))
+        })
+    }
+}
+
+impl<T: FromReflect + Typed, const N: usize> List for SmallVec<[T; N]> {
+    #[inline]
+    fn get(&self, index: usize) -> Option<&dyn Reflect> {
+        <[T]>::get(self, index).map(|value| value as &dyn Reflect)
+    }
+
+    #[inline]
+    fn get_mut(&mut self, index: usize) -> Option<&mut dyn Reflect> {
+        <[T]>::get_mut(self, index).map(|value| value as &mut dyn Reflect)
+    }
+
+    /// # Panic
+    /// - `index > len`
+    /// - the value cannot be converted by [`FromReflect`].
+    #[inline]
+    fn insert(&mut self, index: usize, element: Box<dyn Reflect>) {
+        SmallVec::insert(self, index, crate::impls::take_element(element));
+    }
+
+    #[inline]
+    fn remove(&mut self, index: usize) -> Box<dyn Reflect> {
+        Box::new(SmallVec::remove(self, index))
+    }
+
+    /// # Panic
+    /// - the value cannot be converted by [`FromReflect`].
+    #[inline]
+    fn push(&mut self, value: Box<dyn Reflect>) {
+        SmallVec::push(self, crate::impls::take_element(value));
+    }
+
+    #[inline]
+    fn pop(&mut self) -> Option<Box<dyn Reflect>> {
+        SmallVec::pop(self).map(|value| Box::new(value) as Box<dyn Reflect>)
+    }
+
+    /// # Panic
+    /// - a value cannot be converted by [`FromReflect`].
+    #[inline]
+    fn extend_boxed(&mut self, values: &mut dyn Iterator<Item = Box<dyn Reflect>>) {
+        self.extend(values.map(crate::impls::take_element));
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        SmallVec::len(self)
+    }
+
+    #[inline]
+    fn iter(&self) -> ListItemIter<'_> {
+        ListItemIter::new(self)
+    }
+
+    #[inline]
+    fn drain(&mut self) -> Vec<Box<dyn Reflect>> {
+        SmallVec::drain(self, ..)
+            .map(|value| Box::new(value) as Box<dyn Reflect>)
+            .collect()
+    }
+}
+
+impl<T: FromReflect + Typed, const N: usize> Reflect for SmallVec<[T; N]> {
+    #[inline]
+    fn as_reflect(&self) -> &dyn Reflect {
+        self
+    }
+
+    #[inline]
+    fn as_reflect_mut(&mut self) -> &mut dyn Reflect {
+        self
+    }
+
+    #[inline]
+    fn into_reflect(self: Box<Self>) -> Box<dyn Reflect> {
+        self
+    }
+
+    #[inline]
+    fn set(&mut self, value: Box<dyn Reflect>) -> Result<(), Box<dyn Reflect>> {
+        *self = value.take()?;
+        Ok(())
+    }
+
+    #[inline]
+    fn represented_type_info(&self) -> Option<&'static TypeInfo> {
+        Some(<Self as Typed>::type_info())
+    }
+
+    #[inline]
+    fn reflect_kind(&self) -> ReflectKind {
+        ReflectKind::List
+    }
+
+    #[inline]
+    fn reflect_ref(&self) -> ReflectRef<'_> {
+        ReflectRef::List(self)
+    }
+
+    #[inline]
+    fn reflect_mut(&mut self) -> ReflectMut<'_> {
+        ReflectMut::List(self)
+    }
+
+    #[inline]
+    fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+        ReflectOwned::List(self)
+    }
+
+    #[inline]
+    fn try_apply(&mut self, value: &dyn Reflect) -> Result<(), ApplyError> {
+        list_try_apply(self, value)
+    }
+
+    #[inline]
+    fn try_apply_owned(&mut self, value: Box<dyn Reflect>) -> Result<(), ApplyError> {
+        list_try_apply_owned(self, value)
+    }
+
+    #[inline]
+    fn reflect_partial_eq(&self, other: &dyn Reflect) -> Option<bool> {
+        list_partial_eq(self, other)
+    }
+
+    #[inline]
+    fn reflect_partial_cmp(&self, other: &dyn Reflect) -> Option<Ordering> {
+        list_partial_cmp(self, other)
+    }
+
+    fn reflect_clone(&self) -> Result<Box<dyn Reflect>, ReflectCloneError> {
+        let values = <[T]>::iter(self)
+            .map(|value| {
+                Ok(value
+                    .reflect_clone()?
+                    .take::<T>()
+                    .expect("`Reflect::reflect_clone` should return the same type"))
+            })
+            .collect::<Result<Self, ReflectCloneError>>()?;
+        Ok(Box::new(values))
+    }
+
+    #[inline]
+    fn reflect_hash(&self) -> Option<u64> {
+        list_hash(self)
+    }
+
+    #[inline]
+    fn reflect_debug(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        list_debug(self, f)
+    }
+}
+
+impl<T: FromReflect + Typed + GetTypeTraits, const N: usize> GetTypeTraits for SmallVec<[T; N]> {
+    fn get_type_traits() -> TypeTraits {
+        let mut type_traits = TypeTraits::of::<Self>();
+        type_traits.insert::<TypeTraitDefault>(FromType::<Self>::from_type());
+        type_traits.insert::<TypeTraitFromPtr>(FromType::<Self>::from_type());
+        type_traits.insert::<TypeTraitFromReflect>(FromType::<Self>::from_type());
+        type_traits
+    }
+
+    fn register_dependencies(registry: &mut TypeRegistry) {
+        registry.register::<T>();
+    }
+}
+
+impl<T: FromReflect + Typed, const N: usize> FromReflect for SmallVec<[T; N]> {
+    fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+        let ref_list = reflect.reflect_ref().as_list().ok()?;
+        ref_list.iter().map(T::from_reflect).collect()
+    }
+}
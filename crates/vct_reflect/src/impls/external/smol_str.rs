@@ -0,0 +1,4 @@
+use ::smol_str::SmolStr;
+use vct_reflect_derive::impl_reflect_opaque;
+
+impl_reflect_opaque!((in smol_str) SmolStr (full, partial_ord));
@@ -0,0 +1,4 @@
+use ::uuid::Uuid;
+use vct_reflect_derive::impl_reflect_opaque;
+
+impl_reflect_opaque!((in uuid) Uuid (full, partial_ord));
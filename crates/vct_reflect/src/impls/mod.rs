@@ -27,4 +27,166 @@
 
 mod native;
 
+mod external;
+
 pub(crate) use crate::__macro_exports::alloc_utils::concat;
+
+/// Converts an element inserted into a typed collection ([`List`](crate::ops::List),
+/// [`Map`](crate::ops::Map) or [`Set`](crate::ops::Set)) to `T`.
+///
+/// # Panic
+/// - the value cannot be converted by [`FromReflect`](crate::FromReflect).
+pub(crate) fn take_element<T: crate::FromReflect + crate::info::Typed>(
+    value: alloc::boxed::Box<dyn crate::Reflect>,
+) -> T {
+    T::take_from_reflect(value).unwrap_or_else(|value| {
+        panic!(
+            "Attempted to insert a value of type `{}` into a collection of `{}`",
+            value.reflect_type_path(),
+            <T as crate::info::TypePath>::type_path(),
+        )
+    })
+}
+
+/// Calls `f` with `key` as a `K`, for the lookups of typed [`Map`](crate::ops::Map)
+/// and [`Set`](crate::ops::Set).
+///
+/// `key` is downcast if possible, otherwise converted by [`FromReflect`](crate::FromReflect),
+/// so dynamic keys are supported. Returns `None` if the conversion fails.
+pub(crate) fn lookup_key<K: crate::FromReflect, R>(
+    key: &dyn crate::Reflect,
+    f: impl FnOnce(&K) -> Option<R>,
+) -> Option<R> {
+    match key.downcast_ref::<K>() {
+        Some(key) => f(key),
+        None => f(&K::from_reflect(key)?),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::String;
+    use vct_reflect_derive::reflect_remote;
+
+    use crate::{
+        FromReflect, Reflect,
+        ops::{Enum, Struct},
+    };
+
+    /// Stands in for a type of another crate.
+    mod foreign {
+        use alloc::string::String;
+
+        #[derive(Clone, PartialEq, Debug)]
+        pub struct Point {
+            pub x: u32,
+            pub name: String,
+        }
+
+        #[derive(Clone, PartialEq, Debug)]
+        pub enum Shape {
+            Circle(u32),
+            Rect { w: u32, h: u32 },
+            Empty,
+        }
+
+        #[derive(Clone, PartialEq, Debug)]
+        pub struct Secret(u32);
+
+        impl Secret {
+            pub fn new(value: u32) -> Self {
+                Self(value)
+            }
+        }
+    }
+
+    #[reflect_remote(foreign::Point)]
+    #[derive(Clone)]
+    #[reflect(clone)]
+    struct Point {
+        x: u32,
+        name: String,
+    }
+
+    #[reflect_remote(foreign::Shape)]
+    #[derive(Clone)]
+    #[reflect(clone)]
+    enum Shape {
+        Circle(u32),
+        Rect { w: u32, h: u32 },
+        Empty,
+    }
+
+    #[reflect_remote(foreign::Secret)]
+    #[derive(Clone)]
+    #[reflect(Opaque, clone, partial_eq)]
+    struct Secret;
+
+    impl PartialEq for Secret {
+        fn eq(&self, other: &Self) -> bool {
+            self.0 == other.0
+        }
+    }
+
+    #[test]
+    fn reflect_remote_conversions() {
+        let remote = foreign::Point {
+            x: 1,
+            name: String::from("a"),
+        };
+        let point = Point::from(remote.clone());
+        assert_eq!(point.field("x").unwrap().downcast_ref::<u32>(), Some(&1));
+        assert_eq!(foreign::Point::from(point), remote);
+
+        let remote = foreign::Shape::Rect { w: 2, h: 3 };
+        let shape = Shape::from(remote.clone());
+        assert_eq!(shape.variant_name(), "Rect");
+        assert_eq!(shape.field("h").unwrap().downcast_ref::<u32>(), Some(&3));
+        assert_eq!(foreign::Shape::from(shape), remote);
+
+        let circle = foreign::Shape::from(Shape::from(foreign::Shape::Circle(4)));
+        assert_eq!(circle, foreign::Shape::Circle(4));
+        let empty = foreign::Shape::from(Shape::from(foreign::Shape::Empty));
+        assert_eq!(empty, foreign::Shape::Empty);
+    }
+
+    #[test]
+    fn reflect_remote_in_place() {
+        let mut remote = foreign::Point {
+            x: 1,
+            name: String::from("a"),
+        };
+
+        let value: &dyn Reflect = Point::as_wrapper(&remote);
+        assert_eq!(value.reflect_type_ident(), "Point");
+        let dynamic = Struct::to_dynamic_struct(Point::as_wrapper(&remote));
+        assert_eq!(
+            Point::from_reflect(&dynamic).map(|point| point.0),
+            Some(remote.clone())
+        );
+
+        let mut patch = dynamic;
+        patch.insert("x", 5u32);
+        Point::as_wrapper_mut(&mut remote)
+            .try_apply(&patch)
+            .unwrap();
+        assert_eq!(remote.x, 5);
+
+        let mut shape = foreign::Shape::Circle(1);
+        let rect = Shape::from(foreign::Shape::Rect { w: 2, h: 3 });
+        Shape::as_wrapper_mut(&mut shape).try_apply(&rect).unwrap();
+        assert_eq!(shape, foreign::Shape::Rect { w: 2, h: 3 });
+    }
+
+    #[test]
+    fn reflect_remote_opaque() {
+        let secret = foreign::Secret::new(7);
+        let value: &dyn Reflect = Secret::as_wrapper(&secret);
+        let cloned = value.reflect_clone().unwrap();
+        assert_eq!(value.reflect_partial_eq(&*cloned), Some(true));
+        assert_eq!(
+            cloned.take::<Secret>().ok().map(|secret| secret.0),
+            Some(secret)
+        );
+    }
+}
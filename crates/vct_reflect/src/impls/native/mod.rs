@@ -1,3 +1,20 @@
+mod native_array; // [T; N]
 mod native_basic; // u8 - u64, i8 - i64, f32, f64, usize, isize
-mod native_string;
+mod native_cow; // Cow<'static, str>, Cow<'static, [T]>
+mod native_hash; // FixedHash, NoOpHash, FixedState, RandomState
+mod native_list; // VecDeque<T>, LinkedList<T>, BinaryHeap<T>
+mod native_map; // BTreeMap<K, V>, HashMap<K, V, S>
+mod native_num; // NonZeroU8 - NonZeroIsize, Wrapping<T>, Saturating<T>
+mod native_option; // Option<Box<dyn Reflect>>
+mod native_pointer; // Box<T>, Arc<T>
+mod native_range; // Range<T>, RangeInclusive<T>, Bound<T>
+mod native_result; // Result<T, E>
+mod native_set; // BTreeSet<T>, HashSet<T, S>
+mod native_string; // String, &'static str
 mod native_tuple; // ()  (T1,)  (T1, T2)  ...  (T1, T2, .. T12) // 'static str
+mod native_time; // Duration, Instant
+
+#[cfg(feature = "hashbrown")]
+pub(super) use native_map::impl_reflect_hash_map;
+#[cfg(feature = "hashbrown")]
+pub(super) use native_set::impl_reflect_hash_set;
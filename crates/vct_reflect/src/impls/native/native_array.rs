@@ -0,0 +1,212 @@
+//! Implement reflection traits for arrays `[T; N]` of any length.
+//!
+//! - [`TypePath`] -> [`DynamicTypePath`]
+//! - [`Typed`] -> [`DynamicTyped`]
+//! - [`Array`]
+//! - [`Reflect`]
+//! - [`GetTypeTraits`]
+//! - [`FromReflect`]
+//!
+//! `Default`, `Serialize` and `Deserialize` are only implemented by the standard library
+//! and serde for short arrays, so their type traits are not registered.
+//!
+//! [`DynamicTypePath`]: crate::info::DynamicTypePath
+//! [`DynamicTyped`]: crate::info::DynamicTyped
+
+use crate::{
+    FromReflect, Reflect,
+    cell::{GenericTypeInfoCell, GenericTypePathCell},
+    info::{
+        ArrayInfo, ConstParamInfo, Generics, ReflectKind, TypeInfo, TypeParamInfo, TypePath,
+        Typed,
+    },
+    ops::{
+        ApplyError, Array, ArrayItemIter, ReflectCloneError, ReflectMut, ReflectOwned, ReflectRef,
+        array_debug, array_hash, array_partial_cmp, array_partial_eq, array_try_apply, array_try_apply_owned,
+    },
+    registry::{
+        FromType, GetTypeTraits, TypeRegistry, TypeTraitFromPtr, TypeTraitFromReflect, TypeTraits,
+    },
+};
+use alloc::{boxed::Box, format, vec::Vec};
+use core::{cmp::Ordering, fmt};
+
+impl<T: TypePath, const N: usize> TypePath for [T; N] {
+    fn type_path() -> &'static str {
+        static CELL: GenericTypePathCell = GenericTypePathCell::new();
+        CELL.get_or_insert::<Self, _>(|| format!("[{}; {N}]", T::type_path()))
+    }
+
+    fn type_name() -> &'static str {
+        static CELL: GenericTypePathCell = GenericTypePathCell::new();
+        CELL.get_or_insert::<Self, _>(|| format!("[{}; {N}]", T::type_name()))
+    }
+
+    fn type_ident() -> &'static str {
+        static CELL: GenericTypePathCell = GenericTypePathCell::new();
+        CELL.get_or_insert::<Self, _>(|| format!("[{}; {N}]", T::type_ident()))
+    }
+}
+
+impl<T: Reflect + Typed, const N: usize> Typed for [T; N] {
+    fn type_info() -> &'static TypeInfo {
+        static CELL: GenericTypeInfoCell = GenericTypeInfoCell::new();
+        CELL.get_or_insert::<Self, _>(|| {
+            let generics = Generics::new()
+                .with(TypeParamInfo::new::<T>("T"))
+                .with(ConstParamInfo::new::<usize>("N"));
+            TypeInfo::Array(ArrayInfo::new::<Self, T>(N).with_generics(generics))
+        })
+    }
+}
+
+impl<T: Reflect + Typed, const N: usize> Array for [T; N] {
+    #[inline]
+    fn get(&self, index: usize) -> Option<&dyn Reflect> {
+        <[T]>::get(self, index).map(|value| value as &dyn Reflect)
+    }
+
+    #[inline]
+    fn get_mut(&mut self, index: usize) -> Option<&mut dyn Reflect> {
+        <[T]>::get_mut(self, index).map(|value| value as &mut dyn Reflect)
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        N
+    }
+
+    #[inline]
+    fn iter(&self) -> ArrayItemIter<'_> {
+        ArrayItemIter::new(self)
+    }
+
+    #[inline]
+    fn drain(self: Box<Self>) -> Vec<Box<dyn Reflect>> {
+        self.into_iter()
+            .map(|value| Box::new(value) as Box<dyn Reflect>)
+            .collect()
+    }
+}
+
+impl<T: Reflect + Typed, const N: usize> Reflect for [T; N] {
+    #[inline]
+    fn as_reflect(&self) -> &dyn Reflect {
+        self
+    }
+
+    #[inline]
+    fn as_reflect_mut(&mut self) -> &mut dyn Reflect {
+        self
+    }
+
+    #[inline]
+    fn into_reflect(self: Box<Self>) -> Box<dyn Reflect> {
+        self
+    }
+
+    #[inline]
+    fn set(&mut self, value: Box<dyn Reflect>) -> Result<(), Box<dyn Reflect>> {
+        *self = value.take()?;
+        Ok(())
+    }
+
+    #[inline]
+    fn represented_type_info(&self) -> Option<&'static TypeInfo> {
+        Some(<Self as Typed>::type_info())
+    }
+
+    #[inline]
+    fn reflect_kind(&self) -> ReflectKind {
+        ReflectKind::Array
+    }
+
+    #[inline]
+    fn reflect_ref(&self) -> ReflectRef<'_> {
+        ReflectRef::Array(self)
+    }
+
+    #[inline]
+    fn reflect_mut(&mut self) -> ReflectMut<'_> {
+        ReflectMut::Array(self)
+    }
+
+    #[inline]
+    fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+        ReflectOwned::Array(self)
+    }
+
+    #[inline]
+    fn try_apply(&mut self, value: &dyn Reflect) -> Result<(), ApplyError> {
+        array_try_apply(self, value)
+    }
+
+    #[inline]
+    fn try_apply_owned(&mut self, value: Box<dyn Reflect>) -> Result<(), ApplyError> {
+        array_try_apply_owned(self, value)
+    }
+
+    #[inline]
+    fn reflect_partial_eq(&self, other: &dyn Reflect) -> Option<bool> {
+        array_partial_eq(self, other)
+    }
+
+    #[inline]
+    fn reflect_partial_cmp(&self, other: &dyn Reflect) -> Option<Ordering> {
+        array_partial_cmp(self, other)
+    }
+
+    fn reflect_clone(&self) -> Result<Box<dyn Reflect>, ReflectCloneError> {
+        let values = self
+            .iter()
+            .map(|value| {
+                Ok(value
+                    .reflect_clone()?
+                    .take::<T>()
+                    .expect("`Reflect::reflect_clone` should return the same type"))
+            })
+            .collect::<Result<Vec<T>, ReflectCloneError>>()?;
+        let Ok(array) = <[T; N]>::try_from(values) else {
+            unreachable!("the length of the cloned values is `N`");
+        };
+        Ok(Box::new(array))
+    }
+
+    #[inline]
+    fn reflect_hash(&self) -> Option<u64> {
+        array_hash(self)
+    }
+
+    #[inline]
+    fn reflect_debug(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        array_debug(self, f)
+    }
+}
+
+impl<T: FromReflect + Typed + GetTypeTraits, const N: usize> GetTypeTraits for [T; N] {
+    fn get_type_traits() -> TypeTraits {
+        let mut type_traits = TypeTraits::of::<Self>();
+        type_traits.insert::<TypeTraitFromPtr>(FromType::<Self>::from_type());
+        type_traits.insert::<TypeTraitFromReflect>(FromType::<Self>::from_type());
+        type_traits
+    }
+
+    fn register_dependencies(registry: &mut TypeRegistry) {
+        registry.register::<T>();
+    }
+}
+
+impl<T: FromReflect + Typed, const N: usize> FromReflect for [T; N] {
+    fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+        let ref_array = reflect.reflect_ref().as_array().ok()?;
+        if ref_array.len() != N {
+            return None;
+        }
+
+        let values = ref_array
+            .iter()
+            .map(T::from_reflect)
+            .collect::<Option<Vec<T>>>()?;
+        <[T; N]>::try_from(values).ok()
+    }
+}
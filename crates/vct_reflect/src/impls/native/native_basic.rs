@@ -1,16 +1,18 @@
 use vct_reflect_derive::impl_reflect_opaque;
 
-impl_reflect_opaque!(bool (full));
-impl_reflect_opaque!(char (full));
-impl_reflect_opaque!(u8 (full));
-impl_reflect_opaque!(u16 (full));
-impl_reflect_opaque!(u32 (full));
-impl_reflect_opaque!(u64 (full));
-impl_reflect_opaque!(u128 (full));
-impl_reflect_opaque!(usize (full));
-impl_reflect_opaque!(i8 (full));
-impl_reflect_opaque!(i16 (full));
-impl_reflect_opaque!(i32 (full));
-impl_reflect_opaque!(i64 (full));
-impl_reflect_opaque!(i128 (full));
-impl_reflect_opaque!(isize (full));
+impl_reflect_opaque!(bool (full, partial_ord));
+impl_reflect_opaque!(char (full, partial_ord));
+impl_reflect_opaque!(u8 (full, partial_ord, interpolate));
+impl_reflect_opaque!(u16 (full, partial_ord, interpolate));
+impl_reflect_opaque!(u32 (full, partial_ord, interpolate));
+impl_reflect_opaque!(u64 (full, partial_ord, interpolate));
+impl_reflect_opaque!(u128 (full, partial_ord, interpolate));
+impl_reflect_opaque!(usize (full, partial_ord, interpolate));
+impl_reflect_opaque!(i8 (full, partial_ord, interpolate));
+impl_reflect_opaque!(i16 (full, partial_ord, interpolate));
+impl_reflect_opaque!(i32 (full, partial_ord, interpolate));
+impl_reflect_opaque!(i64 (full, partial_ord, interpolate));
+impl_reflect_opaque!(i128 (full, partial_ord, interpolate));
+impl_reflect_opaque!(isize (full, partial_ord, interpolate));
+impl_reflect_opaque!(f32 (clone, debug, partial_eq, partial_ord, serialize, deserialize, interpolate));
+impl_reflect_opaque!(f64 (clone, debug, partial_eq, partial_ord, serialize, deserialize, interpolate));
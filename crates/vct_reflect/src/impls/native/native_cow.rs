@@ -0,0 +1,372 @@
+//! Implement reflection traits for `Cow<'static, str>` (as `Opaque`) and `Cow<'static, [T]>` (as [`List`]).
+//!
+//! - [`TypePath`] -> [`DynamicTypePath`]
+//! - [`Typed`] -> [`DynamicTyped`]
+//! - [`List`] (slices only)
+//! - [`Reflect`]
+//! - [`GetTypeTraits`]
+//! - [`FromReflect`]
+//!
+//! Mutating a borrowed value clones it first, see [`Cow::to_mut`].
+//!
+//! [`DynamicTypePath`]: crate::info::DynamicTypePath
+//! [`DynamicTyped`]: crate::info::DynamicTyped
+
+use crate::{
+    FromReflect, Reflect,
+    cell::{GenericTypeInfoCell, GenericTypePathCell, NonGenericTypeInfoCell},
+    info::{Generics, ListInfo, OpaqueInfo, ReflectKind, TypeInfo, TypeParamInfo, TypePath, Typed},
+    ops::{
+        ApplyError, List, ListItemIter, ReflectCloneError, ReflectMut, ReflectOwned, ReflectRef,
+        list_debug, list_hash, list_partial_cmp, list_partial_eq, list_try_apply, list_try_apply_owned,
+    },
+    reflect::impl_cast_reflect_fn,
+    reflect_hasher,
+    registry::{
+        FromType, GetTypeTraits, TypeRegistry, TypeTraitDefault, TypeTraitDeserialize,
+        TypeTraitFromPtr, TypeTraitFromReflect, TypeTraitSerialize, TypeTraits,
+    },
+};
+use alloc::{
+    borrow::{Cow, ToOwned},
+    boxed::Box,
+    string::String,
+    vec::Vec,
+};
+use core::{
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+};
+
+// -----------------------------------------------------------------------------
+// Cow<'static, str>
+
+impl TypePath for Cow<'static, str> {
+    #[inline]
+    fn type_path() -> &'static str {
+        "alloc::borrow::Cow<str>"
+    }
+
+    #[inline]
+    fn type_name() -> &'static str {
+        "Cow<str>"
+    }
+
+    #[inline]
+    fn type_ident() -> &'static str {
+        "Cow"
+    }
+
+    #[inline]
+    fn crate_name() -> Option<&'static str> {
+        Some("alloc")
+    }
+
+    #[inline]
+    fn module_path() -> Option<&'static str> {
+        Some("alloc::borrow")
+    }
+}
+
+impl Typed for Cow<'static, str> {
+    fn type_info() -> &'static TypeInfo {
+        static CELL: NonGenericTypeInfoCell = NonGenericTypeInfoCell::new();
+        CELL.get_or_init(|| TypeInfo::Opaque(OpaqueInfo::new::<Self>()))
+    }
+}
+
+impl Reflect for Cow<'static, str> {
+    impl_cast_reflect_fn!();
+
+    #[inline]
+    fn reflect_kind(&self) -> ReflectKind {
+        ReflectKind::Opaque
+    }
+
+    #[inline]
+    fn reflect_ref(&self) -> ReflectRef<'_> {
+        ReflectRef::Opaque(self)
+    }
+
+    #[inline]
+    fn reflect_mut(&mut self) -> ReflectMut<'_> {
+        ReflectMut::Opaque(self)
+    }
+
+    #[inline]
+    fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+        ReflectOwned::Opaque(self)
+    }
+
+    #[inline]
+    fn to_dynamic(&self) -> Box<dyn Reflect> {
+        Box::new(self.clone())
+    }
+
+    fn try_apply(&mut self, value: &dyn Reflect) -> Result<(), ApplyError> {
+        if let Some(value) = value.downcast_ref::<Self>() {
+            *self = value.clone();
+            return Ok(());
+        }
+
+        Err(ApplyError::MismatchedTypes {
+            from_type: Cow::Owned(value.reflect_type_path().to_owned()),
+            to_type: Cow::Borrowed(Self::type_path()),
+        })
+    }
+
+    #[inline]
+    fn reflect_clone(&self) -> Result<Box<dyn Reflect>, ReflectCloneError> {
+        Ok(Box::new(self.clone()))
+    }
+
+    #[inline]
+    fn reflect_partial_eq(&self, other: &dyn Reflect) -> Option<bool> {
+        other.downcast_ref::<Self>().map(|value| self == value)
+    }
+
+    #[inline]
+    fn reflect_partial_cmp(&self, other: &dyn Reflect) -> Option<Ordering> {
+        other
+            .downcast_ref::<Self>()
+            .and_then(|value| self.partial_cmp(value))
+    }
+
+    #[inline]
+    fn reflect_hash(&self) -> Option<u64> {
+        let mut hasher = reflect_hasher();
+        Hash::hash(&**self, &mut hasher);
+        Some(hasher.finish())
+    }
+
+    #[inline]
+    fn reflect_debug(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+impl GetTypeTraits for Cow<'static, str> {
+    fn get_type_traits() -> TypeTraits {
+        let mut type_traits = TypeTraits::of::<Self>();
+        type_traits.insert::<TypeTraitDefault>(FromType::<Self>::from_type());
+        type_traits.insert::<TypeTraitFromPtr>(FromType::<Self>::from_type());
+        type_traits.insert::<TypeTraitFromReflect>(FromType::<Self>::from_type());
+        type_traits.insert::<TypeTraitSerialize>(FromType::<Self>::from_type());
+        type_traits.insert::<TypeTraitDeserialize>(FromType::<Self>::from_type());
+        type_traits
+    }
+}
+
+impl FromReflect for Cow<'static, str> {
+    /// Also accepts a [`String`].
+    fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+        if let Some(value) = reflect.downcast_ref::<Self>() {
+            return Some(value.clone());
+        }
+        reflect
+            .downcast_ref::<String>()
+            .map(|value| Cow::Owned(value.clone()))
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Cow<'static, [T]>
+
+impl<T: TypePath + Clone> TypePath for Cow<'static, [T]> {
+    fn type_path() -> &'static str {
+        static CELL: GenericTypePathCell = GenericTypePathCell::new();
+        CELL.get_or_insert::<Self, _>(|| {
+            crate::impls::concat(&["alloc::borrow::Cow<[", T::type_path(), "]>"])
+        })
+    }
+
+    fn type_name() -> &'static str {
+        static CELL: GenericTypePathCell = GenericTypePathCell::new();
+        CELL.get_or_insert::<Self, _>(|| crate::impls::concat(&["Cow<[", T::type_name(), "]>"]))
+    }
+
+    #[inline]
+    fn type_ident() -> &'static str {
+        "Cow"
+    }
+
+    #[inline]
+    fn crate_name() -> Option<&'static str> {
+        Some("alloc")
+    }
+
+    #[inline]
+    fn module_path() -> Option<&'static str> {
+        Some("alloc::borrow")
+    }
+}
+
+impl<T: FromReflect + Typed + Clone> Typed for Cow<'static, [T]> {
+    fn type_info() -> &'static TypeInfo {
+        static CELL: GenericTypeInfoCell = GenericTypeInfoCell::new();
+        CELL.get_or_insert::<Self, _>(|| {
+            let generics = Generics::new().with(TypeParamInfo::new::<T>("T"));
+            TypeInfo::List(ListInfo::new::<Self, T>().with_generics(generics))
+        })
+    }
+}
+
+impl<T: FromReflect + Typed + Clone> List for Cow<'static, [T]> {
+    #[inline]
+    fn get(&self, index: usize) -> Option<&dyn Reflect> {
+        <[T]>::get(self, index).map(|value| value as &dyn Reflect)
+    }
+
+    #[inline]
+    fn get_mut(&mut self, index: usize) -> Option<&mut dyn Reflect> {
+        self.to_mut()
+            .get_mut(index)
+            .map(|value| value as &mut dyn Reflect)
+    }
+
+    /// # Panic
+    /// - `index > len`
+    /// - the value cannot be converted by [`FromReflect`].
+    #[inline]
+    fn insert(&mut self, index: usize, element: Box<dyn Reflect>) {
+        self.to_mut()
+            .insert(index, crate::impls::take_element(element));
+    }
+
+    #[inline]
+    fn remove(&mut self, index: usize) -> Box<dyn Reflect> {
+        Box::new(self.to_mut().remove(index))
+    }
+
+    /// # Panic
+    /// - the value cannot be converted by [`FromReflect`].
+    #[inline]
+    fn push(&mut self, value: Box<dyn Reflect>) {
+        self.to_mut().push(crate::impls::take_element(value));
+    }
+
+    #[inline]
+    fn pop(&mut self) -> Option<Box<dyn Reflect>> {
+        self.to_mut()
+            .pop()
+            .map(|value| Box::new(value) as Box<dyn Reflect>)
+    }
+
+    /// # Panic
+    /// - a value cannot be converted by [`FromReflect`].
+    #[inline]
+    fn extend_boxed(&mut self, values: &mut dyn Iterator<Item = Box<dyn Reflect>>) {
+        self.to_mut()
+            .extend(values.map(crate::impls::take_element));
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        <[T]>::len(self)
+    }
+
+    #[inline]
+    fn iter(&self) -> ListItemIter<'_> {
+        ListItemIter::new(self)
+    }
+
+    #[inline]
+    fn drain(&mut self) -> Vec<Box<dyn Reflect>> {
+        self.to_mut()
+            .drain(..)
+            .map(|value| Box::new(value) as Box<dyn Reflect>)
+            .collect()
+    }
+}
+
+impl<T: FromReflect + Typed + Clone> Reflect for Cow<'static, [T]> {
+    impl_cast_reflect_fn!();
+
+    #[inline]
+    fn reflect_kind(&self) -> ReflectKind {
+        ReflectKind::List
+    }
+
+    #[inline]
+    fn reflect_ref(&self) -> ReflectRef<'_> {
+        ReflectRef::List(self)
+    }
+
+    #[inline]
+    fn reflect_mut(&mut self) -> ReflectMut<'_> {
+        ReflectMut::List(self)
+    }
+
+    #[inline]
+    fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+        ReflectOwned::List(self)
+    }
+
+    #[inline]
+    fn try_apply(&mut self, value: &dyn Reflect) -> Result<(), ApplyError> {
+        list_try_apply(self, value)
+    }
+
+    #[inline]
+    fn try_apply_owned(&mut self, value: Box<dyn Reflect>) -> Result<(), ApplyError> {
+        list_try_apply_owned(self, value)
+    }
+
+    #[inline]
+    fn reflect_partial_eq(&self, other: &dyn Reflect) -> Option<bool> {
+        list_partial_eq(self, other)
+    }
+
+    #[inline]
+    fn reflect_partial_cmp(&self, other: &dyn Reflect) -> Option<Ordering> {
+        list_partial_cmp(self, other)
+    }
+
+    fn reflect_clone(&self) -> Result<Box<dyn Reflect>, ReflectCloneError> {
+        let values = <[T]>::iter(self)
+            .map(|value| {
+                Ok(value
+                    .reflect_clone()?
+                    .take::<T>()
+                    .expect("`Reflect::reflect_clone` should return the same type"))
+            })
+            .collect::<Result<Vec<T>, ReflectCloneError>>()?;
+        Ok(Box::new(Cow::<'static, [T]>::Owned(values)))
+    }
+
+    #[inline]
+    fn reflect_hash(&self) -> Option<u64> {
+        list_hash(self)
+    }
+
+    #[inline]
+    fn reflect_debug(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        list_debug(self, f)
+    }
+}
+
+impl<T: FromReflect + Typed + GetTypeTraits + Clone> GetTypeTraits for Cow<'static, [T]> {
+    fn get_type_traits() -> TypeTraits {
+        let mut type_traits = TypeTraits::of::<Self>();
+        type_traits.insert::<TypeTraitDefault>(FromType::<Self>::from_type());
+        type_traits.insert::<TypeTraitFromPtr>(FromType::<Self>::from_type());
+        type_traits.insert::<TypeTraitFromReflect>(FromType::<Self>::from_type());
+        type_traits
+    }
+
+    fn register_dependencies(registry: &mut TypeRegistry) {
+        registry.register::<T>();
+    }
+}
+
+impl<T: FromReflect + Typed + Clone> FromReflect for Cow<'static, [T]> {
+    fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+        let ref_list = reflect.reflect_ref().as_list().ok()?;
+        ref_list
+            .iter()
+            .map(T::from_reflect)
+            .collect::<Option<Vec<T>>>()
+            .map(Cow::Owned)
+    }
+}
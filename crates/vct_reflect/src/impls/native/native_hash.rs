@@ -0,0 +1,10 @@
+//! Implement reflection traits for the hash builders of `vct_utils::hash`,
+//! so that they can be the hasher of a reflected hash map or hash set.
+
+use vct_reflect_derive::impl_reflect_opaque;
+use vct_utils::hash::{FixedHash, FixedState, NoOpHash, RandomState};
+
+impl_reflect_opaque!((in vct_utils::hash) FixedHash (clone, debug, default));
+impl_reflect_opaque!((in vct_utils::hash) NoOpHash (clone, debug, default));
+impl_reflect_opaque!((in foldhash::fast) FixedState (clone, debug, default));
+impl_reflect_opaque!((in foldhash::fast) RandomState (clone, debug, default));
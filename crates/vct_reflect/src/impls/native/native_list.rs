@@ -0,0 +1,614 @@
+//! Implement reflection traits for [`VecDeque`], [`LinkedList`] and [`BinaryHeap`] as a [`List`].
+//!
+//! - [`TypePath`] -> [`DynamicTypePath`]
+//! - [`Typed`] -> [`DynamicTyped`]
+//! - [`List`]
+//! - [`Reflect`]
+//! - [`GetTypeTraits`]
+//! - [`FromReflect`]
+//!
+//! Indexed access to a [`LinkedList`] walks the list,
+//! whole-list operations like `iter`, `splice` and `try_apply` walk it once.
+//!
+//! The elements of a [`BinaryHeap`] are listed in its internal order, see [`BinaryHeap::as_slice`].
+//! They can not be mutated in place, `push`/`insert` add an element to the heap
+//! and `pop` removes the greatest one. `try_apply` replaces all elements,
+//! `apply_diff` fails with [`ApplyError::NotSupport`] when an element is modified.
+//! Heaps are equal if they have the same elements, whatever their internal order.
+//!
+//! [`DynamicTypePath`]: crate::info::DynamicTypePath
+//! [`DynamicTyped`]: crate::info::DynamicTyped
+
+use crate::{
+    FromReflect, Reflect,
+    cell::{GenericTypeInfoCell, GenericTypePathCell},
+    info::{Generics, ListInfo, ReflectKind, TypeInfo, TypeParamInfo, TypePath, Typed},
+    ops::{
+        ApplyError, List, ListItemIter, ReflectCloneError, ReflectMut, ReflectOwned, ReflectRef,
+        list_debug, list_hash, list_partial_cmp, list_partial_eq, list_try_apply,
+        list_try_apply_owned,
+    },
+    registry::{
+        FromType, GetTypeTraits, TypeRegistry, TypeTraitDefault, TypeTraitFromPtr,
+        TypeTraitFromReflect, TypeTraits,
+    },
+};
+use alloc::{
+    borrow::{Cow, ToOwned},
+    boxed::Box,
+    collections::{BinaryHeap, LinkedList, VecDeque},
+    vec::Vec,
+};
+use core::{cmp::Ordering, fmt, ops::Range};
+
+macro_rules! impl_reflect_list_common {
+    ($ty:ident, $module:literal, $($bound:tt)*) => {
+        impl<T: TypePath> TypePath for $ty<T> {
+            fn type_path() -> &'static str {
+                static CELL: GenericTypePathCell = GenericTypePathCell::new();
+                CELL.get_or_insert::<Self, _>(|| {
+                    $crate::impls::concat(&[$module, "::", stringify!($ty), "<", T::type_path(), ">"])
+                })
+            }
+
+            fn type_name() -> &'static str {
+                static CELL: GenericTypePathCell = GenericTypePathCell::new();
+                CELL.get_or_insert::<Self, _>(|| {
+                    $crate::impls::concat(&[stringify!($ty), "<", T::type_name(), ">"])
+                })
+            }
+
+            #[inline]
+            fn type_ident() -> &'static str {
+                stringify!($ty)
+            }
+
+            #[inline]
+            fn crate_name() -> Option<&'static str> {
+                Some("alloc")
+            }
+
+            #[inline]
+            fn module_path() -> Option<&'static str> {
+                Some($module)
+            }
+        }
+
+        impl<T: FromReflect + Typed $($bound)*> Typed for $ty<T> {
+            fn type_info() -> &'static TypeInfo {
+                static CELL: GenericTypeInfoCell = GenericTypeInfoCell::new();
+                CELL.get_or_insert::<Self, _>(|| {
+                    let generics = Generics::new().with(TypeParamInfo::new::<T>("T"));
+                    TypeInfo::List(ListInfo::new::<Self, T>().with_generics(generics))
+                })
+            }
+        }
+
+        impl<T: FromReflect + Typed + GetTypeTraits $($bound)*> GetTypeTraits for $ty<T> {
+            fn get_type_traits() -> TypeTraits {
+                let mut type_traits = TypeTraits::of::<Self>();
+                type_traits.insert::<TypeTraitDefault>(FromType::<Self>::from_type());
+                type_traits.insert::<TypeTraitFromPtr>(FromType::<Self>::from_type());
+                type_traits.insert::<TypeTraitFromReflect>(FromType::<Self>::from_type());
+                type_traits
+            }
+
+            fn register_dependencies(registry: &mut TypeRegistry) {
+                registry.register::<T>();
+            }
+        }
+
+        impl<T: FromReflect + Typed $($bound)*> FromReflect for $ty<T> {
+            fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+                let ref_list = reflect.reflect_ref().as_list().ok()?;
+                ref_list.iter().map(T::from_reflect).collect()
+            }
+        }
+    };
+}
+
+impl_reflect_list_common!(VecDeque, "alloc::collections::vec_deque",);
+impl_reflect_list_common!(LinkedList, "alloc::collections::linked_list",);
+impl_reflect_list_common!(BinaryHeap, "alloc::collections::binary_heap", + Ord);
+
+/// The methods of [`Reflect`] shared by all lists.
+macro_rules! impl_reflect_list_fn {
+    () => {
+        #[inline]
+        fn as_reflect(&self) -> &dyn Reflect {
+            self
+        }
+
+        #[inline]
+        fn as_reflect_mut(&mut self) -> &mut dyn Reflect {
+            self
+        }
+
+        #[inline]
+        fn into_reflect(self: Box<Self>) -> Box<dyn Reflect> {
+            self
+        }
+
+        #[inline]
+        fn set(&mut self, value: Box<dyn Reflect>) -> Result<(), Box<dyn Reflect>> {
+            *self = value.take()?;
+            Ok(())
+        }
+
+        #[inline]
+        fn represented_type_info(&self) -> Option<&'static TypeInfo> {
+            Some(<Self as Typed>::type_info())
+        }
+
+        #[inline]
+        fn reflect_kind(&self) -> ReflectKind {
+            ReflectKind::List
+        }
+
+        #[inline]
+        fn reflect_ref(&self) -> ReflectRef<'_> {
+            ReflectRef::List(self)
+        }
+
+        #[inline]
+        fn reflect_mut(&mut self) -> ReflectMut<'_> {
+            ReflectMut::List(self)
+        }
+
+        #[inline]
+        fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+            ReflectOwned::List(self)
+        }
+
+        fn reflect_clone(&self) -> Result<Box<dyn Reflect>, ReflectCloneError> {
+            let values = self
+                .iter()
+                .map(|value| {
+                    Ok(value
+                        .reflect_clone()?
+                        .take::<T>()
+                        .expect("`Reflect::reflect_clone` should return the same type"))
+                })
+                .collect::<Result<Self, ReflectCloneError>>()?;
+            Ok(Box::new(values))
+        }
+
+        #[inline]
+        fn reflect_debug(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            list_debug(self, f)
+        }
+    };
+}
+
+// -----------------------------------------------------------------------------
+// VecDeque<T>
+
+impl<T: FromReflect + Typed> List for VecDeque<T> {
+    #[inline]
+    fn get(&self, index: usize) -> Option<&dyn Reflect> {
+        VecDeque::get(self, index).map(|value| value as &dyn Reflect)
+    }
+
+    #[inline]
+    fn get_mut(&mut self, index: usize) -> Option<&mut dyn Reflect> {
+        VecDeque::get_mut(self, index).map(|value| value as &mut dyn Reflect)
+    }
+
+    /// # Panic
+    /// - `index > len`
+    /// - the value cannot be converted by [`FromReflect`].
+    #[inline]
+    fn insert(&mut self, index: usize, element: Box<dyn Reflect>) {
+        VecDeque::insert(self, index, crate::impls::take_element(element));
+    }
+
+    #[inline]
+    fn remove(&mut self, index: usize) -> Box<dyn Reflect> {
+        let value = VecDeque::remove(self, index).expect("index out of bounds");
+        Box::new(value)
+    }
+
+    /// # Panic
+    /// - the value cannot be converted by [`FromReflect`].
+    #[inline]
+    fn push(&mut self, value: Box<dyn Reflect>) {
+        self.push_back(crate::impls::take_element(value));
+    }
+
+    #[inline]
+    fn pop(&mut self) -> Option<Box<dyn Reflect>> {
+        self.pop_back()
+            .map(|value| Box::new(value) as Box<dyn Reflect>)
+    }
+
+    /// # Panic
+    /// - a value cannot be converted by [`FromReflect`].
+    #[inline]
+    fn extend_boxed(&mut self, values: &mut dyn Iterator<Item = Box<dyn Reflect>>) {
+        self.extend(values.map(crate::impls::take_element));
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        VecDeque::len(self)
+    }
+
+    #[inline]
+    fn iter(&self) -> ListItemIter<'_> {
+        ListItemIter::new(self)
+    }
+
+    #[inline]
+    fn drain(&mut self) -> Vec<Box<dyn Reflect>> {
+        VecDeque::drain(self, ..)
+            .map(|value| Box::new(value) as Box<dyn Reflect>)
+            .collect()
+    }
+}
+
+impl<T: FromReflect + Typed> Reflect for VecDeque<T> {
+    impl_reflect_list_fn!();
+
+    #[inline]
+    fn try_apply(&mut self, value: &dyn Reflect) -> Result<(), ApplyError> {
+        list_try_apply(self, value)
+    }
+
+    #[inline]
+    fn try_apply_owned(&mut self, value: Box<dyn Reflect>) -> Result<(), ApplyError> {
+        list_try_apply_owned(self, value)
+    }
+
+    #[inline]
+    fn reflect_partial_eq(&self, other: &dyn Reflect) -> Option<bool> {
+        list_partial_eq(self, other)
+    }
+
+    #[inline]
+    fn reflect_partial_cmp(&self, other: &dyn Reflect) -> Option<Ordering> {
+        list_partial_cmp(self, other)
+    }
+
+    #[inline]
+    fn reflect_hash(&self) -> Option<u64> {
+        list_hash(self)
+    }
+}
+
+// -----------------------------------------------------------------------------
+// LinkedList<T>
+
+impl<T: FromReflect + Typed> List for LinkedList<T> {
+    #[inline]
+    fn get(&self, index: usize) -> Option<&dyn Reflect> {
+        LinkedList::iter(self)
+            .nth(index)
+            .map(|value| value as &dyn Reflect)
+    }
+
+    #[inline]
+    fn get_mut(&mut self, index: usize) -> Option<&mut dyn Reflect> {
+        self.iter_mut()
+            .nth(index)
+            .map(|value| value as &mut dyn Reflect)
+    }
+
+    /// # Panic
+    /// - `index > len`
+    /// - the value cannot be converted by [`FromReflect`].
+    fn insert(&mut self, index: usize, element: Box<dyn Reflect>) {
+        let mut tail = self.split_off(index);
+        self.push_back(crate::impls::take_element(element));
+        self.append(&mut tail);
+    }
+
+    fn remove(&mut self, index: usize) -> Box<dyn Reflect> {
+        let mut tail = self.split_off(index);
+        let value = tail.pop_front().expect("index out of bounds");
+        self.append(&mut tail);
+        Box::new(value)
+    }
+
+    /// # Panic
+    /// - the value cannot be converted by [`FromReflect`].
+    #[inline]
+    fn push(&mut self, value: Box<dyn Reflect>) {
+        self.push_back(crate::impls::take_element(value));
+    }
+
+    #[inline]
+    fn pop(&mut self) -> Option<Box<dyn Reflect>> {
+        self.pop_back()
+            .map(|value| Box::new(value) as Box<dyn Reflect>)
+    }
+
+    /// # Panic
+    /// - a value cannot be converted by [`FromReflect`].
+    #[inline]
+    fn extend_boxed(&mut self, values: &mut dyn Iterator<Item = Box<dyn Reflect>>) {
+        self.extend(values.map(crate::impls::take_element));
+    }
+
+    /// # Panic
+    /// - the range is out of bounds.
+    /// - a value cannot be converted by [`FromReflect`].
+    fn splice(
+        &mut self,
+        range: Range<usize>,
+        values: Vec<Box<dyn Reflect>>,
+    ) -> Vec<Box<dyn Reflect>> {
+        assert!(
+            range.start <= range.end && range.end <= LinkedList::len(self),
+            "splice range {}..{} is out of bounds for list of length {}",
+            range.start,
+            range.end,
+            LinkedList::len(self),
+        );
+
+        let mut removed = self.split_off(range.start);
+        let mut tail = removed.split_off(range.len());
+        self.extend(values.into_iter().map(crate::impls::take_element));
+        self.append(&mut tail);
+        removed
+            .into_iter()
+            .map(|value| Box::new(value) as Box<dyn Reflect>)
+            .collect()
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        LinkedList::len(self)
+    }
+
+    #[inline]
+    fn iter(&self) -> ListItemIter<'_> {
+        ListItemIter::from_items(LinkedList::iter(self).map(|value| value as &dyn Reflect))
+    }
+
+    #[inline]
+    fn drain(&mut self) -> Vec<Box<dyn Reflect>> {
+        core::mem::take(self)
+            .into_iter()
+            .map(|value| Box::new(value) as Box<dyn Reflect>)
+            .collect()
+    }
+}
+
+impl<T: FromReflect + Typed> Reflect for LinkedList<T> {
+    impl_reflect_list_fn!();
+
+    /// Like [`list_try_apply`], but walks the list once.
+    fn try_apply(&mut self, value: &dyn Reflect) -> Result<(), ApplyError> {
+        let mut values = value.reflect_ref().as_list()?.iter();
+        for (index, (item, value)) in self.iter_mut().zip(values.by_ref()).enumerate() {
+            item.try_apply(value)
+                .map_err(|err| err.with_list_index(index))?;
+        }
+        for value in values {
+            self.push_back(crate::impls::take_element(value.to_dynamic()));
+        }
+        Ok(())
+    }
+
+    /// Like [`list_try_apply_owned`], but walks the list once.
+    fn try_apply_owned(&mut self, value: Box<dyn Reflect>) -> Result<(), ApplyError> {
+        let mut values = value.reflect_owned().into_list()?.drain().into_iter();
+        for (index, (item, value)) in self.iter_mut().zip(values.by_ref()).enumerate() {
+            item.try_apply_owned(value)
+                .map_err(|err| err.with_list_index(index))?;
+        }
+        self.extend(values.map(crate::impls::take_element));
+        Ok(())
+    }
+
+    #[inline]
+    fn reflect_partial_eq(&self, other: &dyn Reflect) -> Option<bool> {
+        list_partial_eq(self, other)
+    }
+
+    #[inline]
+    fn reflect_partial_cmp(&self, other: &dyn Reflect) -> Option<Ordering> {
+        list_partial_cmp(self, other)
+    }
+
+    #[inline]
+    fn reflect_hash(&self) -> Option<u64> {
+        list_hash(self)
+    }
+}
+
+// -----------------------------------------------------------------------------
+// BinaryHeap<T>
+
+impl<T: FromReflect + Typed + Ord> List for BinaryHeap<T> {
+    #[inline]
+    fn get(&self, index: usize) -> Option<&dyn Reflect> {
+        self.as_slice()
+            .get(index)
+            .map(|value| value as &dyn Reflect)
+    }
+
+    /// Always returns `None`, mutating an element could break the heap order.
+    ///
+    /// So `apply_diff` fails with [`ApplyError::NotSupport`] when an element is modified,
+    /// `try_apply` replaces all elements instead.
+    #[inline]
+    fn get_mut(&mut self, _index: usize) -> Option<&mut dyn Reflect> {
+        None
+    }
+
+    /// Pushes the element to the heap, its position is decided by the heap.
+    ///
+    /// # Panic
+    /// - `index > len`
+    /// - the value cannot be converted by [`FromReflect`].
+    fn insert(&mut self, index: usize, element: Box<dyn Reflect>) {
+        assert!(index <= self.len(), "index out of bounds");
+        BinaryHeap::push(self, crate::impls::take_element(element));
+    }
+
+    fn remove(&mut self, index: usize) -> Box<dyn Reflect> {
+        let mut values = core::mem::take(self).into_vec();
+        let value = values.remove(index);
+        *self = BinaryHeap::from(values);
+        Box::new(value)
+    }
+
+    /// # Panic
+    /// - the value cannot be converted by [`FromReflect`].
+    #[inline]
+    fn push(&mut self, value: Box<dyn Reflect>) {
+        BinaryHeap::push(self, crate::impls::take_element(value));
+    }
+
+    /// Removes the greatest element.
+    #[inline]
+    fn pop(&mut self) -> Option<Box<dyn Reflect>> {
+        BinaryHeap::pop(self).map(|value| Box::new(value) as Box<dyn Reflect>)
+    }
+
+    /// # Panic
+    /// - a value cannot be converted by [`FromReflect`].
+    #[inline]
+    fn extend_boxed(&mut self, values: &mut dyn Iterator<Item = Box<dyn Reflect>>) {
+        self.extend(values.map(crate::impls::take_element));
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        BinaryHeap::len(self)
+    }
+
+    #[inline]
+    fn iter(&self) -> ListItemIter<'_> {
+        ListItemIter::new(self)
+    }
+
+    #[inline]
+    fn drain(&mut self) -> Vec<Box<dyn Reflect>> {
+        BinaryHeap::drain(self)
+            .map(|value| Box::new(value) as Box<dyn Reflect>)
+            .collect()
+    }
+}
+
+impl<T: FromReflect + Typed + Ord> Reflect for BinaryHeap<T> {
+    impl_reflect_list_fn!();
+
+    /// Replaces the elements, since they can not be mutated in place.
+    fn try_apply(&mut self, value: &dyn Reflect) -> Result<(), ApplyError> {
+        let ref_list = value.reflect_ref().as_list()?;
+        let values = ref_list
+            .iter()
+            .map(|value| {
+                T::from_reflect(value).ok_or_else(|| ApplyError::MismatchedTypes {
+                    from_type: Cow::Owned(value.reflect_type_path().to_owned()),
+                    to_type: Cow::Borrowed(T::type_path()),
+                })
+            })
+            .collect::<Result<Vec<T>, ApplyError>>()?;
+        *self = BinaryHeap::from(values);
+        Ok(())
+    }
+
+    /// Compares the elements in sorted order, the internal order of equal heaps can differ.
+    ///
+    /// Returns `None` if `other` is a list whose elements cannot be converted to `T`.
+    fn reflect_partial_eq(&self, other: &dyn Reflect) -> Option<bool> {
+        let ReflectRef::List(other_list) = other.reflect_ref() else {
+            return Some(false);
+        };
+        if BinaryHeap::len(self) != other_list.len() {
+            return Some(false);
+        }
+
+        let converted;
+        let other = match other.downcast_ref::<Self>() {
+            Some(other) => other,
+            None => {
+                converted = Self::from_reflect(other)?;
+                &converted
+            }
+        };
+
+        let mut values: Vec<&T> = BinaryHeap::iter(self).collect();
+        let mut others: Vec<&T> = BinaryHeap::iter(other).collect();
+        values.sort_unstable();
+        others.sort_unstable();
+        Some(values == others)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{
+        boxed::Box,
+        collections::{BinaryHeap, LinkedList},
+        vec,
+        vec::Vec,
+    };
+
+    use crate::{
+        Reflect,
+        ops::{ApplyError, Diff, DiffKind, DynamicList, List, ListDiff},
+    };
+
+    #[test]
+    fn binary_heap_eq_ignores_internal_order() {
+        let heapified = BinaryHeap::from(vec![1_u32, 2, 3]);
+        let mut pushed = BinaryHeap::new();
+        for value in [1_u32, 2, 3] {
+            pushed.push(value);
+        }
+        assert_ne!(heapified.as_slice(), pushed.as_slice());
+
+        assert_eq!(heapified.reflect_partial_eq(&pushed), Some(true));
+        let list: DynamicList = [3_u32, 1, 2].into_iter().collect();
+        assert_eq!(heapified.reflect_partial_eq(&list), Some(true));
+        assert_eq!(
+            heapified.reflect_partial_eq(&BinaryHeap::from(vec![1_u32, 2, 4])),
+            Some(false)
+        );
+    }
+
+    #[test]
+    fn binary_heap_rejects_modified_elements() {
+        let mut heap = BinaryHeap::from(vec![1_u32, 2, 3]);
+        let diff = Diff::Modified(DiffKind::List(vec![ListDiff::Modified(
+            0,
+            Diff::Replaced(Box::new(5_u32)),
+        )]));
+        assert!(matches!(
+            heap.as_reflect_mut().apply_diff(&diff),
+            Err(ApplyError::NotSupport { .. })
+        ));
+
+        heap.try_apply(&BinaryHeap::from(vec![4_u32, 5])).unwrap();
+        assert_eq!(heap.into_sorted_vec(), vec![4, 5]);
+    }
+
+    #[test]
+    fn linked_list_whole_list_operations() {
+        let mut list = LinkedList::from([1_u32, 2, 3, 4]);
+        let items: Vec<u32> = List::iter(&list)
+            .map(|value| *value.downcast_ref::<u32>().unwrap())
+            .collect();
+        assert_eq!(items, vec![1, 2, 3, 4]);
+        assert_eq!(List::iter(&list).len(), 4);
+
+        let removed = list.splice(
+            1..3,
+            vec![Box::new(5_u32), Box::new(6_u32), Box::new(7_u32)],
+        );
+        assert_eq!(removed.len(), 2);
+        assert_eq!(removed[1].downcast_ref::<u32>(), Some(&3));
+        assert_eq!(list, LinkedList::from([1, 5, 6, 7, 4]));
+
+        list.try_apply(&LinkedList::from([0_u32, 0, 0, 0, 0, 8]))
+            .unwrap();
+        assert_eq!(list, LinkedList::from([0, 0, 0, 0, 0, 8]));
+        list.try_apply_owned(Box::new(LinkedList::from([9_u32])))
+            .unwrap();
+        assert_eq!(list, LinkedList::from([9, 0, 0, 0, 0, 8]));
+    }
+}
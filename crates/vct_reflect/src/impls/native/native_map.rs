@@ -0,0 +1,554 @@
+//! Implement reflection traits for [`BTreeMap`] and [`HashMap`] as a [`Map`].
+//!
+//! - [`TypePath`] -> [`DynamicTypePath`]
+//! - [`Typed`] -> [`DynamicTyped`]
+//! - [`Map`]
+//! - [`Reflect`]
+//! - [`GetTypeTraits`]
+//! - [`FromReflect`]
+//!
+//! Keys that are not of the key type (e.g. dynamic keys) are converted with [`FromReflect`]
+//! before lookup.
+//!
+//! [`HashMap`] is reflected for any hasher `S: BuildHasher + Default + TypePath`,
+//! see [`impl_reflect_hash_map`] for other hash map types.
+//!
+//! [`DynamicTypePath`]: crate::info::DynamicTypePath
+//! [`DynamicTyped`]: crate::info::DynamicTyped
+
+use crate::{
+    FromReflect, Reflect,
+    cell::{GenericTypeInfoCell, GenericTypePathCell},
+    impls::lookup_key,
+    info::{Generics, MapInfo, ReflectKind, TypeInfo, TypeParamInfo, TypePath, Typed},
+    ops::{
+        ApplyError, Map, ReflectCloneError, ReflectMut, ReflectOwned, ReflectRef, map_debug,
+        map_partial_eq, map_try_apply, map_try_apply_owned,
+    },
+    registry::{
+        FromType, GetTypeTraits, TypeRegistry, TypeTraitDefault, TypeTraitFromPtr,
+        TypeTraitFromReflect, TypeTraits,
+    },
+};
+use alloc::{boxed::Box, collections::BTreeMap, vec::Vec};
+use core::fmt;
+use vct_utils::collections::HashMap;
+
+impl<K: TypePath, V: TypePath> TypePath for BTreeMap<K, V> {
+    fn type_path() -> &'static str {
+        static CELL: GenericTypePathCell = GenericTypePathCell::new();
+        CELL.get_or_insert::<Self, _>(|| {
+            crate::impls::concat(&[
+                "alloc::collections::btree_map::BTreeMap<",
+                K::type_path(),
+                ", ",
+                V::type_path(),
+                ">",
+            ])
+        })
+    }
+
+    fn type_name() -> &'static str {
+        static CELL: GenericTypePathCell = GenericTypePathCell::new();
+        CELL.get_or_insert::<Self, _>(|| {
+            crate::impls::concat(&["BTreeMap<", K::type_name(), ", ", V::type_name(), ">"])
+        })
+    }
+
+    #[inline]
+    fn type_ident() -> &'static str {
+        "BTreeMap"
+    }
+
+    #[inline]
+    fn crate_name() -> Option<&'static str> {
+        Some("alloc")
+    }
+
+    #[inline]
+    fn module_path() -> Option<&'static str> {
+        Some("alloc::collections::btree_map")
+    }
+}
+
+impl<K, V> Typed for BTreeMap<K, V>
+where
+    K: FromReflect + Typed + Ord,
+    V: FromReflect + Typed,
+{
+    fn type_info() -> &'static TypeInfo {
+        static CELL: GenericTypeInfoCell = GenericTypeInfoCell::new();
+        CELL.get_or_insert::<Self, _>(|| {
+            let generics = Generics::new()
+                .with(TypeParamInfo::new::<K>("K"))
+                .with(TypeParamInfo::new::<V>("V"));
+            TypeInfo::Map(MapInfo::new::<Self, K, V>().with_generics(generics))
+        })
+    }
+}
+
+impl<K, V> Map for BTreeMap<K, V>
+where
+    K: FromReflect + Typed + Ord,
+    V: FromReflect + Typed,
+{
+    #[inline]
+    fn get(&self, key: &dyn Reflect) -> Option<&dyn Reflect> {
+        lookup_key(key, |key: &K| BTreeMap::get(self, key)).map(|value| value as &dyn Reflect)
+    }
+
+    #[inline]
+    fn get_mut(&mut self, key: &dyn Reflect) -> Option<&mut dyn Reflect> {
+        lookup_key(key, |key: &K| BTreeMap::get_mut(self, key))
+            .map(|value| value as &mut dyn Reflect)
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        BTreeMap::len(self)
+    }
+
+    fn iter(&self) -> Box<dyn Iterator<Item = (&dyn Reflect, &dyn Reflect)> + '_> {
+        Box::new(
+            BTreeMap::iter(self).map(|(key, value)| (key as &dyn Reflect, value as &dyn Reflect)),
+        )
+    }
+
+    fn drain(&mut self) -> Vec<(Box<dyn Reflect>, Box<dyn Reflect>)> {
+        core::mem::take(self)
+            .into_iter()
+            .map(|(key, value)| {
+                (
+                    Box::new(key) as Box<dyn Reflect>,
+                    Box::new(value) as Box<dyn Reflect>,
+                )
+            })
+            .collect()
+    }
+
+    #[inline]
+    fn retain(&mut self, f: &mut dyn FnMut(&dyn Reflect, &mut dyn Reflect) -> bool) {
+        BTreeMap::retain(self, |key, value| f(key, value));
+    }
+
+    /// # Panic
+    /// - the key or the value cannot be converted by [`FromReflect`].
+    fn insert_boxed(
+        &mut self,
+        key: Box<dyn Reflect>,
+        value: Box<dyn Reflect>,
+    ) -> Option<Box<dyn Reflect>> {
+        let key = crate::impls::take_element::<K>(key);
+        let value = crate::impls::take_element::<V>(value);
+        BTreeMap::insert(self, key, value).map(|old| Box::new(old) as Box<dyn Reflect>)
+    }
+
+    #[inline]
+    fn remove(&mut self, key: &dyn Reflect) -> Option<Box<dyn Reflect>> {
+        lookup_key(key, |key: &K| BTreeMap::remove(self, key))
+            .map(|value| Box::new(value) as Box<dyn Reflect>)
+    }
+}
+
+impl<K, V> Reflect for BTreeMap<K, V>
+where
+    K: FromReflect + Typed + Ord,
+    V: FromReflect + Typed,
+{
+    #[inline]
+    fn as_reflect(&self) -> &dyn Reflect {
+        self
+    }
+
+    #[inline]
+    fn as_reflect_mut(&mut self) -> &mut dyn Reflect {
+        self
+    }
+
+    #[inline]
+    fn into_reflect(self: Box<Self>) -> Box<dyn Reflect> {
+        self
+    }
+
+    #[inline]
+    fn set(&mut self, value: Box<dyn Reflect>) -> Result<(), Box<dyn Reflect>> {
+        *self = value.take()?;
+        Ok(())
+    }
+
+    #[inline]
+    fn represented_type_info(&self) -> Option<&'static TypeInfo> {
+        Some(<Self as Typed>::type_info())
+    }
+
+    #[inline]
+    fn reflect_kind(&self) -> ReflectKind {
+        ReflectKind::Map
+    }
+
+    #[inline]
+    fn reflect_ref(&self) -> ReflectRef<'_> {
+        ReflectRef::Map(self)
+    }
+
+    #[inline]
+    fn reflect_mut(&mut self) -> ReflectMut<'_> {
+        ReflectMut::Map(self)
+    }
+
+    #[inline]
+    fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+        ReflectOwned::Map(self)
+    }
+
+    #[inline]
+    fn try_apply(&mut self, value: &dyn Reflect) -> Result<(), ApplyError> {
+        map_try_apply(self, value)
+    }
+
+    #[inline]
+    fn try_apply_owned(&mut self, value: Box<dyn Reflect>) -> Result<(), ApplyError> {
+        map_try_apply_owned(self, value)
+    }
+
+    #[inline]
+    fn reflect_partial_eq(&self, other: &dyn Reflect) -> Option<bool> {
+        map_partial_eq(self, other)
+    }
+
+    fn reflect_clone(&self) -> Result<Box<dyn Reflect>, ReflectCloneError> {
+        let map = BTreeMap::iter(self)
+            .map(|(key, value)| {
+                let key = key
+                    .reflect_clone()?
+                    .take::<K>()
+                    .expect("`Reflect::reflect_clone` should return the same type");
+                let value = value
+                    .reflect_clone()?
+                    .take::<V>()
+                    .expect("`Reflect::reflect_clone` should return the same type");
+                Ok((key, value))
+            })
+            .collect::<Result<Self, ReflectCloneError>>()?;
+        Ok(Box::new(map))
+    }
+
+    #[inline]
+    fn reflect_debug(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        map_debug(self, f)
+    }
+}
+
+impl<K, V> GetTypeTraits for BTreeMap<K, V>
+where
+    K: FromReflect + Typed + GetTypeTraits + Ord,
+    V: FromReflect + Typed + GetTypeTraits,
+{
+    fn get_type_traits() -> TypeTraits {
+        let mut type_traits = TypeTraits::of::<Self>();
+        type_traits.insert::<TypeTraitDefault>(FromType::<Self>::from_type());
+        type_traits.insert::<TypeTraitFromPtr>(FromType::<Self>::from_type());
+        type_traits.insert::<TypeTraitFromReflect>(FromType::<Self>::from_type());
+        type_traits
+    }
+
+    fn register_dependencies(registry: &mut TypeRegistry) {
+        registry.register::<K>();
+        registry.register::<V>();
+    }
+}
+
+impl<K, V> FromReflect for BTreeMap<K, V>
+where
+    K: FromReflect + Typed + Ord,
+    V: FromReflect + Typed,
+{
+    fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+        let ref_map = reflect.reflect_ref().as_map().ok()?;
+        ref_map
+            .iter()
+            .map(|(key, value)| Some((K::from_reflect(key)?, V::from_reflect(value)?)))
+            .collect()
+    }
+}
+
+/// Implements the reflection traits for a hash map type `$ty<K, V, S>`
+/// with the API of [`hashbrown::HashMap`], generic over the hasher `S`.
+///
+/// `$module` is the module path used by [`TypePath`], `$crate_name` its crate name.
+///
+/// [`hashbrown::HashMap`]: https://docs.rs/hashbrown/latest/hashbrown/struct.HashMap.html
+macro_rules! impl_reflect_hash_map {
+    ($ty:ident, $module:literal, $crate_name:literal) => {
+        const _: () = {
+            use $crate::{
+                FromReflect, Reflect,
+                cell::{GenericTypeInfoCell, GenericTypePathCell},
+                impls::lookup_key,
+                info::{Generics, MapInfo, ReflectKind, TypeInfo, TypeParamInfo, TypePath, Typed},
+                ops::{
+                    ApplyError, Map, ReflectCloneError, ReflectMut, ReflectOwned, ReflectRef,
+                    map_debug, map_partial_eq, map_try_apply, map_try_apply_owned,
+                },
+                registry::{
+                    FromType, GetTypeTraits, TypeRegistry, TypeTraitDefault, TypeTraitFromPtr,
+                    TypeTraitFromReflect, TypeTraits,
+                },
+            };
+            use ::alloc::{boxed::Box, vec::Vec};
+            use ::core::{
+                fmt,
+                hash::{BuildHasher, Hash},
+            };
+
+            impl<K: TypePath, V: TypePath, S: TypePath> TypePath for $ty<K, V, S> {
+                fn type_path() -> &'static str {
+                    static CELL: GenericTypePathCell = GenericTypePathCell::new();
+                    CELL.get_or_insert::<Self, _>(|| {
+                        $crate::impls::concat(&[
+                            $module, "::", stringify!($ty), "<",
+                            K::type_path(), ", ", V::type_path(), ", ", S::type_path(), ">",
+                        ])
+                    })
+                }
+
+                fn type_name() -> &'static str {
+                    static CELL: GenericTypePathCell = GenericTypePathCell::new();
+                    CELL.get_or_insert::<Self, _>(|| {
+                        $crate::impls::concat(&[
+                            stringify!($ty), "<",
+                            K::type_name(), ", ", V::type_name(), ", ", S::type_name(), ">",
+                        ])
+                    })
+                }
+
+                #[inline]
+                fn type_ident() -> &'static str {
+                    stringify!($ty)
+                }
+
+                #[inline]
+                fn crate_name() -> Option<&'static str> {
+                    Some($crate_name)
+                }
+
+                #[inline]
+                fn module_path() -> Option<&'static str> {
+                    Some($module)
+                }
+            }
+
+            impl<K, V, S> Typed for $ty<K, V, S>
+            where
+                K: FromReflect + Typed + Eq + Hash,
+                V: FromReflect + Typed,
+                S: TypePath + BuildHasher + Default + Send + Sync,
+            {
+                fn type_info() -> &'static TypeInfo {
+                    static CELL: GenericTypeInfoCell = GenericTypeInfoCell::new();
+                    CELL.get_or_insert::<Self, _>(|| {
+                        let generics = Generics::new()
+                            .with(TypeParamInfo::new::<K>("K"))
+                            .with(TypeParamInfo::new::<V>("V"))
+                            .with(TypeParamInfo::new::<S>("S"));
+                        TypeInfo::Map(MapInfo::new::<Self, K, V>().with_generics(generics))
+                    })
+                }
+            }
+
+            impl<K, V, S> Map for $ty<K, V, S>
+            where
+                K: FromReflect + Typed + Eq + Hash,
+                V: FromReflect + Typed,
+                S: TypePath + BuildHasher + Default + Send + Sync,
+            {
+                #[inline]
+                fn get(&self, key: &dyn Reflect) -> Option<&dyn Reflect> {
+                    lookup_key(key, |key: &K| $ty::get(self, key)).map(|value| value as &dyn Reflect)
+                }
+
+                #[inline]
+                fn get_mut(&mut self, key: &dyn Reflect) -> Option<&mut dyn Reflect> {
+                    lookup_key(key, |key: &K| $ty::get_mut(self, key))
+                        .map(|value| value as &mut dyn Reflect)
+                }
+
+                #[inline]
+                fn len(&self) -> usize {
+                    $ty::len(self)
+                }
+
+                fn iter(&self) -> Box<dyn Iterator<Item = (&dyn Reflect, &dyn Reflect)> + '_> {
+                    Box::new(
+                        $ty::iter(self)
+                            .map(|(key, value)| (key as &dyn Reflect, value as &dyn Reflect)),
+                    )
+                }
+
+                fn drain(&mut self) -> Vec<(Box<dyn Reflect>, Box<dyn Reflect>)> {
+                    $ty::drain(self)
+                        .map(|(key, value)| {
+                            (
+                                Box::new(key) as Box<dyn Reflect>,
+                                Box::new(value) as Box<dyn Reflect>,
+                            )
+                        })
+                        .collect()
+                }
+
+                #[inline]
+                fn retain(&mut self, f: &mut dyn FnMut(&dyn Reflect, &mut dyn Reflect) -> bool) {
+                    $ty::retain(self, |key, value| f(key, value));
+                }
+
+                /// # Panic
+                /// - the key or the value cannot be converted by [`FromReflect`].
+                fn insert_boxed(
+                    &mut self,
+                    key: Box<dyn Reflect>,
+                    value: Box<dyn Reflect>,
+                ) -> Option<Box<dyn Reflect>> {
+                    let key = $crate::impls::take_element::<K>(key);
+                    let value = $crate::impls::take_element::<V>(value);
+                    $ty::insert(self, key, value).map(|old| Box::new(old) as Box<dyn Reflect>)
+                }
+
+                #[inline]
+                fn remove(&mut self, key: &dyn Reflect) -> Option<Box<dyn Reflect>> {
+                    lookup_key(key, |key: &K| $ty::remove(self, key))
+                        .map(|value| Box::new(value) as Box<dyn Reflect>)
+                }
+            }
+
+            impl<K, V, S> Reflect for $ty<K, V, S>
+            where
+                K: FromReflect + Typed + Eq + Hash,
+                V: FromReflect + Typed,
+                S: TypePath + BuildHasher + Default + Send + Sync,
+            {
+                #[inline]
+                fn as_reflect(&self) -> &dyn Reflect {
+                    self
+                }
+
+                #[inline]
+                fn as_reflect_mut(&mut self) -> &mut dyn Reflect {
+                    self
+                }
+
+                #[inline]
+                fn into_reflect(self: Box<Self>) -> Box<dyn Reflect> {
+                    self
+                }
+
+                #[inline]
+                fn set(&mut self, value: Box<dyn Reflect>) -> Result<(), Box<dyn Reflect>> {
+                    *self = value.take()?;
+                    Ok(())
+                }
+
+                #[inline]
+                fn represented_type_info(&self) -> Option<&'static TypeInfo> {
+                    Some(<Self as Typed>::type_info())
+                }
+
+                #[inline]
+                fn reflect_kind(&self) -> ReflectKind {
+                    ReflectKind::Map
+                }
+
+                #[inline]
+                fn reflect_ref(&self) -> ReflectRef<'_> {
+                    ReflectRef::Map(self)
+                }
+
+                #[inline]
+                fn reflect_mut(&mut self) -> ReflectMut<'_> {
+                    ReflectMut::Map(self)
+                }
+
+                #[inline]
+                fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+                    ReflectOwned::Map(self)
+                }
+
+                #[inline]
+                fn try_apply(&mut self, value: &dyn Reflect) -> Result<(), ApplyError> {
+                    map_try_apply(self, value)
+                }
+
+                #[inline]
+                fn try_apply_owned(&mut self, value: Box<dyn Reflect>) -> Result<(), ApplyError> {
+                    map_try_apply_owned(self, value)
+                }
+
+                #[inline]
+                fn reflect_partial_eq(&self, other: &dyn Reflect) -> Option<bool> {
+                    map_partial_eq(self, other)
+                }
+
+                fn reflect_clone(&self) -> Result<Box<dyn Reflect>, ReflectCloneError> {
+                    let map = $ty::iter(self)
+                        .map(|(key, value)| {
+                            let key = key
+                                .reflect_clone()?
+                                .take::<K>()
+                                .expect("`Reflect::reflect_clone` should return the same type");
+                            let value = value
+                                .reflect_clone()?
+                                .take::<V>()
+                                .expect("`Reflect::reflect_clone` should return the same type");
+                            Ok((key, value))
+                        })
+                        .collect::<Result<Self, ReflectCloneError>>()?;
+                    Ok(Box::new(map))
+                }
+
+                #[inline]
+                fn reflect_debug(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    map_debug(self, f)
+                }
+            }
+
+            impl<K, V, S> GetTypeTraits for $ty<K, V, S>
+            where
+                K: FromReflect + Typed + GetTypeTraits + Eq + Hash,
+                V: FromReflect + Typed + GetTypeTraits,
+                S: TypePath + BuildHasher + Default + Send + Sync,
+            {
+                fn get_type_traits() -> TypeTraits {
+                    let mut type_traits = TypeTraits::of::<Self>();
+                    type_traits.insert::<TypeTraitDefault>(FromType::<Self>::from_type());
+                    type_traits.insert::<TypeTraitFromPtr>(FromType::<Self>::from_type());
+                    type_traits.insert::<TypeTraitFromReflect>(FromType::<Self>::from_type());
+                    type_traits
+                }
+
+                fn register_dependencies(registry: &mut TypeRegistry) {
+                    registry.register::<K>();
+                    registry.register::<V>();
+                }
+            }
+
+            impl<K, V, S> FromReflect for $ty<K, V, S>
+            where
+                K: FromReflect + Typed + Eq + Hash,
+                V: FromReflect + Typed,
+                S: TypePath + BuildHasher + Default + Send + Sync,
+            {
+                fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+                    let ref_map = reflect.reflect_ref().as_map().ok()?;
+                    ref_map
+                        .iter()
+                        .map(|(key, value)| Some((K::from_reflect(key)?, V::from_reflect(value)?)))
+                        .collect()
+                }
+            }
+        };
+    };
+}
+
+#[cfg(feature = "hashbrown")]
+pub(crate) use impl_reflect_hash_map;
+
+impl_reflect_hash_map!(HashMap, "vct_utils::collections::hash_map", "vct_utils");
@@ -0,0 +1,59 @@
+use crate::{FromReflect, Reflect, info::TypePath};
+use core::{
+    fmt::Debug,
+    hash::Hash,
+    num::{
+        NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize, NonZeroU8,
+        NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize, Saturating, Wrapping,
+    },
+};
+use serde::{Deserialize, Serialize};
+use vct_reflect_derive::impl_reflect_opaque;
+
+/// `FromReflect` also accepts the underlying integer, returning `None` if it is zero.
+macro_rules! impl_from_reflect_non_zero {
+    ($($ty:ident: $int:ty),*) => {
+        $(
+            impl FromReflect for $ty {
+                fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+                    if let Some(value) = reflect.downcast_ref::<Self>() {
+                        return Some(*value);
+                    }
+                    $ty::new(*reflect.downcast_ref::<$int>()?)
+                }
+            }
+        )*
+    };
+}
+
+impl_reflect_opaque!((in core::num) NonZeroU8 (clone, debug, hash, partial_eq, partial_ord, serde, FromReflect = false));
+impl_reflect_opaque!((in core::num) NonZeroU16 (clone, debug, hash, partial_eq, partial_ord, serde, FromReflect = false));
+impl_reflect_opaque!((in core::num) NonZeroU32 (clone, debug, hash, partial_eq, partial_ord, serde, FromReflect = false));
+impl_reflect_opaque!((in core::num) NonZeroU64 (clone, debug, hash, partial_eq, partial_ord, serde, FromReflect = false));
+impl_reflect_opaque!((in core::num) NonZeroU128 (clone, debug, hash, partial_eq, partial_ord, serde, FromReflect = false));
+impl_reflect_opaque!((in core::num) NonZeroUsize (clone, debug, hash, partial_eq, partial_ord, serde, FromReflect = false));
+impl_reflect_opaque!((in core::num) NonZeroI8 (clone, debug, hash, partial_eq, partial_ord, serde, FromReflect = false));
+impl_reflect_opaque!((in core::num) NonZeroI16 (clone, debug, hash, partial_eq, partial_ord, serde, FromReflect = false));
+impl_reflect_opaque!((in core::num) NonZeroI32 (clone, debug, hash, partial_eq, partial_ord, serde, FromReflect = false));
+impl_reflect_opaque!((in core::num) NonZeroI64 (clone, debug, hash, partial_eq, partial_ord, serde, FromReflect = false));
+impl_reflect_opaque!((in core::num) NonZeroI128 (clone, debug, hash, partial_eq, partial_ord, serde, FromReflect = false));
+impl_reflect_opaque!((in core::num) NonZeroIsize (clone, debug, hash, partial_eq, partial_ord, serde, FromReflect = false));
+
+impl_from_reflect_non_zero!(
+    NonZeroU8: u8,
+    NonZeroU16: u16,
+    NonZeroU32: u32,
+    NonZeroU64: u64,
+    NonZeroU128: u128,
+    NonZeroUsize: usize,
+    NonZeroI8: i8,
+    NonZeroI16: i16,
+    NonZeroI32: i32,
+    NonZeroI64: i64,
+    NonZeroI128: i128,
+    NonZeroIsize: isize
+);
+
+impl_reflect_opaque!((in core::num) Wrapping<T: Clone + Debug + Hash + PartialEq + PartialOrd + Serialize + for<'de> Deserialize<'de> + TypePath + Send + Sync> (clone, debug, hash, partial_eq, partial_ord, serde));
+// serde only deserializes `Saturating` of primitive integers, so it is serialize only.
+impl_reflect_opaque!((in core::num) Saturating<T: Clone + Debug + Hash + PartialEq + PartialOrd + Serialize + TypePath + Send + Sync> (clone, debug, hash, partial_eq, partial_ord, serialize));
@@ -0,0 +1,249 @@
+//! Implement reflection traits for `Option<Box<dyn Reflect>>` as an `Enum`,
+//! the optional form of `#[reflect(dynamic)]` fields.
+//!
+//! The field of `Some` is declared as `dyn Reflect`, so it is serialized with its type path.
+//! Applying a value of another type to `Some` replaces the boxed value with a clone.
+//!
+//! `Option<T>` is not reflected in general.
+
+use alloc::{borrow::Cow, boxed::Box, string::ToString};
+
+use crate::{
+    FromReflect, Reflect,
+    cell::NonGenericTypeInfoCell,
+    info::{
+        EnumInfo, ReflectKind, TupleVariantInfo, TypeInfo, TypePath, Typed, UnitVariantInfo,
+        UnnamedField, VariantInfo, VariantKind,
+    },
+    ops::{
+        ApplyError, Enum, ReflectCloneError, ReflectMut, ReflectOwned, ReflectRef,
+        VariantFieldIter, enum_partial_eq, enum_try_apply_owned,
+    },
+    reflect::impl_cast_reflect_fn,
+    registry::{FromType, GetTypeTraits, TypeRegistry, TypeTraitFromReflect, TypeTraits},
+};
+
+impl TypePath for Option<Box<dyn Reflect>> {
+    #[inline]
+    fn type_path() -> &'static str {
+        "core::option::Option<alloc::boxed::Box<dyn vct_reflect::Reflect>>"
+    }
+
+    #[inline]
+    fn type_name() -> &'static str {
+        "Option<Box<dyn Reflect>>"
+    }
+
+    #[inline]
+    fn type_ident() -> &'static str {
+        "Option"
+    }
+
+    #[inline]
+    fn crate_name() -> Option<&'static str> {
+        Some("core")
+    }
+
+    #[inline]
+    fn module_path() -> Option<&'static str> {
+        Some("core::option")
+    }
+}
+
+impl Typed for Option<Box<dyn Reflect>> {
+    fn type_info() -> &'static TypeInfo {
+        static CELL: NonGenericTypeInfoCell = NonGenericTypeInfoCell::new();
+        CELL.get_or_init(|| {
+            TypeInfo::Enum(EnumInfo::new::<Self>(&[
+                VariantInfo::Unit(UnitVariantInfo::new("None")),
+                VariantInfo::Tuple(TupleVariantInfo::new(
+                    "Some",
+                    &[UnnamedField::new_unsized::<dyn Reflect>(0)],
+                )),
+            ]))
+        })
+    }
+}
+
+impl Enum for Option<Box<dyn Reflect>> {
+    #[inline]
+    fn field(&self, _name: &str) -> Option<&dyn Reflect> {
+        None
+    }
+
+    #[inline]
+    fn field_at(&self, index: usize) -> Option<&dyn Reflect> {
+        match self {
+            Some(value) if index == 0 => Some(&**value),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn field_mut(&mut self, _name: &str) -> Option<&mut dyn Reflect> {
+        None
+    }
+
+    #[inline]
+    fn field_at_mut(&mut self, index: usize) -> Option<&mut dyn Reflect> {
+        match self {
+            Some(value) if index == 0 => Some(&mut **value),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn index_of(&self, _name: &str) -> Option<usize> {
+        None
+    }
+
+    #[inline]
+    fn name_at(&self, _index: usize) -> Option<&str> {
+        None
+    }
+
+    #[inline]
+    fn iter_fields(&self) -> VariantFieldIter<'_> {
+        VariantFieldIter::new(self)
+    }
+
+    #[inline]
+    fn field_len(&self) -> usize {
+        self.is_some() as usize
+    }
+
+    #[inline]
+    fn variant_name(&self) -> &str {
+        match self {
+            Some(_) => "Some",
+            None => "None",
+        }
+    }
+
+    #[inline]
+    fn variant_index(&self) -> usize {
+        self.is_some() as usize
+    }
+
+    #[inline]
+    fn variant_kind(&self) -> VariantKind {
+        match self {
+            Some(_) => VariantKind::Tuple,
+            None => VariantKind::Unit,
+        }
+    }
+}
+
+impl Reflect for Option<Box<dyn Reflect>> {
+    impl_cast_reflect_fn!();
+
+    #[inline]
+    fn reflect_kind(&self) -> ReflectKind {
+        ReflectKind::Enum
+    }
+
+    #[inline]
+    fn reflect_ref(&self) -> ReflectRef<'_> {
+        ReflectRef::Enum(self)
+    }
+
+    #[inline]
+    fn reflect_mut(&mut self) -> ReflectMut<'_> {
+        ReflectMut::Enum(self)
+    }
+
+    #[inline]
+    fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+        ReflectOwned::Enum(self)
+    }
+
+    #[inline]
+    fn to_dynamic(&self) -> Box<dyn Reflect> {
+        Box::new(self.to_dynamic_enum())
+    }
+
+    fn try_apply(&mut self, value: &dyn Reflect) -> Result<(), ApplyError> {
+        let ReflectRef::Enum(value) = value.reflect_ref() else {
+            return Err(ApplyError::MismatchedKinds {
+                from_kind: value.reflect_kind(),
+                to_kind: ReflectKind::Enum,
+            });
+        };
+
+        match value.variant_name() {
+            "None" => *self = None,
+            "Some" => {
+                let Some(field) = value.field_at(0) else {
+                    return Err(ApplyError::MissingEnumField {
+                        variant_name: Cow::Borrowed("Some"),
+                        field_name: Cow::Borrowed("0"),
+                    });
+                };
+                // Dynamic values are applied in place, values of another type replace the box.
+                match self {
+                    Some(inner)
+                        if field.is_dynamic() || (**inner).type_id() == (*field).type_id() =>
+                    {
+                        inner.try_apply(field).map_err(|e| e.with_tuple_index(0))?;
+                    }
+                    _ => {
+                        let value = field.reflect_clone().map_err(|_| ApplyError::NotSupport {
+                            type_path: Cow::Owned(field.reflect_type_path().to_string()),
+                        })?;
+                        *self = Some(value);
+                    }
+                }
+            }
+            name => {
+                return Err(ApplyError::UnknownVariant {
+                    enum_name: Cow::Borrowed(Self::type_path()),
+                    variant_name: Cow::Owned(name.to_string()),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn try_apply_owned(&mut self, value: Box<dyn Reflect>) -> Result<(), ApplyError> {
+        enum_try_apply_owned(self, value)
+    }
+
+    fn reflect_clone(&self) -> Result<Box<dyn Reflect>, ReflectCloneError> {
+        let value = match self {
+            Some(value) => Some(value.reflect_clone()?),
+            None => None,
+        };
+        Ok(Box::new(value))
+    }
+
+    #[inline]
+    fn reflect_partial_eq(&self, other: &dyn Reflect) -> Option<bool> {
+        enum_partial_eq(self, other)
+    }
+}
+
+impl FromReflect for Option<Box<dyn Reflect>> {
+    fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+        let ReflectRef::Enum(value) = reflect.reflect_ref() else {
+            return None;
+        };
+        match value.variant_name() {
+            "None" => Some(None),
+            "Some" => Some(Some(value.field_at(0)?.reflect_clone().ok()?)),
+            _ => None,
+        }
+    }
+}
+
+impl GetTypeTraits for Option<Box<dyn Reflect>> {
+    fn get_type_traits() -> TypeTraits {
+        let mut type_traits = TypeTraits::of::<Self>();
+        type_traits.insert::<TypeTraitFromReflect>(FromType::<Self>::from_type());
+        type_traits
+    }
+
+    fn register_dependencies(registry: &mut TypeRegistry) {
+        registry.register::<dyn Reflect>();
+    }
+}
@@ -0,0 +1,296 @@
+//! Implement reflection traits for the pointers [`Box`] and [`Arc`].
+//!
+//! - [`TypePath`] -> [`DynamicTypePath`]
+//! - [`Typed`] -> [`DynamicTyped`]
+//! - [`Reflect`]
+//! - [`GetTypeTraits`]
+//! - [`FromReflect`]
+//!
+//! They are transparent: the kind, [`ReflectRef`] and [`ReflectMut`] are those of the pointee,
+//! comparison, hashing and debugging are forwarded to it.
+//! Mutable access to an [`Arc`] is copy-on-write, see [`Arc::make_mut`].
+//! Cloning an [`Arc`] shares the allocation.
+//!
+//! [`TypeTraitDeref`] is registered, so they are serialized and deserialized as the pointee.
+//!
+//! `Rc<T>` is not supported, `Reflect` requires `Send + Sync`.
+//!
+//! [`DynamicTypePath`]: crate::info::DynamicTypePath
+//! [`DynamicTyped`]: crate::info::DynamicTyped
+
+use crate::{
+    FromReflect, Reflect,
+    cell::{GenericTypeInfoCell, GenericTypePathCell},
+    info::{Generics, OpaqueInfo, ReflectKind, TypeInfo, TypeParamInfo, TypePath, Typed},
+    ops::{ApplyError, ReflectCloneError, ReflectMut, ReflectOwned, ReflectRef},
+    registry::{
+        FromType, GetTypeTraits, TypeRegistry, TypeTraitDeref, TypeTraitFromPtr,
+        TypeTraitFromReflect, TypeTraits,
+    },
+};
+use alloc::{boxed::Box, sync::Arc};
+use core::{cmp::Ordering, fmt};
+
+macro_rules! impl_type_path_pointer {
+    ($ty:ident, $module:literal) => {
+        impl<T: TypePath> TypePath for $ty<T> {
+            fn type_path() -> &'static str {
+                static CELL: GenericTypePathCell = GenericTypePathCell::new();
+                CELL.get_or_insert::<Self, _>(|| {
+                    $crate::impls::concat(&[$module, "::", stringify!($ty), "<", T::type_path(), ">"])
+                })
+            }
+
+            fn type_name() -> &'static str {
+                static CELL: GenericTypePathCell = GenericTypePathCell::new();
+                CELL.get_or_insert::<Self, _>(|| {
+                    $crate::impls::concat(&[stringify!($ty), "<", T::type_name(), ">"])
+                })
+            }
+
+            #[inline]
+            fn type_ident() -> &'static str {
+                stringify!($ty)
+            }
+
+            #[inline]
+            fn crate_name() -> Option<&'static str> {
+                Some("alloc")
+            }
+
+            #[inline]
+            fn module_path() -> Option<&'static str> {
+                Some($module)
+            }
+        }
+    };
+}
+
+impl_type_path_pointer!(Box, "alloc::boxed");
+impl_type_path_pointer!(Arc, "alloc::sync");
+
+/// The pointee of `value` if it is a `P`, or `value` itself.
+#[inline]
+fn pointee_or_self<P: Reflect + core::ops::Deref<Target: Reflect>>(value: &dyn Reflect) -> &dyn Reflect {
+    match value.downcast_ref::<P>() {
+        Some(pointer) => (**pointer).as_reflect(),
+        None => value,
+    }
+}
+
+macro_rules! impl_reflect_pointer {
+    ($ty:ident, $($bound:tt)*) => {
+        impl<T: Reflect + Typed $($bound)*> Typed for $ty<T> {
+            fn type_info() -> &'static TypeInfo {
+                static CELL: GenericTypeInfoCell = GenericTypeInfoCell::new();
+                CELL.get_or_insert::<Self, _>(|| {
+                    let generics = Generics::new().with(TypeParamInfo::new::<T>("T"));
+                    TypeInfo::Opaque(OpaqueInfo::new::<Self>().with_generics(generics))
+                })
+            }
+        }
+
+        impl<T: FromReflect + Typed + GetTypeTraits $($bound)*> GetTypeTraits for $ty<T> {
+            fn get_type_traits() -> TypeTraits {
+                let mut type_traits = TypeTraits::of::<Self>();
+                type_traits.insert::<TypeTraitFromPtr>(FromType::<Self>::from_type());
+                type_traits.insert::<TypeTraitFromReflect>(FromType::<Self>::from_type());
+                type_traits.insert::<TypeTraitDeref>(FromType::<Self>::from_type());
+                type_traits
+            }
+
+            fn register_dependencies(registry: &mut TypeRegistry) {
+                registry.register::<T>();
+            }
+        }
+
+        impl<T: FromReflect + Typed $($bound)*> FromReflect for $ty<T> {
+            fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+                T::from_reflect(pointee_or_self::<Self>(reflect)).map($ty::new)
+            }
+        }
+    };
+}
+
+impl_reflect_pointer!(Box,);
+impl_reflect_pointer!(Arc, + Clone);
+
+/// Forward the read-only methods of [`Reflect`] to the pointee.
+macro_rules! impl_reflect_pointer_ref_fn {
+    () => {
+        #[inline]
+        fn as_reflect(&self) -> &dyn Reflect {
+            self
+        }
+
+        #[inline]
+        fn as_reflect_mut(&mut self) -> &mut dyn Reflect {
+            self
+        }
+
+        #[inline]
+        fn into_reflect(self: Box<Self>) -> Box<dyn Reflect> {
+            self
+        }
+
+        #[inline]
+        fn represented_type_info(&self) -> Option<&'static TypeInfo> {
+            (**self).represented_type_info()
+        }
+
+        #[inline]
+        fn reflect_kind(&self) -> ReflectKind {
+            (**self).reflect_kind()
+        }
+
+        #[inline]
+        fn reflect_ref(&self) -> ReflectRef<'_> {
+            (**self).reflect_ref()
+        }
+
+        #[inline]
+        fn reflect_partial_eq(&self, other: &dyn Reflect) -> Option<bool> {
+            (**self).reflect_partial_eq(pointee_or_self::<Self>(other))
+        }
+
+        #[inline]
+        fn reflect_partial_cmp(&self, other: &dyn Reflect) -> Option<Ordering> {
+            (**self).reflect_partial_cmp(pointee_or_self::<Self>(other))
+        }
+
+        #[inline]
+        fn reflect_hash(&self) -> Option<u64> {
+            (**self).reflect_hash()
+        }
+
+        #[inline]
+        fn reflect_debug(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            (**self).reflect_debug(f)
+        }
+    };
+}
+
+impl<T: Reflect + Typed> Reflect for Box<T> {
+    impl_reflect_pointer_ref_fn!();
+
+    fn set(&mut self, value: Box<dyn Reflect>) -> Result<(), Box<dyn Reflect>> {
+        match value.take::<Self>() {
+            Ok(value) => {
+                *self = value;
+                Ok(())
+            }
+            Err(value) => (**self).set(value),
+        }
+    }
+
+    #[inline]
+    fn reflect_mut(&mut self) -> ReflectMut<'_> {
+        (**self).reflect_mut()
+    }
+
+    #[inline]
+    fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+        (*self).reflect_owned()
+    }
+
+    #[inline]
+    fn try_apply(&mut self, value: &dyn Reflect) -> Result<(), ApplyError> {
+        (**self).try_apply(pointee_or_self::<Self>(value))
+    }
+
+    fn reflect_clone(&self) -> Result<Box<dyn Reflect>, ReflectCloneError> {
+        let value = (**self)
+            .reflect_clone()?
+            .take::<T>()
+            .expect("`Reflect::reflect_clone` should return the same type");
+        Ok(Box::new(Box::new(value)))
+    }
+}
+
+impl<T: Reflect + Typed + Clone> Reflect for Arc<T> {
+    impl_reflect_pointer_ref_fn!();
+
+    fn set(&mut self, value: Box<dyn Reflect>) -> Result<(), Box<dyn Reflect>> {
+        match value.take::<Self>() {
+            Ok(value) => {
+                *self = value;
+                Ok(())
+            }
+            Err(value) => Arc::make_mut(self).set(value),
+        }
+    }
+
+    /// Clones the pointee first if the allocation is shared.
+    #[inline]
+    fn reflect_mut(&mut self) -> ReflectMut<'_> {
+        Arc::make_mut(self).reflect_mut()
+    }
+
+    /// Clones the pointee first if the allocation is shared.
+    #[inline]
+    fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+        Box::new(Arc::unwrap_or_clone(*self)).reflect_owned()
+    }
+
+    /// Shares the allocation of `value` if it is an `Arc<T>`,
+    /// otherwise applies `value` to the pointee, cloning it first if the allocation is shared.
+    fn try_apply(&mut self, value: &dyn Reflect) -> Result<(), ApplyError> {
+        if let Some(value) = value.downcast_ref::<Self>() {
+            *self = Arc::clone(value);
+            return Ok(());
+        }
+        Arc::make_mut(self).try_apply(value)
+    }
+
+    /// Returns a pointer sharing the same allocation.
+    #[inline]
+    fn reflect_clone(&self) -> Result<Box<dyn Reflect>, ReflectCloneError> {
+        Ok(Box::new(Arc::clone(self)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{boxed::Box, string::String, sync::Arc};
+    use serde::de::DeserializeSeed;
+    use vct_reflect_derive::Reflect;
+
+    use crate::{
+        registry::TypeRegistry,
+        serde::{ReflectDeserializer, ReflectSerializer},
+    };
+
+    #[derive(Reflect, Clone, PartialEq, Debug)]
+    struct Inner {
+        name: String,
+    }
+
+    #[derive(Reflect, PartialEq, Debug)]
+    struct Outer {
+        shared: Arc<Inner>,
+        boxed: Box<u32>,
+    }
+
+    #[test]
+    fn pointer_field_round_trip() {
+        let mut registry = TypeRegistry::new();
+        registry.register::<Outer>();
+
+        let value = Outer {
+            shared: Arc::new(Inner {
+                name: String::from("a"),
+            }),
+            boxed: Box::new(3),
+        };
+        let json = serde_json::to_string(&ReflectSerializer::new(&value, &registry)).unwrap();
+        assert_eq!(
+            json,
+            r#"{"vct_reflect::impls::native::native_pointer::tests::Outer":{"shared":{"name":"a"},"boxed":3}}"#
+        );
+
+        let output = ReflectDeserializer::new(&registry)
+            .deserialize(&mut serde_json::Deserializer::from_str(&json))
+            .unwrap();
+        assert_eq!(output.take::<Outer>().unwrap(), value);
+    }
+}
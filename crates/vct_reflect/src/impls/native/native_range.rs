@@ -0,0 +1,10 @@
+use crate::info::TypePath;
+use core::{
+    fmt::Debug,
+    ops::{Bound, Range, RangeInclusive},
+};
+use vct_reflect_derive::impl_reflect_opaque;
+
+impl_reflect_opaque!((in core::ops) Range<T: Clone + Debug + PartialEq + TypePath + Send + Sync> (clone, debug, partial_eq));
+impl_reflect_opaque!((in core::ops) RangeInclusive<T: Clone + Debug + PartialEq + TypePath + Send + Sync> (clone, debug, partial_eq));
+impl_reflect_opaque!((in core::ops) Bound<T: Clone + Debug + PartialEq + TypePath + Send + Sync> (clone, debug, partial_eq));
@@ -0,0 +1,16 @@
+//! Implement reflection traits for [`Result`] as an `Enum`.
+//!
+//! Serialization goes through the reflected variants,
+//! so `T` and `E` only need to be registered, see [`GetTypeTraits::register_dependencies`].
+//!
+//! [`GetTypeTraits::register_dependencies`]: crate::registry::GetTypeTraits::register_dependencies
+
+use vct_reflect_derive::impl_reflect;
+
+impl_reflect! {
+    #[reflect(type_path = "core::result::Result")]
+    enum Result<T, E> {
+        Ok(T),
+        Err(E),
+    }
+}
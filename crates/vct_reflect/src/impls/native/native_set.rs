@@ -0,0 +1,475 @@
+//! Implement reflection traits for [`BTreeSet`] and [`HashSet`] as a [`Set`].
+//!
+//! - [`TypePath`] -> [`DynamicTypePath`]
+//! - [`Typed`] -> [`DynamicTyped`]
+//! - [`Set`]
+//! - [`Reflect`]
+//! - [`GetTypeTraits`]
+//! - [`FromReflect`]
+//!
+//! Values that are not of the element type (e.g. dynamic values) are converted with [`FromReflect`]
+//! before lookup.
+//!
+//! [`HashSet`] is reflected for any hasher `S: BuildHasher + Default + TypePath`,
+//! see [`impl_reflect_hash_set`] for other hash set types.
+//!
+//! [`DynamicTypePath`]: crate::info::DynamicTypePath
+//! [`DynamicTyped`]: crate::info::DynamicTyped
+
+use crate::{
+    FromReflect, Reflect,
+    cell::{GenericTypeInfoCell, GenericTypePathCell},
+    impls::lookup_key,
+    info::{Generics, ReflectKind, SetInfo, TypeInfo, TypeParamInfo, TypePath, Typed},
+    ops::{
+        ApplyError, ReflectCloneError, ReflectMut, ReflectOwned, ReflectRef, Set, set_debug,
+        set_partial_eq, set_try_apply, set_try_apply_owned,
+    },
+    registry::{
+        FromType, GetTypeTraits, TypeRegistry, TypeTraitDefault, TypeTraitFromPtr,
+        TypeTraitFromReflect, TypeTraits,
+    },
+};
+use alloc::{boxed::Box, collections::BTreeSet, vec::Vec};
+use core::fmt;
+use vct_utils::collections::HashSet;
+
+impl<T: TypePath> TypePath for BTreeSet<T> {
+    fn type_path() -> &'static str {
+        static CELL: GenericTypePathCell = GenericTypePathCell::new();
+        CELL.get_or_insert::<Self, _>(|| {
+            crate::impls::concat(&[
+                "alloc::collections::btree_set::BTreeSet<",
+                T::type_path(),
+                ">",
+            ])
+        })
+    }
+
+    fn type_name() -> &'static str {
+        static CELL: GenericTypePathCell = GenericTypePathCell::new();
+        CELL.get_or_insert::<Self, _>(|| crate::impls::concat(&["BTreeSet<", T::type_name(), ">"]))
+    }
+
+    #[inline]
+    fn type_ident() -> &'static str {
+        "BTreeSet"
+    }
+
+    #[inline]
+    fn crate_name() -> Option<&'static str> {
+        Some("alloc")
+    }
+
+    #[inline]
+    fn module_path() -> Option<&'static str> {
+        Some("alloc::collections::btree_set")
+    }
+}
+
+impl<T: FromReflect + Typed + Ord> Typed for BTreeSet<T> {
+    fn type_info() -> &'static TypeInfo {
+        static CELL: GenericTypeInfoCell = GenericTypeInfoCell::new();
+        CELL.get_or_insert::<Self, _>(|| {
+            let generics = Generics::new().with(TypeParamInfo::new::<T>("T"));
+            TypeInfo::Set(SetInfo::new::<Self, T>().with_generics(generics))
+        })
+    }
+}
+
+impl<T: FromReflect + Typed + Ord> Set for BTreeSet<T> {
+    #[inline]
+    fn get(&self, value: &dyn Reflect) -> Option<&dyn Reflect> {
+        lookup_key(value, |value: &T| BTreeSet::get(self, value)).map(|value| value as &dyn Reflect)
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        BTreeSet::len(self)
+    }
+
+    fn iter(&self) -> Box<dyn Iterator<Item = &dyn Reflect> + '_> {
+        Box::new(BTreeSet::iter(self).map(|value| value as &dyn Reflect))
+    }
+
+    fn drain(&mut self) -> Vec<Box<dyn Reflect>> {
+        core::mem::take(self)
+            .into_iter()
+            .map(|value| Box::new(value) as Box<dyn Reflect>)
+            .collect()
+    }
+
+    #[inline]
+    fn retain(&mut self, f: &mut dyn FnMut(&dyn Reflect) -> bool) {
+        BTreeSet::retain(self, |value| f(value));
+    }
+
+    /// # Panic
+    /// - the value cannot be converted by [`FromReflect`].
+    #[inline]
+    fn insert_boxed(&mut self, value: Box<dyn Reflect>) -> bool {
+        BTreeSet::insert(self, crate::impls::take_element(value))
+    }
+
+    #[inline]
+    fn remove(&mut self, value: &dyn Reflect) -> bool {
+        lookup_key(value, |value: &T| Some(BTreeSet::remove(self, value))).unwrap_or(false)
+    }
+
+    #[inline]
+    fn contains(&self, value: &dyn Reflect) -> bool {
+        lookup_key(value, |value: &T| Some(BTreeSet::contains(self, value))).unwrap_or(false)
+    }
+}
+
+impl<T: FromReflect + Typed + Ord> Reflect for BTreeSet<T> {
+    #[inline]
+    fn as_reflect(&self) -> &dyn Reflect {
+        self
+    }
+
+    #[inline]
+    fn as_reflect_mut(&mut self) -> &mut dyn Reflect {
+        self
+    }
+
+    #[inline]
+    fn into_reflect(self: Box<Self>) -> Box<dyn Reflect> {
+        self
+    }
+
+    #[inline]
+    fn set(&mut self, value: Box<dyn Reflect>) -> Result<(), Box<dyn Reflect>> {
+        *self = value.take()?;
+        Ok(())
+    }
+
+    #[inline]
+    fn represented_type_info(&self) -> Option<&'static TypeInfo> {
+        Some(<Self as Typed>::type_info())
+    }
+
+    #[inline]
+    fn reflect_kind(&self) -> ReflectKind {
+        ReflectKind::Set
+    }
+
+    #[inline]
+    fn reflect_ref(&self) -> ReflectRef<'_> {
+        ReflectRef::Set(self)
+    }
+
+    #[inline]
+    fn reflect_mut(&mut self) -> ReflectMut<'_> {
+        ReflectMut::Set(self)
+    }
+
+    #[inline]
+    fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+        ReflectOwned::Set(self)
+    }
+
+    #[inline]
+    fn try_apply(&mut self, value: &dyn Reflect) -> Result<(), ApplyError> {
+        set_try_apply(self, value)
+    }
+
+    #[inline]
+    fn try_apply_owned(&mut self, value: Box<dyn Reflect>) -> Result<(), ApplyError> {
+        set_try_apply_owned(self, value)
+    }
+
+    #[inline]
+    fn reflect_partial_eq(&self, other: &dyn Reflect) -> Option<bool> {
+        set_partial_eq(self, other)
+    }
+
+    fn reflect_clone(&self) -> Result<Box<dyn Reflect>, ReflectCloneError> {
+        let set = BTreeSet::iter(self)
+            .map(|value| {
+                Ok(value
+                    .reflect_clone()?
+                    .take::<T>()
+                    .expect("`Reflect::reflect_clone` should return the same type"))
+            })
+            .collect::<Result<Self, ReflectCloneError>>()?;
+        Ok(Box::new(set))
+    }
+
+    #[inline]
+    fn reflect_debug(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        set_debug(self, f)
+    }
+}
+
+impl<T: FromReflect + Typed + GetTypeTraits + Ord> GetTypeTraits for BTreeSet<T> {
+    fn get_type_traits() -> TypeTraits {
+        let mut type_traits = TypeTraits::of::<Self>();
+        type_traits.insert::<TypeTraitDefault>(FromType::<Self>::from_type());
+        type_traits.insert::<TypeTraitFromPtr>(FromType::<Self>::from_type());
+        type_traits.insert::<TypeTraitFromReflect>(FromType::<Self>::from_type());
+        type_traits
+    }
+
+    fn register_dependencies(registry: &mut TypeRegistry) {
+        registry.register::<T>();
+    }
+}
+
+impl<T: FromReflect + Typed + Ord> FromReflect for BTreeSet<T> {
+    fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+        let ref_set = reflect.reflect_ref().as_set().ok()?;
+        ref_set.iter().map(T::from_reflect).collect()
+    }
+}
+
+/// Implements the reflection traits for a hash set type `$ty<T, S>`
+/// with the API of [`hashbrown::HashSet`], generic over the hasher `S`.
+///
+/// `$module` is the module path used by [`TypePath`], `$crate_name` its crate name.
+///
+/// [`hashbrown::HashSet`]: https://docs.rs/hashbrown/latest/hashbrown/struct.HashSet.html
+macro_rules! impl_reflect_hash_set {
+    ($ty:ident, $module:literal, $crate_name:literal) => {
+        const _: () = {
+            use $crate::{
+                FromReflect, Reflect,
+                cell::{GenericTypeInfoCell, GenericTypePathCell},
+                impls::lookup_key,
+                info::{Generics, ReflectKind, SetInfo, TypeInfo, TypeParamInfo, TypePath, Typed},
+                ops::{
+                    ApplyError, ReflectCloneError, ReflectMut, ReflectOwned, ReflectRef, Set,
+                    set_debug, set_partial_eq, set_try_apply, set_try_apply_owned,
+                },
+                registry::{
+                    FromType, GetTypeTraits, TypeRegistry, TypeTraitDefault, TypeTraitFromPtr,
+                    TypeTraitFromReflect, TypeTraits,
+                },
+            };
+            use ::alloc::{boxed::Box, vec::Vec};
+            use ::core::{
+                fmt,
+                hash::{BuildHasher, Hash},
+            };
+
+            impl<T: TypePath, S: TypePath> TypePath for $ty<T, S> {
+                fn type_path() -> &'static str {
+                    static CELL: GenericTypePathCell = GenericTypePathCell::new();
+                    CELL.get_or_insert::<Self, _>(|| {
+                        $crate::impls::concat(&[
+                            $module, "::", stringify!($ty), "<",
+                            T::type_path(), ", ", S::type_path(), ">",
+                        ])
+                    })
+                }
+
+                fn type_name() -> &'static str {
+                    static CELL: GenericTypePathCell = GenericTypePathCell::new();
+                    CELL.get_or_insert::<Self, _>(|| {
+                        $crate::impls::concat(&[
+                            stringify!($ty), "<", T::type_name(), ", ", S::type_name(), ">",
+                        ])
+                    })
+                }
+
+                #[inline]
+                fn type_ident() -> &'static str {
+                    stringify!($ty)
+                }
+
+                #[inline]
+                fn crate_name() -> Option<&'static str> {
+                    Some($crate_name)
+                }
+
+                #[inline]
+                fn module_path() -> Option<&'static str> {
+                    Some($module)
+                }
+            }
+
+            impl<T, S> Typed for $ty<T, S>
+            where
+                T: FromReflect + Typed + Eq + Hash,
+                S: TypePath + BuildHasher + Default + Send + Sync,
+            {
+                fn type_info() -> &'static TypeInfo {
+                    static CELL: GenericTypeInfoCell = GenericTypeInfoCell::new();
+                    CELL.get_or_insert::<Self, _>(|| {
+                        let generics = Generics::new()
+                            .with(TypeParamInfo::new::<T>("T"))
+                            .with(TypeParamInfo::new::<S>("S"));
+                        TypeInfo::Set(SetInfo::new::<Self, T>().with_generics(generics))
+                    })
+                }
+            }
+
+            impl<T, S> Set for $ty<T, S>
+            where
+                T: FromReflect + Typed + Eq + Hash,
+                S: TypePath + BuildHasher + Default + Send + Sync,
+            {
+                #[inline]
+                fn get(&self, value: &dyn Reflect) -> Option<&dyn Reflect> {
+                    lookup_key(value, |value: &T| $ty::get(self, value))
+                        .map(|value| value as &dyn Reflect)
+                }
+
+                #[inline]
+                fn len(&self) -> usize {
+                    $ty::len(self)
+                }
+
+                fn iter(&self) -> Box<dyn Iterator<Item = &dyn Reflect> + '_> {
+                    Box::new($ty::iter(self).map(|value| value as &dyn Reflect))
+                }
+
+                fn drain(&mut self) -> Vec<Box<dyn Reflect>> {
+                    $ty::drain(self)
+                        .map(|value| Box::new(value) as Box<dyn Reflect>)
+                        .collect()
+                }
+
+                #[inline]
+                fn retain(&mut self, f: &mut dyn FnMut(&dyn Reflect) -> bool) {
+                    $ty::retain(self, |value| f(value));
+                }
+
+                /// # Panic
+                /// - the value cannot be converted by [`FromReflect`].
+                #[inline]
+                fn insert_boxed(&mut self, value: Box<dyn Reflect>) -> bool {
+                    $ty::insert(self, $crate::impls::take_element(value))
+                }
+
+                #[inline]
+                fn remove(&mut self, value: &dyn Reflect) -> bool {
+                    lookup_key(value, |value: &T| Some($ty::remove(self, value))).unwrap_or(false)
+                }
+
+                #[inline]
+                fn contains(&self, value: &dyn Reflect) -> bool {
+                    lookup_key(value, |value: &T| Some($ty::contains(self, value))).unwrap_or(false)
+                }
+            }
+
+            impl<T, S> Reflect for $ty<T, S>
+            where
+                T: FromReflect + Typed + Eq + Hash,
+                S: TypePath + BuildHasher + Default + Send + Sync,
+            {
+                #[inline]
+                fn as_reflect(&self) -> &dyn Reflect {
+                    self
+                }
+
+                #[inline]
+                fn as_reflect_mut(&mut self) -> &mut dyn Reflect {
+                    self
+                }
+
+                #[inline]
+                fn into_reflect(self: Box<Self>) -> Box<dyn Reflect> {
+                    self
+                }
+
+                #[inline]
+                fn set(&mut self, value: Box<dyn Reflect>) -> Result<(), Box<dyn Reflect>> {
+                    *self = value.take()?;
+                    Ok(())
+                }
+
+                #[inline]
+                fn represented_type_info(&self) -> Option<&'static TypeInfo> {
+                    Some(<Self as Typed>::type_info())
+                }
+
+                #[inline]
+                fn reflect_kind(&self) -> ReflectKind {
+                    ReflectKind::Set
+                }
+
+                #[inline]
+                fn reflect_ref(&self) -> ReflectRef<'_> {
+                    ReflectRef::Set(self)
+                }
+
+                #[inline]
+                fn reflect_mut(&mut self) -> ReflectMut<'_> {
+                    ReflectMut::Set(self)
+                }
+
+                #[inline]
+                fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+                    ReflectOwned::Set(self)
+                }
+
+                #[inline]
+                fn try_apply(&mut self, value: &dyn Reflect) -> Result<(), ApplyError> {
+                    set_try_apply(self, value)
+                }
+
+                #[inline]
+                fn try_apply_owned(&mut self, value: Box<dyn Reflect>) -> Result<(), ApplyError> {
+                    set_try_apply_owned(self, value)
+                }
+
+                #[inline]
+                fn reflect_partial_eq(&self, other: &dyn Reflect) -> Option<bool> {
+                    set_partial_eq(self, other)
+                }
+
+                fn reflect_clone(&self) -> Result<Box<dyn Reflect>, ReflectCloneError> {
+                    let set = $ty::iter(self)
+                        .map(|value| {
+                            Ok(value
+                                .reflect_clone()?
+                                .take::<T>()
+                                .expect("`Reflect::reflect_clone` should return the same type"))
+                        })
+                        .collect::<Result<Self, ReflectCloneError>>()?;
+                    Ok(Box::new(set))
+                }
+
+                #[inline]
+                fn reflect_debug(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    set_debug(self, f)
+                }
+            }
+
+            impl<T, S> GetTypeTraits for $ty<T, S>
+            where
+                T: FromReflect + Typed + GetTypeTraits + Eq + Hash,
+                S: TypePath + BuildHasher + Default + Send + Sync,
+            {
+                fn get_type_traits() -> TypeTraits {
+                    let mut type_traits = TypeTraits::of::<Self>();
+                    type_traits.insert::<TypeTraitDefault>(FromType::<Self>::from_type());
+                    type_traits.insert::<TypeTraitFromPtr>(FromType::<Self>::from_type());
+                    type_traits.insert::<TypeTraitFromReflect>(FromType::<Self>::from_type());
+                    type_traits
+                }
+
+                fn register_dependencies(registry: &mut TypeRegistry) {
+                    registry.register::<T>();
+                }
+            }
+
+            impl<T, S> FromReflect for $ty<T, S>
+            where
+                T: FromReflect + Typed + Eq + Hash,
+                S: TypePath + BuildHasher + Default + Send + Sync,
+            {
+                fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+                    let ref_set = reflect.reflect_ref().as_set().ok()?;
+                    ref_set.iter().map(T::from_reflect).collect()
+                }
+            }
+        };
+    };
+}
+
+#[cfg(feature = "hashbrown")]
+pub(crate) use impl_reflect_hash_set;
+
+impl_reflect_hash_set!(HashSet, "vct_utils::collections::hash_set", "vct_utils");
@@ -1,9 +1,145 @@
-use alloc::string::String;
+//! Implement reflection traits for [`String`] and `&'static str` (both as `Opaque`).
+//!
+//! `&'static str` cannot be deserialized, so its [`GetTypeTraits`] does not
+//! contain [`TypeTraitDeserialize`](crate::registry::TypeTraitDeserialize).
+
+use crate::{
+    FromReflect, Reflect,
+    cell::NonGenericTypeInfoCell,
+    info::{OpaqueInfo, ReflectKind, TypeInfo, TypePath, Typed},
+    ops::{ApplyError, ReflectCloneError, ReflectMut, ReflectOwned, ReflectRef},
+    reflect::impl_cast_reflect_fn,
+    reflect_hasher,
+    registry::{
+        FromType, GetTypeTraits, TypeTraitDefault, TypeTraitFromPtr, TypeTraitFromReflect,
+        TypeTraitSerialize, TypeTraits,
+    },
+};
+use alloc::{borrow::Cow, boxed::Box, string::String};
+use core::{
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+};
 use vct_reflect_derive::impl_reflect;
 
 impl_reflect!{
-    #[reflect(Opaque, full)]
+    #[reflect(Opaque, full, partial_ord)]
     #[reflect(type_path = "alloc::string::String")]
     struct String;
 }
 
+// -----------------------------------------------------------------------------
+// &'static str
+
+impl TypePath for &'static str {
+    #[inline]
+    fn type_path() -> &'static str {
+        "&str"
+    }
+
+    #[inline]
+    fn type_name() -> &'static str {
+        "&str"
+    }
+
+    #[inline]
+    fn type_ident() -> &'static str {
+        "&str"
+    }
+}
+
+impl Typed for &'static str {
+    fn type_info() -> &'static TypeInfo {
+        static CELL: NonGenericTypeInfoCell = NonGenericTypeInfoCell::new();
+        CELL.get_or_init(|| TypeInfo::Opaque(OpaqueInfo::new::<Self>()))
+    }
+}
+
+impl Reflect for &'static str {
+    impl_cast_reflect_fn!();
+
+    #[inline]
+    fn reflect_kind(&self) -> ReflectKind {
+        ReflectKind::Opaque
+    }
+
+    #[inline]
+    fn reflect_ref(&self) -> ReflectRef<'_> {
+        ReflectRef::Opaque(self)
+    }
+
+    #[inline]
+    fn reflect_mut(&mut self) -> ReflectMut<'_> {
+        ReflectMut::Opaque(self)
+    }
+
+    #[inline]
+    fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+        ReflectOwned::Opaque(self)
+    }
+
+    #[inline]
+    fn to_dynamic(&self) -> Box<dyn Reflect> {
+        Box::new(*self)
+    }
+
+    fn try_apply(&mut self, value: &dyn Reflect) -> Result<(), ApplyError> {
+        if let Some(value) = value.downcast_ref::<Self>() {
+            *self = value;
+            return Ok(());
+        }
+
+        Err(ApplyError::MismatchedTypes {
+            from_type: Cow::Owned(value.reflect_type_path().into()),
+            to_type: Cow::Borrowed(Self::type_path()),
+        })
+    }
+
+    #[inline]
+    fn reflect_clone(&self) -> Result<Box<dyn Reflect>, ReflectCloneError> {
+        Ok(Box::new(*self))
+    }
+
+    #[inline]
+    fn reflect_partial_eq(&self, other: &dyn Reflect) -> Option<bool> {
+        other.downcast_ref::<Self>().map(|value| self == value)
+    }
+
+    #[inline]
+    fn reflect_partial_cmp(&self, other: &dyn Reflect) -> Option<Ordering> {
+        other
+            .downcast_ref::<Self>()
+            .and_then(|value| self.partial_cmp(value))
+    }
+
+    #[inline]
+    fn reflect_hash(&self) -> Option<u64> {
+        let mut hasher = reflect_hasher();
+        Hash::hash(*self, &mut hasher);
+        Some(hasher.finish())
+    }
+
+    #[inline]
+    fn reflect_debug(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(*self, f)
+    }
+}
+
+impl GetTypeTraits for &'static str {
+    fn get_type_traits() -> TypeTraits {
+        let mut type_traits = TypeTraits::of::<Self>();
+        type_traits.insert::<TypeTraitDefault>(FromType::<Self>::from_type());
+        type_traits.insert::<TypeTraitFromPtr>(FromType::<Self>::from_type());
+        type_traits.insert::<TypeTraitFromReflect>(FromType::<Self>::from_type());
+        type_traits.insert::<TypeTraitSerialize>(FromType::<Self>::from_type());
+        type_traits
+    }
+}
+
+impl FromReflect for &'static str {
+    #[inline]
+    fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+        reflect.downcast_ref::<Self>().copied()
+    }
+}
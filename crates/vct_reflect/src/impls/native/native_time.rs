@@ -0,0 +1,6 @@
+use core::time::Duration;
+use vct_os::time::Instant;
+use vct_reflect_derive::impl_reflect_opaque;
+
+impl_reflect_opaque!((in core::time) Duration (full, partial_ord));
+impl_reflect_opaque!((in vct_os::time) Instant (clone, debug, hash, partial_eq, partial_ord));
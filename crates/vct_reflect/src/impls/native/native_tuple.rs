@@ -16,12 +16,12 @@ use crate::{
     info::{ReflectKind, TupleInfo, TypeInfo, TypePath, Typed, UnnamedField},
     ops::{
         ApplyError, ReflectCloneError, ReflectMut, ReflectOwned, ReflectRef, Tuple, TupleFieldIter,
-        tuple_debug, tuple_partial_eq, tuple_try_apply, tuple_hash,
+        tuple_debug, tuple_partial_cmp, tuple_partial_eq, tuple_try_apply, tuple_try_apply_owned, tuple_hash,
     },
     registry::{GetTypeTraits, TypeRegistry, TypeTraits, FromType, TypeTraitDefault, TypeTraitDeserialize, TypeTraitFromPtr, TypeTraitFromReflect, TypeTraitSerialize},
 };
 use alloc::{boxed::Box, vec, vec::Vec};
-use core::fmt;
+use core::{cmp::Ordering, fmt};
 use vct_utils::range_invoke;
 
 macro_rules! impl_type_path_tuple {
@@ -187,6 +187,11 @@ macro_rules! impl_reflect_tuple {
                 tuple_try_apply(self, value)
             }
 
+            #[inline]
+            fn try_apply_owned(&mut self, value: Box<dyn Reflect>) -> Result<(), ApplyError> {
+                tuple_try_apply_owned(self, value)
+            }
+
             #[inline]
             fn reflect_partial_eq(&self, other: &dyn Reflect) -> Option<bool> {
                 if other.is::<Self>() {
@@ -196,6 +201,15 @@ macro_rules! impl_reflect_tuple {
                 }
             }
 
+            #[inline]
+            fn reflect_partial_cmp(&self, other: &dyn Reflect) -> Option<Ordering> {
+                if other.is::<Self>() {
+                    Some(Ordering::Equal)
+                } else {
+                    None
+                }
+            }
+
             fn reflect_clone(&self) -> Result<Box<dyn Reflect>, ReflectCloneError> {
                 Ok(Box::new(()))
             }
@@ -335,11 +349,21 @@ macro_rules! impl_reflect_tuple {
                 tuple_try_apply(self, value)
             }
 
+            #[inline]
+            fn try_apply_owned(&mut self, value: Box<dyn Reflect>) -> Result<(), ApplyError> {
+                tuple_try_apply_owned(self, value)
+            }
+
             #[inline]
             fn reflect_partial_eq(&self, other: &dyn Reflect) -> Option<bool> {
                 tuple_partial_eq(self, other)
             }
 
+            #[inline]
+            fn reflect_partial_cmp(&self, other: &dyn Reflect) -> Option<Ordering> {
+                tuple_partial_cmp(self, other)
+            }
+
             fn reflect_clone(&self) -> Result<Box<dyn Reflect>, ReflectCloneError> {
                 Ok(Box::new((
                     $(
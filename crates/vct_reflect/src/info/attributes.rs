@@ -2,7 +2,7 @@ use alloc::boxed::Box;
 use core::{any::TypeId, fmt};
 use vct_utils::collections::TypeIdMap;
 
-use crate::Reflect;
+use crate::{Reflect, info::TypeInfo};
 
 /// Container for recording custom attributes.
 /// 
@@ -42,6 +42,17 @@ impl CustomAttributes {
         self.attributes.iter().map(|(key, val)| (key, &**val))
     }
 
+    /// Returns an iterator over the stored attributes and their [`TypeInfo`].
+    ///
+    /// Useful for finding attributes whose exact type is unknown,
+    /// e.g. by matching on [`TypeInfo::type_path`] or the kind of the attribute.
+    #[inline]
+    pub fn iter_typed(&self) -> impl ExactSizeIterator<Item = (&'static TypeInfo, &dyn Reflect)> {
+        self.attributes
+            .values()
+            .map(|val| (val.reflect_type_info(), &**val))
+    }
+
     /// Returns `true` if it contains the given attribute type.
     #[inline]
     pub fn contains<T: Reflect>(&self) -> bool {
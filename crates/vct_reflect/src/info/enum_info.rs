@@ -10,7 +10,8 @@ use crate::{
         generics::impl_generic_fn,
         type_struct::impl_type_fn,
     },
-    ops::Enum,
+    ops::{DynamicEnum, DynamicStruct, DynamicTuple, DynamicVariant, Enum},
+    registry::{TypeRegistry, TypeTraitDefault},
 };
 
 /// Container for storing compile-time enum information.
@@ -106,4 +107,43 @@ impl EnumInfo {
     pub fn variant_len(&self) -> usize {
         self.variants.len()
     }
+
+    /// Builds the variant with the given name, with default field values.
+    ///
+    /// Field values are created by the [`TypeTraitDefault`] registered in `registry`,
+    /// the enum's [`TypeInfo`](crate::info::TypeInfo) is attached if the enum is registered.
+    ///
+    /// Returns `None` if the variant doesn't exist or a field type has no [`TypeTraitDefault`].
+    pub fn construct_variant(&self, name: &str, registry: &TypeRegistry) -> Option<DynamicEnum> {
+        let index = self.index_of(name)?;
+        let variant_info = &self.variants[index];
+
+        let default_of = |type_id| {
+            registry
+                .get_type_trait::<TypeTraitDefault>(type_id)
+                .map(TypeTraitDefault::default)
+        };
+
+        let variant = match variant_info {
+            VariantInfo::Struct(info) => {
+                let mut data = DynamicStruct::with_capacity(info.field_len());
+                for field in info.iter() {
+                    data.insert_boxed(field.name(), default_of(field.type_info().type_id())?);
+                }
+                DynamicVariant::Struct(data)
+            }
+            VariantInfo::Tuple(info) => {
+                let mut data = DynamicTuple::with_capacity(info.field_len());
+                for field in info.iter() {
+                    data.insert_boxed(default_of(field.type_info().type_id())?);
+                }
+                DynamicVariant::Tuple(data)
+            }
+            VariantInfo::Unit(_) => DynamicVariant::Unit,
+        };
+
+        let mut value = DynamicEnum::new_with_index(index, variant_info.name(), variant);
+        value.set_type_info(registry.get_type_info(self.type_id()));
+        Some(value)
+    }
 }
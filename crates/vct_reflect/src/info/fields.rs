@@ -16,6 +16,8 @@ pub struct NamedField {
     name: &'static str,
     // `TypeInfo` is created on the first visit, use function pointers to delay it.
     type_info: fn() -> &'static TypeInfo,
+    // Byte offset in the containing struct, if known.
+    offset: Option<usize>,
     // Use `Option` to reduce unnecessary heap requests (when empty content).
     custom_attributes: Option<Arc<CustomAttributes>>,
     #[cfg(feature = "reflect_docs")]
@@ -35,6 +37,24 @@ impl NamedField {
             name,
             type_info: T::type_info,
             ty: Type::of::<T>(),
+            offset: None,
+            custom_attributes: None,
+            #[cfg(feature = "reflect_docs")]
+            docs: None,
+        }
+    }
+
+    /// Creates a new [`NamedField`] for a type that may be unsized,
+    /// such as the `dyn Reflect` of a `#[reflect(dynamic)]` field.
+    ///
+    /// The layout is unknown, see [`Type::of_unsized`].
+    #[inline]
+    pub fn new_unsized<T: Typed + ?Sized>(name: &'static str) -> Self {
+        Self {
+            name,
+            type_info: T::type_info,
+            ty: Type::of_unsized::<T>(),
+            offset: None,
             custom_attributes: None,
             #[cfg(feature = "reflect_docs")]
             docs: None,
@@ -52,6 +72,21 @@ impl NamedField {
     pub fn type_info(&self) -> &'static TypeInfo {
         (self.type_info)()
     }
+
+    /// Sets the byte offset of the field in the containing struct.
+    #[inline]
+    pub fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Returns the byte offset of the field in the containing struct, if known.
+    ///
+    /// `#[derive(Reflect)]` sets it for struct fields, but not for enum variant fields.
+    #[inline]
+    pub fn offset(&self) -> Option<usize> {
+        self.offset
+    }
 }
 
 /// An unnamed (tuple) field.
@@ -61,6 +96,8 @@ pub struct UnnamedField {
     index: usize,
     // `TypeInfo` is created on the first visit, use function pointers to delay it.
     type_info: fn() -> &'static TypeInfo,
+    // Byte offset in the containing struct, if known.
+    offset: Option<usize>,
     // Use `Option` to reduce unnecessary heap requests (when empty content).
     custom_attributes: Option<Arc<CustomAttributes>>,
     #[cfg(feature = "reflect_docs")]
@@ -80,6 +117,24 @@ impl UnnamedField {
             index,
             type_info: T::type_info,
             ty: Type::of::<T>(),
+            offset: None,
+            custom_attributes: None,
+            #[cfg(feature = "reflect_docs")]
+            docs: None,
+        }
+    }
+
+    /// Creates a new [`UnnamedField`] for a type that may be unsized,
+    /// such as the `dyn Reflect` of a `#[reflect(dynamic)]` field.
+    ///
+    /// The layout is unknown, see [`Type::of_unsized`].
+    #[inline]
+    pub fn new_unsized<T: Typed + ?Sized>(index: usize) -> Self {
+        Self {
+            index,
+            type_info: T::type_info,
+            ty: Type::of_unsized::<T>(),
+            offset: None,
             custom_attributes: None,
             #[cfg(feature = "reflect_docs")]
             docs: None,
@@ -97,6 +152,21 @@ impl UnnamedField {
     pub fn type_info(&self) -> &'static TypeInfo {
         (self.type_info)()
     }
+
+    /// Sets the byte offset of the field in the containing struct.
+    #[inline]
+    pub fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Returns the byte offset of the field in the containing struct, if known.
+    ///
+    /// `#[derive(Reflect)]` sets it for struct fields, but not for enum variant fields.
+    #[inline]
+    pub fn offset(&self) -> Option<usize> {
+        self.offset
+    }
 }
 
 /// A container for representing field identifiers.
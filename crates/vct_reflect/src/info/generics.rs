@@ -1,9 +1,14 @@
 use alloc::{borrow::Cow, boxed::Box};
 use core::ops::Deref;
+use vct_os::sync::Arc;
 
 use crate::{
     Reflect,
-    info::{ConstParamData, Type, TypePath, type_struct::impl_type_fn},
+    info::{
+        ConstParamData, CustomAttributes, Type, TypePath,
+        attributes::{impl_custom_attributes_fn, impl_with_custom_attributes},
+        type_struct::impl_type_fn,
+    },
 };
 
 /// Container for storing generic type parameter information.
@@ -12,18 +17,22 @@ pub struct TypeParamInfo {
     ty: Type,
     name: Cow<'static, str>,
     default: Option<Type>,
+    custom_attributes: Option<Arc<CustomAttributes>>,
 }
 
 impl TypeParamInfo {
     impl_type_fn!(ty);
+    impl_custom_attributes_fn!(custom_attributes);
+    impl_with_custom_attributes!(custom_attributes);
 
     /// Creates a new [`TypeParamInfo`].
     #[inline]
     pub fn new<T: TypePath + ?Sized>(name: impl Into<Cow<'static, str>>) -> Self {
         Self {
-            ty: Type::of::<T>(),
+            ty: Type::of_unsized::<T>(),
             name: name.into(),
             default: None,
+            custom_attributes: None,
         }
     }
 
@@ -42,7 +51,7 @@ impl TypeParamInfo {
     /// Sets the default type.
     #[inline]
     pub fn with_default<T: TypePath + ?Sized>(mut self) -> Self {
-        self.default = Some(Type::of::<T>());
+        self.default = Some(Type::of_unsized::<T>());
         self
     }
 }
@@ -54,10 +63,13 @@ pub struct ConstParamInfo {
     ty: Type,
     name: Cow<'static, str>,
     default: Option<ConstParamData>,
+    custom_attributes: Option<Arc<CustomAttributes>>,
 }
 
 impl ConstParamInfo {
     impl_type_fn!(ty);
+    impl_custom_attributes_fn!(custom_attributes);
+    impl_with_custom_attributes!(custom_attributes);
 
     /// Creates a new [`ConstParamInfo`].
     #[inline]
@@ -66,6 +78,7 @@ impl ConstParamInfo {
             ty: Type::of::<T>(),
             name: name.into(),
             default: None,
+            custom_attributes: None,
         }
     }
 
@@ -122,6 +135,11 @@ impl GenericInfo {
         Self::Const(info) => info.ty(),
     });
 
+    impl_custom_attributes_fn!(self => match self {
+        Self::Type(info) => &info.custom_attributes,
+        Self::Const(info) => &info.custom_attributes,
+    });
+
     /// Returns the parameter name.
     #[inline]
     pub fn name(&self) -> &Cow<'static, str> {
@@ -160,6 +178,31 @@ impl Generics {
         self.0.iter().find(|info| info.name() == name)
     }
 
+    /// Returns the concrete [`Type`] of the type parameter with the given name, if any.
+    ///
+    /// Type infos are created for instantiated types, e.g. for `Vec<u8>`,
+    /// the type of the parameter `T` is `u8`.
+    ///
+    /// Complexity: O(N)
+    pub fn get_type(&self, name: &str) -> Option<&Type> {
+        match self.get(name)? {
+            GenericInfo::Type(info) => Some(info.ty()),
+            GenericInfo::Const(_) => None,
+        }
+    }
+
+    /// Returns an iterator over the type parameters, skipping const parameters.
+    ///
+    /// The type arguments used by reflected fields are registered along with the outer type,
+    /// see [`GetTypeTraits::register_dependencies`](crate::registry::GetTypeTraits::register_dependencies).
+    #[inline]
+    pub fn type_params(&self) -> impl Iterator<Item = &TypeParamInfo> {
+        self.0.iter().filter_map(|info| match info {
+            GenericInfo::Type(info) => Some(info),
+            GenericInfo::Const(_) => None,
+        })
+    }
+
     /// Appends a parameter.
     ///
     /// Complexity: O(N)
@@ -4,7 +4,10 @@ mod docs_macro;
 mod type_path;
 pub use type_path::{DynamicTypePath, TypePath, TypePathTable};
 
-mod type_struct;
+mod type_uuid;
+pub use type_uuid::TypeUuid;
+
+pub(crate) mod type_struct;
 pub use type_struct::Type;
 
 mod attributes;
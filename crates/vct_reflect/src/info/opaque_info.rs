@@ -34,9 +34,22 @@ impl OpaqueInfo {
 
     /// Creates a new [`OpaqueInfo`].
     #[inline]
-    pub fn new<T: Reflect + TypePath + ?Sized>() -> Self {
+    pub fn new<T: Reflect + TypePath>() -> Self {
+        Self::from_type(Type::of::<T>())
+    }
+
+    /// Creates a new [`OpaqueInfo`] for a type that may be unsized, such as `dyn Reflect`.
+    ///
+    /// The layout is unknown, see [`Type::of_unsized`].
+    #[inline]
+    pub fn new_unsized<T: TypePath + ?Sized>() -> Self {
+        Self::from_type(Type::of_unsized::<T>())
+    }
+
+    #[inline]
+    fn from_type(ty: Type) -> Self {
         Self {
-            ty: Type::of::<T>(),
+            ty,
             generics: Generics::new(),
             custom_attributes: None,
             #[cfg(feature = "reflect_docs")]
@@ -5,7 +5,7 @@ use core::{
 
 use crate::info::{
     ArrayInfo, CustomAttributes, EnumInfo, Generics, ListInfo, MapInfo, OpaqueInfo, SetInfo,
-    StructInfo, TupleInfo, TupleStructInfo, Type, TypePathTable,
+    StructInfo, TupleInfo, TupleStructInfo, Type, TypePathTable, TypeUuid,
 };
 
 /// An enum representing the kind of type.
@@ -130,6 +130,24 @@ impl TypeInfo {
         self.ty().path()
     }
 
+    /// Returns the size of the type in bytes, or `None` if it is unknown.
+    #[inline]
+    pub fn size_of(&self) -> Option<usize> {
+        self.ty().size_of()
+    }
+
+    /// Returns the alignment of the type in bytes, or `None` if it is unknown.
+    #[inline]
+    pub fn align_of(&self) -> Option<usize> {
+        self.ty().align_of()
+    }
+
+    /// Returns the stable [`TypeUuid`], if any.
+    #[inline]
+    pub fn type_uuid(&self) -> Option<TypeUuid> {
+        self.ty().uuid()
+    }
+
     /// Returns `true` if the types are the same.
     #[inline]
     pub fn type_is<T: Any>(&self) -> bool {
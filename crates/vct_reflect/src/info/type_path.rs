@@ -1,5 +1,7 @@
 use core::fmt;
 
+use crate::info::TypeUuid;
+
 /// A static accessor to type paths and names.
 pub trait TypePath: 'static {
     /// Returns the fully qualified path of the underlying type.
@@ -33,6 +35,13 @@ pub trait TypePath: 'static {
     fn module_path() -> Option<&'static str> {
         None
     }
+
+    /// Returns the stable [`TypeUuid`] of the type, or [`None`] if it has none.
+    ///
+    /// Set by `#[reflect(uuid = "...")]`.
+    fn type_uuid() -> Option<TypeUuid> {
+        None
+    }
 }
 
 /// Dynamic dispatch for [`TypePath`].
@@ -53,6 +62,9 @@ pub trait DynamicTypePath {
 
     /// See [`TypePath::module_path`].
     fn reflect_module_path(&self) -> Option<&str>;
+
+    /// See [`TypePath::type_uuid`].
+    fn reflect_type_uuid(&self) -> Option<TypeUuid>;
 }
 
 impl<T: TypePath> DynamicTypePath for T {
@@ -80,6 +92,11 @@ impl<T: TypePath> DynamicTypePath for T {
     fn reflect_module_path(&self) -> Option<&str> {
         Self::module_path()
     }
+
+    #[inline]
+    fn reflect_type_uuid(&self) -> Option<TypeUuid> {
+        Self::type_uuid()
+    }
 }
 
 /// Provides dynamic access to all methods on [`TypePath`].
@@ -94,6 +111,7 @@ pub struct TypePathTable {
     type_ident: fn() -> &'static str,
     crate_name: fn() -> Option<&'static str>,
     module_path: fn() -> Option<&'static str>,
+    type_uuid: Option<TypeUuid>,
 }
 
 impl TypePathTable {
@@ -106,6 +124,7 @@ impl TypePathTable {
             type_ident: T::type_ident,
             crate_name: T::crate_name,
             module_path: T::module_path,
+            type_uuid: T::type_uuid(),
         }
     }
 
@@ -138,6 +157,12 @@ impl TypePathTable {
     pub fn module_path(&self) -> Option<&'static str> {
         (self.module_path)()
     }
+
+    /// See [`TypePath::type_uuid`]
+    #[inline(always)]
+    pub fn type_uuid(&self) -> Option<TypeUuid> {
+        self.type_uuid
+    }
 }
 
 impl fmt::Debug for TypePathTable {
@@ -148,6 +173,7 @@ impl fmt::Debug for TypePathTable {
             .field("type_ident", &(self.type_ident)())
             .field("crate_name", &(self.crate_name)())
             .field("module_path", &(self.module_path)())
+            .field("type_uuid", &self.type_uuid)
             .finish()
     }
 }
@@ -1,27 +1,42 @@
 use core::{
+    alloc::Layout,
     any::{Any, TypeId},
     fmt::{Debug, Formatter},
     hash::Hash,
 };
 
-use crate::info::{TypePath, TypePathTable};
+use crate::info::{TypePath, TypePathTable, TypeUuid};
 
 /// The base representation of a Rust type.
 ///
-/// Includes a [`TypeId`] and a [`TypePathTable`].
+/// Includes a [`TypeId`], a [`TypePathTable`] and the [`Layout`] of sized types.
 #[derive(Copy, Clone)]
 pub struct Type {
     type_path_table: TypePathTable,
     type_id: TypeId,
+    layout: Option<Layout>,
 }
 
 impl Type {
     /// Creates a new [`Type`] from a type that implements [`TypePath`].
     #[inline]
-    pub fn of<T: TypePath + ?Sized>() -> Self {
+    pub fn of<T: TypePath>() -> Self {
         Self {
             type_path_table: TypePathTable::of::<T>(),
             type_id: TypeId::of::<T>(),
+            layout: Some(Layout::new::<T>()),
+        }
+    }
+
+    /// Creates a new [`Type`] from a type that may be unsized.
+    ///
+    /// The [`Layout`] is unknown, even if the type is sized.
+    #[inline]
+    pub fn of_unsized<T: TypePath + ?Sized>() -> Self {
+        Self {
+            type_path_table: TypePathTable::of::<T>(),
+            type_id: TypeId::of::<T>(),
+            layout: None,
         }
     }
 
@@ -31,6 +46,24 @@ impl Type {
         self.type_id
     }
 
+    /// Returns the [`Layout`] of the type, or [`None`] if it is unknown.
+    #[inline]
+    pub fn layout(&self) -> Option<Layout> {
+        self.layout
+    }
+
+    /// Returns the size of the type in bytes, or [`None`] if it is unknown.
+    #[inline]
+    pub fn size_of(&self) -> Option<usize> {
+        self.layout.map(|layout| layout.size())
+    }
+
+    /// Returns the alignment of the type in bytes, or [`None`] if it is unknown.
+    #[inline]
+    pub fn align_of(&self) -> Option<usize> {
+        self.layout.map(|layout| layout.align())
+    }
+
     /// See [`TypePath::type_path`].
     #[inline]
     pub fn path(&self) -> &'static str {
@@ -61,6 +94,12 @@ impl Type {
         self.type_path_table.module_path()
     }
 
+    /// See [`TypePath::type_uuid`].
+    #[inline]
+    pub fn uuid(&self) -> Option<TypeUuid> {
+        self.type_path_table.type_uuid()
+    }
+
     /// Returns the [`TypePathTable`].
     #[inline]
     pub fn type_path_table(&self) -> &TypePathTable {
@@ -104,7 +143,7 @@ impl Debug for Type {
     }
 }
 
-/// impl `ty` `type_id` `type_path` `type_path_table` `is`
+/// impl `ty` `type_id` `type_path` `type_path_table` `size_of` `align_of` `type_uuid` `is`
 macro_rules! impl_type_fn {
     ($field:ident) => {
         $crate::info::type_struct::impl_type_fn!(self => &self.$field);
@@ -134,6 +173,24 @@ macro_rules! impl_type_fn {
             &self.ty().type_path_table()
         }
 
+        /// Returns the size of the type in bytes, or `None` if it is unknown.
+        #[inline]
+        pub fn size_of(&self) -> ::core::option::Option<usize> {
+            self.ty().size_of()
+        }
+
+        /// Returns the alignment of the type in bytes, or `None` if it is unknown.
+        #[inline]
+        pub fn align_of(&self) -> ::core::option::Option<usize> {
+            self.ty().align_of()
+        }
+
+        /// Returns the stable [`TypeUuid`](crate::info::TypeUuid), if any.
+        #[inline]
+        pub fn type_uuid(&self) -> ::core::option::Option<$crate::info::TypeUuid> {
+            self.ty().uuid()
+        }
+
         /// Check if the given type matches this one.
         ///
         /// This only compares the [`TypeId`] of the types.
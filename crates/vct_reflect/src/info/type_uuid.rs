@@ -0,0 +1,81 @@
+use core::fmt;
+
+/// A stable identifier of a type, see [`TypePath::type_uuid`].
+///
+/// Unlike the type path, it does not change when the type is moved or renamed,
+/// so it is suitable for long-lived serialized data.
+///
+/// [`TypePath::type_uuid`]: crate::info::TypePath::type_uuid
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TypeUuid(u128);
+
+impl TypeUuid {
+    /// Creates a [`TypeUuid`] from its 128-bit value.
+    #[inline(always)]
+    pub const fn from_u128(value: u128) -> Self {
+        Self(value)
+    }
+
+    /// Returns the 128-bit value.
+    #[inline(always)]
+    pub const fn as_u128(&self) -> u128 {
+        self.0
+    }
+
+    /// Parses a UUID string, with or without hyphens.
+    ///
+    /// `"67e55044-10b1-426f-9247-bb680e5fe0c8"` or `"67e5504410b1426f9247bb680e5fe0c8"`.
+    ///
+    /// Returns `None` if the string is not a valid UUID.
+    pub const fn parse(s: &str) -> Option<Self> {
+        let bytes = s.as_bytes();
+        let hyphenated = match bytes.len() {
+            32 => false,
+            36 => true,
+            _ => return None,
+        };
+
+        let mut value = 0u128;
+        let mut index = 0;
+        while index < bytes.len() {
+            let byte = bytes[index];
+            if hyphenated && matches!(index, 8 | 13 | 18 | 23) {
+                if byte != b'-' {
+                    return None;
+                }
+            } else {
+                let digit = match byte {
+                    b'0'..=b'9' => byte - b'0',
+                    b'a'..=b'f' => byte - b'a' + 10,
+                    b'A'..=b'F' => byte - b'A' + 10,
+                    _ => return None,
+                };
+                value = (value << 4) | digit as u128;
+            }
+            index += 1;
+        }
+        Some(Self(value))
+    }
+}
+
+/// Formats as a lowercase hyphenated UUID, e.g. `67e55044-10b1-426f-9247-bb680e5fe0c8`.
+impl fmt::Display for TypeUuid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let v = self.0;
+        write!(
+            f,
+            "{:08x}-{:04x}-{:04x}-{:04x}-{:012x}",
+            (v >> 96) as u32,
+            (v >> 80) as u16,
+            (v >> 64) as u16,
+            (v >> 48) as u16,
+            v & 0xFFFF_FFFF_FFFF,
+        )
+    }
+}
+
+impl fmt::Debug for TypeUuid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "TypeUuid({self})")
+    }
+}
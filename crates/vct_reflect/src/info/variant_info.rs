@@ -9,6 +9,31 @@ use crate::info::{
     docs_macro::impl_docs_fn,
 };
 
+/// Implements `discriminant` and `with_discriminant` helpers.
+macro_rules! impl_discriminant_fn {
+    () => {
+        /// Returns the discriminant value, if recorded.
+        ///
+        /// Recorded by the proc-macro crate for enums with
+        /// a `#[repr(...)]` attribute or explicit discriminants.
+        #[inline]
+        pub fn discriminant(&self) -> Option<i128> {
+            self.discriminant
+        }
+
+        /// Sets the discriminant value.
+        ///
+        /// Used by the proc-macro crate.
+        #[inline]
+        pub fn with_discriminant(self, discriminant: i128) -> Self {
+            Self {
+                discriminant: Some(discriminant),
+                ..self
+            }
+        }
+    };
+}
+
 /// Describes the form of an enum variant.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum VariantKind {
@@ -65,6 +90,7 @@ pub struct StructVariantInfo {
     fields: Box<[NamedField]>,
     field_names: Box<[&'static str]>,
     field_indices: HashMap<&'static str, usize>,
+    discriminant: Option<i128>,
     // Use `Option` to reduce unnecessary heap requests (when empty content).
     custom_attributes: Option<Arc<CustomAttributes>>,
     #[cfg(feature = "reflect_docs")]
@@ -73,6 +99,7 @@ pub struct StructVariantInfo {
 
 impl StructVariantInfo {
     impl_docs_fn!(docs);
+    impl_discriminant_fn!();
     impl_custom_attributes_fn!(custom_attributes);
     impl_with_custom_attributes!(custom_attributes);
 
@@ -91,6 +118,7 @@ impl StructVariantInfo {
             fields: fields.to_vec().into_boxed_slice(),
             field_names,
             field_indices,
+            discriminant: None,
             custom_attributes: None,
             #[cfg(feature = "reflect_docs")]
             docs: None,
@@ -153,6 +181,7 @@ impl StructVariantInfo {
 pub struct TupleVariantInfo {
     name: &'static str,
     fields: Box<[UnnamedField]>,
+    discriminant: Option<i128>,
     // Use `Option` to reduce unnecessary heap requests (when empty content).
     custom_attributes: Option<Arc<CustomAttributes>>,
     #[cfg(feature = "reflect_docs")]
@@ -161,6 +190,7 @@ pub struct TupleVariantInfo {
 
 impl TupleVariantInfo {
     impl_docs_fn!(docs);
+    impl_discriminant_fn!();
     impl_custom_attributes_fn!(custom_attributes);
     impl_with_custom_attributes!(custom_attributes);
 
@@ -170,6 +200,7 @@ impl TupleVariantInfo {
         Self {
             name,
             fields: fields.to_vec().into_boxed_slice(),
+            discriminant: None,
             custom_attributes: None,
             #[cfg(feature = "reflect_docs")]
             docs: None,
@@ -211,6 +242,7 @@ impl TupleVariantInfo {
 #[derive(Clone, Debug)]
 pub struct UnitVariantInfo {
     name: &'static str,
+    discriminant: Option<i128>,
     // Use `Option` to reduce unnecessary heap requests (when empty content).
     custom_attributes: Option<Arc<CustomAttributes>>,
     #[cfg(feature = "reflect_docs")]
@@ -219,6 +251,7 @@ pub struct UnitVariantInfo {
 
 impl UnitVariantInfo {
     impl_docs_fn!(docs);
+    impl_discriminant_fn!();
     impl_custom_attributes_fn!(custom_attributes);
     impl_with_custom_attributes!(custom_attributes);
 
@@ -227,6 +260,7 @@ impl UnitVariantInfo {
     pub fn new(name: &'static str) -> Self {
         Self {
             name,
+            discriminant: None,
             custom_attributes: None,
             #[cfg(feature = "reflect_docs")]
             docs: None,
@@ -309,6 +343,16 @@ impl VariantInfo {
         }
     }
 
+    /// The discriminant value of the enum variant, if recorded.
+    #[inline]
+    pub fn discriminant(&self) -> Option<i128> {
+        match self {
+            Self::Struct(info) => info.discriminant(),
+            Self::Tuple(info) => info.discriminant(),
+            Self::Unit(info) => info.discriminant(),
+        }
+    }
+
     /// Returns the [kind] of this variant.
     ///
     /// [kind]: VariantKind
@@ -5,15 +5,35 @@ extern crate std;
 
 extern crate alloc;
 
+pub mod cfg {
+    pub use vct_cfg::std;
+
+    vct_cfg::define_alias! {
+        #[cfg(feature = "auto_register")] => auto_register,
+        #[cfg(feature = "auto_register_inventory")] => auto_register_inventory,
+        #[cfg(feature = "reflect_docs")] => reflect_docs,
+    }
+
+    /// Returns the names of the aliases of this crate that are currently active.
+    pub const fn active_aliases() -> &'static [&'static str] {
+        vct_cfg::active_aliases![std, auto_register, auto_register_inventory, reflect_docs]
+    }
+}
+
 pub mod access;
 pub mod cell;
+pub mod func;
 pub mod info;
+pub mod migrate;
 pub mod ops;
 pub mod registry;
 pub mod serde;
+pub mod snapshot;
 
 mod reflect;
-pub use reflect::{FromReflect, Reflect, Reflectable, reflect_hasher};
+pub use reflect::{
+    FromReflect, Reflect, ReflectHasher, Reflectable, reflect_hasher, set_reflect_hasher,
+};
 
 mod impls;
 
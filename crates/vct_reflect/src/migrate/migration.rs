@@ -0,0 +1,67 @@
+use alloc::string::String;
+use vct_os::sync::Arc;
+
+use crate::{
+    info::{TypeInfo, Typed},
+    ops::DynamicStruct,
+};
+
+/// Converts the data of a versioned struct from one version to the next.
+///
+/// The older shape is described by a struct `S` with the fields of that version,
+/// the data is deserialized as a [`DynamicStruct`] of this shape before being migrated.
+/// The migration fails with a reason when the data cannot be converted.
+///
+/// Usually registered with [`TypeRegistry::register_migration`](crate::registry::TypeRegistry::register_migration).
+#[derive(Clone)]
+pub struct Migration {
+    from_version: u32,
+    shape: &'static TypeInfo,
+    func: Arc<dyn Fn(DynamicStruct) -> Result<DynamicStruct, String> + Send + Sync>,
+}
+
+impl Migration {
+    /// Creates a migration from `from_version` to `from_version + 1`,
+    /// reading the data of `from_version` in the shape of `S`.
+    ///
+    /// # Panic
+    ///
+    /// - `S` is not a struct with named fields.
+    pub fn new<S: Typed>(
+        from_version: u32,
+        func: impl Fn(DynamicStruct) -> Result<DynamicStruct, String> + Send + Sync + 'static,
+    ) -> Self {
+        let shape = S::type_info();
+        if !matches!(shape, TypeInfo::Struct(_)) {
+            panic!(
+                "Called `Migration::new`, but the shape `{}` is not a struct",
+                S::type_path(),
+            );
+        }
+        Self {
+            from_version,
+            shape,
+            func: Arc::new(func),
+        }
+    }
+
+    /// Returns the version this migration converts from.
+    #[inline]
+    pub fn from_version(&self) -> u32 {
+        self.from_version
+    }
+
+    /// Returns the [`TypeInfo`] of the data of [`from_version`](Self::from_version),
+    /// always a [`TypeInfo::Struct`].
+    #[inline]
+    pub fn shape(&self) -> &'static TypeInfo {
+        self.shape
+    }
+
+    /// Converts `value` into the data of the next version,
+    /// returns the reason of the failure if it cannot be converted.
+    #[inline]
+    pub fn apply(&self, value: DynamicStruct) -> Result<DynamicStruct, String> {
+        (self.func)(value)
+    }
+}
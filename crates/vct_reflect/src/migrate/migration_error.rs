@@ -0,0 +1,19 @@
+use alloc::{borrow::Cow, string::String};
+use vct_utils::error::define_error;
+
+define_error! {
+    /// A enumeration of all error outcomes
+    /// that might happen when migrating data of an older version.
+    #[derive(Debug)]
+    pub enum MigrationError {
+        /// The data was saved by a newer version of the type.
+        UnknownVersion { type_path: Cow<'static, str>, version: u32 } =>
+            "type `{type_path}` has no version {version}",
+        /// No migration is registered from the version.
+        MissingMigration { type_path: Cow<'static, str>, from_version: u32 } =>
+            "type `{type_path}` has no migration from version {from_version}",
+        /// The migration from the version failed.
+        Failed { type_path: Cow<'static, str>, from_version: u32, reason: String } =>
+            "failed to migrate type `{type_path}` from version {from_version}: {reason}",
+    }
+}
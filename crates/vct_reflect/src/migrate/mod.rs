@@ -0,0 +1,7 @@
+//! Versioned structs and the migrations of their older serialized shapes.
+mod migration;
+mod migration_error;
+mod type_trait_migrations;
+pub use migration::Migration;
+pub use migration_error::MigrationError;
+pub use type_trait_migrations::TypeTraitMigrations;
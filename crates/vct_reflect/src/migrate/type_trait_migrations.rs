@@ -0,0 +1,216 @@
+use alloc::{borrow::Cow, vec::Vec};
+
+use crate::{
+    migrate::{Migration, MigrationError},
+    ops::DynamicStruct,
+};
+
+/// Registered for `#[reflect(version = N)]` structs, holds their current version
+/// and the migrations from older versions.
+///
+/// Versioned structs are serialized as a tuple of the version and the struct.
+/// When deserializing data of an older version, the data is read in the shape of
+/// that version, then migrated step by step up to the current version.
+///
+/// Self-describing formats also accept a bare struct, the data saved before the
+/// struct was versioned, it is read as the [oldest version](Self::oldest_version).
+///
+/// # Example
+///
+/// ```ignore
+/// #[derive(Reflect)]
+/// #[reflect(version = 2)]
+/// struct Foo { name: String, size: u32 }
+///
+/// // The shape of version 1.
+/// #[derive(Reflect)]
+/// struct FooV1 { name: String, len: u32 }
+///
+/// let mut registry = TypeRegistry::new();
+/// registry.register::<Foo>();
+/// registry.register_migration::<Foo, FooV1>(1, |old| {
+///     let len = old.field("len").ok_or("missing field `len`")?;
+///     let mut new = DynamicStruct::new();
+///     new.insert_boxed("name", old.field("name").unwrap().to_dynamic());
+///     new.insert_boxed("size", len.to_dynamic());
+///     Ok(new)
+/// });
+/// ```
+#[derive(Clone)]
+pub struct TypeTraitMigrations {
+    version: u32,
+    migrations: Vec<Migration>,
+}
+
+impl TypeTraitMigrations {
+    /// Creates the type trait of a struct of version `version`, without migrations.
+    #[inline]
+    pub const fn new(version: u32) -> Self {
+        Self {
+            version,
+            migrations: Vec::new(),
+        }
+    }
+
+    /// Returns the current version.
+    #[inline]
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    /// Returns the oldest version with a registered migration,
+    /// or the current version if there is none.
+    #[inline]
+    pub fn oldest_version(&self) -> u32 {
+        self.migrations
+            .iter()
+            .map(Migration::from_version)
+            .min()
+            .unwrap_or(self.version)
+    }
+
+    /// Inserts a migration, replacing the one from the same version.
+    ///
+    /// # Panic
+    ///
+    /// - The migration is not from an older version.
+    pub fn insert(&mut self, migration: Migration) {
+        if migration.from_version() >= self.version {
+            panic!(
+                "Called `TypeTraitMigrations::insert`, but the migration from version {} is not older than version {}",
+                migration.from_version(),
+                self.version,
+            );
+        }
+        match self
+            .migrations
+            .iter_mut()
+            .find(|m| m.from_version() == migration.from_version())
+        {
+            Some(old) => *old = migration,
+            None => self.migrations.push(migration),
+        }
+    }
+
+    /// Returns the migration from `from_version`.
+    #[inline]
+    pub fn get(&self, from_version: u32) -> Option<&Migration> {
+        self.migrations
+            .iter()
+            .find(|m| m.from_version() == from_version)
+    }
+
+    /// Migrates `value` of version `from_version` to the current version,
+    /// applying the migration of each version in turn.
+    ///
+    /// `type_path` is only used in errors.
+    pub fn migrate(
+        &self,
+        type_path: &str,
+        from_version: u32,
+        mut value: DynamicStruct,
+    ) -> Result<DynamicStruct, MigrationError> {
+        if from_version > self.version {
+            return Err(MigrationError::UnknownVersion {
+                type_path: Cow::Owned(type_path.into()),
+                version: from_version,
+            });
+        }
+        for version in from_version..self.version {
+            let Some(migration) = self.get(version) else {
+                return Err(MigrationError::MissingMigration {
+                    type_path: Cow::Owned(type_path.into()),
+                    from_version: version,
+                });
+            };
+            value = migration
+                .apply(value)
+                .map_err(|reason| MigrationError::Failed {
+                    type_path: Cow::Owned(type_path.into()),
+                    from_version: version,
+                    reason,
+                })?;
+        }
+        Ok(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{
+        format,
+        string::{String, ToString},
+    };
+    use serde::de::DeserializeSeed;
+    use vct_reflect_derive::Reflect;
+
+    use crate::{
+        ops::{DynamicStruct, Struct},
+        registry::TypeRegistry,
+        serde::ReflectDeserializer,
+    };
+
+    #[derive(Reflect, PartialEq, Debug)]
+    #[reflect(version = 2)]
+    struct Foo {
+        name: String,
+        size: u32,
+    }
+
+    #[derive(Reflect)]
+    struct FooV1 {
+        name: String,
+        len: u32,
+    }
+
+    fn registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::new();
+        registry.register::<Foo>();
+        registry.register_migration::<Foo, FooV1>(1, |old| {
+            let len = old.field("len").and_then(|len| len.downcast_ref::<u32>());
+            let Some(&len) = len.filter(|len| **len != 0) else {
+                return Err(String::from("`len` is zero"));
+            };
+            let mut new = DynamicStruct::new();
+            new.insert_boxed("name", old.field("name").unwrap().to_dynamic());
+            new.insert("size", len);
+            Ok(new)
+        });
+        registry
+    }
+
+    fn deserialize(registry: &TypeRegistry, value: &str) -> Result<Foo, serde_json::Error> {
+        let json =
+            format!(r#"{{"vct_reflect::migrate::type_trait_migrations::tests::Foo":{value}}}"#);
+        let output = ReflectDeserializer::new(registry)
+            .deserialize(&mut serde_json::Deserializer::from_str(&json))?;
+        Ok(output.take::<Foo>().unwrap())
+    }
+
+    #[test]
+    fn migrate_versioned_and_bare_data() {
+        let registry = registry();
+        let expected = Foo {
+            name: String::from("a"),
+            size: 3,
+        };
+
+        let current = deserialize(&registry, r#"[2,{"name":"a","size":3}]"#).unwrap();
+        assert_eq!(current, expected);
+        let versioned = deserialize(&registry, r#"[1,{"name":"a","len":3}]"#).unwrap();
+        assert_eq!(versioned, expected);
+        let bare = deserialize(&registry, r#"{"name":"a","len":3}"#).unwrap();
+        assert_eq!(bare, expected);
+    }
+
+    #[test]
+    fn failed_migration() {
+        let registry = registry();
+
+        let error = deserialize(&registry, r#"{"name":"a","len":0}"#).unwrap_err();
+        assert!(
+            error.to_string().contains("from version 1: `len` is zero"),
+            "{error}"
+        );
+    }
+}
@@ -1,67 +1,117 @@
-use alloc::borrow::Cow;
-use core::{error, fmt};
+use alloc::{borrow::Cow, boxed::Box, string::ToString, vec};
+use vct_utils::error::define_error;
 
-use crate::info::{ReflectKind, ReflectKindError};
+use crate::{
+    access::{Accessor, PathAccessor},
+    info::{FieldId, ReflectKind, ReflectKindError},
+};
 
-/// A enumeration of all error outcomes
-/// that might happen when running [`try_apply`](crate::PartialReflect::try_apply).
-#[derive(Debug)]
-pub enum ApplyError {
-    /// Special reflection type, not allowed to apply.
-    NotSupport { type_path: Cow<'static, str> },
-    /// Tried to apply incompatible types.
-    MismatchedTypes {
-        from_type: Cow<'static, str>,
-        to_type: Cow<'static, str>
-    },
-    /// Attempted to apply the wrong [kind](ReflectKind) to a type, e.g. a struct to an enum.
-    MismatchedKinds {
-        from_kind: ReflectKind,
-        to_kind: ReflectKind,
-    },
-    /// Enum variant that we tried to apply to was missing a field.
-    MissingEnumField {
-        variant_name: Cow<'static, str>,
-        field_name: Cow<'static, str>,
-    },
-    /// Attempted to apply an [array-like] type to another of different size, e.g. a [u8; 4] to [u8; 3].
-    DifferentSize {
-        from_size: usize,
-        to_size: usize
-    },
-    /// The enum we tried to apply to didn't contain a variant with the give name.
-    UnknownVariant {
-        enum_name: Cow<'static, str>,
-        variant_name: Cow<'static, str>,
-    },
+define_error! {
+    /// A enumeration of all error outcomes
+    /// that might happen when running [`try_apply`](crate::PartialReflect::try_apply).
+    #[derive(Debug)]
+    pub enum ApplyError {
+        /// Special reflection type, not allowed to apply.
+        NotSupport { type_path: Cow<'static, str> } =>
+            "type `{type_path}` does not support `apply`",
+        /// Tried to apply incompatible types.
+        MismatchedTypes {
+            from_type: Cow<'static, str>,
+            to_type: Cow<'static, str>,
+        } => "attempted to apply `{from_type}` to `{to_type}`",
+        /// Attempted to apply the wrong [kind](ReflectKind) to a type, e.g. a struct to an enum.
+        MismatchedKinds {
+            from_kind: ReflectKind,
+            to_kind: ReflectKind,
+        } => "attempted to apply `{from_kind}` to `{to_kind}`",
+        /// Enum variant that we tried to apply to was missing a field.
+        MissingEnumField {
+            variant_name: Cow<'static, str>,
+            field_name: Cow<'static, str>,
+        } => "enum variant `{variant_name}` doesn't have a field `{field_name}`",
+        /// Attempted to apply an [array-like] type to another of different size, e.g. a [u8; 4] to [u8; 3].
+        DifferentSize {
+            from_size: usize,
+            to_size: usize,
+        } => "attempted to apply type with {from_size} size to {to_size} size",
+        /// Attempted to apply a diff to a field or element that does not exist.
+        MissingField {
+            type_path: Cow<'static, str>,
+            field: FieldId,
+        } => "type `{type_path}` doesn't have a field or element `{field}`",
+        /// Attempted to apply a number that is out of range or not integral for the numeric type.
+        NumericOutOfRange {
+            number: f64,
+            to_type: Cow<'static, str>,
+        } => "number `{number}` cannot be represented by `{to_type}`",
+        /// The enum we tried to apply to didn't contain a variant with the give name.
+        UnknownVariant {
+            enum_name: Cow<'static, str>,
+            variant_name: Cow<'static, str>,
+        } => "variant `{variant_name}` does not exist on enum `{enum_name}`",
+        /// An error occurred while applying a nested field or element.
+        ///
+        /// `path` is relative to the value `try_apply` was called on, e.g. `.transform.translation[2]`.
+        Nested {
+            path: PathAccessor,
+            error: Box<ApplyError>,
+        } => "at `{path}`: {error}",
+    }
 }
 
-impl fmt::Display for ApplyError {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+impl ApplyError {
+    /// Prepends `accessor` to the path of this error,
+    /// wrapping it in [`ApplyError::Nested`] if it has no path yet.
+    pub fn with_accessor(self, accessor: Accessor<'static>) -> Self {
         match self {
-            Self::NotSupport { type_path } => {
-                write!(f, "type `{type_path}` does not support `apply`")
-            },
-            Self::MismatchedTypes { from_type, to_type } => {
-                write!(f, "attempted to apply `{from_type}` to `{to_type}`")
-            },
-            Self::MismatchedKinds { from_kind, to_kind } => {
-                write!(f, "attempted to apply `{from_kind}` to `{to_kind}`")
-            },
-            Self::MissingEnumField { variant_name, field_name } => {
-                write!(f, "enum variant `{variant_name}` doesn't have a field `{field_name}`")
-            },
-            Self::DifferentSize { from_size, to_size } => {
-                write!(f, "attempted to apply type with {from_size} size to {to_size} size")
-            },
-            Self::UnknownVariant {enum_name, variant_name } => {
-                write!(f, "variant `{variant_name}` does not exist on enum `{enum_name}`")
+            Self::Nested { mut path, error } => {
+                path.push_front(accessor.into());
+                Self::Nested { path, error }
             }
+            error => Self::Nested {
+                path: PathAccessor::from(vec![accessor.into()]),
+                error: Box::new(error),
+            },
         }
     }
-}
 
-impl error::Error for ApplyError {}
+    /// Prepends a named field access, see [`with_accessor`](Self::with_accessor).
+    #[inline]
+    pub fn with_field_name(self, name: &str) -> Self {
+        self.with_accessor(Accessor::FieldName(Cow::Owned(name.to_string())))
+    }
+
+    /// Prepends a tuple field access, see [`with_accessor`](Self::with_accessor).
+    #[inline]
+    pub fn with_tuple_index(self, index: usize) -> Self {
+        self.with_accessor(Accessor::TupleIndex(index))
+    }
+
+    /// Prepends a list or array element access, see [`with_accessor`](Self::with_accessor).
+    #[inline]
+    pub fn with_list_index(self, index: usize) -> Self {
+        self.with_accessor(Accessor::ListIndex(index))
+    }
+
+    /// Returns the path of the failing field relative to the applied value,
+    /// or `None` if the error occurred on the value itself.
+    #[inline]
+    pub fn path(&self) -> Option<&PathAccessor> {
+        match self {
+            Self::Nested { path, .. } => Some(path),
+            _ => None,
+        }
+    }
+
+    /// Returns the underlying error without its path context.
+    #[inline]
+    pub fn inner(&self) -> &ApplyError {
+        match self {
+            Self::Nested { error, .. } => error,
+            error => error,
+        }
+    }
+}
 
 impl From<ReflectKindError> for ApplyError {
     #[inline]
@@ -1,5 +1,6 @@
 use alloc::{boxed::Box, vec::Vec};
 use core::{
+    cmp::Ordering,
     fmt,
     hash::{Hash, Hasher},
 };
@@ -8,7 +9,7 @@ use crate::{
     Reflect,
     cell::NonGenericTypeInfoCell,
     info::{ArrayInfo, OpaqueInfo, ReflectKind, TypeInfo, TypePath, Typed},
-    ops::{ApplyError, ReflectMut, ReflectOwned, ReflectRef},
+    ops::{ApplyError, ReflectCloneError, ReflectMut, ReflectOwned, ReflectRef},
     reflect::impl_cast_reflect_fn,
     reflect_hasher,
 };
@@ -123,16 +124,37 @@ impl Reflect for DynamicArray {
         array_try_apply(self, value)
     }
 
+    #[inline]
+    fn try_apply_owned(&mut self, value: Box<dyn Reflect>) -> Result<(), ApplyError> {
+        array_try_apply_owned(self, value)
+    }
+
     #[inline]
     fn reflect_hash(&self) -> Option<u64> {
         array_hash(self)
     }
 
+    fn reflect_clone(&self) -> Result<Box<dyn Reflect>, ReflectCloneError> {
+        Ok(Box::new(DynamicArray {
+            array_info: self.array_info,
+            values: self
+                .values
+                .iter()
+                .map(|value| value.reflect_clone())
+                .collect::<Result<_, _>>()?,
+        }))
+    }
+
     #[inline]
     fn reflect_partial_eq(&self, other: &dyn Reflect) -> Option<bool> {
         array_partial_eq(self, other)
     }
 
+    #[inline]
+    fn reflect_partial_cmp(&self, other: &dyn Reflect) -> Option<Ordering> {
+        array_partial_cmp(self, other)
+    }
+
     #[inline]
     fn reflect_debug(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "DynamicArray(")?;
@@ -336,7 +358,30 @@ pub fn array_try_apply(x: &mut dyn Array, y: &dyn Reflect) -> Result<(), ApplyEr
 
     for (idx, y_item) in y.iter().enumerate() {
         let item = x.get_mut(idx).unwrap();
-        item.try_apply(y_item)?;
+        item.try_apply(y_item)
+            .map_err(|err| err.with_list_index(idx))?;
+    }
+    Ok(())
+}
+
+/// A function used to assist in the implementation of `try_apply_owned`
+///
+/// Avoid compilation overhead when implementing multiple types.
+#[inline(never)]
+pub fn array_try_apply_owned(x: &mut dyn Array, y: Box<dyn Reflect>) -> Result<(), ApplyError> {
+    let y = y.reflect_owned().into_array()?;
+
+    if x.len() != y.len() {
+        return Err(ApplyError::DifferentSize {
+            from_size: y.len(),
+            to_size: x.len(),
+        });
+    }
+
+    for (idx, y_item) in y.drain().into_iter().enumerate() {
+        let item = x.get_mut(idx).unwrap();
+        item.try_apply_owned(y_item)
+            .map_err(|err| err.with_list_index(idx))?;
     }
     Ok(())
 }
@@ -364,6 +409,26 @@ pub fn array_partial_eq(x: &dyn Array, y: &dyn Reflect) -> Option<bool> {
     Some(true)
 }
 
+/// A function used to assist in the implementation of `reflect_partial_cmp`
+///
+/// Compares lexicographically, like the ordering of slices.
+///
+/// Avoid compilation overhead when implementing multiple types.
+#[inline(never)]
+pub fn array_partial_cmp(x: &dyn Array, y: &dyn Reflect) -> Option<Ordering> {
+    let ReflectRef::Array(y) = y.reflect_ref() else {
+        return None;
+    };
+
+    for (x_value, y_value) in x.iter().zip(y.iter()) {
+        match x_value.reflect_partial_cmp(y_value)? {
+            Ordering::Equal => {}
+            ordering => return Some(ordering),
+        }
+    }
+    Some(x.len().cmp(&y.len()))
+}
+
 /// A function used to assist in the implementation of `reflect_hash`
 ///
 /// Avoid compilation overhead when implementing multiple types.
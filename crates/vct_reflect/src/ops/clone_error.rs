@@ -13,6 +13,11 @@ pub enum ReflectCloneError {
         field: FieldId,
         variant: Option<Cow<'static, str>>,
     },
+    /// The destination of [`crate::Reflect::reflect_clone_into`] is not of the same type.
+    MismatchedTypes {
+        from_type: Cow<'static, str>,
+        to_type: Cow<'static, str>,
+    },
 }
 
 impl fmt::Display for ReflectCloneError {
@@ -35,6 +40,9 @@ impl fmt::Display for ReflectCloneError {
                     }
                 )
             },
+            Self::MismatchedTypes { from_type, to_type } => {
+                write!(f, "cannot clone `{from_type}` into `{to_type}`")
+            },
         }
     }
 }
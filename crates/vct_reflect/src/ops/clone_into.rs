@@ -0,0 +1,102 @@
+use alloc::borrow::{Cow, ToOwned};
+
+use crate::{
+    Reflect,
+    ops::{ReflectCloneError, ReflectMut, ReflectRef},
+};
+
+/// A function used to assist in the implementation of `reflect_clone_into`
+///
+/// Clones `src` into `dst` field by field, so values that are already allocated in `dst`
+/// (e.g. the items of a list) are reused. Values that cannot be cloned in place,
+/// such as maps, sets, enums of a different variant and opaque types,
+/// are replaced by their [`Reflect::reflect_clone`].
+///
+/// Not inline: recursive function.
+pub(crate) fn reflect_clone_into(
+    src: &dyn Reflect,
+    dst: &mut dyn Reflect,
+) -> Result<(), ReflectCloneError> {
+    if src.type_id() != (*dst).type_id() {
+        return Err(mismatched_types(src, dst));
+    }
+    // Dynamic types of the same type can have different shapes.
+    if src.is_dynamic() {
+        return replace(src, dst);
+    }
+
+    match (src.reflect_ref(), dst.reflect_mut()) {
+        (ReflectRef::Struct(src), ReflectMut::Struct(dst)) => {
+            for (index, field) in src.iter_fields().enumerate() {
+                if let Some(dst_field) = dst.field_at_mut(index) {
+                    field.reflect_clone_into(dst_field)?;
+                }
+            }
+        }
+        (ReflectRef::TupleStruct(src), ReflectMut::TupleStruct(dst)) => {
+            for (index, field) in src.iter_fields().enumerate() {
+                if let Some(dst_field) = dst.field_mut(index) {
+                    field.reflect_clone_into(dst_field)?;
+                }
+            }
+        }
+        (ReflectRef::Tuple(src), ReflectMut::Tuple(dst)) => {
+            for (index, field) in src.iter_fields().enumerate() {
+                if let Some(dst_field) = dst.field_mut(index) {
+                    field.reflect_clone_into(dst_field)?;
+                }
+            }
+        }
+        (ReflectRef::Array(src), ReflectMut::Array(dst)) => {
+            for (index, item) in src.iter().enumerate() {
+                if let Some(dst_item) = dst.get_mut(index) {
+                    item.reflect_clone_into(dst_item)?;
+                }
+            }
+        }
+        (ReflectRef::List(src), ReflectMut::List(dst)) => {
+            while dst.len() > src.len() {
+                dst.pop();
+            }
+            for (index, item) in src.iter().enumerate() {
+                match dst.get_mut(index) {
+                    Some(dst_item) => item.reflect_clone_into(dst_item)?,
+                    None => dst.push(item.reflect_clone()?),
+                }
+            }
+        }
+        (ReflectRef::Enum(src_enum), ReflectMut::Enum(dst_enum))
+            if src_enum.variant_index() == dst_enum.variant_index() =>
+        {
+            for index in 0..src_enum.field_len() {
+                if let (Some(field), Some(dst_field)) =
+                    (src_enum.field_at(index), dst_enum.field_at_mut(index))
+                {
+                    field.reflect_clone_into(dst_field)?;
+                }
+            }
+        }
+        // Pointers and transparent types forward to their inner value.
+        (ReflectRef::Opaque(inner), ReflectMut::Opaque(dst_inner))
+            if inner.type_id() != src.type_id() =>
+        {
+            inner.reflect_clone_into(dst_inner)?;
+        }
+        _ => return replace(src, dst),
+    }
+    Ok(())
+}
+
+fn replace(src: &dyn Reflect, dst: &mut dyn Reflect) -> Result<(), ReflectCloneError> {
+    match dst.set(src.reflect_clone()?) {
+        Ok(()) => Ok(()),
+        Err(value) => Err(mismatched_types(&*value, dst)),
+    }
+}
+
+fn mismatched_types(src: &dyn Reflect, dst: &dyn Reflect) -> ReflectCloneError {
+    ReflectCloneError::MismatchedTypes {
+        from_type: Cow::Owned(src.reflect_type_path().to_owned()),
+        to_type: Cow::Owned(dst.reflect_type_path().to_owned()),
+    }
+}
@@ -0,0 +1,18 @@
+use alloc::borrow::Cow;
+use vct_utils::error::define_error;
+
+define_error! {
+    /// A enumeration of all error outcomes
+    /// that might happen when converting a dynamic value into its concrete type.
+    #[derive(Debug)]
+    pub enum ConcreteError {
+        /// The dynamic value does not represent any type.
+        MissingTypeInfo => "the dynamic value does not have a represented type info",
+        /// The represented type has no `TypeTraitFromReflect` in the registry.
+        MissingFromReflect { type_path: Cow<'static, str> } =>
+            "type `{type_path}` has no `TypeTraitFromReflect` in the registry",
+        /// `FromReflect` failed, the dynamic value doesn't match the represented type.
+        FromReflectFailed { type_path: Cow<'static, str> } =>
+            "failed to convert the dynamic value into `{type_path}`",
+    }
+}
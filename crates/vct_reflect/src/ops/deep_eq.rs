@@ -0,0 +1,167 @@
+use alloc::{
+    borrow::Cow,
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt;
+
+use crate::{Reflect, access::Accessor, ops::ReflectRef};
+
+/// The first difference found by [`reflect_deep_eq`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MismatchReport {
+    path: Vec<Accessor<'static>>,
+    expected: String,
+    actual: String,
+}
+
+impl MismatchReport {
+    /// Returns the access path of the differing value, empty if the root values differ.
+    ///
+    /// Maps and sets are compared as a whole, so the path ends at them.
+    #[inline]
+    pub fn path(&self) -> &[Accessor<'static>] {
+        &self.path
+    }
+
+    /// Returns the debug string of the expected value.
+    #[inline]
+    pub fn expected(&self) -> &str {
+        &self.expected
+    }
+
+    /// Returns the debug string of the actual value.
+    #[inline]
+    pub fn actual(&self) -> &str {
+        &self.actual
+    }
+}
+
+impl fmt::Display for MismatchReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("values differ at `")?;
+        if self.path.is_empty() {
+            f.write_str("<root>")?;
+        }
+        for accessor in &self.path {
+            fmt::Display::fmt(accessor, f)?;
+        }
+        write!(
+            f,
+            "`: expected `{}`, found `{}`",
+            self.expected, self.actual
+        )
+    }
+}
+
+impl core::error::Error for MismatchReport {}
+
+/// Compares two reflected values structurally,
+/// returning the path and debug strings of the first difference.
+///
+/// `expected` and `actual` are compared field by field, element by element.
+/// Maps, sets and opaque values are compared with [`Reflect::reflect_partial_eq`],
+/// a `None` result is considered a difference.
+///
+/// Unlike [`Reflect::reflect_partial_eq`], the concrete types are not compared,
+/// so a value is equal to its dynamic representation.
+pub fn reflect_deep_eq(expected: &dyn Reflect, actual: &dyn Reflect) -> Result<(), MismatchReport> {
+    let mut path = Vec::new();
+    match deep_eq(expected, actual, &mut path) {
+        None => Ok(()),
+        Some((expected, actual)) => Err(MismatchReport {
+            path,
+            expected: format!("{expected:?}"),
+            actual: format!("{actual:?}"),
+        }),
+    }
+}
+
+type Mismatch<'a> = Option<(&'a dyn Reflect, &'a dyn Reflect)>;
+
+/// Returns the first differing values, leaving their path in `path`.
+///
+/// Not inline: recursive function.
+fn deep_eq<'a>(
+    x: &'a dyn Reflect,
+    y: &'a dyn Reflect,
+    path: &mut Vec<Accessor<'static>>,
+) -> Mismatch<'a> {
+    macro_rules! compare_items {
+        ($iter:expr, $accessor:expr) => {
+            for (index, (x, y)) in $iter.enumerate() {
+                path.push($accessor(index));
+                if let Some(mismatch) = deep_eq(x, y, path) {
+                    return Some(mismatch);
+                }
+                path.pop();
+            }
+        };
+    }
+
+    let equal = match (x.reflect_ref(), y.reflect_ref()) {
+        (ReflectRef::Struct(xs), ReflectRef::Struct(ys)) => {
+            if xs.field_len() != ys.field_len() {
+                return Some((x, y));
+            }
+            for (index, x_field) in xs.iter_fields().enumerate() {
+                let name = xs.name_at(index).unwrap();
+                let Some(y_field) = ys.field(name) else {
+                    return Some((x, y));
+                };
+                path.push(Accessor::FieldName(Cow::Owned(name.to_string())));
+                if let Some(mismatch) = deep_eq(x_field, y_field, path) {
+                    return Some(mismatch);
+                }
+                path.pop();
+            }
+            true
+        }
+        (ReflectRef::TupleStruct(xs), ReflectRef::TupleStruct(ys)) => {
+            if xs.field_len() != ys.field_len() {
+                return Some((x, y));
+            }
+            compare_items!(xs.iter_fields().zip(ys.iter_fields()), Accessor::TupleIndex);
+            true
+        }
+        (ReflectRef::Tuple(xs), ReflectRef::Tuple(ys)) => {
+            if xs.field_len() != ys.field_len() {
+                return Some((x, y));
+            }
+            compare_items!(xs.iter_fields().zip(ys.iter_fields()), Accessor::TupleIndex);
+            true
+        }
+        // Report the first differing element before a length mismatch.
+        (ReflectRef::List(xs), ReflectRef::List(ys)) => {
+            compare_items!(xs.iter().zip(ys.iter()), Accessor::ListIndex);
+            xs.len() == ys.len()
+        }
+        (ReflectRef::Array(xs), ReflectRef::Array(ys)) => {
+            compare_items!(xs.iter().zip(ys.iter()), Accessor::ListIndex);
+            xs.len() == ys.len()
+        }
+        (ReflectRef::Enum(xs), ReflectRef::Enum(ys)) => {
+            if xs.variant_name() != ys.variant_name() || xs.field_len() != ys.field_len() {
+                return Some((x, y));
+            }
+            for (index, (x_field, y_field)) in xs.iter_fields().zip(ys.iter_fields()).enumerate() {
+                match x_field.name() {
+                    Some(name) => path.push(Accessor::FieldName(Cow::Owned(name.to_string()))),
+                    None => path.push(Accessor::TupleIndex(index)),
+                }
+                if let Some(mismatch) = deep_eq(x_field.value(), y_field.value(), path) {
+                    return Some(mismatch);
+                }
+                path.pop();
+            }
+            true
+        }
+        (ReflectRef::Map(_), ReflectRef::Map(_))
+        | (ReflectRef::Set(_), ReflectRef::Set(_))
+        | (ReflectRef::Opaque(_), ReflectRef::Opaque(_)) => x.reflect_partial_eq(y) == Some(true),
+        _ => false,
+    };
+
+    if equal { None } else { Some((x, y)) }
+}
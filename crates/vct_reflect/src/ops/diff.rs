@@ -0,0 +1,399 @@
+use alloc::{borrow::Cow, boxed::Box, format, string::ToString, vec::Vec};
+use vct_utils::CowStr;
+
+use crate::{
+    Reflect,
+    info::{FieldId, ReflectKind},
+    ops::{ApplyError, ReflectCloneError, ReflectMut, ReflectRef},
+};
+
+/// A structural delta between two reflected values, created by [`diff`].
+///
+/// Applying it with [`apply_diff`] to the first value makes it equal to the second one.
+///
+/// [`diff`]: crate::Reflect#method.diff
+/// [`apply_diff`]: crate::Reflect#method.apply_diff
+#[derive(Debug)]
+pub enum Diff {
+    /// The values are equal.
+    Unchanged,
+    /// The value is replaced, e.g. an opaque value changed or the kinds differ.
+    Replaced(Box<dyn Reflect>),
+    /// Some fields, elements or entries changed.
+    Modified(DiffKind),
+}
+
+impl Diff {
+    /// Returns `true` if the values are equal.
+    #[inline]
+    pub fn is_unchanged(&self) -> bool {
+        matches!(self, Self::Unchanged)
+    }
+}
+
+/// The changes of a [`Diff::Modified`], by the kind of the value.
+///
+/// Only changed fields or elements are recorded.
+#[derive(Debug)]
+pub enum DiffKind {
+    Struct(Vec<(CowStr, Diff)>),
+    TupleStruct(Vec<(usize, Diff)>),
+    Tuple(Vec<(usize, Diff)>),
+    Array(Vec<(usize, Diff)>),
+    List(Vec<ListDiff>),
+    Map(Vec<MapDiff>),
+    Set {
+        inserted: Vec<Box<dyn Reflect>>,
+        removed: Vec<Box<dyn Reflect>>,
+    },
+    /// Fields of the current variant, the variant itself is unchanged.
+    Enum(Vec<(usize, Diff)>),
+}
+
+impl DiffKind {
+    fn is_empty(&self) -> bool {
+        match self {
+            Self::Struct(fields) => fields.is_empty(),
+            Self::TupleStruct(fields) | Self::Tuple(fields) | Self::Array(fields) => {
+                fields.is_empty()
+            }
+            Self::List(edits) => edits.is_empty(),
+            Self::Map(edits) => edits.is_empty(),
+            Self::Set { inserted, removed } => inserted.is_empty() && removed.is_empty(),
+            Self::Enum(fields) => fields.is_empty(),
+        }
+    }
+}
+
+/// An edit of a list, edits are applied in order.
+///
+/// Elements are compared by position, so elements after an insertion point
+/// are recorded as modified rather than shifted.
+#[derive(Debug)]
+pub enum ListDiff {
+    Modified(usize, Diff),
+    Inserted(usize, Box<dyn Reflect>),
+    Removed(usize),
+}
+
+/// An edit of a map entry.
+#[derive(Debug)]
+pub enum MapDiff {
+    Modified(Box<dyn Reflect>, Diff),
+    Inserted(Box<dyn Reflect>, Box<dyn Reflect>),
+    Removed(Box<dyn Reflect>),
+}
+
+impl dyn Reflect {
+    /// Returns the [`Diff`] that turns `self` into `other`.
+    ///
+    /// Changed values are captured with [`reflect_clone`], falling back to
+    /// [`to_dynamic`] for non-opaque values.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a changed opaque value does not support `reflect_clone`.
+    ///
+    /// [`reflect_clone`]: Reflect::reflect_clone
+    /// [`to_dynamic`]: Reflect::to_dynamic
+    #[inline]
+    pub fn diff(&self, other: &dyn Reflect) -> Result<Diff, ReflectCloneError> {
+        diff_value(self, other)
+    }
+
+    /// Applies a [`Diff`] created by [`diff`](Self::diff) to this value.
+    #[inline]
+    pub fn apply_diff(&mut self, diff: &Diff) -> Result<(), ApplyError> {
+        apply_diff_value(self, diff)
+    }
+}
+
+fn capture(value: &dyn Reflect) -> Result<Box<dyn Reflect>, ReflectCloneError> {
+    value.reflect_clone().or_else(|err| match value.reflect_kind() {
+        ReflectKind::Opaque => Err(err),
+        _ => Ok(value.to_dynamic()),
+    })
+}
+
+#[inline]
+fn replaced(value: &dyn Reflect) -> Result<Diff, ReflectCloneError> {
+    capture(value).map(Diff::Replaced)
+}
+
+#[inline]
+fn modified(kind: DiffKind) -> Result<Diff, ReflectCloneError> {
+    if kind.is_empty() {
+        Ok(Diff::Unchanged)
+    } else {
+        Ok(Diff::Modified(kind))
+    }
+}
+
+fn diff_indexed<'a>(
+    x: impl Iterator<Item = &'a dyn Reflect>,
+    y: impl Iterator<Item = &'a dyn Reflect>,
+) -> Result<Vec<(usize, Diff)>, ReflectCloneError> {
+    let mut fields = Vec::new();
+    for (index, (x, y)) in x.zip(y).enumerate() {
+        let diff = diff_value(x, y)?;
+        if !diff.is_unchanged() {
+            fields.push((index, diff));
+        }
+    }
+    Ok(fields)
+}
+
+/// Not inline: recursive function.
+fn diff_value(x: &dyn Reflect, y: &dyn Reflect) -> Result<Diff, ReflectCloneError> {
+    match (x.reflect_ref(), y.reflect_ref()) {
+        (ReflectRef::Struct(x), ReflectRef::Struct(y)) => {
+            if x.field_len() != y.field_len() {
+                return replaced(y);
+            }
+            let mut fields = Vec::new();
+            for (index, y_field) in y.iter_fields().enumerate() {
+                let name = y.name_at(index).unwrap();
+                let Some(x_field) = x.field(name) else {
+                    return replaced(y);
+                };
+                let diff = diff_value(x_field, y_field)?;
+                if !diff.is_unchanged() {
+                    fields.push((CowStr::new(name), diff));
+                }
+            }
+            modified(DiffKind::Struct(fields))
+        }
+        (ReflectRef::TupleStruct(x), ReflectRef::TupleStruct(y)) => {
+            if x.field_len() != y.field_len() {
+                return replaced(y);
+            }
+            modified(DiffKind::TupleStruct(diff_indexed(
+                x.iter_fields(),
+                y.iter_fields(),
+            )?))
+        }
+        (ReflectRef::Tuple(x), ReflectRef::Tuple(y)) => {
+            if x.field_len() != y.field_len() {
+                return replaced(y);
+            }
+            modified(DiffKind::Tuple(diff_indexed(
+                x.iter_fields(),
+                y.iter_fields(),
+            )?))
+        }
+        (ReflectRef::Array(x), ReflectRef::Array(y)) => {
+            if x.len() != y.len() {
+                return replaced(y);
+            }
+            modified(DiffKind::Array(diff_indexed(x.iter(), y.iter())?))
+        }
+        (ReflectRef::List(x), ReflectRef::List(y)) => {
+            let common = x.len().min(y.len());
+            let mut edits = Vec::new();
+            for (index, diff) in diff_indexed(x.iter(), y.iter())? {
+                edits.push(ListDiff::Modified(index, diff));
+            }
+            for index in common..y.len() {
+                edits.push(ListDiff::Inserted(index, capture(y.get(index).unwrap())?));
+            }
+            for index in (common..x.len()).rev() {
+                edits.push(ListDiff::Removed(index));
+            }
+            modified(DiffKind::List(edits))
+        }
+        (ReflectRef::Map(x), ReflectRef::Map(y)) => {
+            let mut edits = Vec::new();
+            for (key, y_value) in y.iter() {
+                match x.get(key) {
+                    Some(x_value) => {
+                        let diff = diff_value(x_value, y_value)?;
+                        if !diff.is_unchanged() {
+                            edits.push(MapDiff::Modified(capture(key)?, diff));
+                        }
+                    }
+                    None => edits.push(MapDiff::Inserted(capture(key)?, capture(y_value)?)),
+                }
+            }
+            for (key, _) in x.iter() {
+                if y.get(key).is_none() {
+                    edits.push(MapDiff::Removed(capture(key)?));
+                }
+            }
+            modified(DiffKind::Map(edits))
+        }
+        (ReflectRef::Set(x), ReflectRef::Set(y)) => {
+            let mut inserted = Vec::new();
+            let mut removed = Vec::new();
+            for value in y.iter() {
+                if !x.contains(value) {
+                    inserted.push(capture(value)?);
+                }
+            }
+            for value in x.iter() {
+                if !y.contains(value) {
+                    removed.push(capture(value)?);
+                }
+            }
+            modified(DiffKind::Set { inserted, removed })
+        }
+        (ReflectRef::Enum(x), ReflectRef::Enum(y)) => {
+            if x.variant_name() != y.variant_name() || x.field_len() != y.field_len() {
+                return replaced(y.as_reflect());
+            }
+            modified(DiffKind::Enum(diff_indexed(
+                x.iter_fields().map(|field| field.value()),
+                y.iter_fields().map(|field| field.value()),
+            )?))
+        }
+        (ReflectRef::Opaque(x), ReflectRef::Opaque(y)) if x.reflect_partial_eq(y) == Some(true) => {
+            Ok(Diff::Unchanged)
+        }
+        _ => replaced(y),
+    }
+}
+
+fn missing_field(value: &dyn Reflect, field: FieldId) -> ApplyError {
+    ApplyError::MissingField {
+        type_path: Cow::Owned(value.reflect_type_path().to_string()),
+        field,
+    }
+}
+
+/// Not inline: recursive function.
+fn apply_diff_value(x: &mut dyn Reflect, diff: &Diff) -> Result<(), ApplyError> {
+    let kind = match diff {
+        Diff::Unchanged => return Ok(()),
+        Diff::Replaced(value) => return x.try_apply(&**value),
+        Diff::Modified(kind) => kind,
+    };
+
+    match (x.reflect_mut(), kind) {
+        (ReflectMut::Struct(x), DiffKind::Struct(fields)) => {
+            for (name, diff) in fields {
+                match x.field_mut(name) {
+                    Some(field) => apply_diff_value(field, diff)?,
+                    None => {
+                        return Err(missing_field(
+                            x.as_reflect(),
+                            FieldId::Named(Cow::Owned(name.to_string())),
+                        ));
+                    }
+                }
+            }
+        }
+        (ReflectMut::TupleStruct(x), DiffKind::TupleStruct(fields)) => {
+            for (index, diff) in fields {
+                match x.field_mut(*index) {
+                    Some(field) => apply_diff_value(field, diff)?,
+                    None => return Err(missing_field(x.as_reflect(), FieldId::Unnamed(*index))),
+                }
+            }
+        }
+        (ReflectMut::Tuple(x), DiffKind::Tuple(fields)) => {
+            for (index, diff) in fields {
+                match x.field_mut(*index) {
+                    Some(field) => apply_diff_value(field, diff)?,
+                    None => return Err(missing_field(x.as_reflect(), FieldId::Unnamed(*index))),
+                }
+            }
+        }
+        (ReflectMut::Array(x), DiffKind::Array(items)) => {
+            for (index, diff) in items {
+                match x.get_mut(*index) {
+                    Some(item) => apply_diff_value(item, diff)?,
+                    None => return Err(missing_field(x.as_reflect(), FieldId::Unnamed(*index))),
+                }
+            }
+        }
+        (ReflectMut::List(x), DiffKind::List(edits)) => {
+            for edit in edits {
+                let len = x.len();
+                match edit {
+                    ListDiff::Modified(index, diff) => match x.get_mut(*index) {
+                        Some(item) => apply_diff_value(item, diff)?,
+                        // The elements can not be mutated in place, e.g. of a `BinaryHeap`.
+                        None if *index < len => {
+                            return Err(ApplyError::NotSupport {
+                                type_path: Cow::Owned(x.reflect_type_path().to_string()),
+                            });
+                        }
+                        None => {
+                            return Err(missing_field(x.as_reflect(), FieldId::Unnamed(*index)));
+                        }
+                    },
+                    ListDiff::Inserted(index, value) if *index <= x.len() => {
+                        x.insert(*index, value.to_dynamic());
+                    }
+                    ListDiff::Removed(index) if *index < x.len() => {
+                        x.remove(*index);
+                    }
+                    ListDiff::Inserted(index, _) | ListDiff::Removed(index) => {
+                        return Err(missing_field(x.as_reflect(), FieldId::Unnamed(*index)));
+                    }
+                }
+            }
+        }
+        (ReflectMut::Map(x), DiffKind::Map(edits)) => {
+            for edit in edits {
+                match edit {
+                    MapDiff::Modified(key, diff) => match x.get_mut(&**key) {
+                        Some(value) => apply_diff_value(value, diff)?,
+                        None => {
+                            return Err(missing_field(
+                                x.as_reflect(),
+                                FieldId::Named(Cow::Owned(format!("{key:?}"))),
+                            ));
+                        }
+                    },
+                    MapDiff::Inserted(key, value) => {
+                        x.insert_boxed(key.to_dynamic(), value.to_dynamic());
+                    }
+                    MapDiff::Removed(key) => {
+                        x.remove(&**key);
+                    }
+                }
+            }
+        }
+        (ReflectMut::Set(x), DiffKind::Set { inserted, removed }) => {
+            for value in removed {
+                x.remove(&**value);
+            }
+            for value in inserted {
+                x.insert_boxed(value.to_dynamic());
+            }
+        }
+        (ReflectMut::Enum(x), DiffKind::Enum(fields)) => {
+            for (index, diff) in fields {
+                match x.field_at_mut(*index) {
+                    Some(field) => apply_diff_value(field, diff)?,
+                    None => {
+                        return Err(ApplyError::MissingEnumField {
+                            variant_name: Cow::Owned(x.variant_name().to_string()),
+                            field_name: Cow::Owned(index.to_string()),
+                        });
+                    }
+                }
+            }
+        }
+        (x, _) => {
+            return Err(ApplyError::MismatchedKinds {
+                from_kind: diff_kind(kind),
+                to_kind: x.kind(),
+            });
+        }
+    }
+    Ok(())
+}
+
+fn diff_kind(kind: &DiffKind) -> ReflectKind {
+    match kind {
+        DiffKind::Struct(_) => ReflectKind::Struct,
+        DiffKind::TupleStruct(_) => ReflectKind::TupleStruct,
+        DiffKind::Tuple(_) => ReflectKind::Tuple,
+        DiffKind::Array(_) => ReflectKind::Array,
+        DiffKind::List(_) => ReflectKind::List,
+        DiffKind::Map(_) => ReflectKind::Map,
+        DiffKind::Set { .. } => ReflectKind::Set,
+        DiffKind::Enum(_) => ReflectKind::Enum,
+    }
+}
@@ -0,0 +1,61 @@
+/// Builds a [`DynamicStruct`](crate::ops::DynamicStruct) from field names and values.
+///
+/// Each value is inserted with [`DynamicStruct::insert`](crate::ops::DynamicStruct::insert),
+/// so it must implement [`Reflect`](crate::Reflect). Nested dynamic values can be built with
+/// `dyn_struct!` and [`dyn_enum!`](crate::dyn_enum) as well.
+///
+/// # Example
+///
+/// ```ignore
+/// let value = dyn_struct! {
+///     health: 10_u32,
+///     name: String::from("bob"),
+///     position: dyn_struct! { x: 1_i32, y: 2_i32 },
+/// };
+/// assert_eq!(value.field_len(), 3);
+/// ```
+#[macro_export]
+macro_rules! dyn_struct {
+    ($($name:ident : $value:expr),* $(,)?) => {{
+        #[allow(unused_mut)]
+        let mut dynamic_struct = $crate::ops::DynamicStruct::new();
+        $(
+            dynamic_struct.insert(::core::stringify!($name), $value);
+        )*
+        dynamic_struct
+    }};
+}
+
+/// Builds a [`DynamicEnum`](crate::ops::DynamicEnum) from a variant,
+/// written like a unit, tuple or struct variant.
+///
+/// Field values must implement [`Reflect`](crate::Reflect), see [`dyn_struct!`](crate::dyn_struct).
+///
+/// # Example
+///
+/// ```ignore
+/// let none = dyn_enum!(None);
+/// let some = dyn_enum!(Some(10_u32));
+/// let moved = dyn_enum!(Move { x: 1_i32, y: 2_i32 });
+/// assert_eq!(some.variant_name(), "Some");
+/// ```
+#[macro_export]
+macro_rules! dyn_enum {
+    ($variant:ident) => {
+        $crate::ops::DynamicEnum::new(::core::stringify!($variant), ())
+    };
+    ($variant:ident ( $($value:expr),* $(,)? )) => {{
+        #[allow(unused_mut)]
+        let mut dynamic_tuple = $crate::ops::DynamicTuple::new();
+        $(
+            dynamic_tuple.insert($value);
+        )*
+        $crate::ops::DynamicEnum::new(::core::stringify!($variant), dynamic_tuple)
+    }};
+    ($variant:ident { $($name:ident : $value:expr),* $(,)? }) => {
+        $crate::ops::DynamicEnum::new(
+            ::core::stringify!($variant),
+            $crate::dyn_struct! { $($name : $value),* },
+        )
+    };
+}
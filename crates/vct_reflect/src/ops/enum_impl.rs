@@ -14,12 +14,14 @@ use crate::{
     cell::NonGenericTypeInfoCell,
     info::{EnumInfo, OpaqueInfo, ReflectKind, TypeInfo, TypePath, Typed, VariantKind},
     ops::{
-        ApplyError, DynamicStruct, DynamicTuple, DynamicVariant, ReflectMut, ReflectOwned,
-        ReflectRef, Struct, Tuple, VariantFieldIter,
+        ApplyError, ConcreteError, DynamicStruct, DynamicTuple, DynamicVariant, ReflectCloneError,
+        ReflectMut, ReflectOwned, ReflectRef, Struct, Tuple, VariantFieldIter,
     },
     reflect::impl_cast_reflect_fn,
     reflect_hasher,
+    registry::{TypeRegistry, TypeTraitFromReflect},
 };
+use vct_utils::CowStr;
 
 /// Representing [`Enum`]`, used to dynamically modify the type of data and information.
 ///
@@ -181,7 +183,7 @@ impl DynamicEnum {
                 DynamicVariant::Unit,
             ),
             VariantKind::Tuple => {
-                let mut data = DynamicTuple::new();
+                let mut data = DynamicTuple::with_capacity(value.field_len());
                 for field in value.iter_fields() {
                     data.insert_boxed(field.value().to_dynamic());
                 }
@@ -192,10 +194,10 @@ impl DynamicEnum {
                 )
             }
             VariantKind::Struct => {
-                let mut data = DynamicStruct::new();
+                let mut data = DynamicStruct::with_capacity(value.field_len());
                 for field in value.iter_fields() {
                     let name = field.name().unwrap();
-                    data.insert_boxed(name.to_owned(), field.value().to_dynamic());
+                    data.insert_boxed(CowStr::new(name), field.value().to_dynamic());
                 }
                 DynamicEnum::new_with_index(
                     value.variant_index(),
@@ -208,6 +210,29 @@ impl DynamicEnum {
         dyn_enum.set_type_info(type_info);
         dyn_enum
     }
+
+    /// Converts this value into the concrete enum it represents.
+    ///
+    /// Uses the represented [`TypeInfo`] to look up the [`TypeTraitFromReflect`] in `registry`.
+    pub fn try_into_concrete(
+        &self,
+        registry: &TypeRegistry,
+    ) -> Result<Box<dyn Reflect>, ConcreteError> {
+        let info = self.enum_info.ok_or(ConcreteError::MissingTypeInfo)?;
+        let type_path = || Cow::Borrowed(info.type_path());
+
+        let from_reflect = registry
+            .get_type_trait::<TypeTraitFromReflect>(info.type_id())
+            .ok_or_else(|| ConcreteError::MissingFromReflect {
+                type_path: type_path(),
+            })?;
+
+        from_reflect
+            .from_reflect(self)
+            .ok_or_else(|| ConcreteError::FromReflectFailed {
+                type_path: type_path(),
+            })
+    }
 }
 
 impl Reflect for DynamicEnum {
@@ -251,14 +276,18 @@ impl Reflect for DynamicEnum {
                     for y_field in y.iter_fields() {
                         let name = y_field.name().unwrap();
                         if let Some(field) = self.field_mut(name) {
-                            field.try_apply(y_field.value())?;
+                            field
+                                .try_apply(y_field.value())
+                                .map_err(|err| err.with_field_name(name))?;
                         }
                     }
                 }
                 VariantKind::Tuple => {
                     for (index, y_field) in y.iter_fields().enumerate() {
                         if let Some(field) = self.field_at_mut(index) {
-                            field.try_apply(y_field.value())?;
+                            field
+                                .try_apply(y_field.value())
+                                .map_err(|err| err.with_tuple_index(index))?;
                         }
                     }
                 }
@@ -268,17 +297,17 @@ impl Reflect for DynamicEnum {
             let dyn_variant = match y.variant_kind() {
                 VariantKind::Unit => DynamicVariant::Unit,
                 VariantKind::Tuple => {
-                    let mut dyn_tuple = DynamicTuple::new();
+                    let mut dyn_tuple = DynamicTuple::with_capacity(y.field_len());
                     for y_field in y.iter_fields() {
                         dyn_tuple.insert_boxed(y_field.value().to_dynamic());
                     }
                     DynamicVariant::Tuple(dyn_tuple)
                 }
                 VariantKind::Struct => {
-                    let mut dyn_struct = DynamicStruct::new();
+                    let mut dyn_struct = DynamicStruct::with_capacity(y.field_len());
                     for y_field in y.iter_fields() {
                         dyn_struct.insert_boxed(
-                            y_field.name().unwrap().to_owned(),
+                            CowStr::new(y_field.name().unwrap()),
                             y_field.value().to_dynamic(),
                         );
                     }
@@ -290,6 +319,25 @@ impl Reflect for DynamicEnum {
         Ok(())
     }
 
+    #[inline]
+    fn try_apply_owned(&mut self, value: Box<dyn Reflect>) -> Result<(), ApplyError> {
+        enum_try_apply_owned(self, value)
+    }
+
+    fn reflect_clone(&self) -> Result<Box<dyn Reflect>, ReflectCloneError> {
+        let variant = match &self.variant {
+            DynamicVariant::Unit => DynamicVariant::Unit,
+            DynamicVariant::Tuple(data) => DynamicVariant::Tuple(data.reflect_clone_dynamic()?),
+            DynamicVariant::Struct(data) => DynamicVariant::Struct(data.reflect_clone_dynamic()?),
+        };
+        Ok(Box::new(DynamicEnum {
+            enum_info: self.enum_info,
+            variant_index: self.variant_index,
+            variant_name: self.variant_name.clone(),
+            variant,
+        }))
+    }
+
     #[inline]
     fn reflect_partial_eq(&self, other: &dyn Reflect) -> Option<bool> {
         enum_partial_eq(self, other)
@@ -505,6 +553,43 @@ impl Enum for DynamicEnum {
     }
 }
 
+/// A function used to assist in the implementation of `try_apply_owned`
+///
+/// Avoid compilation overhead when implementing multiple types.
+#[inline(never)]
+pub fn enum_try_apply_owned(x: &mut dyn Enum, y: Box<dyn Reflect>) -> Result<(), ApplyError> {
+    // Only a `DynamicEnum` of the same variant can give up its fields,
+    // other values are applied by reference.
+    let y = match y.take::<DynamicEnum>() {
+        Ok(y) if y.variant_name == x.variant_name() => y,
+        Ok(y) => return x.try_apply(&y),
+        Err(y) => return x.try_apply(&*y),
+    };
+
+    match y.variant {
+        DynamicVariant::Struct(data) => {
+            for (name, y_field) in data.into_named_fields() {
+                if let Some(field) = x.field_mut(&name) {
+                    field
+                        .try_apply_owned(y_field)
+                        .map_err(|err| err.with_field_name(&name))?;
+                }
+            }
+        }
+        DynamicVariant::Tuple(data) => {
+            for (index, y_field) in data.into_iter().enumerate() {
+                if let Some(field) = x.field_at_mut(index) {
+                    field
+                        .try_apply_owned(y_field)
+                        .map_err(|err| err.with_tuple_index(index))?;
+                }
+            }
+        }
+        DynamicVariant::Unit => {}
+    }
+    Ok(())
+}
+
 /// A function used to assist in the implementation of `reflect_partial_eq`
 ///
 /// Avoid compilation overhead when implementing multiple types.
@@ -2,14 +2,16 @@ use crate::{
     Reflect,
     cell::NonGenericTypeInfoCell,
     info::{ListInfo, OpaqueInfo, ReflectKind, TypeInfo, TypePath, Typed},
-    ops::{ApplyError, ReflectMut, ReflectOwned, ReflectRef},
+    ops::{ApplyError, ReflectCloneError, ReflectMut, ReflectOwned, ReflectRef},
     reflect::impl_cast_reflect_fn,
     reflect_hasher,
 };
 use alloc::{boxed::Box, vec::Vec};
 use core::{
+    cmp::Ordering,
     fmt,
     hash::{Hash, Hasher},
+    ops::Range,
 };
 
 /// Represents a [`List`], used to dynamically modify data and its reflected type information.
@@ -77,6 +79,18 @@ impl DynamicList {
         }
     }
 
+    /// See [`Vec::reserve`]
+    #[inline]
+    pub fn reserve(&mut self, additional: usize) {
+        self.values.reserve(additional);
+    }
+
+    /// See [`Vec::shrink_to_fit`]
+    #[inline]
+    pub fn shrink_to_fit(&mut self) {
+        self.values.shrink_to_fit();
+    }
+
     /// Sets the [`TypeInfo`] to be represented by this `DynamicList`.
     ///
     /// # Panic
@@ -147,16 +161,37 @@ impl Reflect for DynamicList {
         list_try_apply(self, value)
     }
 
+    #[inline]
+    fn try_apply_owned(&mut self, value: Box<dyn Reflect>) -> Result<(), ApplyError> {
+        list_try_apply_owned(self, value)
+    }
+
     #[inline]
     fn reflect_hash(&self) -> Option<u64> {
         list_hash(self)
     }
 
+    fn reflect_clone(&self) -> Result<Box<dyn Reflect>, ReflectCloneError> {
+        Ok(Box::new(DynamicList {
+            list_info: self.list_info,
+            values: self
+                .values
+                .iter()
+                .map(|value| value.reflect_clone())
+                .collect::<Result<_, _>>()?,
+        }))
+    }
+
     #[inline]
     fn reflect_partial_eq(&self, other: &dyn Reflect) -> Option<bool> {
         list_partial_eq(self, other)
     }
 
+    #[inline]
+    fn reflect_partial_cmp(&self, other: &dyn Reflect) -> Option<Ordering> {
+        list_partial_cmp(self, other)
+    }
+
     #[inline]
     fn reflect_debug(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "DynamicList(")?;
@@ -253,6 +288,39 @@ pub trait List: Reflect {
         }
     }
 
+    /// Appends all elements of `values` to the _back_ of the list.
+    #[inline]
+    fn extend_boxed(&mut self, values: &mut dyn Iterator<Item = Box<dyn Reflect>>) {
+        values.for_each(|value| self.push(value));
+    }
+
+    /// Replaces the elements in `range` with `values`, returning the removed elements.
+    ///
+    /// `values` does not need to have the same length as `range`.
+    ///
+    /// # Panics
+    /// Panics if the start of the range is greater than the end,
+    /// or if the end of the range is greater than `len`.
+    fn splice(
+        &mut self,
+        range: Range<usize>,
+        values: Vec<Box<dyn Reflect>>,
+    ) -> Vec<Box<dyn Reflect>> {
+        assert!(
+            range.start <= range.end && range.end <= self.len(),
+            "splice range {}..{} is out of bounds for list of length {}",
+            range.start,
+            range.end,
+            self.len(),
+        );
+
+        let removed = range.clone().map(|_| self.remove(range.start)).collect();
+        for (offset, value) in values.into_iter().enumerate() {
+            self.insert(range.start + offset, value);
+        }
+        removed
+    }
+
     /// Returns the number of elements in the list.
     fn len(&self) -> usize;
 
@@ -302,15 +370,31 @@ pub trait List: Reflect {
     }
 }
 
+/// An iterator over the elements of a [`List`].
 pub struct ListItemIter<'a> {
-    list: &'a dyn List,
-    index: usize,
+    inner: ListItemIterInner<'a>,
+}
+
+enum ListItemIterInner<'a> {
+    Indexed { list: &'a dyn List, index: usize },
+    Items(Box<dyn ExactSizeIterator<Item = &'a dyn Reflect> + 'a>),
 }
 
-impl ListItemIter<'_> {
+impl<'a> ListItemIter<'a> {
+    /// Iterates over `list` by [`List::get`].
     #[inline(always)]
-    pub fn new(list: &dyn List) -> ListItemIter<'_> {
-        ListItemIter { list, index: 0 }
+    pub fn new(list: &'a dyn List) -> Self {
+        ListItemIter {
+            inner: ListItemIterInner::Indexed { list, index: 0 },
+        }
+    }
+
+    /// Iterates over `items`, for lists whose indexed access is not constant time.
+    #[inline]
+    pub fn from_items(items: impl ExactSizeIterator<Item = &'a dyn Reflect> + 'a) -> Self {
+        ListItemIter {
+            inner: ListItemIterInner::Items(Box::new(items)),
+        }
     }
 }
 
@@ -319,15 +403,25 @@ impl<'a> Iterator for ListItemIter<'a> {
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        let value = self.list.get(self.index);
-        self.index += value.is_some() as usize;
-        value
+        match &mut self.inner {
+            ListItemIterInner::Indexed { list, index } => {
+                let value = list.get(*index);
+                *index += value.is_some() as usize;
+                value
+            }
+            ListItemIterInner::Items(items) => items.next(),
+        }
     }
 
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let size = self.list.len();
-        (size - self.index, Some(size))
+        match &self.inner {
+            ListItemIterInner::Indexed { list, index } => {
+                let size = list.len() - index;
+                (size, Some(size))
+            }
+            ListItemIterInner::Items(items) => items.size_hint(),
+        }
     }
 }
 
@@ -364,6 +458,20 @@ impl List for DynamicList {
         self.values.pop()
     }
 
+    #[inline]
+    fn extend_boxed(&mut self, values: &mut dyn Iterator<Item = Box<dyn Reflect>>) {
+        self.values.extend(values);
+    }
+
+    #[inline]
+    fn splice(
+        &mut self,
+        range: Range<usize>,
+        values: Vec<Box<dyn Reflect>>,
+    ) -> Vec<Box<dyn Reflect>> {
+        self.values.splice(range, values).collect()
+    }
+
     #[inline]
     fn len(&self) -> usize {
         self.values.len()
@@ -400,7 +508,8 @@ pub fn list_try_apply(x: &mut dyn List, y: &dyn Reflect) -> Result<(), ApplyErro
     for (idx, y_item) in y.iter().enumerate() {
         if idx < x.len() {
             if let Some(item) = x.get_mut(idx) {
-                item.try_apply(y_item)?;
+                item.try_apply(y_item)
+                    .map_err(|err| err.with_list_index(idx))?;
             }
         } else {
             x.push(y_item.to_dynamic());
@@ -409,6 +518,26 @@ pub fn list_try_apply(x: &mut dyn List, y: &dyn Reflect) -> Result<(), ApplyErro
     Ok(())
 }
 
+/// A function used to assist in the implementation of `try_apply_owned`
+///
+/// Avoid compilation overhead when implementing multiple types.
+#[inline(never)]
+pub fn list_try_apply_owned(x: &mut dyn List, y: Box<dyn Reflect>) -> Result<(), ApplyError> {
+    let mut y = y.reflect_owned().into_list()?;
+
+    for (idx, y_item) in y.drain().into_iter().enumerate() {
+        if idx < x.len() {
+            if let Some(item) = x.get_mut(idx) {
+                item.try_apply_owned(y_item)
+                    .map_err(|err| err.with_list_index(idx))?;
+            }
+        } else {
+            x.push(y_item);
+        }
+    }
+    Ok(())
+}
+
 /// A function used to assist in the implementation of `reflect_partial_eq`
 ///
 /// Avoid compilation overhead when implementing multiple types.
@@ -432,6 +561,26 @@ pub fn list_partial_eq(x: &dyn List, y: &dyn Reflect) -> Option<bool> {
     Some(true)
 }
 
+/// A function used to assist in the implementation of `reflect_partial_cmp`
+///
+/// Compares lexicographically, like the ordering of slices.
+///
+/// Avoid compilation overhead when implementing multiple types.
+#[inline(never)]
+pub fn list_partial_cmp(x: &dyn List, y: &dyn Reflect) -> Option<Ordering> {
+    let ReflectRef::List(y) = y.reflect_ref() else {
+        return None;
+    };
+
+    for (x_value, y_value) in x.iter().zip(y.iter()) {
+        match x_value.reflect_partial_cmp(y_value)? {
+            Ordering::Equal => {}
+            ordering => return Some(ordering),
+        }
+    }
+    Some(x.len().cmp(&y.len()))
+}
+
 /// A function used to assist in the implementation of `reflect_partial_eq`
 ///
 /// Avoid compilation overhead when implementing multiple types.
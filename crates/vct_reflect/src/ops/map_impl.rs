@@ -2,7 +2,7 @@ use crate::{
     Reflect,
     cell::NonGenericTypeInfoCell,
     info::{MapInfo, OpaqueInfo, ReflectKind, TypeInfo, TypePath, Typed},
-    ops::{ApplyError, ReflectMut, ReflectOwned, ReflectRef},
+    ops::{ApplyError, ReflectCloneError, ReflectMut, ReflectOwned, ReflectRef},
     reflect::impl_cast_reflect_fn,
 };
 use alloc::{boxed::Box, format, vec::Vec};
@@ -70,6 +70,20 @@ impl DynamicMap {
         }
     }
 
+    /// See [`HashTable::reserve`]
+    #[inline]
+    pub fn reserve(&mut self, additional: usize) {
+        self.hash_table
+            .reserve(additional, |(key, _)| Self::internal_hash(key.as_ref()));
+    }
+
+    /// See [`HashTable::shrink_to_fit`]
+    #[inline]
+    pub fn shrink_to_fit(&mut self) {
+        self.hash_table
+            .shrink_to_fit(|(key, _)| Self::internal_hash(key.as_ref()));
+    }
+
     /// Sets the [`TypeInfo`] to be represented by this `DynamicMap`.
     ///
     /// # Panic
@@ -95,7 +109,12 @@ impl DynamicMap {
         self.insert_boxed(Box::new(key), Box::new(value));
     }
 
-    fn internal_hash(value: &dyn Reflect) -> u64 {
+    /// Hashes a key with [`Reflect::reflect_hash`], also used by [`DynamicSet`](crate::ops::DynamicSet).
+    ///
+    /// # Panic
+    ///
+    /// If the value does not support hashing.
+    pub(crate) fn internal_hash(value: &dyn Reflect) -> u64 {
         value.reflect_hash().expect(&{
             let type_path = (value).reflect_type_path();
             if !value.is_dynamic() {
@@ -144,6 +163,11 @@ impl Reflect for DynamicMap {
         map_try_apply(self, value)
     }
 
+    #[inline]
+    fn try_apply_owned(&mut self, value: Box<dyn Reflect>) -> Result<(), ApplyError> {
+        map_try_apply_owned(self, value)
+    }
+
     #[inline]
     fn reflect_kind(&self) -> ReflectKind {
         ReflectKind::Map
@@ -164,6 +188,15 @@ impl Reflect for DynamicMap {
         ReflectOwned::Map(self)
     }
 
+    fn reflect_clone(&self) -> Result<Box<dyn Reflect>, ReflectCloneError> {
+        let mut map = DynamicMap::with_capacity(self.len());
+        map.set_type_info(self.map_info);
+        for (key, value) in self.hash_table.iter() {
+            map.insert_boxed(key.reflect_clone()?, value.reflect_clone()?);
+        }
+        Ok(Box::new(map))
+    }
+
     #[inline]
     fn reflect_partial_eq(&self, other: &dyn Reflect) -> Option<bool> {
         map_partial_eq(self, other)
@@ -186,8 +219,9 @@ impl fmt::Debug for DynamicMap {
 
 impl FromIterator<(Box<dyn Reflect>, Box<dyn Reflect>)> for DynamicMap {
     fn from_iter<I: IntoIterator<Item = (Box<dyn Reflect>, Box<dyn Reflect>)>>(items: I) -> Self {
-        let mut this = DynamicMap::new();
-        for (key, value) in items.into_iter() {
+        let items = items.into_iter();
+        let mut this = DynamicMap::with_capacity(items.size_hint().0);
+        for (key, value) in items {
             this.insert_boxed(key, value);
         }
         this
@@ -196,8 +230,9 @@ impl FromIterator<(Box<dyn Reflect>, Box<dyn Reflect>)> for DynamicMap {
 
 impl<K: Reflect, V: Reflect> FromIterator<(K, V)> for DynamicMap {
     fn from_iter<I: IntoIterator<Item = (K, V)>>(items: I) -> Self {
-        let mut this = DynamicMap::new();
-        for (key, value) in items.into_iter() {
+        let items = items.into_iter();
+        let mut this = DynamicMap::with_capacity(items.size_hint().0);
+        for (key, value) in items {
             this.insert(key, value);
         }
         this
@@ -269,7 +304,7 @@ pub trait Map: Reflect {
 
     /// Creates a new [`DynamicMap`] from this map.
     fn to_dynamic_map(&self) -> DynamicMap {
-        let mut map = DynamicMap::new();
+        let mut map = DynamicMap::with_capacity(self.len());
         map.set_type_info(self.represented_type_info());
         for (key, value) in self.iter() {
             map.insert_boxed(key.to_dynamic(), value.to_dynamic());
@@ -293,6 +328,41 @@ pub trait Map: Reflect {
     /// If the map did have this key present, the removed value is returned.
     fn remove(&mut self, key: &dyn Reflect) -> Option<Box<dyn Reflect>>;
 
+    /// Returns a mutable reference to the value associated with the given key,
+    /// inserting the value returned by `f` first if the key is not present.
+    ///
+    /// The default implementation looks up the key twice,
+    /// and clones it with [`Reflect::to_dynamic`] when inserting.
+    fn get_or_insert_with_boxed(
+        &mut self,
+        key: Box<dyn Reflect>,
+        f: &mut dyn FnMut() -> Box<dyn Reflect>,
+    ) -> &mut dyn Reflect {
+        if self.get(&*key).is_none() {
+            let lookup = key.to_dynamic();
+            self.insert_boxed(key, f());
+            return self
+                .get_mut(&*lookup)
+                .expect("the value was inserted just now");
+        }
+        self.get_mut(&*key)
+            .expect("the value was checked to be present")
+    }
+
+    /// Calls `f` on the value associated with the given key.
+    ///
+    /// Returns `false` if the key is not present, `f` is not called.
+    #[inline]
+    fn update(&mut self, key: &dyn Reflect, f: &mut dyn FnMut(&mut dyn Reflect)) -> bool {
+        match self.get_mut(key) {
+            Some(value) => {
+                f(value);
+                true
+            }
+            None => false,
+        }
+    }
+
     /// Get actual [`MapInfo`] of underlying types.
     ///
     /// If it is a dynamic type, it will return `None`.
@@ -389,6 +459,29 @@ impl Map for DynamicMap {
         }
     }
 
+    fn get_or_insert_with_boxed(
+        &mut self,
+        key: Box<dyn Reflect>,
+        f: &mut dyn FnMut() -> Box<dyn Reflect>,
+    ) -> &mut dyn Reflect {
+        let hash = Self::internal_hash(&*key);
+        let entry = self.hash_table.entry(hash, Self::internal_eq(&*key), |(key, _)| {
+            Self::internal_hash(&**key)
+        });
+        let (_, value) = match entry {
+            hash_table::Entry::Occupied(entry) => entry.into_mut(),
+            hash_table::Entry::Vacant(entry) => {
+                assert_eq!(
+                    key.reflect_partial_eq(&*key),
+                    Some(true),
+                    "keys inserted in `Map`-like types are expected to reflect `PartialEq`"
+                );
+                entry.insert((key, f())).into_mut()
+            }
+        };
+        &mut **value
+    }
+
     #[inline]
     fn reflect_map_info(&self) -> Option<&'static MapInfo> {
         None
@@ -418,6 +511,24 @@ pub fn map_try_apply(x: &mut dyn Map, y: &dyn Reflect) -> Result<(), ApplyError>
     Ok(())
 }
 
+/// A function used to assist in the implementation of `try_apply_owned`
+///
+/// Avoid compilation overhead when implementing multiple types.
+#[inline(never)]
+pub fn map_try_apply_owned(x: &mut dyn Map, y: Box<dyn Reflect>) -> Result<(), ApplyError> {
+    let mut y = y.reflect_owned().into_map()?;
+    x.retain(&mut |key, _| y.get(key).is_some());
+
+    for (key, y_val) in y.drain() {
+        if let Some(x_val) = x.get_mut(&*key) {
+            x_val.try_apply_owned(y_val)?;
+        } else {
+            x.insert_boxed(key, y_val);
+        }
+    }
+    Ok(())
+}
+
 /// A function used to assist in the implementation of `reflect_partial_eq`
 ///
 /// Avoid compilation overhead when implementing multiple types.
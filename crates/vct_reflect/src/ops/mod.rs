@@ -4,45 +4,81 @@ pub use apply_error::ApplyError;
 mod clone_error;
 pub use clone_error::ReflectCloneError;
 
+mod clone_into;
+pub(crate) use clone_into::reflect_clone_into; // Only used for `Reflect::reflect_clone_into`
+
+mod concrete_error;
+pub use concrete_error::ConcreteError;
+
 mod kind;
 pub use kind::{ReflectMut, ReflectOwned, ReflectRef};
 
 mod struct_impl;
 pub(crate) use struct_impl::struct_debug; // Only used for `PartialReflect::reflect_debug`
-pub use struct_impl::{DynamicStruct, GetStructField, Struct, StructFieldIter, struct_partial_eq};
+pub use struct_impl::{
+    DynamicStruct, GetStructField, Struct, StructFieldIter, struct_partial_eq, struct_try_apply_owned,
+};
 
 mod tuple_struct_impl;
 pub(crate) use tuple_struct_impl::tuple_struct_debug; // Only used for `PartialReflect::reflect_debug`
 pub use tuple_struct_impl::{
     DynamicTupleStruct, GetTupleStructField, TupleStruct, TupleStructFieldIter,
-    tuple_struct_partial_eq,
+    tuple_struct_partial_eq, tuple_struct_try_apply_owned,
 };
 
 mod tuple_impl;
 pub(crate) use tuple_impl::{tuple_debug, tuple_hash}; // Only used for `PartialReflect::reflect_debug`
 pub use tuple_impl::{
-    DynamicTuple, GetTupleField, Tuple, TupleFieldIter, tuple_partial_eq, tuple_try_apply,
+    DynamicTuple, GetTupleField, Tuple, TupleFieldIter, tuple_partial_cmp, tuple_partial_eq,
+    tuple_try_apply, tuple_try_apply_owned,
 };
 
 mod list_impl;
 pub(crate) use list_impl::list_debug; // Only used for `PartialReflect::reflect_debug`
-pub use list_impl::{DynamicList, List, ListItemIter, list_partial_eq};
+pub use list_impl::{
+    DynamicList, List, ListItemIter, list_hash, list_partial_cmp, list_partial_eq, list_try_apply,
+    list_try_apply_owned,
+};
 
 mod array_impl;
-pub(crate) use array_impl::array_debug; // Only used for `PartialReflect::reflect_debug`
-pub use array_impl::{Array, ArrayItemIter, DynamicArray, array_partial_eq};
+pub(crate) use array_impl::{array_debug, array_hash}; // Only used for `PartialReflect::reflect_debug`
+pub use array_impl::{
+    Array, ArrayItemIter, DynamicArray, array_partial_cmp, array_partial_eq, array_try_apply,
+    array_try_apply_owned,
+};
 
 mod map_impl;
 pub(crate) use map_impl::map_debug; // Only used for `PartialReflect::reflect_debug`
-pub use map_impl::{DynamicMap, Map, map_partial_eq};
+pub use map_impl::{DynamicMap, Map, map_partial_eq, map_try_apply, map_try_apply_owned};
 
 mod set_impl;
 pub(crate) use set_impl::set_debug; // Only used for `PartialReflect::reflect_debug`
-pub use set_impl::{DynamicSet, Set, set_partial_eq};
+pub use set_impl::{DynamicSet, Set, set_partial_eq, set_try_apply, set_try_apply_owned};
 
 mod variant_impl;
 pub use variant_impl::{DynamicVariant, VariantField, VariantFieldIter};
 
 mod enum_impl;
 pub(crate) use enum_impl::enum_debug; // Only used for `PartialReflect::reflect_debug`
-pub use enum_impl::{DynamicEnum, Enum, enum_partial_eq};
+pub use enum_impl::{DynamicEnum, Enum, enum_partial_eq, enum_try_apply_owned};
+
+mod dynamic_macros;
+pub use crate::{dyn_enum, dyn_struct};
+
+mod diff;
+pub use diff::{Diff, DiffKind, ListDiff, MapDiff};
+
+mod size_of;
+pub use size_of::reflect_size_of;
+
+mod numeric;
+pub use numeric::{as_f64, as_i64, try_apply_numeric};
+
+mod deep_eq;
+pub use deep_eq::{MismatchReport, reflect_deep_eq};
+
+mod visitor;
+pub use visitor::{ReflectVisitor, walk};
+
+mod pretty_print;
+pub use pretty_print::{PrettyPrintOptions, reflect_pretty_print};
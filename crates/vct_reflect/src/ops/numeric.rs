@@ -0,0 +1,159 @@
+use alloc::borrow::{Cow, ToOwned};
+
+use crate::{Reflect, ops::ApplyError};
+
+/// Calls `$mac!` with all primitive integer types.
+macro_rules! integer_types {
+    ($mac:ident) => {
+        $mac!(
+            u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+        )
+    };
+}
+
+/// Calls `$mac!` with all primitive float types.
+macro_rules! float_types {
+    ($mac:ident) => {
+        $mac!(f32, f64)
+    };
+}
+
+/// Reads a primitive number as `f64`.
+///
+/// Returns `None` if `value` is not a primitive integer or float.
+/// Integers beyond `2^53` are rounded to the nearest representable value.
+pub fn as_f64(value: &dyn Reflect) -> Option<f64> {
+    macro_rules! cast {
+        ($($ty:ty),*) => {
+            $(
+                if let Some(&number) = value.downcast_ref::<$ty>() {
+                    return Some(number as f64);
+                }
+            )*
+        };
+    }
+
+    integer_types!(cast);
+    float_types!(cast);
+    None
+}
+
+/// Reads a primitive number as `i64`.
+///
+/// Returns `None` if `value` is not a primitive integer or float,
+/// or if it cannot be represented exactly, e.g. `u64::MAX` or `0.5`.
+pub fn as_i64(value: &dyn Reflect) -> Option<i64> {
+    macro_rules! cast_integer {
+        ($($ty:ty),*) => {
+            $(
+                if let Some(&number) = value.downcast_ref::<$ty>() {
+                    return i64::try_from(number).ok();
+                }
+            )*
+        };
+    }
+
+    integer_types!(cast_integer);
+    let number = as_f64(value)?;
+    let integer = number as i64;
+    // `i64::MAX as f64` is `2^63`, which saturates back to `i64::MAX`.
+    (integer as f64 == number && integer != i64::MAX).then_some(integer)
+}
+
+/// Writes `number` into a primitive number, converting it to the type of `dst`.
+///
+/// Integers only accept integral values in their range,
+/// `f32` takes the nearest representable value.
+///
+/// # Errors
+/// - [`ApplyError::MismatchedTypes`] if `dst` is not a primitive integer or float.
+/// - [`ApplyError::NumericOutOfRange`] if `number` cannot be represented by an integer `dst`.
+pub fn try_apply_numeric(dst: &mut dyn Reflect, number: f64) -> Result<(), ApplyError> {
+    macro_rules! apply_integer {
+        ($($ty:ty),*) => {
+            $(
+                if let Some(dst) = dst.downcast_mut::<$ty>() {
+                    let integer = number as $ty;
+                    // Casts saturate, values at the bounds of wide integers may have been rounded.
+                    if integer as f64 != number
+                        || (integer == <$ty>::MAX && <$ty>::BITS >= f64::MANTISSA_DIGITS)
+                    {
+                        return Err(ApplyError::NumericOutOfRange {
+                            number,
+                            to_type: Cow::Borrowed(stringify!($ty)),
+                        });
+                    }
+                    *dst = integer;
+                    return Ok(());
+                }
+            )*
+        };
+    }
+
+    macro_rules! apply_float {
+        ($($ty:ty),*) => {
+            $(
+                if let Some(dst) = dst.downcast_mut::<$ty>() {
+                    *dst = number as $ty;
+                    return Ok(());
+                }
+            )*
+        };
+    }
+
+    integer_types!(apply_integer);
+    float_types!(apply_float);
+    Err(ApplyError::MismatchedTypes {
+        from_type: Cow::Borrowed("f64"),
+        to_type: Cow::Owned(dst.reflect_type_path().to_owned()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{as_f64, as_i64, try_apply_numeric};
+    use crate::ops::ApplyError;
+
+    #[test]
+    fn float_reads() {
+        assert_eq!(as_f64(&1.5f32), Some(1.5));
+        assert_eq!(as_f64(&-2.25f64), Some(-2.25));
+        assert_eq!(as_f64(&7u8), Some(7.0));
+        assert_eq!(as_f64(&true), None);
+
+        assert_eq!(as_i64(&3.0f32), Some(3));
+        assert_eq!(as_i64(&-4.0f64), Some(-4));
+        assert_eq!(as_i64(&0.5f64), None);
+        assert_eq!(as_i64(&f64::NAN), None);
+        assert_eq!(as_i64(&9.3e18f64), None);
+    }
+
+    #[test]
+    fn float_writes() {
+        let mut single = 0.0f32;
+        try_apply_numeric(&mut single, 0.1).unwrap();
+        assert_eq!(single, 0.1f32);
+
+        let mut double = 0.0f64;
+        try_apply_numeric(&mut double, -1.5).unwrap();
+        assert_eq!(double, -1.5);
+
+        let mut integer = 0u8;
+        try_apply_numeric(&mut integer, 200.0).unwrap();
+        assert_eq!(integer, 200);
+        assert!(matches!(
+            try_apply_numeric(&mut integer, 0.5),
+            Err(ApplyError::NumericOutOfRange { .. })
+        ));
+        assert!(matches!(
+            try_apply_numeric(&mut integer, 256.0),
+            Err(ApplyError::NumericOutOfRange { .. })
+        ));
+
+        let mut flag = false;
+        assert!(matches!(
+            try_apply_numeric(&mut flag, 1.0),
+            Err(ApplyError::MismatchedTypes { .. })
+        ));
+    }
+}
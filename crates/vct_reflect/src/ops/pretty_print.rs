@@ -0,0 +1,282 @@
+use core::fmt::{self, Write};
+
+use crate::{
+    Reflect,
+    info::{TypeInfo, VariantKind},
+    ops::ReflectRef,
+};
+
+/// Options of [`reflect_pretty_print`].
+#[derive(Clone, Copy, Debug)]
+pub struct PrettyPrintOptions {
+    indent: usize,
+    max_items: Option<usize>,
+    max_depth: Option<usize>,
+    type_annotations: bool,
+}
+
+impl PrettyPrintOptions {
+    /// Creates the default options: 4 spaces of indentation, type annotations and no limits.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            indent: 4,
+            max_items: None,
+            max_depth: None,
+            type_annotations: true,
+        }
+    }
+
+    /// Sets the number of spaces used for each indentation level.
+    #[inline]
+    pub const fn with_indent(mut self, indent: usize) -> Self {
+        self.indent = indent;
+        self
+    }
+
+    /// Sets the maximum number of fields or items printed for each value.
+    ///
+    /// The remaining ones are summarized as `... N more`.
+    #[inline]
+    pub const fn with_max_items(mut self, max_items: usize) -> Self {
+        self.max_items = Some(max_items);
+        self
+    }
+
+    /// Sets the maximum nesting depth printed, the root value is at depth `0`.
+    ///
+    /// The content of values at this depth is replaced by `..`.
+    #[inline]
+    pub const fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = Some(max_depth);
+        self
+    }
+
+    /// Sets whether each value is preceded by its type path.
+    #[inline]
+    pub const fn with_type_annotations(mut self, type_annotations: bool) -> Self {
+        self.type_annotations = type_annotations;
+        self
+    }
+}
+
+impl Default for PrettyPrintOptions {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Writes a reflected value as an indented, type-annotated tree.
+///
+/// Each value is preceded by its type path, opaque values are written with
+/// [`Reflect::reflect_debug`]. Long or deep values can be truncated with the `options`.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// let mut output = String::new();
+/// reflect_pretty_print(&foo, &mut output, PrettyPrintOptions::new().with_max_items(8))?;
+/// // my_crate::Foo {
+/// //     id: u32 7,
+/// //     tags: [u8; 2] [
+/// //         u8 1,
+/// //         u8 2,
+/// //     ],
+/// // }
+/// ```
+pub fn reflect_pretty_print(
+    value: &dyn Reflect,
+    writer: &mut impl Write,
+    options: PrettyPrintOptions,
+) -> fmt::Result {
+    PrettyPrinter { writer, options }.value(value, 0)
+}
+
+struct PrettyPrinter<'a> {
+    writer: &'a mut dyn Write,
+    options: PrettyPrintOptions,
+}
+
+impl PrettyPrinter<'_> {
+    fn type_path(&mut self, value: &dyn Reflect) -> fmt::Result {
+        if self.options.type_annotations {
+            let type_path = value
+                .represented_type_info()
+                .map(TypeInfo::type_path)
+                .unwrap_or_else(|| value.reflect_type_path());
+            self.writer.write_str(type_path)?;
+        }
+        Ok(())
+    }
+
+    fn space(&mut self) -> fmt::Result {
+        if self.options.type_annotations {
+            self.writer.write_char(' ')?;
+        }
+        Ok(())
+    }
+
+    fn indent(&mut self, depth: usize) -> fmt::Result {
+        for _ in 0..depth * self.options.indent {
+            self.writer.write_char(' ')?;
+        }
+        Ok(())
+    }
+
+    /// Not inline: recursive function.
+    fn value(&mut self, value: &dyn Reflect, depth: usize) -> fmt::Result {
+        self.type_path(value)?;
+        match value.reflect_ref() {
+            ReflectRef::Struct(value) => {
+                self.space()?;
+                let fields = (0..value.field_len())
+                    .filter_map(|index| Some((value.name_at(index)?, value.field_at(index)?)));
+                self.block(
+                    ("{", "}"),
+                    value.field_len(),
+                    fields,
+                    depth,
+                    |this, (name, field)| {
+                        write!(this.writer, "{name}: ")?;
+                        this.value(field, depth + 1)
+                    },
+                )
+            }
+            ReflectRef::TupleStruct(value) => self.block(
+                ("(", ")"),
+                value.field_len(),
+                value.iter_fields(),
+                depth,
+                |this, field| this.value(field, depth + 1),
+            ),
+            ReflectRef::Tuple(value) => {
+                self.space()?;
+                self.block(
+                    ("(", ")"),
+                    value.field_len(),
+                    value.iter_fields(),
+                    depth,
+                    |this, field| this.value(field, depth + 1),
+                )
+            }
+            ReflectRef::List(value) => {
+                self.space()?;
+                self.block(
+                    ("[", "]"),
+                    value.len(),
+                    value.iter(),
+                    depth,
+                    |this, item| this.value(item, depth + 1),
+                )
+            }
+            ReflectRef::Array(value) => {
+                self.space()?;
+                self.block(
+                    ("[", "]"),
+                    value.len(),
+                    value.iter(),
+                    depth,
+                    |this, item| this.value(item, depth + 1),
+                )
+            }
+            ReflectRef::Set(value) => {
+                self.space()?;
+                self.block(
+                    ("{", "}"),
+                    value.len(),
+                    value.iter(),
+                    depth,
+                    |this, item| this.value(item, depth + 1),
+                )
+            }
+            ReflectRef::Map(value) => {
+                self.space()?;
+                self.block(
+                    ("{", "}"),
+                    value.len(),
+                    value.iter(),
+                    depth,
+                    |this, (key, item)| {
+                        this.value(key, depth + 1)?;
+                        this.writer.write_str(": ")?;
+                        this.value(item, depth + 1)
+                    },
+                )
+            }
+            ReflectRef::Enum(value) => {
+                if self.options.type_annotations {
+                    self.writer.write_str("::")?;
+                }
+                self.writer.write_str(value.variant_name())?;
+                match value.variant_kind() {
+                    VariantKind::Struct => {
+                        self.writer.write_char(' ')?;
+                        self.block(
+                            ("{", "}"),
+                            value.field_len(),
+                            value.iter_fields(),
+                            depth,
+                            |this, field| {
+                                write!(this.writer, "{}: ", field.name().unwrap_or_default())?;
+                                this.value(field.value(), depth + 1)
+                            },
+                        )
+                    }
+                    VariantKind::Tuple => self.block(
+                        ("(", ")"),
+                        value.field_len(),
+                        value.iter_fields(),
+                        depth,
+                        |this, field| this.value(field.value(), depth + 1),
+                    ),
+                    VariantKind::Unit => Ok(()),
+                }
+            }
+            ReflectRef::Opaque(value) => {
+                self.space()?;
+                write!(self.writer, "{value:?}")
+            }
+        }
+    }
+
+    /// Writes the `entries` of a value between the `open` and `close` delimiters,
+    /// one per line.
+    fn block<T>(
+        &mut self,
+        (open, close): (&str, &str),
+        len: usize,
+        entries: impl Iterator<Item = T>,
+        depth: usize,
+        mut entry: impl FnMut(&mut Self, T) -> fmt::Result,
+    ) -> fmt::Result {
+        self.writer.write_str(open)?;
+        if len == 0 {
+            return self.writer.write_str(close);
+        }
+        if self
+            .options
+            .max_depth
+            .is_some_and(|max_depth| depth >= max_depth)
+        {
+            return write!(self.writer, " .. {close}");
+        }
+
+        self.writer.write_char('\n')?;
+        let shown = self
+            .options
+            .max_items
+            .map_or(len, |max_items| max_items.min(len));
+        for item in entries.take(shown) {
+            self.indent(depth + 1)?;
+            entry(self, item)?;
+            self.writer.write_str(",\n")?;
+        }
+        if shown < len {
+            self.indent(depth + 1)?;
+            writeln!(self.writer, "... {} more", len - shown)?;
+        }
+        self.indent(depth)?;
+        self.writer.write_str(close)
+    }
+}
@@ -2,10 +2,10 @@ use crate::{
     Reflect,
     cell::NonGenericTypeInfoCell,
     info::{OpaqueInfo, ReflectKind, SetInfo, TypeInfo, TypePath, Typed},
-    ops::{ApplyError, ReflectMut, ReflectOwned, ReflectRef},
+    ops::{ApplyError, DynamicMap, ReflectCloneError, ReflectMut, ReflectOwned, ReflectRef},
     reflect::impl_cast_reflect_fn,
 };
-use alloc::{boxed::Box, format, vec::Vec};
+use alloc::{boxed::Box, vec::Vec};
 use core::fmt;
 use vct_utils::collections::{HashTable, hash_table};
 
@@ -74,6 +74,20 @@ impl DynamicSet {
         }
     }
 
+    /// See [`HashTable::reserve`]
+    #[inline]
+    pub fn reserve(&mut self, additional: usize) {
+        self.hash_table
+            .reserve(additional, |value| DynamicMap::internal_hash(value.as_ref()));
+    }
+
+    /// See [`HashTable::shrink_to_fit`]
+    #[inline]
+    pub fn shrink_to_fit(&mut self) {
+        self.hash_table
+            .shrink_to_fit(|value| DynamicMap::internal_hash(value.as_ref()));
+    }
+
     /// Sets the [`TypeInfo`] to be represented by this `DynamicSet`.
     ///
     /// # Panic
@@ -99,27 +113,6 @@ impl DynamicSet {
         self.insert_boxed(Box::new(value));
     }
 
-    fn internal_hash(value: &dyn Reflect) -> u64 {
-        value.reflect_hash().expect(&{
-            let type_path = (value).reflect_type_path();
-            if !value.is_dynamic() {
-                format!(
-                    "the given value of type `{}` does not support hashing",
-                    type_path
-                )
-            } else {
-                match value.represented_type_info() {
-                    None => format!("the dynamic type `{}` does not support hashing", type_path),
-                    Some(target) => format!(
-                        "the dynamic type `{}` (target: `{}`) does not support hashing",
-                        type_path,
-                        target.type_path(),
-                    ),
-                }
-            }
-        })
-    }
-
     fn internal_eq(value: &dyn Reflect) -> impl FnMut(&Box<dyn Reflect>) -> bool + '_ {
         |other| {
             value
@@ -147,6 +140,11 @@ impl Reflect for DynamicSet {
         set_try_apply(self, value)
     }
 
+    #[inline]
+    fn try_apply_owned(&mut self, value: Box<dyn Reflect>) -> Result<(), ApplyError> {
+        set_try_apply_owned(self, value)
+    }
+
     #[inline]
     fn reflect_kind(&self) -> ReflectKind {
         ReflectKind::Set
@@ -167,6 +165,15 @@ impl Reflect for DynamicSet {
         ReflectOwned::Set(self)
     }
 
+    fn reflect_clone(&self) -> Result<Box<dyn Reflect>, ReflectCloneError> {
+        let mut set = DynamicSet::with_capacity(self.len());
+        set.set_type_info(self.set_info);
+        for value in self.hash_table.iter() {
+            set.insert_boxed(value.reflect_clone()?);
+        }
+        Ok(Box::new(set))
+    }
+
     #[inline]
     fn reflect_partial_eq(&self, other: &dyn Reflect) -> Option<bool> {
         set_partial_eq(self, other)
@@ -189,7 +196,8 @@ impl fmt::Debug for DynamicSet {
 
 impl FromIterator<Box<dyn Reflect>> for DynamicSet {
     fn from_iter<I: IntoIterator<Item = Box<dyn Reflect>>>(values: I) -> Self {
-        let mut this = DynamicSet::new();
+        let values = values.into_iter();
+        let mut this = DynamicSet::with_capacity(values.size_hint().0);
 
         for value in values {
             this.insert_boxed(value);
@@ -201,7 +209,8 @@ impl FromIterator<Box<dyn Reflect>> for DynamicSet {
 
 impl<T: Reflect> FromIterator<T> for DynamicSet {
     fn from_iter<I: IntoIterator<Item = T>>(values: I) -> Self {
-        let mut this = DynamicSet::new();
+        let values = values.into_iter();
+        let mut this = DynamicSet::with_capacity(values.size_hint().0);
 
         for value in values {
             this.insert(value);
@@ -269,7 +278,7 @@ pub trait Set: Reflect {
 
     /// Creates a new [`DynamicSet`] from this set.
     fn to_dynamic_set(&self) -> DynamicSet {
-        let mut set = DynamicSet::new();
+        let mut set = DynamicSet::with_capacity(self.len());
         set.set_type_info(self.represented_type_info());
         for value in self.iter() {
             set.insert_boxed(value.to_dynamic());
@@ -292,6 +301,43 @@ pub trait Set: Reflect {
     /// Checks if the given value is contained in the set
     fn contains(&self, value: &dyn Reflect) -> bool;
 
+    /// Returns a [`DynamicSet`] of the values in `self` or `other`.
+    ///
+    /// The result represents the type of `self`.
+    fn reflect_union(&self, other: &dyn Set) -> DynamicSet {
+        let mut set = self.to_dynamic_set();
+        for value in other.iter() {
+            if !set.contains(value) {
+                set.insert_boxed(value.to_dynamic());
+            }
+        }
+        set
+    }
+
+    /// Returns a [`DynamicSet`] of the values in both `self` and `other`.
+    ///
+    /// The result represents the type of `self`.
+    fn reflect_intersection(&self, other: &dyn Set) -> DynamicSet {
+        let mut set = DynamicSet::new();
+        set.set_type_info(self.represented_type_info());
+        for value in self.iter().filter(|value| other.contains(*value)) {
+            set.insert_boxed(value.to_dynamic());
+        }
+        set
+    }
+
+    /// Returns a [`DynamicSet`] of the values in `self` but not in `other`.
+    ///
+    /// The result represents the type of `self`.
+    fn reflect_difference(&self, other: &dyn Set) -> DynamicSet {
+        let mut set = DynamicSet::new();
+        set.set_type_info(self.represented_type_info());
+        for value in self.iter().filter(|value| !other.contains(*value)) {
+            set.insert_boxed(value.to_dynamic());
+        }
+        set
+    }
+
     /// Get actual [`SetInfo`] of underlying types.
     ///
     /// If it is a dynamic type, it will return `None`.
@@ -317,7 +363,7 @@ impl Set for DynamicSet {
     #[inline]
     fn get(&self, value: &dyn Reflect) -> Option<&dyn Reflect> {
         self.hash_table
-            .find(Self::internal_hash(value), Self::internal_eq(value))
+            .find(DynamicMap::internal_hash(value), Self::internal_eq(value))
             .map(|value| &**value)
     }
 
@@ -349,7 +395,7 @@ impl Set for DynamicSet {
         );
         match self
             .hash_table
-            .find_mut(Self::internal_hash(&*value), Self::internal_eq(&*value))
+            .find_mut(DynamicMap::internal_hash(&*value), Self::internal_eq(&*value))
         {
             Some(old) => {
                 *old = value;
@@ -357,9 +403,9 @@ impl Set for DynamicSet {
             }
             None => {
                 self.hash_table.insert_unique(
-                    Self::internal_hash(value.as_ref()),
+                    DynamicMap::internal_hash(value.as_ref()),
                     value,
-                    |boxed| Self::internal_hash(boxed.as_ref()),
+                    |boxed| DynamicMap::internal_hash(boxed.as_ref()),
                 );
                 true
             }
@@ -369,7 +415,7 @@ impl Set for DynamicSet {
     #[inline]
     fn remove(&mut self, value: &dyn Reflect) -> bool {
         self.hash_table
-            .find_entry(Self::internal_hash(value), Self::internal_eq(value))
+            .find_entry(DynamicMap::internal_hash(value), Self::internal_eq(value))
             .map(hash_table::OccupiedEntry::remove)
             .is_ok()
     }
@@ -377,7 +423,7 @@ impl Set for DynamicSet {
     #[inline]
     fn contains(&self, value: &dyn Reflect) -> bool {
         self.hash_table
-            .find(Self::internal_hash(value), Self::internal_eq(value))
+            .find(DynamicMap::internal_hash(value), Self::internal_eq(value))
             .is_some()
     }
 }
@@ -398,6 +444,22 @@ pub fn set_try_apply(x: &mut dyn Set, y: &dyn Reflect) -> Result<(), ApplyError>
     Ok(())
 }
 
+/// A function used to assist in the implementation of `try_apply_owned`
+///
+/// Avoid compilation overhead when implementing multiple types.
+#[inline(never)]
+pub fn set_try_apply_owned(x: &mut dyn Set, y: Box<dyn Reflect>) -> Result<(), ApplyError> {
+    let mut y = y.reflect_owned().into_set()?;
+    x.retain(&mut |val| y.contains(val));
+
+    for y_val in y.drain() {
+        if !x.contains(&*y_val) {
+            x.insert_boxed(y_val);
+        }
+    }
+    Ok(())
+}
+
 /// A function used to assist in the implementation of `reflect_partial_eq`
 ///
 /// Avoid compilation overhead when implementing multiple types.
@@ -0,0 +1,45 @@
+use alloc::string::String;
+
+use crate::{Reflect, ops::ReflectRef};
+
+/// Estimates the memory footprint of a reflected value in bytes.
+///
+/// The result is the inline size of the value, taken from the [`TypeInfo`] layout where available,
+/// plus the heap memory owned by it: the capacity of [`String`]s and
+/// the footprint of each item of lists, sets and maps.
+///
+/// This is an estimate, the unused capacity of collections and
+/// the heap memory of other opaque types are not counted.
+///
+/// [`TypeInfo`]: crate::info::TypeInfo
+pub fn reflect_size_of(value: &dyn Reflect) -> usize {
+    let inline_size = value
+        .reflect_type_info()
+        .size_of()
+        .unwrap_or_else(|| size_of_val(value));
+    inline_size + heap_size_of(value)
+}
+
+/// Not inline: recursive function.
+fn heap_size_of(value: &dyn Reflect) -> usize {
+    match value.reflect_ref() {
+        ReflectRef::Struct(value) => value.iter_fields().map(heap_size_of).sum(),
+        ReflectRef::TupleStruct(value) => value.iter_fields().map(heap_size_of).sum(),
+        ReflectRef::Tuple(value) => value.iter_fields().map(heap_size_of).sum(),
+        ReflectRef::Array(value) => value.iter().map(heap_size_of).sum(),
+        ReflectRef::Enum(value) => value
+            .iter_fields()
+            .map(|field| heap_size_of(field.value()))
+            .sum(),
+        ReflectRef::List(value) => value.iter().map(reflect_size_of).sum(),
+        ReflectRef::Set(value) => value.iter().map(reflect_size_of).sum(),
+        ReflectRef::Map(value) => value
+            .iter()
+            .map(|(key, value)| reflect_size_of(key) + reflect_size_of(value))
+            .sum(),
+        ReflectRef::Opaque(value) => match value.downcast_ref::<String>() {
+            Some(string) => string.capacity(),
+            None => 0,
+        },
+    }
+}
@@ -2,13 +2,12 @@ use crate::{
     Reflect,
     cell::NonGenericTypeInfoCell,
     info::{OpaqueInfo, ReflectKind, StructInfo, TypeInfo, TypePath, Typed},
-    ops::{ApplyError, ReflectMut, ReflectOwned, ReflectRef},
+    ops::{ApplyError, ReflectCloneError, ReflectMut, ReflectOwned, ReflectRef},
     reflect::impl_cast_reflect_fn,
 };
-use alloc::{borrow::Cow, boxed::Box, vec::Vec};
+use alloc::{boxed::Box, vec::Vec};
 use core::fmt;
-use std::borrow::ToOwned;
-use vct_utils::collections::HashMap;
+use vct_utils::{CowStr, collections::HashMap};
 
 /// Represents a [`Struct`], used to dynamically modify data and its reflected type information.
 ///
@@ -20,8 +19,8 @@ use vct_utils::collections::HashMap;
 pub struct DynamicStruct {
     struct_info: Option<&'static TypeInfo>,
     fields: Vec<Box<dyn Reflect>>,
-    field_names: Vec<Cow<'static, str>>,
-    field_indices: HashMap<Cow<'static, str>, usize>,
+    field_names: Vec<CowStr>,
+    field_indices: HashMap<CowStr, usize>,
 }
 
 impl TypePath for DynamicStruct {
@@ -81,6 +80,21 @@ impl DynamicStruct {
         }
     }
 
+    /// See [`Vec::reserve`]
+    #[inline]
+    pub fn reserve(&mut self, additional: usize) {
+        self.fields.reserve(additional);
+        self.field_names.reserve(additional);
+        self.field_indices.reserve(additional);
+    }
+
+    /// See [`Vec::shrink_to_fit`]
+    #[inline]
+    pub fn shrink_to_fit(&mut self) {
+        self.fields.shrink_to_fit();
+        self.field_names.shrink_to_fit();
+        self.field_indices.shrink_to_fit();
+    }
 
     /// Sets the [`StructInfo`] to be represented by this `DynamicStruct`.
     #[inline]
@@ -97,11 +111,31 @@ impl DynamicStruct {
         self.struct_info = struct_info;
     }
 
+    /// Clones each field with [`Reflect::reflect_clone`], keeping the represented type info.
+    pub(crate) fn reflect_clone_dynamic(&self) -> Result<Self, ReflectCloneError> {
+        Ok(Self {
+            struct_info: self.struct_info,
+            fields: self
+                .fields
+                .iter()
+                .map(|field| field.reflect_clone())
+                .collect::<Result<_, _>>()?,
+            field_names: self.field_names.clone(),
+            field_indices: self.field_indices.clone(),
+        })
+    }
+
+    /// Consumes the struct, returning its field names and values in order.
+    #[inline]
+    pub(crate) fn into_named_fields(self) -> impl Iterator<Item = (CowStr, Box<dyn Reflect>)> {
+        self.field_names.into_iter().zip(self.fields)
+    }
+
     /// Inserts a field named `name` with value `value` into the struct.
     ///
     /// If the field already exists, it is overwritten.
-    pub fn insert_boxed(&mut self, name: impl Into<Cow<'static, str>>, value: Box<dyn Reflect>) {
-        let name: Cow<'static, str> = name.into();
+    pub fn insert_boxed(&mut self, name: impl Into<CowStr>, value: Box<dyn Reflect>) {
+        let name: CowStr = name.into();
         if let Some(index) = self.field_indices.get(&name) {
             self.fields[*index] = value;
         } else {
@@ -116,7 +150,7 @@ impl DynamicStruct {
     ///
     /// If the field already exists, it is overwritten.
     #[inline]
-    pub fn insert<'a, T: Reflect>(&mut self, name: impl Into<Cow<'static, str>>, value: T) {
+    pub fn insert<'a, T: Reflect>(&mut self, name: impl Into<CowStr>, value: T) {
         self.insert_boxed(name, Box::new(value));
     }
 
@@ -145,6 +179,11 @@ impl Reflect for DynamicStruct {
         struct_try_apply(self, value)
     }
 
+    #[inline]
+    fn try_apply_owned(&mut self, value: Box<dyn Reflect>) -> Result<(), ApplyError> {
+        struct_try_apply_owned(self, value)
+    }
+
     #[inline]
     fn reflect_kind(&self) -> ReflectKind {
         ReflectKind::Struct
@@ -165,6 +204,11 @@ impl Reflect for DynamicStruct {
         ReflectOwned::Struct(self)
     }
 
+    #[inline]
+    fn reflect_clone(&self) -> Result<Box<dyn Reflect>, ReflectCloneError> {
+        Ok(Box::new(self.reflect_clone_dynamic()?))
+    }
+
     #[inline]
     fn reflect_partial_eq(&self, other: &dyn Reflect) -> Option<bool> {
         struct_partial_eq(self, other)
@@ -185,10 +229,11 @@ impl fmt::Debug for DynamicStruct {
     }
 }
 
-impl<'a, N: Into<Cow<'static, str>>> FromIterator<(N, Box<dyn Reflect>)> for DynamicStruct {
+impl<'a, N: Into<CowStr>> FromIterator<(N, Box<dyn Reflect>)> for DynamicStruct {
     fn from_iter<T: IntoIterator<Item = (N, Box<dyn Reflect>)>>(fields: T) -> Self {
-        let mut dynamic_struct = DynamicStruct::new();
-        for (name, value) in fields.into_iter() {
+        let fields = fields.into_iter();
+        let mut dynamic_struct = DynamicStruct::with_capacity(fields.size_hint().0);
+        for (name, value) in fields {
             dynamic_struct.insert_boxed(name, value);
         }
         dynamic_struct
@@ -249,7 +294,7 @@ pub trait Struct: Reflect {
         let mut dynamic_struct = DynamicStruct::with_capacity(self.field_len());
         dynamic_struct.set_type_info(self.represented_type_info());
         for (i, val) in self.iter_fields().enumerate() {
-            dynamic_struct.insert_boxed(self.name_at(i).unwrap().to_owned(), val.to_dynamic());
+            dynamic_struct.insert_boxed(CowStr::new(self.name_at(i).unwrap()), val.to_dynamic());
         }
         dynamic_struct
     }
@@ -405,7 +450,30 @@ pub fn struct_try_apply(x: &mut dyn Struct, y: &dyn Reflect) -> Result<(), Apply
     for (idx, y_field) in y.iter_fields().enumerate() {
         let name = y.name_at(idx).unwrap();
         if let Some(field) = x.field_mut(name) {
-            field.try_apply(y_field)?;
+            field
+                .try_apply(y_field)
+                .map_err(|err| err.with_field_name(name))?;
+        }
+    }
+    Ok(())
+}
+
+/// A function used to assist in the implementation of `try_apply_owned`
+///
+/// Avoid compilation overhead when implementing multiple types.
+#[inline(never)]
+pub fn struct_try_apply_owned(x: &mut dyn Struct, y: Box<dyn Reflect>) -> Result<(), ApplyError> {
+    // Only a `DynamicStruct` can give up its fields, other values are applied by reference.
+    let y = match y.take::<DynamicStruct>() {
+        Ok(y) => y,
+        Err(y) => return struct_try_apply(x, &*y),
+    };
+
+    for (name, y_field) in y.into_named_fields() {
+        if let Some(field) = x.field_mut(&name) {
+            field
+                .try_apply_owned(y_field)
+                .map_err(|err| err.with_field_name(&name))?;
         }
     }
     Ok(())
@@ -460,3 +528,139 @@ pub(crate) fn struct_debug(dyn_struct: &dyn Struct, f: &mut fmt::Formatter<'_>)
     }
     debug.finish()
 }
+
+#[cfg(test)]
+mod tests {
+    use alloc::{string::String, vec::Vec};
+    use serde::de::DeserializeSeed;
+    use vct_reflect_derive::Reflect;
+
+    use super::{DynamicStruct, Struct};
+    use crate::{
+        FromReflect, Reflect,
+        info::Typed,
+        registry::TypeRegistry,
+        serde::{InternalDeserializer, InternalSerializer},
+    };
+
+    #[derive(Reflect, PartialEq, Debug)]
+    struct Position {
+        x: u32,
+        y: u32,
+    }
+
+    #[derive(Reflect, PartialEq, Debug)]
+    struct Entity {
+        name: String,
+        #[reflect(flatten)]
+        position: Position,
+        id: u32,
+    }
+
+    #[derive(Reflect, PartialEq, Debug)]
+    #[reflect(rename_all = "SCREAMING_SNAKE_CASE")]
+    struct Stats {
+        max_health: u32,
+    }
+
+    #[derive(Reflect, PartialEq, Debug)]
+    #[reflect(rename_all = "camelCase")]
+    struct Player {
+        display_name: String,
+        #[reflect(rename = "id")]
+        entity_id: u32,
+        #[reflect(flatten)]
+        base_stats: Stats,
+    }
+
+    fn entity() -> Entity {
+        Entity {
+            name: String::from("a"),
+            position: Position { x: 2, y: 3 },
+            id: 1,
+        }
+    }
+
+    fn names(value: &dyn Struct) -> Vec<&str> {
+        (0..value.field_len())
+            .map(|index| value.name_at(index).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn flatten_fields() {
+        let mut entity = entity();
+        assert_eq!(names(&entity), ["name", "id", "x", "y"]);
+        let info = Entity::type_info().as_struct().unwrap();
+        assert_eq!(info.field_names(), ["name", "id", "x", "y"]);
+
+        assert_eq!(entity.field("x").unwrap().downcast_ref::<u32>(), Some(&2));
+        assert_eq!(entity.field_at(3).unwrap().downcast_ref::<u32>(), Some(&3));
+        let fields: Vec<u32> = entity
+            .iter_fields()
+            .skip(1)
+            .map(|field| *field.downcast_ref::<u32>().unwrap())
+            .collect();
+        assert_eq!(fields, [1, 2, 3]);
+
+        *entity
+            .field_mut("y")
+            .unwrap()
+            .downcast_mut::<u32>()
+            .unwrap() = 5;
+        assert_eq!(entity.position.y, 5);
+    }
+
+    #[test]
+    fn flatten_dynamic() {
+        let dynamic = entity().to_dynamic_struct();
+        assert_eq!(names(&dynamic), ["name", "id", "x", "y"]);
+        assert_eq!(Entity::from_reflect(&dynamic), Some(entity()));
+
+        let mut patch = DynamicStruct::new();
+        patch.insert("x", 7u32);
+        patch.insert("id", 4u32);
+        let mut entity = entity();
+        entity.try_apply(&patch).unwrap();
+        assert_eq!(entity.position, Position { x: 7, y: 3 });
+        assert_eq!(entity.id, 4);
+    }
+
+    #[test]
+    fn flatten_serde() {
+        let mut registry = TypeRegistry::new();
+        registry.register::<Entity>();
+
+        let json = serde_json::to_string(&InternalSerializer::new(&entity(), &registry)).unwrap();
+        assert_eq!(json, r#"{"name":"a","id":1,"x":2,"y":3}"#);
+
+        let output = InternalDeserializer::of::<Entity>(&registry)
+            .deserialize(&mut serde_json::Deserializer::from_str(&json))
+            .unwrap();
+        assert_eq!(Entity::from_reflect(&*output), Some(entity()));
+    }
+
+    #[test]
+    fn rename_all() {
+        let player = Player {
+            display_name: String::from("a"),
+            entity_id: 1,
+            base_stats: Stats { max_health: 10 },
+        };
+        assert_eq!(names(&player), ["displayName", "id", "MAX_HEALTH"]);
+        let info = Player::type_info().as_struct().unwrap();
+        assert_eq!(info.field_names(), ["displayName", "id", "MAX_HEALTH"]);
+        assert!(player.field("display_name").is_none());
+        assert_eq!(player.field("id").unwrap().downcast_ref::<u32>(), Some(&1));
+
+        let mut registry = TypeRegistry::new();
+        registry.register::<Player>();
+        let json = serde_json::to_string(&InternalSerializer::new(&player, &registry)).unwrap();
+        assert_eq!(json, r#"{"displayName":"a","id":1,"MAX_HEALTH":10}"#);
+
+        let output = InternalDeserializer::of::<Player>(&registry)
+            .deserialize(&mut serde_json::Deserializer::from_str(&json))
+            .unwrap();
+        assert_eq!(Player::from_reflect(&*output), Some(player));
+    }
+}
@@ -2,11 +2,11 @@ use crate::{
     Reflect,
     cell::NonGenericTypeInfoCell,
     info::{OpaqueInfo, ReflectKind, TupleInfo, TypeInfo, TypePath, Typed},
-    ops::{ApplyError, ReflectMut, ReflectOwned, ReflectRef},
+    ops::{ApplyError, ReflectCloneError, ReflectMut, ReflectOwned, ReflectRef},
     reflect::impl_cast_reflect_fn,
 };
 use alloc::{boxed::Box, vec::Vec};
-use core::{fmt, hash::Hash};
+use core::{cmp::Ordering, fmt, hash::Hash};
 
 /// Represents a [`Tuple`], used to dynamically modify data and its reflected type information.
 ///
@@ -92,6 +92,18 @@ impl DynamicTuple {
         self.tuple_info = tuple_info;
     }
 
+    /// Clones each field with [`Reflect::reflect_clone`], keeping the represented type info.
+    pub(crate) fn reflect_clone_dynamic(&self) -> Result<Self, ReflectCloneError> {
+        Ok(Self {
+            tuple_info: self.tuple_info,
+            fields: self
+                .fields
+                .iter()
+                .map(|field| field.reflect_clone())
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
     /// Appends an element with value `value` to the tuple.
     #[inline]
     pub fn insert_boxed(&mut self, value: Box<dyn Reflect>) {
@@ -145,11 +157,26 @@ impl Reflect for DynamicTuple {
         tuple_try_apply(self, value)
     }
 
+    #[inline]
+    fn try_apply_owned(&mut self, value: Box<dyn Reflect>) -> Result<(), ApplyError> {
+        tuple_try_apply_owned(self, value)
+    }
+
+    #[inline]
+    fn reflect_clone(&self) -> Result<Box<dyn Reflect>, ReflectCloneError> {
+        Ok(Box::new(self.reflect_clone_dynamic()?))
+    }
+
     #[inline]
     fn reflect_partial_eq(&self, other: &dyn Reflect) -> Option<bool> {
         tuple_partial_eq(self, other)
     }
 
+    #[inline]
+    fn reflect_partial_cmp(&self, other: &dyn Reflect) -> Option<Ordering> {
+        tuple_partial_cmp(self, other)
+    }
+
     #[inline]
     fn reflect_debug(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "DynamicTuple(")?;
@@ -362,7 +389,27 @@ pub fn tuple_try_apply(x: &mut dyn Tuple, y: &dyn Reflect) -> Result<(), ApplyEr
 
     for (idx, y_field) in y.iter_fields().enumerate() {
         if let Some(field) = x.field_mut(idx) {
-            field.try_apply(y_field)?;
+            field
+                .try_apply(y_field)
+                .map_err(|err| err.with_tuple_index(idx))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// A function used to assist in the implementation of `try_apply_owned`
+///
+/// Avoid compilation overhead when implementing multiple types.
+#[inline(never)]
+pub fn tuple_try_apply_owned(x: &mut dyn Tuple, y: Box<dyn Reflect>) -> Result<(), ApplyError> {
+    let y = y.reflect_owned().into_tuple()?;
+
+    for (idx, y_field) in y.drain().into_iter().enumerate() {
+        if let Some(field) = x.field_mut(idx) {
+            field
+                .try_apply_owned(y_field)
+                .map_err(|err| err.with_tuple_index(idx))?;
         }
     }
 
@@ -391,6 +438,30 @@ pub fn tuple_partial_eq(x: &dyn Tuple, y: &dyn Reflect) -> Option<bool> {
     Some(true)
 }
 
+/// A function used to assist in the implementation of `reflect_partial_cmp`
+///
+/// Compares lexicographically, tuples with different lengths are not comparable.
+///
+/// Avoid compilation overhead when implementing multiple types.
+#[inline(never)]
+pub fn tuple_partial_cmp(x: &dyn Tuple, y: &dyn Reflect) -> Option<Ordering> {
+    let ReflectRef::Tuple(y) = y.reflect_ref() else {
+        return None;
+    };
+
+    if x.field_len() != y.field_len() {
+        return None;
+    }
+
+    for (x_value, y_value) in x.iter_fields().zip(y.iter_fields()) {
+        match x_value.reflect_partial_cmp(y_value)? {
+            Ordering::Equal => {}
+            ordering => return Some(ordering),
+        }
+    }
+    Some(Ordering::Equal)
+}
+
 /// A function used to assist in the implementation of `reflect_hash`
 ///
 /// Avoid compilation overhead when implementing multiple types.
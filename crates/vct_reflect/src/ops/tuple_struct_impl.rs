@@ -5,7 +5,7 @@ use crate::{
     Reflect,
     cell::NonGenericTypeInfoCell,
     info::{OpaqueInfo, ReflectKind, TupleStructInfo, TypeInfo, TypePath, Typed},
-    ops::{ApplyError, DynamicTuple, ReflectMut, ReflectOwned, ReflectRef, Tuple},
+    ops::{ApplyError, DynamicTuple, ReflectCloneError, ReflectMut, ReflectOwned, ReflectRef, Tuple},
     reflect::impl_cast_reflect_fn,
 };
 
@@ -130,6 +130,11 @@ impl Reflect for DynamicTupleStruct {
         tuple_try_apply(self, value)
     }
 
+    #[inline]
+    fn try_apply_owned(&mut self, value: Box<dyn Reflect>) -> Result<(), ApplyError> {
+        tuple_struct_try_apply_owned(self, value)
+    }
+
     #[inline]
     fn reflect_kind(&self) -> ReflectKind {
         ReflectKind::TupleStruct
@@ -150,6 +155,17 @@ impl Reflect for DynamicTupleStruct {
         ReflectOwned::TupleStruct(self)
     }
 
+    fn reflect_clone(&self) -> Result<Box<dyn Reflect>, ReflectCloneError> {
+        Ok(Box::new(DynamicTupleStruct {
+            tuple_struct_info: self.tuple_struct_info,
+            fields: self
+                .fields
+                .iter()
+                .map(|field| field.reflect_clone())
+                .collect::<Result<_, _>>()?,
+        }))
+    }
+
     #[inline]
     fn reflect_partial_eq(&self, other: &dyn Reflect) -> Option<bool> {
         tuple_struct_partial_eq(self, other)
@@ -349,7 +365,33 @@ pub fn tuple_try_apply(x: &mut dyn TupleStruct, y: &dyn Reflect) -> Result<(), A
 
     for (idx, y_field) in y.iter_fields().enumerate() {
         if let Some(field) = x.field_mut(idx) {
-            field.try_apply(y_field)?;
+            field
+                .try_apply(y_field)
+                .map_err(|err| err.with_tuple_index(idx))?;
+        }
+    }
+    Ok(())
+}
+
+/// A function used to assist in the implementation of `try_apply_owned`
+///
+/// Avoid compilation overhead when implementing multiple types.
+#[inline(never)]
+pub fn tuple_struct_try_apply_owned(
+    x: &mut dyn TupleStruct,
+    y: Box<dyn Reflect>,
+) -> Result<(), ApplyError> {
+    // Only a `DynamicTupleStruct` can give up its fields, other values are applied by reference.
+    let y = match y.take::<DynamicTupleStruct>() {
+        Ok(y) => y,
+        Err(y) => return tuple_try_apply(x, &*y),
+    };
+
+    for (idx, y_field) in y.into_iter().enumerate() {
+        if let Some(field) = x.field_mut(idx) {
+            field
+                .try_apply_owned(y_field)
+                .map_err(|err| err.with_tuple_index(idx))?;
         }
     }
     Ok(())
@@ -0,0 +1,189 @@
+use alloc::{borrow::Cow, vec::Vec};
+
+use crate::{
+    Reflect,
+    access::Accessor,
+    ops::{Enum, ReflectRef},
+};
+
+/// A visitor of reflected values, driven by [`walk`].
+///
+/// Every callback receives the access path from the root value,
+/// for fields and elements the path already ends with their own [`Accessor`].
+///
+/// All callbacks have an empty default implementation,
+/// so a visitor only needs to implement the ones it cares about.
+///
+/// # Example
+///
+/// ```ignore
+/// use vct_reflect::{Reflect, access::Accessor, ops::{ReflectVisitor, walk}};
+///
+/// struct CountOpaque(usize);
+///
+/// impl ReflectVisitor for CountOpaque {
+///     fn visit_opaque(&mut self, _path: &[Accessor<'_>], _value: &dyn Reflect) {
+///         self.0 += 1;
+///     }
+/// }
+///
+/// let mut visitor = CountOpaque(0);
+/// walk(&(1_u8, (2_u16, 3_u32)), &mut visitor);
+/// assert_eq!(visitor.0, 3);
+/// ```
+pub trait ReflectVisitor {
+    /// Called for every value before its children, including the root value.
+    ///
+    /// Returns `false` to skip the children of this value.
+    #[inline]
+    fn visit_value(&mut self, path: &[Accessor<'_>], value: &dyn Reflect) -> bool {
+        let _ = (path, value);
+        true
+    }
+
+    /// Called for each named field of a struct or struct variant.
+    #[inline]
+    fn visit_struct_field(&mut self, path: &[Accessor<'_>], name: &str, value: &dyn Reflect) {
+        let _ = (path, name, value);
+    }
+
+    /// Called for each field of a tuple, tuple struct or tuple variant.
+    #[inline]
+    fn visit_tuple_field(&mut self, path: &[Accessor<'_>], index: usize, value: &dyn Reflect) {
+        let _ = (path, index, value);
+    }
+
+    /// Called for each element of a list or array.
+    #[inline]
+    fn visit_list_element(&mut self, path: &[Accessor<'_>], index: usize, value: &dyn Reflect) {
+        let _ = (path, index, value);
+    }
+
+    /// Called for each entry of a map, `path` is the path of the map.
+    ///
+    /// [`Accessor`] cannot address map entries, so [`walk`] does not descend into them.
+    #[inline]
+    fn visit_map_entry(&mut self, path: &[Accessor<'_>], key: &dyn Reflect, value: &dyn Reflect) {
+        let _ = (path, key, value);
+    }
+
+    /// Called for each element of a set, `path` is the path of the set.
+    ///
+    /// [`Accessor`] cannot address set elements, so [`walk`] does not descend into them.
+    #[inline]
+    fn visit_set_element(&mut self, path: &[Accessor<'_>], value: &dyn Reflect) {
+        let _ = (path, value);
+    }
+
+    /// Called for an enum before the fields of its current variant.
+    #[inline]
+    fn visit_enum_variant(&mut self, path: &[Accessor<'_>], value: &dyn Enum) {
+        let _ = (path, value);
+    }
+
+    /// Called for an opaque value.
+    #[inline]
+    fn visit_opaque(&mut self, path: &[Accessor<'_>], value: &dyn Reflect) {
+        let _ = (path, value);
+    }
+}
+
+/// Walks a reflected value depth-first, invoking the callbacks of `visitor`.
+///
+/// See [`ReflectVisitor`] for the available callbacks.
+pub fn walk(value: &dyn Reflect, visitor: &mut impl ReflectVisitor) {
+    let mut path = Vec::new();
+    walk_value(value, visitor, &mut path);
+}
+
+/// Not inline: recursive function.
+fn walk_value<'a>(
+    value: &'a dyn Reflect,
+    visitor: &mut impl ReflectVisitor,
+    path: &mut Vec<Accessor<'a>>,
+) {
+    if !visitor.visit_value(path, value) {
+        return;
+    }
+
+    match value.reflect_ref() {
+        ReflectRef::Struct(value) => {
+            for (index, field) in value.iter_fields().enumerate() {
+                let name = value.name_at(index).unwrap();
+                path.push(Accessor::FieldName(Cow::Borrowed(name)));
+                visitor.visit_struct_field(path, name, field);
+                walk_value(field, visitor, path);
+                path.pop();
+            }
+        }
+        ReflectRef::TupleStruct(value) => {
+            for (index, field) in value.iter_fields().enumerate() {
+                walk_tuple_field(index, field, visitor, path);
+            }
+        }
+        ReflectRef::Tuple(value) => {
+            for (index, field) in value.iter_fields().enumerate() {
+                walk_tuple_field(index, field, visitor, path);
+            }
+        }
+        ReflectRef::List(value) => {
+            for (index, item) in value.iter().enumerate() {
+                walk_list_element(index, item, visitor, path);
+            }
+        }
+        ReflectRef::Array(value) => {
+            for (index, item) in value.iter().enumerate() {
+                walk_list_element(index, item, visitor, path);
+            }
+        }
+        ReflectRef::Map(value) => {
+            for (key, item) in value.iter() {
+                visitor.visit_map_entry(path, key, item);
+            }
+        }
+        ReflectRef::Set(value) => {
+            for item in value.iter() {
+                visitor.visit_set_element(path, item);
+            }
+        }
+        ReflectRef::Enum(value) => {
+            visitor.visit_enum_variant(path, value);
+            for (index, field) in value.iter_fields().enumerate() {
+                match field.name() {
+                    Some(name) => {
+                        path.push(Accessor::FieldName(Cow::Borrowed(name)));
+                        visitor.visit_struct_field(path, name, field.value());
+                        walk_value(field.value(), visitor, path);
+                        path.pop();
+                    }
+                    None => walk_tuple_field(index, field.value(), visitor, path),
+                }
+            }
+        }
+        ReflectRef::Opaque(value) => visitor.visit_opaque(path, value),
+    }
+}
+
+fn walk_tuple_field<'a>(
+    index: usize,
+    field: &'a dyn Reflect,
+    visitor: &mut impl ReflectVisitor,
+    path: &mut Vec<Accessor<'a>>,
+) {
+    path.push(Accessor::TupleIndex(index));
+    visitor.visit_tuple_field(path, index, field);
+    walk_value(field, visitor, path);
+    path.pop();
+}
+
+fn walk_list_element<'a>(
+    index: usize,
+    item: &'a dyn Reflect,
+    visitor: &mut impl ReflectVisitor,
+    path: &mut Vec<Accessor<'a>>,
+) {
+    path.push(Accessor::ListIndex(index));
+    visitor.visit_list_element(path, index, item);
+    walk_value(item, visitor, path);
+    path.pop();
+}
@@ -23,3 +23,63 @@ pub trait FromReflect: Reflect + Sized {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use vct_reflect_derive::Reflect;
+
+    use super::FromReflect;
+    use crate::ops::DynamicStruct;
+
+    /// Does not implement `Default`.
+    #[derive(Reflect, PartialEq, Debug)]
+    enum Mode {
+        Fast,
+        Slow,
+    }
+
+    fn default_retries() -> u32 {
+        3
+    }
+
+    fn default_mode() -> Mode {
+        Mode::Slow
+    }
+
+    #[derive(Reflect, PartialEq, Debug)]
+    struct Config {
+        level: u32,
+        #[reflect(default = "default_retries")]
+        retries: u32,
+        #[reflect(default = "default_mode")]
+        mode: Mode,
+        #[reflect(ignore, default = "default_retries")]
+        cached: u32,
+    }
+
+    #[test]
+    fn default_path() {
+        let mut input = DynamicStruct::new();
+        input.insert("level", 1u32);
+        let config = Config::from_reflect(&input).unwrap();
+        assert_eq!(
+            config,
+            Config {
+                level: 1,
+                retries: 3,
+                mode: Mode::Slow,
+                cached: 3,
+            }
+        );
+
+        input.insert("retries", 5u32);
+        input.insert("mode", Mode::Fast);
+        let config = Config::from_reflect(&input).unwrap();
+        assert_eq!((config.retries, config.mode), (5, Mode::Fast));
+        assert_eq!(config.cached, 3);
+
+        let mut missing = DynamicStruct::new();
+        missing.insert("retries", 5u32);
+        assert!(Config::from_reflect(&missing).is_none());
+    }
+}
@@ -0,0 +1,93 @@
+use alloc::boxed::Box;
+use core::hash::{BuildHasher, Hasher};
+use vct_os::sync::OnceLock;
+use vct_utils::hash::{DefaultHasher, FixedHash};
+
+static HASHER_BUILDER: OnceLock<fn() -> Box<dyn Hasher>> = OnceLock::new();
+
+/// Sets the function that creates the hasher returned by [`reflect_hasher`].
+///
+/// By default [`FixedHash`] is used, which is deterministic but not guaranteed
+/// to be stable across platforms or versions.
+///
+/// Can only be set once, returns `false` if a builder was already set.
+/// It must be set before any value is hashed, otherwise hashes already stored
+/// (e.g. inside a [`DynamicMap`]) become inconsistent.
+///
+/// [`DynamicMap`]: crate::ops::DynamicMap
+#[inline]
+pub fn set_reflect_hasher(builder: fn() -> Box<dyn Hasher>) -> bool {
+    HASHER_BUILDER.set(builder).is_ok()
+}
+
+/// The hasher used by [`Reflect::reflect_hash`](crate::Reflect::reflect_hash).
+///
+/// See [`reflect_hasher`] and [`set_reflect_hasher`].
+pub enum ReflectHasher {
+    /// The default [`FixedHash`] hasher.
+    Fixed(DefaultHasher<'static>),
+    /// A hasher created by the builder passed to [`set_reflect_hasher`].
+    Custom(Box<dyn Hasher>),
+}
+
+impl Hasher for ReflectHasher {
+    #[inline]
+    fn finish(&self) -> u64 {
+        match self {
+            Self::Fixed(hasher) => hasher.finish(),
+            Self::Custom(hasher) => hasher.finish(),
+        }
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        match self {
+            Self::Fixed(hasher) => hasher.write(bytes),
+            Self::Custom(hasher) => hasher.write(bytes),
+        }
+    }
+
+    #[inline]
+    fn write_u8(&mut self, i: u8) {
+        match self {
+            Self::Fixed(hasher) => hasher.write_u8(i),
+            Self::Custom(hasher) => hasher.write_u8(i),
+        }
+    }
+
+    #[inline]
+    fn write_u32(&mut self, i: u32) {
+        match self {
+            Self::Fixed(hasher) => hasher.write_u32(i),
+            Self::Custom(hasher) => hasher.write_u32(i),
+        }
+    }
+
+    #[inline]
+    fn write_u64(&mut self, i: u64) {
+        match self {
+            Self::Fixed(hasher) => hasher.write_u64(i),
+            Self::Custom(hasher) => hasher.write_u64(i),
+        }
+    }
+
+    #[inline]
+    fn write_usize(&mut self, i: usize) {
+        match self {
+            Self::Fixed(hasher) => hasher.write_usize(i),
+            Self::Custom(hasher) => hasher.write_usize(i),
+        }
+    }
+}
+
+/// Get the hasher used for reflected hashing.
+///
+/// Returns a [`FixedHash`] hasher unless another one was configured
+/// through [`set_reflect_hasher`].
+#[inline]
+pub fn reflect_hasher() -> ReflectHasher {
+    match HASHER_BUILDER.get() {
+        Some(builder) => ReflectHasher::Custom(builder()),
+        None => ReflectHasher::Fixed(FixedHash.build_hasher()),
+    }
+}
@@ -1,11 +1,5 @@
-use core::hash::BuildHasher;
-use vct_utils::hash::{DefaultHasher, FixedHash};
-
-/// Get Fixed Hasher
-#[inline(always)]
-pub fn reflect_hasher() -> DefaultHasher<'static> {
-    FixedHash.build_hasher()
-}
+mod hasher;
+pub use hasher::{ReflectHasher, reflect_hasher, set_reflect_hasher};
 
 mod reflect_impl;
 pub use reflect_impl::Reflect;
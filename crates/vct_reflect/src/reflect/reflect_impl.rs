@@ -6,6 +6,7 @@ use crate::{
         enum_debug, list_debug, map_debug, set_debug, struct_debug, tuple_debug,
         tuple_struct_debug,
     },
+    registry::{GetTypeTraits, TypeTraits},
 };
 use alloc::{
     borrow::{Cow, ToOwned},
@@ -13,7 +14,9 @@ use alloc::{
 };
 use core::{
     any::{Any, TypeId},
+    cmp::Ordering,
     fmt,
+    hash::Hasher,
 };
 
 pub trait Reflect: DynamicTypePath + DynamicTyped + Send + Sync + Any {
@@ -152,6 +155,17 @@ pub trait Reflect: DynamicTypePath + DynamicTyped + Send + Sync + Any {
     /// If `self` is a dynamic data type, clear it first (or start from an empty container).
     fn try_apply(&mut self, value: &dyn Reflect) -> Result<(), ApplyError>;
 
+    /// Applies an owned reflected value to this value.
+    ///
+    /// Behaves like [`try_apply`](Reflect::try_apply), but sub-values of `value` are moved
+    /// into `self` (via [`reflect_owned`](Reflect::reflect_owned)) instead of being cloned.
+    ///
+    /// The default implementation falls back to [`try_apply`](Reflect::try_apply).
+    #[inline]
+    fn try_apply_owned(&mut self, value: Box<dyn Reflect>) -> Result<(), ApplyError> {
+        self.try_apply(&*value)
+    }
+
     /// Applies a reflected value to this value.
     ///
     /// Usually not required to implement; defaults to using [`Reflect::try_apply`].
@@ -166,6 +180,7 @@ pub trait Reflect: DynamicTypePath + DynamicTyped + Send + Sync + Any {
     /// - If `Self` is an opaque type and `value` cannot be downcast to `Self`
     #[inline]
     fn apply(&mut self, value: &dyn Reflect) {
+        vct_utils::counter!("reflect.apply");
         Reflect::try_apply(self, value).unwrap();
     }
 
@@ -180,6 +195,21 @@ pub trait Reflect: DynamicTypePath + DynamicTyped + Send + Sync + Any {
         })
     }
 
+    /// Clones `self` into `dst`, which must be of the same type.
+    ///
+    /// Unlike [`reflect_clone`], this reuses the existing value instead of allocating a new one:
+    /// fields are cloned one by one and the items of lists are cloned in place.
+    /// Maps, sets, enums of a different variant and opaque types without `Clone`
+    /// fall back to replacing the value with its [`reflect_clone`].
+    ///
+    /// Ignored fields of `dst` are left untouched.
+    ///
+    /// [`reflect_clone`]: Reflect::reflect_clone
+    #[inline]
+    fn reflect_clone_into(&self, dst: &mut dyn Reflect) -> Result<(), ReflectCloneError> {
+        crate::ops::reflect_clone_into(self.as_reflect(), dst)
+    }
+
     /// Returns a "partial equality" comparison result.
     ///
     /// If the underlying type does not support equality testing, returns `None`.
@@ -189,6 +219,14 @@ pub trait Reflect: DynamicTypePath + DynamicTyped + Send + Sync + Any {
         None
     }
 
+    /// Returns an ordering between this value and `other`.
+    ///
+    /// If the underlying type does not support ordering, returns `None`.
+    #[inline]
+    fn reflect_partial_cmp(&self, _other: &dyn Reflect) -> Option<Ordering> {
+        None
+    }
+
     /// Returns a hash of the value (which includes the type).
     ///
     /// If the underlying type does not support hashing, returns `None`.
@@ -197,6 +235,16 @@ pub trait Reflect: DynamicTypePath + DynamicTyped + Send + Sync + Any {
         None
     }
 
+    /// Feeds the hash of the value into `state`,
+    /// allowing reflected values to be hashed with an external [`Hasher`].
+    ///
+    /// If the underlying type does not support hashing, returns `None` and leaves `state` untouched.
+    #[inline]
+    fn reflect_hash_with(&self, state: &mut dyn Hasher) -> Option<()> {
+        state.write_u64(self.reflect_hash()?);
+        Some(())
+    }
+
     /// Debug formatter for the value.
     ///
     /// Any value that is not an implementor of other `Reflect` subtraits
@@ -301,7 +349,15 @@ impl Typed for dyn Reflect {
     /// [`dyn Reflect`]: crate::Reflect
     fn type_info() -> &'static TypeInfo {
         static CELL: NonGenericTypeInfoCell = NonGenericTypeInfoCell::new();
-        CELL.get_or_init(|| TypeInfo::Opaque(OpaqueInfo::new::<Self>()))
+        CELL.get_or_init(|| TypeInfo::Opaque(OpaqueInfo::new_unsized::<Self>()))
+    }
+}
+
+/// Registered for `#[reflect(dynamic)]` fields, whose values are (de)serialized with their type path.
+impl GetTypeTraits for dyn Reflect {
+    #[inline]
+    fn get_type_traits() -> TypeTraits {
+        TypeTraits::of::<Self>()
     }
 }
 
@@ -331,3 +387,89 @@ macro_rules! impl_cast_reflect_fn {
 }
 
 pub(crate) use impl_cast_reflect_fn;
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::String;
+    use vct_reflect_derive::Reflect;
+
+    use crate::{Reflect, ops::ReflectCloneError};
+
+    /// A non-reflected value, such as a channel or a handle.
+    #[derive(PartialEq, Debug)]
+    struct Handle(u32);
+
+    /// Cloning a handle opens a new one.
+    fn reopen(handle: &Handle) -> Handle {
+        Handle(handle.0 + 1)
+    }
+
+    #[derive(Reflect, PartialEq, Debug)]
+    struct Connection {
+        name: String,
+        #[reflect(ignore, clone_with = "reopen")]
+        handle: Handle,
+    }
+
+    #[derive(Reflect, Default, PartialEq, Debug)]
+    #[reflect(default)]
+    struct Pooled {
+        size: u32,
+        #[reflect(ignore, clone_with = "Clone::clone")]
+        label: String,
+        #[reflect(ignore)]
+        cache: u32,
+    }
+
+    #[derive(Reflect, PartialEq, Debug)]
+    enum Source {
+        Remote(u32, #[reflect(ignore, clone_with = "reopen")] Handle),
+        Local,
+    }
+
+    #[derive(Reflect)]
+    struct Uncloneable {
+        #[reflect(ignore)]
+        _handle: Handle,
+    }
+
+    #[test]
+    fn clone_with() {
+        let connection = Connection {
+            name: String::from("a"),
+            handle: Handle(1),
+        };
+        let cloned = connection.reflect_clone().unwrap().take::<Connection>().unwrap();
+        assert_eq!(
+            cloned,
+            Connection {
+                name: String::from("a"),
+                handle: Handle(2),
+            }
+        );
+
+        let pooled = Pooled {
+            size: 4,
+            label: String::from("b"),
+            cache: 7,
+        };
+        let cloned = pooled.reflect_clone().unwrap().take::<Pooled>().unwrap();
+        assert_eq!(
+            cloned,
+            Pooled {
+                cache: 0,
+                ..pooled
+            }
+        );
+
+        let source = Source::Remote(3, Handle(5));
+        let cloned = source.reflect_clone().unwrap().take::<Source>().unwrap();
+        assert_eq!(cloned, Source::Remote(3, Handle(6)));
+
+        let uncloneable = Uncloneable { _handle: Handle(0) };
+        assert!(matches!(
+            uncloneable.reflect_clone(),
+            Err(ReflectCloneError::FieldNotCloneable { .. })
+        ));
+    }
+}
@@ -0,0 +1,154 @@
+use core::mem;
+use vct_os::sync::{Arc, PoisonError, RwLock};
+
+use crate::registry::TypeRegistry;
+#[cfg(doc)]
+use crate::{
+    registry::ReflectDefaultBuilder,
+    serde::{ReflectDeserializer, ReflectSerializer},
+};
+
+/// The error message when neither a registry nor a current registry is available.
+pub(crate) const NO_CURRENT_REGISTRY: &str =
+    "no registry was given and there is no current registry, see `TypeRegistry::scope`";
+
+vct_os::thread_local_cell! {
+    static CURRENT_REGISTRY: RwLock<Option<Arc<TypeRegistry>>> = RwLock::new(None);
+}
+
+/// Restores the previous current registry when a scope ends, even by unwinding.
+struct RestoreCurrent(Option<Arc<TypeRegistry>>);
+
+impl Drop for RestoreCurrent {
+    fn drop(&mut self) {
+        set_current(self.0.take());
+    }
+}
+
+fn set_current(registry: Option<Arc<TypeRegistry>>) -> Option<Arc<TypeRegistry>> {
+    CURRENT_REGISTRY.with(|current| {
+        mem::replace(
+            &mut *current.write().unwrap_or_else(PoisonError::into_inner),
+            registry,
+        )
+    })
+}
+
+impl TypeRegistry {
+    /// Runs `f` with this registry as the [current](Self::current) registry of the thread,
+    /// so code deep in the stack can use it without having it passed through every call.
+    ///
+    /// Scopes can be nested, the previous registry is restored when `f` returns.
+    ///
+    /// [`ReflectSerializer::current`], [`ReflectDeserializer::current`] and
+    /// [`ReflectDefaultBuilder::current`] use the current registry.
+    ///
+    /// Without `std`, there is a single current registry shared by every thread.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let registry = Arc::new(registry);
+    /// registry.scope(|_| {
+    ///     // Somewhere down the stack, e.g. in a `serde::Serialize` impl.
+    ///     serde_json::to_string(&ReflectSerializer::current(&value))
+    /// });
+    /// ```
+    pub fn scope<R>(self: &Arc<Self>, f: impl FnOnce(&Self) -> R) -> R {
+        let _restore = RestoreCurrent(set_current(Some(self.clone())));
+        f(self)
+    }
+
+    /// Returns the current registry of the thread, set by [`scope`](Self::scope).
+    #[inline]
+    pub fn current() -> Option<Arc<Self>> {
+        CURRENT_REGISTRY.with(|current| {
+            current
+                .read()
+                .unwrap_or_else(PoisonError::into_inner)
+                .clone()
+        })
+    }
+
+    /// Calls `f` with the current registry of the thread, set by [`scope`](Self::scope).
+    ///
+    /// Returns `None` if there is no current registry.
+    #[inline]
+    pub fn with_current<R>(f: impl FnOnce(&Self) -> R) -> Option<R> {
+        Self::current().map(|registry| f(&registry))
+    }
+
+    /// Calls `f` with `registry`, or with the current registry if it is `None`.
+    ///
+    /// Returns `None` if neither is available.
+    #[inline]
+    pub(crate) fn with_or_current<R>(
+        registry: Option<&Self>,
+        f: impl FnOnce(&Self) -> R,
+    ) -> Option<R> {
+        match registry {
+            Some(registry) => Some(f(registry)),
+            None => Self::with_current(f),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::String;
+    use serde::de::DeserializeSeed;
+    use vct_os::sync::Arc;
+    use vct_reflect_derive::Reflect;
+
+    use crate::{
+        info::Typed,
+        registry::{ReflectDefaultBuilder, TypeRegistry},
+        serde::{ReflectDeserializer, ReflectSerializer},
+    };
+
+    #[derive(Reflect, PartialEq, Debug)]
+    struct Foo {
+        name: String,
+        size: u32,
+    }
+
+    #[test]
+    fn round_trip_with_current_registry() {
+        let value = Foo {
+            name: String::from("a"),
+            size: 2,
+        };
+
+        let mut registry = TypeRegistry::new();
+        registry.register::<Foo>();
+        let registry = Arc::new(registry);
+
+        let output = registry.scope(|_| {
+            let json = serde_json::to_string(&ReflectSerializer::current(&value)).unwrap();
+            ReflectDeserializer::current()
+                .deserialize(&mut serde_json::Deserializer::from_str(&json))
+                .unwrap()
+        });
+        assert_eq!(output.take::<Foo>().unwrap(), value);
+
+        let default = registry.scope(|_| ReflectDefaultBuilder::current().build(Foo::type_info()));
+        assert_eq!(
+            default.unwrap().take::<Foo>().unwrap(),
+            Foo {
+                name: String::new(),
+                size: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn no_current_registry() {
+        let value = 1_u32;
+        assert!(serde_json::to_string(&ReflectSerializer::current(&value)).is_err());
+        assert!(
+            ReflectDeserializer::current()
+                .deserialize(&mut serde_json::Deserializer::from_str(r#"{"u32":1}"#))
+                .is_err()
+        );
+    }
+}
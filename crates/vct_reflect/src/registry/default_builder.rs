@@ -0,0 +1,179 @@
+use alloc::{borrow::Cow, boxed::Box, vec::Vec};
+use vct_utils::error::define_error;
+
+use crate::{
+    Reflect,
+    info::{TypeInfo, VariantInfo},
+    ops::{
+        DynamicArray, DynamicEnum, DynamicList, DynamicMap, DynamicSet, DynamicStruct,
+        DynamicTuple, DynamicTupleStruct, DynamicVariant,
+    },
+    registry::{TypeRegistry, TypeTraitDefault, TypeTraitFromReflect},
+};
+
+define_error! {
+    /// A enumeration of all error outcomes
+    /// that might happen when building a value with [`ReflectDefaultBuilder`].
+    #[derive(Debug)]
+    pub enum DefaultBuildError {
+        /// An opaque type has no `TypeTraitDefault` in the registry.
+        MissingDefault { type_path: Cow<'static, str> } =>
+            "type `{type_path}` has no `TypeTraitDefault` in the registry",
+        /// An enum has no variant to build.
+        EmptyEnum { type_path: Cow<'static, str> } =>
+            "enum `{type_path}` has no variants",
+        /// `FromReflect` rejected the value built for the type.
+        FromReflectFailed { type_path: Cow<'static, str> } =>
+            "failed to convert the built value into `{type_path}`",
+        /// No registry was given and there is no current registry.
+        MissingRegistry => "no registry was given and there is no current registry",
+    }
+}
+
+/// Constructs default values of registered types from their [`TypeInfo`] alone.
+///
+/// Types with a [`TypeTraitDefault`] use it directly. Other types are built recursively:
+///
+/// - structs and tuples get the default value of each field,
+/// - arrays get the default value of each element,
+/// - lists, maps and sets are empty,
+/// - enums use their first variant.
+///
+/// Built values are converted into the concrete type with its [`TypeTraitFromReflect`],
+/// if registered, otherwise a dynamic value representing the type is returned.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// let builder = ReflectDefaultBuilder::new(&registry);
+/// let value = builder.build(Foo::type_info())?;
+/// ```
+#[derive(Clone, Copy)]
+pub struct ReflectDefaultBuilder<'a> {
+    registry: Option<&'a TypeRegistry>,
+}
+
+impl<'a> ReflectDefaultBuilder<'a> {
+    /// Creates a builder looking up type traits in `registry`.
+    #[inline]
+    pub const fn new(registry: &'a TypeRegistry) -> Self {
+        Self {
+            registry: Some(registry),
+        }
+    }
+
+    /// Creates a builder looking up type traits in the [current](TypeRegistry::current)
+    /// registry at the time of building.
+    #[inline]
+    pub const fn current() -> Self {
+        Self { registry: None }
+    }
+
+    /// Builds a fully-initialized value of the type described by `info`.
+    pub fn build(&self, info: &'static TypeInfo) -> Result<Box<dyn Reflect>, DefaultBuildError> {
+        let Some(registry) = self.registry else {
+            return TypeRegistry::with_current(|registry| {
+                ReflectDefaultBuilder::new(registry).build(info)
+            })
+            .unwrap_or(Err(DefaultBuildError::MissingRegistry));
+        };
+
+        let type_id = info.type_id();
+        if let Some(default) = registry.get_type_trait::<TypeTraitDefault>(type_id) {
+            return Ok(default.default());
+        }
+
+        let type_path = || Cow::Borrowed(info.type_path());
+
+        let value: Box<dyn Reflect> = match info {
+            TypeInfo::Struct(struct_info) => {
+                let mut value = DynamicStruct::with_capacity(struct_info.field_len());
+                for field in struct_info.iter() {
+                    value.insert_boxed(field.name(), self.build(field.type_info())?);
+                }
+                value.set_type_info(Some(info));
+                Box::new(value)
+            }
+            TypeInfo::TupleStruct(tuple_struct_info) => {
+                let mut value = DynamicTupleStruct::with_capacity(tuple_struct_info.field_len());
+                for field in tuple_struct_info.iter() {
+                    value.insert_boxed(self.build(field.type_info())?);
+                }
+                value.set_type_info(Some(info));
+                Box::new(value)
+            }
+            TypeInfo::Tuple(tuple_info) => {
+                let mut value = DynamicTuple::with_capacity(tuple_info.field_len());
+                for field in tuple_info.iter() {
+                    value.insert_boxed(self.build(field.type_info())?);
+                }
+                value.set_type_info(Some(info));
+                Box::new(value)
+            }
+            TypeInfo::Array(array_info) => {
+                let values = (0..array_info.capacity())
+                    .map(|_| self.build(array_info.item_info()))
+                    .collect::<Result<Vec<_>, _>>()?;
+                let mut value = DynamicArray::new(values.into_boxed_slice());
+                value.set_type_info(Some(info));
+                Box::new(value)
+            }
+            TypeInfo::List(_) => {
+                let mut value = DynamicList::new();
+                value.set_type_info(Some(info));
+                Box::new(value)
+            }
+            TypeInfo::Map(_) => {
+                let mut value = DynamicMap::new();
+                value.set_type_info(Some(info));
+                Box::new(value)
+            }
+            TypeInfo::Set(_) => {
+                let mut value = DynamicSet::new();
+                value.set_type_info(Some(info));
+                Box::new(value)
+            }
+            TypeInfo::Enum(enum_info) => {
+                let Some(variant_info) = enum_info.variant_at(0) else {
+                    return Err(DefaultBuildError::EmptyEnum {
+                        type_path: type_path(),
+                    });
+                };
+                let variant = match variant_info {
+                    VariantInfo::Struct(variant_info) => {
+                        let mut data = DynamicStruct::with_capacity(variant_info.field_len());
+                        for field in variant_info.iter() {
+                            data.insert_boxed(field.name(), self.build(field.type_info())?);
+                        }
+                        DynamicVariant::Struct(data)
+                    }
+                    VariantInfo::Tuple(variant_info) => {
+                        let mut data = DynamicTuple::with_capacity(variant_info.field_len());
+                        for field in variant_info.iter() {
+                            data.insert_boxed(self.build(field.type_info())?);
+                        }
+                        DynamicVariant::Tuple(data)
+                    }
+                    VariantInfo::Unit(_) => DynamicVariant::Unit,
+                };
+                let mut value = DynamicEnum::new_with_index(0, variant_info.name(), variant);
+                value.set_type_info(Some(info));
+                Box::new(value)
+            }
+            TypeInfo::Opaque(_) => {
+                return Err(DefaultBuildError::MissingDefault {
+                    type_path: type_path(),
+                });
+            }
+        };
+
+        let Some(from_reflect) = registry.get_type_trait::<TypeTraitFromReflect>(type_id) else {
+            return Ok(value);
+        };
+        from_reflect
+            .from_reflect(&*value)
+            .ok_or_else(|| DefaultBuildError::FromReflectFailed {
+                type_path: type_path(),
+            })
+    }
+}
@@ -10,5 +10,14 @@ pub use from_type::FromType;
 mod type_registry;
 pub use type_registry::{TypeRegistry, TypeRegistryArc};
 
+mod current_registry;
+pub(crate) use current_registry::NO_CURRENT_REGISTRY;
+
+mod registry_view;
+pub use registry_view::{CachedTypeTraits, TypeRegistryView};
+
 mod traits;
 pub use traits::*;
+
+mod default_builder;
+pub use default_builder::{DefaultBuildError, ReflectDefaultBuilder};
@@ -0,0 +1,136 @@
+use core::any::TypeId;
+use vct_utils::collections::TypeIdMap;
+
+use crate::{
+    info::TypeInfo,
+    registry::{
+        TypeRegistry, TypeTraitDeserialize, TypeTraitFromReflect, TypeTraitSerialize, TypeTraits,
+    },
+};
+
+/// The [`TypeTraits`] of a type, with its commonly used type traits already resolved.
+#[derive(Clone, Copy)]
+pub struct CachedTypeTraits<'a> {
+    type_traits: &'a TypeTraits,
+    serialize: Option<&'a TypeTraitSerialize>,
+    deserialize: Option<&'a TypeTraitDeserialize>,
+    from_reflect: Option<&'a TypeTraitFromReflect>,
+}
+
+impl<'a> CachedTypeTraits<'a> {
+    /// Resolves the commonly used type traits of `type_traits`.
+    #[inline]
+    pub fn new(type_traits: &'a TypeTraits) -> Self {
+        Self {
+            type_traits,
+            serialize: type_traits.get::<TypeTraitSerialize>(),
+            deserialize: type_traits.get::<TypeTraitDeserialize>(),
+            from_reflect: type_traits.get::<TypeTraitFromReflect>(),
+        }
+    }
+
+    /// Returns the [`TypeTraits`] of the type.
+    #[inline]
+    pub fn type_traits(&self) -> &'a TypeTraits {
+        self.type_traits
+    }
+
+    /// Returns the [`TypeInfo`] of the type.
+    #[inline]
+    pub fn type_info(&self) -> &'static TypeInfo {
+        self.type_traits.type_info()
+    }
+
+    /// Returns the [`TypeTraitSerialize`] of the type, if registered.
+    #[inline]
+    pub fn serialize(&self) -> Option<&'a TypeTraitSerialize> {
+        self.serialize
+    }
+
+    /// Returns the [`TypeTraitDeserialize`] of the type, if registered.
+    #[inline]
+    pub fn deserialize(&self) -> Option<&'a TypeTraitDeserialize> {
+        self.deserialize
+    }
+
+    /// Returns the [`TypeTraitFromReflect`] of the type, if registered.
+    #[inline]
+    pub fn from_reflect(&self) -> Option<&'a TypeTraitFromReflect> {
+        self.from_reflect
+    }
+}
+
+/// A borrowed view of a [`TypeRegistry`] caching the lookups of a set of types.
+///
+/// Each cached type is resolved once into a [`CachedTypeTraits`],
+/// so hot loops (e.g. deserializing many values of the same types)
+/// get the [`TypeTraits`] and common type traits with a single lookup.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// let view = TypeRegistryView::new(&registry, [TypeId::of::<Foo>(), TypeId::of::<Bar>()]);
+/// let from_reflect = view.get(TypeId::of::<Foo>())?.from_reflect()?;
+/// ```
+pub struct TypeRegistryView<'a> {
+    registry: &'a TypeRegistry,
+    cache: TypeIdMap<CachedTypeTraits<'a>>,
+}
+
+impl<'a> TypeRegistryView<'a> {
+    /// Creates a view caching the given types.
+    ///
+    /// Types not registered in `registry` are skipped.
+    pub fn new(registry: &'a TypeRegistry, type_ids: impl IntoIterator<Item = TypeId>) -> Self {
+        let mut view = Self {
+            registry,
+            cache: TypeIdMap::new(),
+        };
+        view.extend(type_ids);
+        view
+    }
+
+    /// Creates a view caching all types of the registry.
+    pub fn all(registry: &'a TypeRegistry) -> Self {
+        Self {
+            registry,
+            cache: registry
+                .iter()
+                .map(|type_traits| (type_traits.type_id(), CachedTypeTraits::new(type_traits)))
+                .collect(),
+        }
+    }
+
+    /// Caches the given types, skipping the ones not registered in the registry.
+    pub fn extend(&mut self, type_ids: impl IntoIterator<Item = TypeId>) {
+        for type_id in type_ids {
+            if let Some(type_traits) = self.registry.get(type_id) {
+                self.cache
+                    .insert(type_id, CachedTypeTraits::new(type_traits));
+            }
+        }
+    }
+
+    /// Returns the underlying [`TypeRegistry`].
+    #[inline]
+    pub fn registry(&self) -> &'a TypeRegistry {
+        self.registry
+    }
+
+    /// Returns `true` if the type with the given [`TypeId`] is cached.
+    #[inline]
+    pub fn is_cached(&self, type_id: TypeId) -> bool {
+        self.cache.contains_key(&type_id)
+    }
+
+    /// Returns the [`CachedTypeTraits`] of the type with the given [`TypeId`].
+    ///
+    /// Types that are not cached are resolved from the registry on each call.
+    #[inline]
+    pub fn get(&self, type_id: TypeId) -> Option<CachedTypeTraits<'a>> {
+        match self.cache.get(&type_id) {
+            Some(cached) => Some(*cached),
+            None => self.registry.get(type_id).map(CachedTypeTraits::new),
+        }
+    }
+}
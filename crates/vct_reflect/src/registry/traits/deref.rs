@@ -0,0 +1,53 @@
+use core::{any::TypeId, ops::Deref};
+
+use crate::{Reflect, info::Typed, registry::FromType};
+
+/// See [`Deref`]
+///
+/// Registered for smart pointers such as `Box<T>` and `Arc<T>`,
+/// so that serialization and deserialization can reach the pointee.
+///
+/// This is a fixed type dereference, type errors can cause panic.
+#[derive(Clone)]
+pub struct TypeTraitDeref {
+    pointee_type_id: TypeId,
+    func: fn(&dyn Reflect) -> &dyn Reflect,
+}
+
+impl TypeTraitDeref {
+    /// Returns the [`TypeId`] of the pointee type.
+    #[inline]
+    pub fn pointee_type_id(&self) -> TypeId {
+        self.pointee_type_id
+    }
+
+    /// Call T's [`Deref`]
+    ///
+    /// [`TypeTraitDeref`] does not have a type flag,
+    /// but the functions used internally are type specific.
+    ///
+    /// # Panic
+    /// - Mismatched Type
+    #[inline(always)]
+    pub fn deref<'a>(&self, value: &'a dyn Reflect) -> &'a dyn Reflect {
+        (self.func)(value)
+    }
+}
+
+impl<T: Deref<Target: Reflect> + Typed + Reflect> FromType<T> for TypeTraitDeref {
+    fn from_type() -> Self {
+        Self {
+            pointee_type_id: TypeId::of::<T::Target>(),
+            func: |value| match value.downcast_ref::<T>() {
+                Some(val) => val.deref().as_reflect(),
+                None => {
+                    panic!(
+                        "Deref type mismatched, Deref Type `{}` with Value Type: {}",
+                        T::type_path(),
+                        value.reflect_type_path(),
+                    );
+                }
+            },
+        }
+    }
+}
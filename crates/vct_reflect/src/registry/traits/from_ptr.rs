@@ -1,13 +1,16 @@
-use core::any::TypeId;
-use vct_ptr::{Ptr, PtrMut};
+use alloc::boxed::Box;
+use core::{alloc::Layout, any::TypeId, mem::ManuallyDrop, ptr::NonNull};
+use vct_ptr::{Ptr, PtrMut, Unaligned};
 
-use crate::{Reflect, info::Typed, registry::FromType};
+use crate::{Reflect, info::Typed, ops::ReflectCloneError, registry::FromType};
 
 #[derive(Clone)]
 pub struct TypeTraitFromPtr {
     type_id: TypeId,
+    layout: Layout,
     from_ptr: unsafe fn(Ptr) -> &dyn Reflect,
     from_ptr_mut: unsafe fn(PtrMut) -> &mut dyn Reflect,
+    clone_unaligned: unsafe fn(Ptr<Unaligned>) -> Result<Box<dyn Reflect>, ReflectCloneError>,
 }
 
 #[expect(unsafe_code, reason = "Cast pointers to references is unsafe.")]
@@ -62,6 +65,112 @@ impl TypeTraitFromPtr {
     pub fn from_ptr_mut(&self) -> unsafe fn(PtrMut) -> &mut dyn Reflect {
         self.from_ptr_mut
     }
+
+    /// Returns the [`Layout`] of the type that the [`ReflectFromPtr`] was constructed for.
+    pub fn layout(&self) -> Layout {
+        self.layout
+    }
+
+    /// Convert an unaligned `Ptr` into `&dyn Reflect`.
+    ///
+    /// References must be aligned, so this returns `None` if `val` is not
+    /// properly aligned for the type. Such values can only be read by copy,
+    /// see [`TypeTraitFromPtr::clone_unaligned`].
+    ///
+    /// # Safety
+    ///
+    /// `val` must be a pointer to value of the type that the [`ReflectFromPtr`] was constructed for.
+    /// This can be verified by checking that the type id returned by [`ReflectFromPtr::type_id`] is the expected one.
+    pub unsafe fn from_ptr_unaligned<'a>(
+        &self,
+        val: Ptr<'a, Unaligned>,
+    ) -> Option<&'a dyn Reflect> {
+        let ptr = NonNull::from(val);
+        if !ptr.addr().get().is_multiple_of(self.layout.align()) {
+            return None;
+        }
+        // SAFETY: the pointer is aligned, the rest is uphold by the caller.
+        unsafe { Some((self.from_ptr)(Ptr::new(ptr))) }
+    }
+
+    /// Convert an unaligned `PtrMut` into `&mut dyn Reflect`,
+    /// returns `None` if `val` is not properly aligned for the type.
+    ///
+    /// # Safety
+    ///
+    /// `val` must be a pointer to a value of the type that the [`ReflectFromPtr`] was constructed for
+    /// This can be verified by checking that the type id returned by [`ReflectFromPtr::type_id`] is the expected one.
+    pub unsafe fn from_ptr_unaligned_mut<'a>(
+        &self,
+        val: PtrMut<'a, Unaligned>,
+    ) -> Option<&'a mut dyn Reflect> {
+        let ptr = NonNull::from(val);
+        if !ptr.addr().get().is_multiple_of(self.layout.align()) {
+            return None;
+        }
+        // SAFETY: the pointer is aligned, the rest is uphold by the caller.
+        unsafe { Some((self.from_ptr_mut)(PtrMut::new(ptr))) }
+    }
+
+    /// Clone the value behind a possibly unaligned `Ptr`, e.g. a field of a `#[repr(packed)]` type.
+    ///
+    /// The value is copied out with [`read_unaligned`](core::ptr::read_unaligned),
+    /// then cloned with [`Reflect::reflect_clone`], the copy is never dropped.
+    ///
+    /// # Safety
+    ///
+    /// `val` must be a pointer to value of the type that the [`ReflectFromPtr`] was constructed for.
+    /// This can be verified by checking that the type id returned by [`ReflectFromPtr::type_id`] is the expected one.
+    pub unsafe fn clone_unaligned(
+        &self,
+        val: Ptr<'_, Unaligned>,
+    ) -> Result<Box<dyn Reflect>, ReflectCloneError> {
+        // SAFETY: contract uphold by the caller.
+        unsafe { (self.clone_unaligned)(val) }
+    }
+
+    /// Convert a `Ptr` to the first of `len` contiguous values into `&dyn Reflect` of each value,
+    /// e.g. the items of a type-erased column.
+    ///
+    /// # Safety
+    ///
+    /// `val` must be a pointer to `len` contiguous values of the type that the [`ReflectFromPtr`]
+    /// was constructed for, like a slice `&[T]`.
+    pub unsafe fn from_slice_ptr<'a>(
+        &self,
+        val: Ptr<'a>,
+        len: usize,
+    ) -> impl ExactSizeIterator<Item = &'a dyn Reflect> + use<'a> {
+        let from_ptr = self.from_ptr;
+        let size = self.layout.size();
+        (0..len).map(move |index| {
+            // SAFETY: the value at `index` is in bounds and aligned as in a slice,
+            // the rest is uphold by the caller.
+            unsafe { from_ptr(val.byte_add(index * size)) }
+        })
+    }
+
+    /// Convert a `PtrMut` to the first of `len` contiguous values into `&mut dyn Reflect` of each value.
+    ///
+    /// # Safety
+    ///
+    /// `val` must be a pointer to `len` contiguous values of the type that the [`ReflectFromPtr`]
+    /// was constructed for, like a slice `&mut [T]`.
+    pub unsafe fn from_slice_ptr_mut<'a>(
+        &self,
+        val: PtrMut<'a>,
+        len: usize,
+    ) -> impl ExactSizeIterator<Item = &'a mut dyn Reflect> + use<'a> {
+        let from_ptr_mut = self.from_ptr_mut;
+        let size = self.layout.size();
+        let ptr = NonNull::from(val);
+        (0..len).map(move |index| {
+            // SAFETY: each index is visited once, so the values are borrowed mutably only once.
+            // The value at `index` is in bounds and aligned as in a slice,
+            // the rest is uphold by the caller.
+            unsafe { from_ptr_mut(PtrMut::new(ptr.byte_add(index * size))) }
+        })
+    }
 }
 
 #[expect(unsafe_code, reason = "Cast pointers to references is unsafe.")]
@@ -69,6 +178,7 @@ impl<T: Typed + Reflect> FromType<T> for TypeTraitFromPtr {
     fn from_type() -> Self {
         TypeTraitFromPtr {
             type_id: TypeId::of::<T>(),
+            layout: Layout::new::<T>(),
             from_ptr: |ptr| {
                 // SAFETY: `from_ptr_mut` is either called in `ReflectFromPtr::as_reflect`
                 // or returned by `ReflectFromPtr::from_ptr`, both lay out the invariants
@@ -79,6 +189,63 @@ impl<T: Typed + Reflect> FromType<T> for TypeTraitFromPtr {
                 // SAFETY: same as above, but for `as_reflect_mut`, `from_ptr_mut` and `deref_mut`.
                 unsafe { ptr.deref_mut::<T>() as &mut dyn Reflect }
             },
+            clone_unaligned: |ptr| {
+                // SAFETY: `ptr` points to a `T`, see `ReflectFromPtr::clone_unaligned`.
+                // The copy shares its resources with the original, so it must not be dropped.
+                let copy = ManuallyDrop::new(unsafe {
+                    NonNull::from(ptr).cast::<T>().as_ptr().read_unaligned()
+                });
+                copy.reflect_clone()
+            },
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::String;
+    use core::ptr::NonNull;
+    use vct_ptr::{Ptr, Unaligned};
+
+    use crate::registry::{FromType, TypeTraitFromPtr};
+
+    #[repr(C, packed)]
+    struct Packed {
+        tag: u8,
+        value: u32,
+        name: String,
+    }
+
+    /// Puts `value` at an odd address.
+    #[repr(C, align(8))]
+    struct Aligned(Packed);
+
+    #[test]
+    #[expect(unsafe_code, reason = "Reads fields of a packed struct through pointers.")]
+    fn clone_unaligned() {
+        let Aligned(packed) = &Aligned(Packed {
+            tag: 1,
+            value: 7,
+            name: String::from("packed"),
+        });
+        assert_eq!(packed.tag, 1);
+
+        let from_ptr = <TypeTraitFromPtr as FromType<u32>>::from_type();
+        let ptr = NonNull::new((&raw const packed.value).cast::<u8>().cast_mut()).unwrap();
+        // SAFETY: `ptr` points to the `u32` field.
+        let value_ptr = unsafe { Ptr::<Unaligned>::new(ptr) };
+        // SAFETY: `value_ptr` points to a `u32`.
+        assert!(unsafe { from_ptr.from_ptr_unaligned(value_ptr) }.is_none());
+        // SAFETY: same as above.
+        let value = unsafe { from_ptr.clone_unaligned(value_ptr) }.unwrap();
+        assert_eq!(value.take::<u32>().unwrap(), 7);
+
+        let from_ptr = <TypeTraitFromPtr as FromType<String>>::from_type();
+        let ptr = NonNull::new((&raw const packed.name).cast::<u8>().cast_mut()).unwrap();
+        // SAFETY: `ptr` points to the `String` field.
+        let name_ptr = unsafe { Ptr::<Unaligned>::new(ptr) };
+        // SAFETY: `name_ptr` points to a `String`.
+        let name = unsafe { from_ptr.clone_unaligned(name_ptr) }.unwrap();
+        assert_eq!(name.take::<String>().unwrap(), "packed");
+    }
+}
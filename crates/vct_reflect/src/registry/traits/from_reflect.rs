@@ -1,6 +1,10 @@
 use alloc::boxed::Box;
 
-use crate::{FromReflect, Reflect, info::Typed, registry::FromType};
+use crate::{
+    FromReflect, Reflect,
+    info::Typed,
+    registry::{FromType, TypeTraitDefault},
+};
 
 /// See [`FromReflect`]
 #[derive(Clone)]
@@ -17,6 +21,27 @@ impl TypeTraitFromReflect {
     pub fn from_reflect(&self, param_1: &dyn Reflect) -> Option<Box<dyn Reflect>> {
         (self.func)(param_1)
     }
+
+    /// Like [`from_reflect`](Self::from_reflect), but fields missing from `param_1`
+    /// fall back to their values in `default`.
+    ///
+    /// If the conversion fails, the default value is created and `param_1`
+    /// is applied onto it, so data saved by an older version of the type can still be loaded.
+    /// Returns `None` if applying fails, e.g. because a present field has the wrong type.
+    ///
+    /// `default` should be the [`TypeTraitDefault`] registered for the same type.
+    pub fn from_reflect_or_default(
+        &self,
+        param_1: &dyn Reflect,
+        default: &TypeTraitDefault,
+    ) -> Option<Box<dyn Reflect>> {
+        if let Some(value) = (self.func)(param_1) {
+            return Some(value);
+        }
+        let mut value = default.default();
+        value.try_apply(param_1).ok()?;
+        Some(value)
+    }
 }
 
 impl<T: Typed + FromReflect> FromType<T> for TypeTraitFromReflect {
@@ -0,0 +1,30 @@
+use crate::{Reflect, info::Typed, registry::FromType};
+use alloc::boxed::Box;
+use core::str::FromStr;
+
+/// See [`FromStr`]
+///
+/// It is not registered by default, register it for types
+/// that should be parsed from user input, e.g. by [`set_by_path`].
+///
+/// [`set_by_path`]: crate::access::set_by_path
+#[derive(Clone)]
+pub struct TypeTraitFromStr {
+    func: fn(&str) -> Option<Box<dyn Reflect>>,
+}
+
+impl TypeTraitFromStr {
+    /// Call T's [`FromStr`], returning `None` if the string cannot be parsed.
+    #[inline(always)]
+    pub fn from_str(&self, s: &str) -> Option<Box<dyn Reflect>> {
+        (self.func)(s)
+    }
+}
+
+impl<T: FromStr + Typed + Reflect> FromType<T> for TypeTraitFromStr {
+    fn from_type() -> Self {
+        Self {
+            func: |s| Some(Box::new(T::from_str(s).ok()?)),
+        }
+    }
+}
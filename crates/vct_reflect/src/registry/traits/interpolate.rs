@@ -0,0 +1,108 @@
+use alloc::boxed::Box;
+
+use crate::{Reflect, info::Typed, registry::FromType};
+
+/// A type that can be linearly interpolated between two values.
+///
+/// Used by [`TypeTraitInterpolate`], can be derived for structs
+/// whose fields all implement it through `#[reflect(interpolate)]`.
+pub trait Interpolate {
+    /// Returns the value at `t` between `self` (`t = 0.0`) and `other` (`t = 1.0`).
+    fn interpolate(&self, other: &Self, t: f32) -> Self;
+}
+
+macro_rules! impl_interpolate_float {
+    ($($ty:ty),*) => {
+        $(
+            impl Interpolate for $ty {
+                #[inline]
+                fn interpolate(&self, other: &Self, t: f32) -> Self {
+                    *self + (*other - *self) * t as $ty
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_interpolate_int {
+    ($($ty:ty),*) => {
+        $(
+            impl Interpolate for $ty {
+                /// The result is truncated toward zero.
+                #[inline]
+                fn interpolate(&self, other: &Self, t: f32) -> Self {
+                    let (x, y) = (*self as f64, *other as f64);
+                    (x + (y - x) * t as f64) as $ty
+                }
+            }
+        )*
+    };
+}
+
+impl_interpolate_float!(f32, f64);
+impl_interpolate_int!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// See [`Interpolate`]
+///
+/// This is a fixed type interpolation, type errors can cause panic.
+#[derive(Clone)]
+pub struct TypeTraitInterpolate {
+    func: fn(&dyn Reflect, &dyn Reflect, f32) -> Box<dyn Reflect>,
+}
+
+impl TypeTraitInterpolate {
+    /// Call T's [`Interpolate`]
+    ///
+    /// [`TypeTraitInterpolate`] does not have a type flag,
+    /// but the functions used internally are type specific.
+    ///
+    /// # Panic
+    /// - Mismatched Type
+    #[inline(always)]
+    pub fn interpolate(&self, a: &dyn Reflect, b: &dyn Reflect, t: f32) -> Box<dyn Reflect> {
+        (self.func)(a, b, t)
+    }
+}
+
+impl<T: Interpolate + Typed + Reflect> FromType<T> for TypeTraitInterpolate {
+    fn from_type() -> Self {
+        Self {
+            func: |a, b, t| match (a.downcast_ref::<T>(), b.downcast_ref::<T>()) {
+                (Some(a), Some(b)) => Box::new(a.interpolate(b, t)),
+                _ => {
+                    panic!(
+                        "Interpolate type mismatched, Interpolate Type `{}` with Value Types: `{}`, `{}`",
+                        T::type_path(),
+                        a.reflect_type_path(),
+                        b.reflect_type_path(),
+                    );
+                }
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::any::TypeId;
+
+    use super::TypeTraitInterpolate;
+    use crate::registry::TypeRegistry;
+
+    #[test]
+    fn interpolate_floats() {
+        let registry = TypeRegistry::new();
+
+        let interpolate = registry
+            .get_type_trait::<TypeTraitInterpolate>(TypeId::of::<f32>())
+            .unwrap();
+        let value = interpolate.interpolate(&1.0f32, &3.0f32, 0.25);
+        assert_eq!(value.downcast_ref::<f32>(), Some(&1.5));
+
+        let interpolate = registry
+            .get_type_trait::<TypeTraitInterpolate>(TypeId::of::<f64>())
+            .unwrap();
+        let value = interpolate.interpolate(&-2.0f64, &2.0f64, 0.5);
+        assert_eq!(value.downcast_ref::<f64>(), Some(&0.0));
+    }
+}
@@ -10,8 +10,20 @@ pub use from_ptr::TypeTraitFromPtr;
 mod default;
 pub use default::TypeTraitDefault;
 
+mod deref;
+pub use deref::TypeTraitDeref;
+
+mod transparent;
+pub use transparent::TypeTraitTransparent;
+
 mod serialize;
 pub use serialize::TypeTraitSerialize;
 
 mod deserialize;
 pub use deserialize::TypeTraitDeserialize;
+
+mod from_str;
+pub use from_str::TypeTraitFromStr;
+
+mod interpolate;
+pub use interpolate::{Interpolate, TypeTraitInterpolate};
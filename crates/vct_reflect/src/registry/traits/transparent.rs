@@ -0,0 +1,41 @@
+use core::any::TypeId;
+
+use crate::Reflect;
+
+/// Registered for `#[reflect(transparent)]` types,
+/// so that serialization and deserialization go through their single reflected field.
+///
+/// This is a fixed type field access, type errors can cause panic.
+#[derive(Clone)]
+pub struct TypeTraitTransparent {
+    field_type_id: TypeId,
+    func: fn(&dyn Reflect) -> &dyn Reflect,
+}
+
+impl TypeTraitTransparent {
+    /// Creates the type trait of a transparent type whose field is of type `F`.
+    ///
+    /// `func` returns the field of a value of the transparent type.
+    #[inline]
+    pub fn new<F: Reflect>(func: fn(&dyn Reflect) -> &dyn Reflect) -> Self {
+        Self {
+            field_type_id: TypeId::of::<F>(),
+            func,
+        }
+    }
+
+    /// Returns the [`TypeId`] of the field type.
+    #[inline]
+    pub fn field_type_id(&self) -> TypeId {
+        self.field_type_id
+    }
+
+    /// Returns the field of `value`.
+    ///
+    /// # Panic
+    /// - Mismatched Type
+    #[inline(always)]
+    pub fn field<'a>(&self, value: &'a dyn Reflect) -> &'a dyn Reflect {
+        (self.func)(value)
+    }
+}
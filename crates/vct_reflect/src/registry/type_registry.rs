@@ -1,18 +1,26 @@
+use alloc::string::String;
 use core::{any::TypeId, fmt};
 
 use crate::{
-    info::{TypeInfo, Typed},
+    info::{TypeInfo, TypeUuid, Typed},
+    migrate::{Migration, TypeTraitMigrations},
+    ops::DynamicStruct,
     registry::{FromType, GetTypeTraits, TypeTrait, TypeTraits},
+    serde::RegistrySchema,
 };
 use vct_os::sync::{Arc, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard};
 use vct_utils::collections::{HashMap, HashSet, TypeIdMap, hash_map};
 
 /// A registry of reflected types.
+#[derive(Clone)]
 pub struct TypeRegistry {
     traits_map: TypeIdMap<TypeTraits>,
     type_path_to_id: HashMap<&'static str, TypeId>,
+    type_path_aliases: HashMap<&'static str, TypeId>,
     type_name_to_id: HashMap<&'static str, TypeId>,
+    type_uuid_to_id: HashMap<TypeUuid, TypeId>,
     ambiguous_names: HashSet<&'static str>,
+    generation: u64,
 }
 
 impl TypeRegistry {
@@ -22,8 +30,11 @@ impl TypeRegistry {
         Self {
             traits_map: TypeIdMap::new(),
             type_path_to_id: HashMap::<_, _>::new(),
+            type_path_aliases: HashMap::<_, _>::new(),
             type_name_to_id: HashMap::<_, _>::new(),
+            type_uuid_to_id: HashMap::<_, _>::new(),
             ambiguous_names: HashSet::new(),
+            generation: 0,
         }
     }
 
@@ -33,6 +44,7 @@ impl TypeRegistry {
         type_traits: &TypeTraits,
         type_path_to_id: &mut HashMap<&'static str, TypeId>,
         type_name_to_id: &mut HashMap<&'static str, TypeId>,
+        type_uuid_to_id: &mut HashMap<TypeUuid, TypeId>,
         ambiguous_names: &mut HashSet<&'static str>,
     ) {
         let type_name = type_traits.type_info().type_path_table().name();
@@ -47,6 +59,10 @@ impl TypeRegistry {
         }
         // For new type, assuming that the full path cannot be duplicated.
         type_path_to_id.insert(type_traits.type_info().type_path(), type_traits.type_id());
+        // Same as full path, UUIDs are assumed to be unique.
+        if let Some(type_uuid) = type_traits.type_info().type_uuid() {
+            type_uuid_to_id.insert(type_uuid, type_traits.type_id());
+        }
     }
 
     // If key [`TypeId`] has already exist, the function will do nothing and return `false`.
@@ -59,11 +75,14 @@ impl TypeRegistry {
         match self.traits_map.entry(type_id) {
             hash_map::Entry::Occupied(_) => false, // duplicated
             hash_map::Entry::Vacant(entry) => {
-                let type_traits = get_type_traits();
+                let mut type_traits = get_type_traits();
+                self.generation += 1;
+                type_traits.set_generation(self.generation);
                 Self::add_new_type_indices(
                     &type_traits,
                     &mut self.type_path_to_id,
                     &mut self.type_name_to_id,
+                    &mut self.type_uuid_to_id,
                     &mut self.ambiguous_names,
                 );
                 entry.insert(type_traits);
@@ -88,7 +107,11 @@ impl TypeRegistry {
     ///   But full_path and type_name table will not be modified.  
     /// - If the key [`TypeId`] does not exist, the value will be inserted.
     ///   And type path will be inserted to full_path and type_name table.
-    pub fn insert_type_traits(&mut self, type_traits: TypeTraits) {
+    ///
+    /// In both cases, the value gets a new [generation](TypeTraits::generation).
+    pub fn insert_type_traits(&mut self, mut type_traits: TypeTraits) {
+        self.generation += 1;
+        type_traits.set_generation(self.generation);
         match self.traits_map.entry(type_traits.type_id()) {
             hash_map::Entry::Occupied(mut entry) => {
                 *entry.get_mut() = type_traits;
@@ -99,6 +122,7 @@ impl TypeRegistry {
                     &type_traits,
                     &mut self.type_path_to_id,
                     &mut self.type_name_to_id,
+                    &mut self.type_uuid_to_id,
                     &mut self.ambiguous_names,
                 );
                 entry.insert(type_traits);
@@ -109,7 +133,7 @@ impl TypeRegistry {
     /// Create a new [`TypeRegistry`].
     ///
     /// This function will register some types by default,
-    /// such as `u8`-`u128`, `i8`-`i128`, `usize`, and `isize`.
+    /// such as `u8`-`u128`, `i8`-`i128`, `usize`, `isize`, `f32` and `f64`.
     pub fn new() -> Self {
         let mut registry = Self::empty();
 
@@ -125,6 +149,8 @@ impl TypeRegistry {
         registry.register::<i128>();
         registry.register::<usize>();
         registry.register::<isize>();
+        registry.register::<f32>();
+        registry.register::<f64>();
 
         // TODO: bool String
 
@@ -136,7 +162,7 @@ impl TypeRegistry {
     /// Register [`GetTypeTraits::get_type_traits`] for `T`.
     ///
     /// This will also recursively register any type dependencies as specified by [`GetTypeTraits::register_dependencies`].
-    pub fn register<T: GetTypeTraits>(&mut self) {
+    pub fn register<T: GetTypeTraits + ?Sized>(&mut self) {
         if self.register_internal(TypeId::of::<T>(), T::get_type_traits) {
             T::register_dependencies(self);
         }
@@ -148,6 +174,17 @@ impl TypeRegistry {
         self.register::<T>();
     }
 
+    /// Registers all types marked with `#[reflect(auto_register)]`.
+    ///
+    /// With the `auto_register_inventory` feature, marked non-generic types are collected
+    /// automatically at link time. Otherwise, registration functions must be added with
+    /// `push_registration_fn` before calling this.
+    #[cfg(feature = "auto_register")]
+    #[inline]
+    pub fn register_auto_types(&mut self) {
+        crate::__macro_exports::auto_register::register_types(self);
+    }
+
     /// Registers the type type_trait `D` for type `T`.
     ///
     /// Type `T` must be registered in advance.
@@ -166,6 +203,147 @@ impl TypeRegistry {
         }
     }
 
+    /// Registers the type trait `data` for type `T`.
+    ///
+    /// Unlike [`register_type_trait`](Self::register_type_trait), the value is
+    /// created by the caller, so it doesn't need to implement [`FromType<T>`].
+    ///
+    /// Type `T` must be registered in advance.
+    ///
+    /// # Panic
+    ///
+    /// - Type 'T' is not registered.
+    pub fn register_type_trait_with<T: Typed, D: TypeTrait>(&mut self, data: D) {
+        match self.traits_map.get_mut(&TypeId::of::<T>()) {
+            Some(type_traits) => type_traits.insert(data),
+            None => panic!(
+                "Called `TypeRegistry::register_type_trait_with`, but the type `{}` of type_trait `{}` without registering",
+                T::type_path(),
+                core::any::type_name::<D>(),
+            ),
+        }
+    }
+
+    /// Registers a migration of the versioned struct `T` from `from_version` to the next version,
+    /// the data of `from_version` is read in the shape of `S`, see [`Migration`].
+    /// `func` returns the reason of the failure if the data cannot be migrated.
+    ///
+    /// Type `T` must be registered in advance, the dependencies of `S` are registered.
+    ///
+    /// # Panic
+    ///
+    /// - Type 'T' is not registered.
+    /// - Type 'T' is not declared with `#[reflect(version = N)]`.
+    /// - `from_version` is not older than `N`, or `S` is not a struct.
+    pub fn register_migration<T: Typed, S: Typed + GetTypeTraits>(
+        &mut self,
+        from_version: u32,
+        func: impl Fn(DynamicStruct) -> Result<DynamicStruct, String> + Send + Sync + 'static,
+    ) {
+        let migration = Migration::new::<S>(from_version, func);
+        S::register_dependencies(self);
+        match self.get_type_trait_mut::<TypeTraitMigrations>(TypeId::of::<T>()) {
+            Some(migrations) => migrations.insert(migration),
+            None => panic!(
+                "Called `TypeRegistry::register_migration`, but the type `{}` is not registered or not versioned",
+                T::type_path(),
+            ),
+        }
+    }
+
+    /// Removes the type `T` from this registry, returning its [`TypeTraits`] if it was registered.
+    ///
+    /// Dependencies registered alongside `T` are kept, as other types may still use them.
+    #[inline]
+    pub fn remove<T: 'static>(&mut self) -> Option<TypeTraits> {
+        self.remove_by_id(TypeId::of::<T>())
+    }
+
+    /// Removes the type with the given [`TypeId`] from this registry,
+    /// returning its [`TypeTraits`] if it was registered.
+    ///
+    /// The type path, type name, UUID and aliases of the type are removed as well,
+    /// so later lookups won't resolve to it. This allows dynamically loaded plugins
+    /// to unregister their types before being unloaded.
+    pub fn remove_by_id(&mut self, type_id: TypeId) -> Option<TypeTraits> {
+        let type_traits = self.traits_map.remove(&type_id)?;
+        self.generation += 1;
+
+        let type_info = type_traits.type_info();
+        self.type_path_to_id.remove(type_info.type_path());
+        self.type_path_aliases.retain(|_, id| *id != type_id);
+        if let Some(type_uuid) = type_info.type_uuid() {
+            self.type_uuid_to_id.remove(&type_uuid);
+        }
+
+        let type_name = type_info.type_path_table().name();
+        if self.ambiguous_names.contains(type_name) {
+            // Other types still share this name, it is no longer ambiguous if only one is left.
+            let mut remaining = self
+                .traits_map
+                .values()
+                .filter(|item| item.type_info().type_path_table().name() == type_name);
+            if let (Some(item), None) = (remaining.next(), remaining.next()) {
+                let id = item.type_id();
+                self.ambiguous_names.remove(type_name);
+                self.type_name_to_id.insert(type_name, id);
+            }
+        } else {
+            self.type_name_to_id.remove(type_name);
+        }
+
+        Some(type_traits)
+    }
+
+    /// Returns the current generation of this registry.
+    ///
+    /// The generation is increased each time a type is added, overwritten or removed,
+    /// and every [`TypeTraits`] records the generation at which it was added.
+    #[inline]
+    pub fn get_generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// Returns `true` if the type with the given [`TypeId`] is registered
+    /// and its registration was added at the given `generation`.
+    ///
+    /// Returns `false` if the type has been removed or re-registered since then.
+    #[inline]
+    pub fn is_current(&self, type_id: TypeId, generation: u64) -> bool {
+        self.get(type_id)
+            .is_some_and(|type_traits| type_traits.generation() == generation)
+    }
+
+    /// Registers `alias` as an additional [type path] of the type with the given [`TypeId`].
+    ///
+    /// Aliases are used by [`get_with_type_path`](Self::get_with_type_path) (and thus by deserialization)
+    /// when no registered type has the exact path, so renamed or moved types can still be
+    /// resolved from legacy data.
+    ///
+    /// The type does not need to be registered in advance.
+    ///
+    /// Returns `false` and does nothing if `alias` is already the real path of a registered type.
+    /// Otherwise, an existing alias with the same name is overwritten.
+    ///
+    /// [type path]: TypePath::type_path
+    pub fn register_alias(&mut self, alias: &'static str, type_id: TypeId) -> bool {
+        if self.type_path_to_id.contains_key(alias) {
+            return false;
+        }
+        self.type_path_aliases.insert(alias, type_id);
+        true
+    }
+
+    /// Returns the [`TypeId`] of the type with the given [type path] or alias.
+    ///
+    /// [type path]: TypePath::type_path
+    pub fn get_type_id_with_type_path(&self, type_path: &str) -> Option<TypeId> {
+        match self.type_path_to_id.get(type_path) {
+            Some(id) => Some(*id),
+            None => self.type_path_aliases.get(type_path).copied(),
+        }
+    }
+
     /// Whether the type with given [`TypeId`] has been registered in this registry.
     #[inline]
     pub fn contains(&self, type_id: TypeId) -> bool {
@@ -185,22 +363,26 @@ impl TypeRegistry {
 
     /// Returns a reference to the [`TypeTraits`] of the type with the given [type path].
     ///
+    /// Falls back to aliases registered with [`register_alias`](Self::register_alias).
+    ///
     /// [type path]: TypePath::type_path
     pub fn get_with_type_path(&self, type_path: &str) -> Option<&TypeTraits> {
         // Manual inline
-        match self.type_path_to_id.get(type_path) {
-            Some(id) => self.get(*id),
+        match self.get_type_id_with_type_path(type_path) {
+            Some(id) => self.get(id),
             None => None,
         }
     }
 
     /// Returns a mutable reference to the [`TypeTraits`] of the type with the given [type path].
     ///
+    /// Falls back to aliases registered with [`register_alias`](Self::register_alias).
+    ///
     /// [type path]: TypePath::type_path
     pub fn get_with_type_path_mut(&mut self, type_path: &str) -> Option<&mut TypeTraits> {
         // Manual inline
-        match self.type_path_to_id.get(type_path) {
-            Some(id) => self.get_mut(*id),
+        match self.get_type_id_with_type_path(type_path) {
+            Some(id) => self.get_mut(id),
             None => None,
         }
     }
@@ -225,6 +407,22 @@ impl TypeRegistry {
         }
     }
 
+    /// Returns a reference to the [`TypeTraits`] of the type with the given [`TypeUuid`].
+    pub fn get_with_type_uuid(&self, type_uuid: TypeUuid) -> Option<&TypeTraits> {
+        match self.type_uuid_to_id.get(&type_uuid) {
+            Some(id) => self.get(*id),
+            None => None,
+        }
+    }
+
+    /// Returns a mutable reference to the [`TypeTraits`] of the type with the given [`TypeUuid`].
+    pub fn get_with_type_uuid_mut(&mut self, type_uuid: TypeUuid) -> Option<&mut TypeTraits> {
+        match self.type_uuid_to_id.get(&type_uuid) {
+            Some(id) => self.get_mut(*id),
+            None => None,
+        }
+    }
+
     /// Returns `true` if the given [type name] is ambiguous, that is, it matches multiple registered types.
     ///
     /// [type name]: TypePath::type_name
@@ -255,6 +453,15 @@ impl TypeRegistry {
         self.get(type_id).map(TypeTraits::type_info)
     }
 
+    /// Returns a serializable schema describing all registered types.
+    ///
+    /// The schema lists the kind, fields, variants, generics, docs and custom attributes
+    /// of each type, for use by external tools. See [`RegistrySchema`] for the format.
+    #[inline]
+    pub fn export_schema(&self) -> RegistrySchema<'_> {
+        RegistrySchema::new(self)
+    }
+
     /// Returns an iterator over the [`TypeTraits`]s of the registered types.
     #[inline]
     pub fn iter(&self) -> impl Iterator<Item = &TypeTraits> {
@@ -284,10 +491,19 @@ impl Default for TypeRegistry {
     }
 }
 
+/// A thread-safe shared [`TypeRegistry`].
+///
+/// Besides locking, [`snapshot`](Self::snapshot) gives read-mostly users, such as serializers,
+/// an immutable copy of the registry that can be used without holding any lock.
+/// The copy is shared until the next [`write`](Self::write).
 #[derive(Clone, Default)]
 pub struct TypeRegistryArc {
     /// The wrapped [`TypeRegistry`].
+    ///
+    /// Modifying it through this field directly does not invalidate the
+    /// [snapshot](Self::snapshot), use [`write`](Self::write) instead.
     pub internal: Arc<RwLock<TypeRegistry>>,
+    snapshot: Arc<RwLock<Option<Arc<TypeRegistry>>>>,
 }
 
 impl TypeRegistryArc {
@@ -297,10 +513,39 @@ impl TypeRegistryArc {
     }
 
     /// Takes a write lock on the underlying [`TypeRegistry`].
+    ///
+    /// This invalidates the current [snapshot](Self::snapshot).
     pub fn write(&self) -> RwLockWriteGuard<'_, TypeRegistry> {
-        self.internal
+        let guard = self
+            .internal
             .write()
-            .unwrap_or_else(PoisonError::into_inner)
+            .unwrap_or_else(PoisonError::into_inner);
+        // Cleared while holding the write lock, so no reader can store an outdated copy.
+        *self
+            .snapshot
+            .write()
+            .unwrap_or_else(PoisonError::into_inner) = None;
+        guard
+    }
+
+    /// Returns an immutable snapshot of the underlying [`TypeRegistry`].
+    ///
+    /// The snapshot is cloned from the registry on the first call after a [`write`](Self::write),
+    /// then shared by later calls, which only briefly lock to clone the [`Arc`].
+    /// Unlike [`read`](Self::read), it doesn't block writers while it is used.
+    pub fn snapshot(&self) -> Arc<TypeRegistry> {
+        if let Some(snapshot) = &*self.snapshot.read().unwrap_or_else(PoisonError::into_inner) {
+            return snapshot.clone();
+        }
+
+        // Hold the read lock while storing, so a writer can't modify the registry in between.
+        let registry = self.read();
+        let mut slot = self
+            .snapshot
+            .write()
+            .unwrap_or_else(PoisonError::into_inner);
+        slot.get_or_insert_with(|| Arc::new(registry.clone()))
+            .clone()
     }
 }
 
@@ -42,17 +42,37 @@ impl dyn TypeTrait {
 pub struct TypeTraits {
     trait_map: TypeIdMap<Box<dyn TypeTrait>>,
     type_info: &'static TypeInfo,
+    generation: u64,
 }
 
 impl TypeTraits {
     #[inline]
-    pub fn of<T: Typed>() -> Self {
+    pub fn of<T: Typed + ?Sized>() -> Self {
         Self {
             trait_map: TypeIdMap::new(),
             type_info: T::type_info(),
+            generation: 0,
         }
     }
 
+    /// Returns the generation of the [`TypeRegistry`] at which this registration was added.
+    ///
+    /// It is `0` for values that have never been added to a registry.
+    /// Comparing it with [`TypeRegistry::get_generation`] detects stale lookups
+    /// after a type has been removed and registered again.
+    ///
+    /// [`TypeRegistry`]: crate::registry::TypeRegistry
+    /// [`TypeRegistry::get_generation`]: crate::registry::TypeRegistry::get_generation
+    #[inline]
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    #[inline]
+    pub(crate) fn set_generation(&mut self, generation: u64) {
+        self.generation = generation;
+    }
+
     #[inline]
     pub fn type_info(&self) -> &'static TypeInfo {
         self.type_info
@@ -68,6 +88,23 @@ impl TypeTraits {
         self.trait_map.insert(TypeId::of::<T>(), Box::new(data));
     }
 
+    /// Inserts a type trait whose concrete type is only known at runtime,
+    /// e.g. one created by a plugin without going through [`FromType`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `type_id` is not the [`TypeId`] of the concrete type behind `data`.
+    ///
+    /// [`FromType`]: crate::registry::FromType
+    pub fn insert_arbitrary(&mut self, type_id: TypeId, data: Box<dyn TypeTrait>) {
+        assert_eq!(
+            type_id,
+            (*data).type_id(),
+            "Called `TypeTraits::insert_arbitrary` with a `TypeId` that doesn't match the type trait",
+        );
+        self.trait_map.insert(type_id, data);
+    }
+
     #[inline]
     pub fn remove<T: TypeTrait>(&mut self) -> Option<Box<T>> {
         // TODO: Use downcast_uncheck to reduce once type check
@@ -149,6 +186,7 @@ impl Clone for TypeTraits {
         Self {
             trait_map: new_map,
             type_info: self.type_info,
+            generation: self.generation,
         }
     }
 }
@@ -157,6 +195,7 @@ impl fmt::Debug for TypeTraits {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("TypeTraits")
             .field("type_info", &self.type_info)
+            .field("generation", &self.generation)
             .finish()
     }
 }
@@ -1,5 +1,5 @@
 use alloc::{boxed::Box, format};
-use core::fmt;
+use core::{any::TypeId, fmt};
 use serde::{
     Deserializer,
     de::{DeserializeSeed, Error, IgnoredAny, MapAccess, Visitor},
@@ -8,8 +8,10 @@ use serde::{
 use crate::{
     Reflect,
     info::{TypeInfo, Typed},
+    migrate::TypeTraitMigrations,
     registry::{
-        GetTypeTraits, TypeRegistry, TypeTraitDeserialize, TypeTraitFromReflect, TypeTraits,
+        GetTypeTraits, NO_CURRENT_REGISTRY, TypeRegistry, TypeTraitDeref, TypeTraitDeserialize,
+        TypeTraitFromReflect, TypeTraitTransparent, TypeTraits,
     },
 };
 
@@ -18,6 +20,7 @@ use super::{
     list_visitor::ListVisitor, map_visitor::MapVisitor, option_visitor::OptionVisitor,
     set_visitor::SetVisitor, struct_visitor::StructVisitor,
     tuple_struct_visitor::TupleStructVisitor, tuple_visitor::TupleVisitor,
+    versioned_struct_visitor::VersionedStructVisitor,
 };
 
 pub struct InternalDeserializer<'a, P: DeserializerProcessor = ()> {
@@ -39,7 +42,7 @@ impl<'a> InternalDeserializer<'a, ()> {
     #[inline]
     pub fn of<T: Typed + GetTypeTraits>(registry: &'a TypeRegistry) -> Self {
         let type_traits = registry
-            .get(core::any::TypeId::of::<T>())
+            .get(TypeId::of::<T>())
             .unwrap_or_else(|| panic!("no type_traits found for type `{}`", T::type_path()));
 
         Self {
@@ -102,26 +105,39 @@ impl<'de, P: DeserializerProcessor> DeserializeSeed<'de> for InternalDeserialize
 
         let dynamic_value: Box<dyn Reflect> = match self.type_traits.type_info() {
             TypeInfo::Struct(struct_info) => {
-                let mut dynamic_struct = deserializer.deserialize_struct(
-                    struct_info
-                        .type_path_table()
-                        .ident(),
-                    struct_info.field_names(),
-                    StructVisitor {
-                        struct_info,
-                        registry: self.registry,
-                        processor: self.processor,
-                    },
-                )?;
+                let mut dynamic_struct = match self.type_traits.get::<TypeTraitMigrations>() {
+                    Some(migrations) => {
+                        let visitor = VersionedStructVisitor {
+                            struct_info,
+                            migrations,
+                            registry: self.registry,
+                            processor: self.processor,
+                        };
+                        // Self-describing formats also accept a bare struct,
+                        // the data saved before the struct was versioned.
+                        if deserializer.is_human_readable() {
+                            deserializer.deserialize_any(visitor)?
+                        } else {
+                            deserializer.deserialize_tuple(2, visitor)?
+                        }
+                    }
+                    None => deserializer.deserialize_struct(
+                        struct_info.type_path_table().ident(),
+                        struct_info.field_names(),
+                        StructVisitor {
+                            struct_info,
+                            registry: self.registry,
+                            processor: self.processor,
+                        },
+                    )?,
+                };
                 dynamic_struct.set_type_info(Some(self.type_traits.type_info()));
                 Box::new(dynamic_struct)
             }
             TypeInfo::TupleStruct(tuple_struct_info) => {
                 let mut dynamic_tuple_struct = if tuple_struct_info.field_len() == 1 {
                     deserializer.deserialize_newtype_struct(
-                        tuple_struct_info
-                            .type_path_table()
-                            .ident(),
+                        tuple_struct_info.type_path_table().ident(),
                         TupleStructVisitor {
                             tuple_struct_info,
                             registry: self.registry,
@@ -130,9 +146,7 @@ impl<'de, P: DeserializerProcessor> DeserializeSeed<'de> for InternalDeserialize
                     )?
                 } else {
                     deserializer.deserialize_tuple_struct(
-                        tuple_struct_info
-                            .type_path_table()
-                            .ident(),
+                        tuple_struct_info.type_path_table().ident(),
                         tuple_struct_info.field_len(),
                         TupleStructVisitor {
                             tuple_struct_info,
@@ -219,10 +233,35 @@ impl<'de, P: DeserializerProcessor> DeserializeSeed<'de> for InternalDeserialize
                 dynamic_enum.set_type_info(Some(self.type_traits.type_info()));
                 Box::new(dynamic_enum)
             }
+            TypeInfo::Opaque(_) if self.type_traits.type_id() == TypeId::of::<dyn Reflect>() => {
+                // `#[reflect(dynamic)]` fields carry their own type path.
+                return ReflectDeserializer {
+                    registry: Some(self.registry),
+                    processor: self.processor,
+                }
+                .deserialize(deserializer);
+            }
             TypeInfo::Opaque(_) => {
-                return Err(Error::custom(
-                    "No deserialization method available for this Opauqe was found.",
-                ));
+                // Transparent types and smart pointers are deserialized as their field or pointee,
+                // then converted below.
+                let inner_type_id =
+                    if let Some(transparent) = self.type_traits.get::<TypeTraitTransparent>() {
+                        transparent.field_type_id()
+                    } else if let Some(deref) = self.type_traits.get::<TypeTraitDeref>() {
+                        deref.pointee_type_id()
+                    } else {
+                        return Err(Error::custom(
+                            "No deserialization method available for this Opauqe was found.",
+                        ));
+                    };
+                let Some(inner_traits) = self.registry.get(inner_type_id) else {
+                    return Err(Error::custom(format!(
+                        "no type_traits found for the inner type of `{}`",
+                        self.type_traits.type_info().type_path(),
+                    )));
+                };
+                InternalDeserializer::new_internal(inner_traits, self.registry, self.processor)
+                    .deserialize(deserializer)?
             }
         };
 
@@ -237,7 +276,7 @@ impl<'de, P: DeserializerProcessor> DeserializeSeed<'de> for InternalDeserialize
 }
 
 pub struct ReflectDeserializer<'a, P: DeserializerProcessor = ()> {
-    registry: &'a TypeRegistry,
+    registry: Option<&'a TypeRegistry>,
     processor: Option<&'a mut P>,
 }
 
@@ -245,7 +284,19 @@ impl<'a> ReflectDeserializer<'a, ()> {
     #[inline]
     pub fn new(registry: &'a TypeRegistry) -> Self {
         Self {
-            registry,
+            registry: Some(registry),
+            processor: None,
+        }
+    }
+
+    /// Creates a deserializer using the [current](TypeRegistry::current) registry
+    /// at the time of deserialization.
+    ///
+    /// Deserialization fails if there is no current registry.
+    #[inline]
+    pub fn current() -> Self {
+        Self {
+            registry: None,
             processor: None,
         }
     }
@@ -255,7 +306,7 @@ impl<'a, P: DeserializerProcessor> ReflectDeserializer<'a, P> {
     #[inline]
     pub fn with_processor(registry: &'a TypeRegistry, processor: &'a mut P) -> Self {
         Self {
-            registry,
+            registry: Some(registry),
             processor: Some(processor),
         }
     }
@@ -301,10 +352,14 @@ impl<'de, P: DeserializerProcessor> DeserializeSeed<'de> for ReflectDeserializer
             }
         }
 
-        deserializer.deserialize_map(ReflectDeserializerVisitor {
-            registry: self.registry,
-            processor: self.processor,
+        let _timer = vct_utils::timer!("reflect.deserialize");
+        TypeRegistry::with_or_current(self.registry, |registry| {
+            deserializer.deserialize_map(ReflectDeserializerVisitor {
+                registry,
+                processor: self.processor,
+            })
         })
+        .unwrap_or_else(|| Err(Error::custom(NO_CURRENT_REGISTRY)))
     }
 }
 
@@ -13,6 +13,7 @@ mod set_visitor;
 mod struct_visitor;
 mod tuple_struct_visitor;
 mod tuple_visitor;
+mod versioned_struct_visitor;
 
 mod struct_like_utils;
 mod tuple_like_utils;
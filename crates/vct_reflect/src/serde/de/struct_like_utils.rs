@@ -12,7 +12,7 @@ use crate::{
     info::{NamedField, StructInfo, StructVariantInfo},
     ops::DynamicStruct,
     registry::TypeRegistry,
-    serde::SkipSerde,
+    serde::{SkipSerde, SkipSerializing},
 };
 
 use super::{DeserializerProcessor, InternalDeserializer};
@@ -138,7 +138,8 @@ where
     let mut dynamic_struct = DynamicStruct::with_capacity(info.field_len());
 
     while let Some(Ident(key)) = map.next_key::<Ident>()? {
-        let field_ty = info.field::<V::Error>(&key)?.ty();
+        let field = info.field::<V::Error>(&key)?;
+        let field_ty = field.ty();
 
         // cannot skip here, we need to call `next_value_seed`.
 
@@ -153,7 +154,7 @@ where
             registry,
             processor.as_deref_mut(),
         ))?;
-        dynamic_struct.insert_boxed(key, value);
+        dynamic_struct.insert_boxed(field.name(), value);
     }
 
     for field in info.iter_fields() {
@@ -197,6 +198,14 @@ where
             continue;
         }
 
+        // Always skipped fields are not in the sequence.
+        if field
+            .get_attribute::<SkipSerializing>()
+            .is_some_and(SkipSerializing::is_always)
+        {
+            continue;
+        }
+
         let Some(type_traits) = registry.get(field.type_id()) else {
             return Err(Error::custom(format!(
                 "no type_traits found for type `{:?}`",
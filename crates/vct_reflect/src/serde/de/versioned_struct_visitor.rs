@@ -0,0 +1,148 @@
+use alloc::borrow::Cow;
+use core::{fmt, fmt::Formatter};
+use serde::{
+    Deserializer,
+    de::{DeserializeSeed, Error, MapAccess, SeqAccess, Visitor},
+};
+
+use crate::{
+    info::{StructInfo, TypeInfo},
+    migrate::{MigrationError, TypeTraitMigrations},
+    ops::DynamicStruct,
+    registry::TypeRegistry,
+};
+
+use super::{DeserializerProcessor, struct_visitor::StructVisitor};
+
+/// A [`Visitor`] for deserializing versioned [`Struct`] values,
+/// serialized as `(version, struct)`, or as a bare struct saved before versioning.
+///
+/// Data of an older version is read in the shape of its [`Migration`],
+/// then migrated to the current version.
+///
+/// [`Struct`]: crate::ops::Struct
+/// [`Migration`]: crate::migrate::Migration
+pub(super) struct VersionedStructVisitor<'a, P: DeserializerProcessor> {
+    pub struct_info: &'static StructInfo,
+    pub migrations: &'a TypeTraitMigrations,
+    pub registry: &'a TypeRegistry,
+    pub processor: Option<&'a mut P>,
+}
+
+impl<P: DeserializerProcessor> VersionedStructVisitor<'_, P> {
+    /// Returns the shape of the data of `version`, `None` for the current version.
+    fn shape<E: Error>(&self, version: u32) -> Result<Option<&'static TypeInfo>, E> {
+        let type_path = self.struct_info.type_path();
+        if version == self.migrations.version() {
+            return Ok(None);
+        }
+        if version > self.migrations.version() {
+            return Err(Error::custom(MigrationError::UnknownVersion {
+                type_path: Cow::Borrowed(type_path),
+                version,
+            }));
+        }
+        match self.migrations.get(version) {
+            Some(migration) => Ok(Some(migration.shape())),
+            None => Err(Error::custom(MigrationError::MissingMigration {
+                type_path: Cow::Borrowed(type_path),
+                from_version: version,
+            })),
+        }
+    }
+
+    /// Returns the [`StructInfo`] of `shape`, the current one for `None`.
+    fn struct_info(&self, shape: Option<&'static TypeInfo>) -> &'static StructInfo {
+        match shape {
+            Some(TypeInfo::Struct(struct_info)) => struct_info,
+            Some(_) => unreachable!("the shape of a migration is always a struct"),
+            None => self.struct_info,
+        }
+    }
+
+    /// Migrates `value` of `version`, read in the shape `shape`, to the current version.
+    fn migrate<E: Error>(
+        &self,
+        version: u32,
+        shape: Option<&'static TypeInfo>,
+        mut value: DynamicStruct,
+    ) -> Result<DynamicStruct, E> {
+        if shape.is_none() {
+            return Ok(value);
+        }
+        value.set_type_info(shape);
+        self.migrations
+            .migrate(self.struct_info.type_path(), version, value)
+            .map_err(Error::custom)
+    }
+}
+
+impl<'de, P: DeserializerProcessor> Visitor<'de> for VersionedStructVisitor<'_, P> {
+    type Value = DynamicStruct;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("reflected versioned struct value")
+    }
+
+    fn visit_seq<A>(mut self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let version: u32 = seq
+            .next_element()?
+            .ok_or_else(|| Error::invalid_length(0, &self))?;
+        let shape = self.shape(version)?;
+
+        let value = seq
+            .next_element_seed(StructFieldsDeserializer {
+                struct_info: self.struct_info(shape),
+                registry: self.registry,
+                processor: self.processor.take(),
+            })?
+            .ok_or_else(|| Error::invalid_length(1, &self))?;
+
+        self.migrate(version, shape, value)
+    }
+
+    /// A bare struct is the data saved before the struct was versioned,
+    /// read as the oldest version.
+    fn visit_map<V>(mut self, map: V) -> Result<Self::Value, V::Error>
+    where
+        V: MapAccess<'de>,
+    {
+        let version = self.migrations.oldest_version();
+        let shape = self.shape(version)?;
+
+        let value = StructVisitor {
+            struct_info: self.struct_info(shape),
+            registry: self.registry,
+            processor: self.processor.take(),
+        }
+        .visit_map(map)?;
+
+        self.migrate(version, shape, value)
+    }
+}
+
+/// Deserializes the fields of a struct in the shape of `struct_info`.
+struct StructFieldsDeserializer<'a, P: DeserializerProcessor> {
+    struct_info: &'static StructInfo,
+    registry: &'a TypeRegistry,
+    processor: Option<&'a mut P>,
+}
+
+impl<'de, P: DeserializerProcessor> DeserializeSeed<'de> for StructFieldsDeserializer<'_, P> {
+    type Value = DynamicStruct;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_struct(
+            self.struct_info.type_path_table().ident(),
+            self.struct_info.field_names(),
+            StructVisitor {
+                struct_info: self.struct_info,
+                registry: self.registry,
+                processor: self.processor,
+            },
+        )
+    }
+}
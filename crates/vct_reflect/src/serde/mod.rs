@@ -1,5 +1,11 @@
 mod skip_field;
-pub use skip_field::SkipSerde;
+pub use skip_field::{SkipSerde, SkipSerializing};
+
+mod schema;
+pub use schema::{RegistrySchema, TypeSchema};
+
+mod reflect_serde;
+pub use reflect_serde::{reflect_deserialize, reflect_serialize};
 
 mod de;
 mod ser;
@@ -0,0 +1,65 @@
+use alloc::format;
+use core::any::TypeId;
+use serde::{Deserializer, Serialize, Serializer, de::DeserializeSeed, de::Error};
+
+use crate::{
+    FromReflect, Reflect,
+    cell::GenericTypeCell,
+    info::Typed,
+    registry::{GetTypeTraits, TypeRegistry},
+    serde::{InternalDeserializer, InternalSerializer},
+};
+
+/// Calls `f` with the [current](TypeRegistry::current) registry if `T` is registered in it,
+/// otherwise with a registry containing `T` and its dependencies, created once per type.
+fn with_registry_of<T: GetTypeTraits, R>(f: impl FnOnce(&TypeRegistry) -> R) -> R {
+    if let Some(registry) = TypeRegistry::current()
+        && registry.contains(TypeId::of::<T>())
+    {
+        return f(&registry);
+    }
+
+    static CELL: GenericTypeCell<TypeRegistry> = GenericTypeCell::new();
+    f(CELL.get_or_insert::<T, _>(|| {
+        let mut registry = TypeRegistry::new();
+        registry.register::<T>();
+        registry
+    }))
+}
+
+/// Serializes `value` through the reflection serializers, without the type path.
+///
+/// This is the `serde::Serialize` impl generated by `#[reflect(derive_serde)]`.
+///
+/// The [current](TypeRegistry::current) registry is used if `T` is registered in it,
+/// otherwise a registry of `T` and its dependencies, created on the first call and kept.
+pub fn reflect_serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Reflect + GetTypeTraits,
+    S: Serializer,
+{
+    with_registry_of::<T, _>(|registry| {
+        InternalSerializer::new(value, registry).serialize(serializer)
+    })
+}
+
+/// Deserializes a `T` through the reflection deserializers and [`FromReflect`].
+///
+/// This is the `serde::Deserialize` impl generated by `#[reflect(derive_serde)]`,
+/// the registry is chosen like [`reflect_serialize`].
+pub fn reflect_deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: FromReflect + Typed + GetTypeTraits,
+    D: Deserializer<'de>,
+{
+    with_registry_of::<T, _>(|registry| {
+        let value = InternalDeserializer::of::<T>(registry).deserialize(deserializer)?;
+        T::take_from_reflect(value).map_err(|value| {
+            Error::custom(format!(
+                "cannot convert `{}` to `{}`",
+                value.reflect_type_path(),
+                T::type_path()
+            ))
+        })
+    })
+}
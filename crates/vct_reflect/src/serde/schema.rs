@@ -0,0 +1,317 @@
+use alloc::vec::Vec;
+use serde::{
+    Serialize, Serializer,
+    ser::{SerializeMap, SerializeSeq, SerializeStruct},
+};
+
+use crate::{
+    info::{
+        ConstParamData, CustomAttributes, GenericInfo, NamedField, TypeInfo, TypeUuid,
+        UnnamedField, VariantInfo,
+    },
+    registry::TypeRegistry,
+};
+
+use super::InternalSerializer;
+
+/// Returns the docs of `$info`, or `None` without the `reflect_docs` feature.
+macro_rules! docs_of {
+    ($info:expr) => {{
+        #[cfg(feature = "reflect_docs")]
+        let docs: Option<&str> = $info.docs();
+        #[cfg(not(feature = "reflect_docs"))]
+        let docs: Option<&str> = None;
+        docs
+    }};
+}
+
+/// A serializable description of all types in a [`TypeRegistry`].
+///
+/// Created by [`TypeRegistry::export_schema`], it is serialized as a struct
+/// with a single `types` field listing a [`TypeSchema`] for each registered type,
+/// sorted by type path.
+///
+/// Custom attributes are serialized with the registry,
+/// so this fails if an attribute value cannot be serialized.
+pub struct RegistrySchema<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> RegistrySchema<'a> {
+    /// Creates a schema of all types in `registry`.
+    #[inline]
+    pub fn new(registry: &'a TypeRegistry) -> Self {
+        Self { registry }
+    }
+}
+
+impl Serialize for RegistrySchema<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut infos: Vec<&'static TypeInfo> = self
+            .registry
+            .iter()
+            .map(|type_traits| type_traits.type_info())
+            .collect();
+        infos.sort_unstable_by_key(|info| info.type_path());
+
+        let mut state = serializer.serialize_struct("RegistrySchema", 1)?;
+        state.serialize_field(
+            "types",
+            &SeqSchema(
+                infos
+                    .iter()
+                    .map(|info| TypeSchema::new(info, self.registry)),
+            ),
+        )?;
+        state.end()
+    }
+}
+
+/// A serializable description of a single type.
+///
+/// Every type has the `type_path`, `type_name`, `kind`, `uuid`, `docs`, `generics`
+/// and `attributes` fields, followed by fields depending on its kind:
+///
+/// - `Struct`, `TupleStruct` and `Tuple`: `fields`
+/// - `List` and `Set`: `item`
+/// - `Array`: `item` and `capacity`
+/// - `Map`: `key` and `value`
+/// - `Enum`: `variants`
+/// - `Opaque`: none
+///
+/// Other types are referred to by their type path.
+pub struct TypeSchema<'a> {
+    info: &'static TypeInfo,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> TypeSchema<'a> {
+    /// Creates a schema of the type described by `info`.
+    ///
+    /// The registry is used to serialize custom attributes.
+    #[inline]
+    pub fn new(info: &'static TypeInfo, registry: &'a TypeRegistry) -> Self {
+        Self { info, registry }
+    }
+}
+
+impl Serialize for TypeSchema<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let info = self.info;
+        let field_len = match info {
+            TypeInfo::Opaque(_) => 7,
+            TypeInfo::Array(_) | TypeInfo::Map(_) => 9,
+            _ => 8,
+        };
+
+        let mut state = serializer.serialize_struct("TypeSchema", field_len)?;
+        state.serialize_field("type_path", info.type_path())?;
+        state.serialize_field("type_name", info.type_path_table().name())?;
+        state.serialize_field("kind", &format_args!("{}", info.kind()))?;
+        state.serialize_field("uuid", &info.type_uuid().map(UuidSchema))?;
+        state.serialize_field("docs", &docs_of!(info))?;
+        state.serialize_field(
+            "generics",
+            &SeqSchema(info.generics().iter().map(GenericSchema)),
+        )?;
+        state.serialize_field(
+            "attributes",
+            &AttributesSchema(info.custom_attributes(), self.registry),
+        )?;
+
+        match info {
+            TypeInfo::Struct(info) => {
+                state.serialize_field(
+                    "fields",
+                    &SeqSchema(info.iter().map(|f| NamedFieldSchema(f, self.registry))),
+                )?;
+            }
+            TypeInfo::TupleStruct(info) => {
+                state.serialize_field(
+                    "fields",
+                    &SeqSchema(info.iter().map(|f| UnnamedFieldSchema(f, self.registry))),
+                )?;
+            }
+            TypeInfo::Tuple(info) => {
+                state.serialize_field(
+                    "fields",
+                    &SeqSchema(info.iter().map(|f| UnnamedFieldSchema(f, self.registry))),
+                )?;
+            }
+            TypeInfo::List(info) => {
+                state.serialize_field("item", info.item_info().type_path())?;
+            }
+            TypeInfo::Array(info) => {
+                state.serialize_field("item", info.item_info().type_path())?;
+                state.serialize_field("capacity", &info.capacity())?;
+            }
+            TypeInfo::Map(info) => {
+                state.serialize_field("key", info.key_info().type_path())?;
+                state.serialize_field("value", info.value_info().type_path())?;
+            }
+            TypeInfo::Set(info) => {
+                state.serialize_field("item", info.value_info().type_path())?;
+            }
+            TypeInfo::Enum(info) => {
+                state.serialize_field(
+                    "variants",
+                    &SeqSchema(info.iter().map(|v| VariantSchema(v, self.registry))),
+                )?;
+            }
+            TypeInfo::Opaque(_) => {}
+        }
+
+        state.end()
+    }
+}
+
+/// Serializes the items of an iterator as a sequence.
+struct SeqSchema<I>(I);
+
+impl<I> Serialize for SeqSchema<I>
+where
+    I: ExactSizeIterator + Clone,
+    I::Item: Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_seq(Some(self.0.len()))?;
+        for item in self.0.clone() {
+            state.serialize_element(&item)?;
+        }
+        state.end()
+    }
+}
+
+struct UuidSchema(TypeUuid);
+
+impl Serialize for UuidSchema {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&self.0)
+    }
+}
+
+/// Serializes custom attributes as a map from their type path to their value.
+struct AttributesSchema<'a>(Option<&'a CustomAttributes>, &'a TypeRegistry);
+
+impl Serialize for AttributesSchema<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let Some(attributes) = self.0 else {
+            return serializer.serialize_map(Some(0))?.end();
+        };
+        let mut state = serializer.serialize_map(Some(attributes.len()))?;
+        for (_, value) in attributes.iter() {
+            state.serialize_entry(
+                value.reflect_type_path(),
+                &InternalSerializer::new(value, self.1),
+            )?;
+        }
+        state.end()
+    }
+}
+
+struct GenericSchema<'a>(&'a GenericInfo);
+
+impl Serialize for GenericSchema<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("GenericSchema", 4)?;
+        state.serialize_field("name", &**self.0.name())?;
+        state.serialize_field("is_const", &self.0.is_const())?;
+        state.serialize_field("type_path", self.0.ty().path())?;
+        match self.0 {
+            GenericInfo::Type(info) => {
+                state.serialize_field("default", &info.default().map(|ty| ty.path()))?;
+            }
+            GenericInfo::Const(info) => {
+                state.serialize_field("default", &info.default().map(ConstSchema))?;
+            }
+        }
+        state.end()
+    }
+}
+
+struct ConstSchema(ConstParamData);
+
+impl Serialize for ConstSchema {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.0 {
+            ConstParamData::U8(val) => serializer.serialize_u8(val),
+            ConstParamData::U16(val) => serializer.serialize_u16(val),
+            ConstParamData::U32(val) => serializer.serialize_u32(val),
+            ConstParamData::U64(val) => serializer.serialize_u64(val),
+            ConstParamData::U128(val) => serializer.serialize_u128(val),
+            ConstParamData::Usize(val) => serializer.serialize_u64(val as u64),
+            ConstParamData::I8(val) => serializer.serialize_i8(val),
+            ConstParamData::I16(val) => serializer.serialize_i16(val),
+            ConstParamData::I32(val) => serializer.serialize_i32(val),
+            ConstParamData::I64(val) => serializer.serialize_i64(val),
+            ConstParamData::I128(val) => serializer.serialize_i128(val),
+            ConstParamData::Isize(val) => serializer.serialize_i64(val as i64),
+            ConstParamData::Char(val) => serializer.serialize_char(val),
+            ConstParamData::Bool(val) => serializer.serialize_bool(val),
+        }
+    }
+}
+
+struct NamedFieldSchema<'a>(&'a NamedField, &'a TypeRegistry);
+
+impl Serialize for NamedFieldSchema<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let field = self.0;
+        let mut state = serializer.serialize_struct("FieldSchema", 4)?;
+        state.serialize_field("name", field.name())?;
+        state.serialize_field("type_path", field.ty().path())?;
+        state.serialize_field("docs", &docs_of!(field))?;
+        state.serialize_field(
+            "attributes",
+            &AttributesSchema(field.custom_attributes(), self.1),
+        )?;
+        state.end()
+    }
+}
+
+struct UnnamedFieldSchema<'a>(&'a UnnamedField, &'a TypeRegistry);
+
+impl Serialize for UnnamedFieldSchema<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let field = self.0;
+        let mut state = serializer.serialize_struct("FieldSchema", 4)?;
+        state.serialize_field("index", &field.index())?;
+        state.serialize_field("type_path", field.ty().path())?;
+        state.serialize_field("docs", &docs_of!(field))?;
+        state.serialize_field(
+            "attributes",
+            &AttributesSchema(field.custom_attributes(), self.1),
+        )?;
+        state.end()
+    }
+}
+
+struct VariantSchema<'a>(&'a VariantInfo, &'a TypeRegistry);
+
+impl Serialize for VariantSchema<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let variant = self.0;
+        let mut state = serializer.serialize_struct("VariantSchema", 6)?;
+        state.serialize_field("name", variant.name())?;
+        state.serialize_field("kind", &format_args!("{:?}", variant.variant_kind()))?;
+        state.serialize_field("discriminant", &variant.discriminant())?;
+        state.serialize_field("docs", &docs_of!(variant))?;
+        state.serialize_field(
+            "attributes",
+            &AttributesSchema(variant.custom_attributes(), self.1),
+        )?;
+        match variant {
+            VariantInfo::Struct(info) => state.serialize_field(
+                "fields",
+                &SeqSchema(info.iter().map(|f| NamedFieldSchema(f, self.1))),
+            )?,
+            VariantInfo::Tuple(info) => state.serialize_field(
+                "fields",
+                &SeqSchema(info.iter().map(|f| UnnamedFieldSchema(f, self.1))),
+            )?,
+            VariantInfo::Unit(_) => state.serialize_field("fields", &[(); 0][..])?,
+        }
+        state.end()
+    }
+}
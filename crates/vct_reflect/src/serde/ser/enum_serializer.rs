@@ -4,11 +4,12 @@ use serde::{
     ser::{Error, SerializeStructVariant, SerializeTupleVariant},
 };
 
-use super::{InternalSerializer, SerializerProcessor};
+use super::{FieldSerializer, SerializerProcessor};
 use crate::{
-    info::{TypeInfo, VariantInfo, VariantKind},
+    info::{NamedField, TypeInfo, VariantInfo, VariantKind},
     ops::Enum,
     registry::TypeRegistry,
+    serde::SkipSerializing,
 };
 
 /// A serializer for [`Enum`] values.
@@ -71,19 +72,38 @@ impl<P: SerializerProcessor> Serialize for EnumSerializer<'_, P> {
                         )));
                     }
                 };
+                let is_skipped = |field_info: &NamedField| {
+                    field_info
+                        .get_attribute::<SkipSerializing>()
+                        .is_some_and(|skip| {
+                            self.enum_value
+                                .field(field_info.name())
+                                .is_some_and(|value| skip.should_skip(value))
+                        })
+                };
+                let skipped_len = struct_info.iter().filter(|f| is_skipped(f)).count();
+
                 let mut state = serializer.serialize_struct_variant(
                     enum_name,
                     variant_index,
                     variant_name,
-                    field_len,
+                    field_len - skipped_len,
                 )?;
 
                 for field_info in struct_info.iter() {
+                    if is_skipped(field_info) {
+                        continue;
+                    }
                     let name = field_info.name();
                     if let Some(value) = self.enum_value.field(name) {
                         state.serialize_field(
                             name,
-                            &InternalSerializer::new_internal(value, self.registry, self.processor),
+                            &FieldSerializer::new_internal(
+                                value,
+                                field_info.type_id(),
+                                self.registry,
+                                self.processor,
+                            ),
                         )?;
                     } else {
                         return Err(Error::custom(format!(
@@ -97,12 +117,18 @@ impl<P: SerializerProcessor> Serialize for EnumSerializer<'_, P> {
             }
             VariantKind::Tuple if field_len == 1 => {
                 let field = self.enum_value.field_at(0).unwrap();
+                let field_type_id = match variant_info {
+                    VariantInfo::Tuple(tuple_info) => tuple_info.field_at(0).map(|f| f.type_id()),
+                    _ => None,
+                }
+                .unwrap_or_else(|| (*field).type_id());
 
                 if type_info.type_path_table().module_path() == Some("core::option")
                     && type_info.type_path_table().ident() == "Option"
                 {
-                    serializer.serialize_some(&InternalSerializer::new_internal(
+                    serializer.serialize_some(&FieldSerializer::new_internal(
                         field,
+                        field_type_id,
                         self.registry,
                         self.processor,
                     ))
@@ -111,7 +137,12 @@ impl<P: SerializerProcessor> Serialize for EnumSerializer<'_, P> {
                         enum_name,
                         variant_index,
                         variant_name,
-                        &InternalSerializer::new_internal(field, self.registry, self.processor),
+                        &FieldSerializer::new_internal(
+                            field,
+                            field_type_id,
+                            self.registry,
+                            self.processor,
+                        ),
                     )
                 }
             }
@@ -134,8 +165,9 @@ impl<P: SerializerProcessor> Serialize for EnumSerializer<'_, P> {
                 for field_info in tuple_info.iter() {
                     let index = field_info.index();
                     if let Some(value) = self.enum_value.field_at(index) {
-                        state.serialize_field(&InternalSerializer::new_internal(
+                        state.serialize_field(&FieldSerializer::new_internal(
                             value,
+                            field_info.type_id(),
                             self.registry,
                             self.processor,
                         ))?;
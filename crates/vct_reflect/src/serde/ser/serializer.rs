@@ -1,10 +1,14 @@
 use alloc::format;
+use core::any::TypeId;
 use serde::{Serialize, Serializer, ser::SerializeMap};
 
 use crate::{
     Reflect,
     ops::ReflectRef,
-    registry::{TypeRegistry, TypeTraitSerialize},
+    registry::{
+        NO_CURRENT_REGISTRY, TypeRegistry, TypeTraitDeref, TypeTraitSerialize,
+        TypeTraitTransparent,
+    },
 };
 
 use super::{
@@ -81,6 +85,18 @@ impl<'a, P: SerializerProcessor> Serialize for InternalSerializer<'a, P> {
             return p.serialize(self.value, serializer);
         }
 
+        // Smart pointers are serialized as their pointee
+        if let Some(p) = self.registry.get_type_trait::<TypeTraitDeref>(self.value.type_id()) {
+            return InternalSerializer::new_internal(p.deref(self.value), self.registry, self.processor)
+                .serialize(serializer);
+        }
+
+        // Transparent types are serialized as their field
+        if let Some(p) = self.registry.get_type_trait::<TypeTraitTransparent>(self.value.type_id()) {
+            return InternalSerializer::new_internal(p.field(self.value), self.registry, self.processor)
+                .serialize(serializer);
+        }
+
         match self.value.reflect_ref() {
             ReflectRef::Struct(struct_value) => StructSerializer {
                 struct_value,
@@ -140,7 +156,7 @@ impl<'a, P: SerializerProcessor> Serialize for InternalSerializer<'a, P> {
 /// A serializer with type path attached
 pub struct ReflectSerializer<'a, P: SerializerProcessor = ()> {
     value: &'a dyn Reflect,
-    registry: &'a TypeRegistry,
+    registry: Option<&'a TypeRegistry>,
     processor: Option<&'a P>,
 }
 
@@ -153,7 +169,20 @@ impl<'a> ReflectSerializer<'a, ()> {
     pub fn new(value: &'a dyn Reflect, registry: &'a TypeRegistry) -> Self {
         Self {
             value,
-            registry,
+            registry: Some(registry),
+            processor: None,
+        }
+    }
+
+    /// Creates a serializer using the [current](TypeRegistry::current) registry
+    /// at the time of serialization.
+    ///
+    /// Serialization fails if there is no current registry.
+    #[inline]
+    pub fn current(value: &'a dyn Reflect) -> Self {
+        Self {
+            value,
+            registry: None,
             processor: None,
         }
     }
@@ -172,7 +201,7 @@ impl<'a, P: SerializerProcessor> ReflectSerializer<'a, P> {
     ) -> Self {
         Self {
             value,
-            registry,
+            registry: Some(registry),
             processor: Some(processor),
         }
     }
@@ -180,26 +209,73 @@ impl<'a, P: SerializerProcessor> ReflectSerializer<'a, P> {
 
 impl<P: SerializerProcessor> Serialize for ReflectSerializer<'_, P> {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        let mut state = serializer.serialize_map(Some(1))?;
-        state.serialize_entry(
-            self.value
-                .represented_type_info()
-                .ok_or_else(|| {
-                    if self.value.is_dynamic() {
-                        serde::ser::Error::custom(format!(
-                            "cannot get represented type from dynamic type: `{}`.",
-                            self.value.reflect_type_path(),
-                        ))
-                    } else {
-                        serde::ser::Error::custom(format!(
-                            "cannot get type info for `{}`.",
-                            self.value.reflect_type_path(),
-                        ))
-                    }
-                })?
-                .type_path(),
-            &InternalSerializer::new_internal(self.value, self.registry, self.processor),
-        )?;
-        state.end()
+        let _timer = vct_utils::timer!("reflect.serialize");
+        TypeRegistry::with_or_current(self.registry, |registry| {
+            let mut state = serializer.serialize_map(Some(1))?;
+            state.serialize_entry(
+                self.value
+                    .represented_type_info()
+                    .ok_or_else(|| {
+                        if self.value.is_dynamic() {
+                            serde::ser::Error::custom(format!(
+                                "cannot get represented type from dynamic type: `{}`.",
+                                self.value.reflect_type_path(),
+                            ))
+                        } else {
+                            serde::ser::Error::custom(format!(
+                                "cannot get type info for `{}`.",
+                                self.value.reflect_type_path(),
+                            ))
+                        }
+                    })?
+                    .type_path(),
+                &InternalSerializer::new_internal(self.value, registry, self.processor),
+            )?;
+            state.end()
+        })
+        .unwrap_or_else(|| Err(serde::ser::Error::custom(NO_CURRENT_REGISTRY)))
+    }
+}
+
+/// A serializer for fields, the type path is attached if the field is declared as `dyn Reflect`.
+///
+/// See `#[reflect(dynamic)]`.
+pub(super) struct FieldSerializer<'a, P: SerializerProcessor> {
+    value: &'a dyn Reflect,
+    field_type_id: TypeId,
+    registry: &'a TypeRegistry,
+    processor: Option<&'a P>,
+}
+
+impl<'a, P: SerializerProcessor> FieldSerializer<'a, P> {
+    #[inline]
+    pub(super) fn new_internal(
+        value: &'a dyn Reflect,
+        field_type_id: TypeId,
+        registry: &'a TypeRegistry,
+        processor: Option<&'a P>,
+    ) -> Self {
+        Self {
+            value,
+            field_type_id,
+            registry,
+            processor,
+        }
+    }
+}
+
+impl<P: SerializerProcessor> Serialize for FieldSerializer<'_, P> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if self.field_type_id == TypeId::of::<dyn Reflect>() {
+            ReflectSerializer {
+                value: self.value,
+                registry: Some(self.registry),
+                processor: self.processor,
+            }
+            .serialize(serializer)
+        } else {
+            InternalSerializer::new_internal(self.value, self.registry, self.processor)
+                .serialize(serializer)
+        }
     }
 }
@@ -1,12 +1,18 @@
 use alloc::format;
 use serde::{
     Serialize,
-    ser::{Error, SerializeStruct},
+    ser::{Error, SerializeStruct, SerializeTuple},
 };
 
-use crate::{info::TypeInfo, ops::Struct, registry::TypeRegistry, serde::SkipSerde};
+use crate::{
+    info::{NamedField, StructInfo, TypeInfo},
+    migrate::TypeTraitMigrations,
+    ops::Struct,
+    registry::TypeRegistry,
+    serde::{SkipSerde, SkipSerializing},
+};
 
-use super::{InternalSerializer, SerializerProcessor};
+use super::{FieldSerializer, SerializerProcessor};
 
 /// A serializer for [`Struct`] values.
 pub(super) struct StructSerializer<'a, P: SerializerProcessor> {
@@ -36,27 +42,79 @@ impl<P: SerializerProcessor> Serialize for StructSerializer<'_, P> {
             }
         };
 
+        let fields = StructFieldsSerializer {
+            struct_value: self.struct_value,
+            struct_info,
+            registry: self.registry,
+            processor: self.processor,
+        };
+
+        // Versioned structs are serialized as `(version, struct)`.
+        match self
+            .registry
+            .get_type_trait::<TypeTraitMigrations>(struct_info.type_id())
+        {
+            Some(migrations) => {
+                let mut state = serializer.serialize_tuple(2)?;
+                state.serialize_element(&migrations.version())?;
+                state.serialize_element(&fields)?;
+                state.end()
+            }
+            None => fields.serialize(serializer),
+        }
+    }
+}
+
+/// Serializes the fields of a [`Struct`] with its [`StructInfo`].
+struct StructFieldsSerializer<'a, P: SerializerProcessor> {
+    struct_value: &'a dyn Struct,
+    struct_info: &'a StructInfo,
+    registry: &'a TypeRegistry,
+    processor: Option<&'a P>,
+}
+
+impl<P: SerializerProcessor> StructFieldsSerializer<'_, P> {
+    /// Returns `true` if the field is marked by [`SkipSerde`] or [`SkipSerializing`].
+    fn should_skip(&self, field_info: &NamedField) -> bool {
+        if field_info.has_attribute::<SkipSerde>() {
+            return true;
+        }
+        match field_info.get_attribute::<SkipSerializing>() {
+            Some(skip) => self
+                .struct_value
+                .field(field_info.name())
+                .is_some_and(|value| skip.should_skip(value)),
+            None => false,
+        }
+    }
+}
+
+impl<P: SerializerProcessor> Serialize for StructFieldsSerializer<'_, P> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let struct_info = self.struct_info;
+
         let field_len = struct_info
             .iter()
-            .map(|f| !f.has_attribute::<SkipSerde>() as usize)
+            .map(|f| !self.should_skip(f) as usize)
             .sum::<usize>();
 
-        let mut state = serializer.serialize_struct(
-            struct_info
-                .type_path_table()
-                .ident(),
-            field_len,
-        )?;
+        let mut state =
+            serializer.serialize_struct(struct_info.type_path_table().ident(), field_len)?;
 
         for field_info in struct_info.iter() {
-            if field_info.has_attribute::<SkipSerde>() {
+            if self.should_skip(field_info) {
                 continue;
             }
             let name = field_info.name();
             if let Some(value) = self.struct_value.field(name) {
                 state.serialize_field(
                     name,
-                    &InternalSerializer::new_internal(value, self.registry, self.processor),
+                    &FieldSerializer::new_internal(
+                        value,
+                        field_info.type_id(),
+                        self.registry,
+                        self.processor,
+                    ),
                 )?;
             } else {
                 return Err(Error::custom(format!(
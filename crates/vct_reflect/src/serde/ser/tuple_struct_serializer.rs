@@ -4,7 +4,7 @@ use serde::{
     ser::{Error, SerializeTupleStruct},
 };
 
-use super::{InternalSerializer, SerializerProcessor};
+use super::{FieldSerializer, SerializerProcessor};
 use crate::{info::TypeInfo, ops::TupleStruct, registry::TypeRegistry, serde::SkipSerde};
 
 /// A serializer for [`TupleStruct`] values.
@@ -53,8 +53,9 @@ impl<P: SerializerProcessor> Serialize for TupleStructSerializer<'_, P> {
             }
             let index = field_info.index();
             if let Some(value) = self.tuple_struct.field(index) {
-                state.serialize_field(&InternalSerializer::new_internal(
+                state.serialize_field(&FieldSerializer::new_internal(
                     value,
+                    field_info.type_id(),
                     self.registry,
                     self.processor,
                 ))?;
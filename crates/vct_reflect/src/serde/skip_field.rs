@@ -150,3 +150,173 @@ impl Reflect for SkipSerde {
         }
     }
 }
+
+/// A custom attribute use to skip serialization of fields.
+///
+/// Unlike [`SkipSerde`], the field is still deserialized if its value is present.
+/// Usually created by `#[reflect(skip_serializing)]` or `#[reflect(skip_serializing_if = "...")]`.
+///
+/// Non-self-describing formats can only rely on [`SkipSerializing::always`],
+/// because the deserializer cannot know whether a conditionally skipped field is present.
+#[derive(Clone, Copy)]
+pub struct SkipSerializing {
+    predicate: Option<fn(&dyn Reflect) -> bool>,
+}
+
+impl SkipSerializing {
+    /// Always skip the field during serialization.
+    #[inline]
+    pub const fn always() -> Self {
+        Self { predicate: None }
+    }
+
+    /// Skip the field during serialization if `predicate` returns `true`.
+    #[inline]
+    pub const fn when(predicate: fn(&dyn Reflect) -> bool) -> Self {
+        Self { predicate: Some(predicate) }
+    }
+
+    /// Returns `true` if the field is always skipped.
+    #[inline]
+    pub const fn is_always(&self) -> bool {
+        self.predicate.is_none()
+    }
+
+    /// Returns `true` if the field with the given value should be skipped.
+    #[inline]
+    pub fn should_skip(&self, value: &dyn Reflect) -> bool {
+        match self.predicate {
+            None => true,
+            Some(predicate) => predicate(value),
+        }
+    }
+}
+
+impl TypePath for SkipSerializing {
+    #[inline]
+    fn type_path() -> &'static str {
+        "vct_reflect::serde::SkipSerializing"
+    }
+
+    #[inline]
+    fn type_name() -> &'static str {
+        "SkipSerializing"
+    }
+
+    #[inline]
+    fn type_ident() -> &'static str {
+        "SkipSerializing"
+    }
+
+    #[inline]
+    fn crate_name() -> Option<&'static str> {
+        Some("vct_reflect")
+    }
+
+    #[inline]
+    fn module_path() -> Option<&'static str> {
+        Some("vct_reflect::serde")
+    }
+}
+
+impl Typed for SkipSerializing {
+    fn type_info() -> &'static TypeInfo {
+        static CELL: NonGenericTypeInfoCell = NonGenericTypeInfoCell::new();
+        CELL.get_or_init(|| TypeInfo::Opaque(OpaqueInfo::new::<Self>()))
+    }
+}
+
+impl Reflect for SkipSerializing {
+    impl_cast_reflect_fn!();
+
+    fn reflect_kind(&self) -> ReflectKind {
+        ReflectKind::Opaque
+    }
+
+    fn reflect_ref(&self) -> ReflectRef<'_> {
+        ReflectRef::Opaque(self)
+    }
+
+    fn reflect_mut(&mut self) -> ReflectMut<'_> {
+        ReflectMut::Opaque(self)
+    }
+
+    fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+        ReflectOwned::Opaque(self)
+    }
+
+    fn try_apply(&mut self, _value: &dyn Reflect) -> Result<(), ApplyError> {
+        Err(ApplyError::NotSupport { type_path: Cow::Borrowed(Self::type_path()) })
+    }
+
+    fn reflect_clone(&self) -> Result<Box<dyn Reflect>, ReflectCloneError> {
+        Ok(Box::new(*self).into_reflect())
+    }
+
+    fn to_dynamic(&self) -> Box<dyn Reflect> {
+        Box::new(*self)
+    }
+
+    fn reflect_debug(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.is_always() {
+            f.write_str("SkipSerializing::always()")
+        } else {
+            f.write_str("SkipSerializing::when(..)")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{collections::VecDeque, string::String};
+    use serde::de::DeserializeSeed;
+    use vct_reflect_derive::Reflect;
+
+    use crate::{
+        FromReflect,
+        registry::TypeRegistry,
+        serde::{InternalDeserializer, InternalSerializer},
+    };
+
+    #[derive(Reflect, PartialEq, Debug)]
+    struct Session {
+        name: String,
+        #[reflect(skip_serializing, default)]
+        token: u64,
+        #[reflect(skip_serializing_if = "VecDeque::is_empty", default)]
+        tags: VecDeque<String>,
+    }
+
+    fn round_trip(registry: &TypeRegistry, session: &Session) -> (String, Session) {
+        let json = serde_json::to_string(&InternalSerializer::new(session, registry)).unwrap();
+        let output = InternalDeserializer::of::<Session>(registry)
+            .deserialize(&mut serde_json::Deserializer::from_str(&json))
+            .unwrap();
+        (json, Session::from_reflect(&*output).unwrap())
+    }
+
+    #[test]
+    fn skipped_fields_use_default() {
+        let mut registry = TypeRegistry::new();
+        registry.register::<Session>();
+
+        let mut session = Session {
+            name: String::from("a"),
+            token: 42,
+            tags: VecDeque::new(),
+        };
+        let (json, output) = round_trip(&registry, &session);
+        assert_eq!(json, r#"{"name":"a"}"#);
+        assert_eq!(output, Session { token: 0, ..session });
+
+        session = Session {
+            name: String::from("b"),
+            token: 42,
+            tags: VecDeque::from([String::from("x")]),
+        };
+        let (json, output) = round_trip(&registry, &session);
+        assert_eq!(json, r#"{"name":"b","tags":["x"]}"#);
+        assert_eq!(output.tags, session.tags);
+        assert_eq!(output.token, 0);
+    }
+}
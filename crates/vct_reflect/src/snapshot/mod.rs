@@ -0,0 +1,3 @@
+// Owned copies of value trees, restored with `try_apply`.
+mod reflect_snapshot;
+pub use reflect_snapshot::ReflectSnapshot;
@@ -0,0 +1,120 @@
+use alloc::{boxed::Box, vec::Vec};
+
+use crate::{Reflect, ops::ApplyError};
+
+/// An owned copy of one or more reflected value trees.
+///
+/// Values are captured with [`Reflect::reflect_clone`], falling back to [`Reflect::to_dynamic`],
+/// and restored in the same order with [`Reflect::try_apply`].
+///
+/// # Example
+///
+/// ```ignore
+/// let mut state = (1_u32, 2_u8);
+/// let snapshot = ReflectSnapshot::capture(&state);
+///
+/// state.0 = 10;
+/// snapshot.restore(&mut state).unwrap();
+/// assert_eq!(state, (1, 2));
+/// ```
+pub struct ReflectSnapshot {
+    values: Vec<Box<dyn Reflect>>,
+}
+
+impl ReflectSnapshot {
+    /// Creates an empty snapshot.
+    #[inline]
+    pub const fn new() -> Self {
+        Self { values: Vec::new() }
+    }
+
+    /// Captures a single value tree.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be cloned, see [`Reflect::to_dynamic`].
+    #[inline]
+    pub fn capture(value: &dyn Reflect) -> Self {
+        let mut snapshot = Self::new();
+        snapshot.push(value);
+        snapshot
+    }
+
+    /// Captures a set of value trees, in iteration order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a value cannot be cloned, see [`Reflect::to_dynamic`].
+    pub fn capture_all<'a>(values: impl IntoIterator<Item = &'a dyn Reflect>) -> Self {
+        let mut snapshot = Self::new();
+        values.into_iter().for_each(|value| snapshot.push(value));
+        snapshot
+    }
+
+    /// Appends a copy of `value` to the snapshot.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be cloned, see [`Reflect::to_dynamic`].
+    pub fn push(&mut self, value: &dyn Reflect) {
+        let copy = value.reflect_clone().unwrap_or_else(|_| value.to_dynamic());
+        self.values.push(copy);
+    }
+
+    /// Returns the number of captured values.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns `true` if no value has been captured.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Returns the captured value at `index`.
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<&dyn Reflect> {
+        self.values.get(index).map(|value| &**value)
+    }
+
+    /// Returns an iterator over the captured values.
+    #[inline]
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = &dyn Reflect> {
+        self.values.iter().map(|value| &**value)
+    }
+
+    /// Restores the first captured value into `target`.
+    ///
+    /// Nothing is done if the snapshot is empty.
+    ///
+    /// `target` is updated through [`Reflect::try_apply`], so on error it may be partially restored.
+    #[inline]
+    pub fn restore(&self, target: &mut dyn Reflect) -> Result<(), ApplyError> {
+        match self.values.first() {
+            Some(value) => target.try_apply(&**value),
+            None => Ok(()),
+        }
+    }
+
+    /// Restores the captured values into `targets`, in the order they were captured.
+    ///
+    /// Restoration stops at the shorter of the two sequences, or at the first error.
+    pub fn restore_all<'a>(
+        &self,
+        targets: impl IntoIterator<Item = &'a mut dyn Reflect>,
+    ) -> Result<(), ApplyError> {
+        for (value, target) in self.values.iter().zip(targets) {
+            target.try_apply(&**value)?;
+        }
+        Ok(())
+    }
+}
+
+impl Default for ReflectSnapshot {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,361 @@
+//! Provides [`BiMap`], a bidirectional one-to-one map.
+
+use core::{
+    fmt::Debug,
+    hash::{BuildHasher, Hash},
+};
+
+use hashbrown::Equivalent;
+
+use crate::{collections::HashMap, hash::FixedHash};
+
+/// The pairs that were removed from a [`BiMap`] by [`BiMap::insert`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Overwritten<L, R> {
+    /// Neither value was present, nothing was overwritten.
+    Neither,
+    /// The left value was present, the pair containing it was removed.
+    Left(L, R),
+    /// The right value was present, the pair containing it was removed.
+    Right(L, R),
+    /// The exact same pair was present and has been replaced.
+    Pair(L, R),
+    /// Both values were present in two different pairs, both were removed.
+    ///
+    /// The first pair contains the left value, the second one contains the right value.
+    Both((L, R), (L, R)),
+}
+
+impl<L, R> Overwritten<L, R> {
+    /// Returns `true` if nothing was overwritten.
+    #[inline]
+    pub fn is_neither(&self) -> bool {
+        matches!(self, Self::Neither)
+    }
+}
+
+/// A bidirectional map, in which every left value corresponds to exactly one right value.
+///
+/// Built on two of the crate's [`HashMap`], so it defaults to [`FixedHash`]
+/// and lookups in both directions take constant time.
+/// Both values are stored twice, therefore `L` and `R` must be [`Clone`].
+///
+/// # Example
+///
+/// ```
+/// # use vct_utils::collections::BiMap;
+/// let mut map = BiMap::new();
+/// map.insert(1, "one");
+/// map.insert(2, "two");
+///
+/// assert_eq!(map.get_by_left(&1), Some(&"one"));
+/// assert_eq!(map.get_by_right("two"), Some(&2));
+///
+/// // Re-binding a value removes the old pair.
+/// map.insert(3, "one");
+/// assert_eq!(map.get_by_left(&1), None);
+/// assert_eq!(map.len(), 2);
+/// ```
+pub struct BiMap<L, R, S = FixedHash> {
+    left_to_right: HashMap<L, R, S>,
+    right_to_left: HashMap<R, L, S>,
+}
+
+impl<L, R> BiMap<L, R, FixedHash> {
+    /// Create a empty [`BiMap`]
+    #[inline]
+    pub const fn new() -> Self {
+        Self::with_hashers(FixedHash, FixedHash)
+    }
+
+    /// Create a empty [`BiMap`] with specific capacity
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            left_to_right: HashMap::with_capacity_and_hasher(capacity, FixedHash),
+            right_to_left: HashMap::with_capacity_and_hasher(capacity, FixedHash),
+        }
+    }
+}
+
+impl<L, R, S> BiMap<L, R, S> {
+    /// Creates an empty [`BiMap`] which will use the given hash builders.
+    ///
+    /// `left_hasher` hashes the left values, `right_hasher` hashes the right values.
+    #[inline]
+    pub const fn with_hashers(left_hasher: S, right_hasher: S) -> Self {
+        Self {
+            left_to_right: HashMap::with_hasher(left_hasher),
+            right_to_left: HashMap::with_hasher(right_hasher),
+        }
+    }
+
+    /// Creates an empty [`BiMap`] which will use clones of the given hash builder.
+    #[inline]
+    pub fn with_hasher(hash_builder: S) -> Self
+    where
+        S: Clone,
+    {
+        Self::with_hashers(hash_builder.clone(), hash_builder)
+    }
+
+    /// Returns the number of pairs in the map.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.left_to_right.len()
+    }
+
+    /// Returns `true` if the map contains no pairs.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.left_to_right.is_empty()
+    }
+
+    /// Clears the map, removing all pairs.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.left_to_right.clear();
+        self.right_to_left.clear();
+    }
+
+    /// An iterator visiting all pairs in arbitrary order.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (&L, &R)> + '_ {
+        self.left_to_right.iter()
+    }
+
+    /// An iterator visiting all left values in arbitrary order.
+    #[inline]
+    pub fn left_values(&self) -> impl Iterator<Item = &L> + '_ {
+        self.left_to_right.keys()
+    }
+
+    /// An iterator visiting all right values in arbitrary order.
+    #[inline]
+    pub fn right_values(&self) -> impl Iterator<Item = &R> + '_ {
+        self.right_to_left.keys()
+    }
+}
+
+impl<L, R, S> BiMap<L, R, S>
+where
+    L: Eq + Hash + Clone,
+    R: Eq + Hash + Clone,
+    S: BuildHasher,
+{
+    /// Returns the right value corresponding to the left value.
+    #[inline]
+    pub fn get_by_left<Q>(&self, left: &Q) -> Option<&R>
+    where
+        Q: Hash + Equivalent<L> + ?Sized,
+    {
+        self.left_to_right.get(left)
+    }
+
+    /// Returns the left value corresponding to the right value.
+    #[inline]
+    pub fn get_by_right<Q>(&self, right: &Q) -> Option<&L>
+    where
+        Q: Hash + Equivalent<R> + ?Sized,
+    {
+        self.right_to_left.get(right)
+    }
+
+    /// Returns `true` if the map contains the left value.
+    #[inline]
+    pub fn contains_left<Q>(&self, left: &Q) -> bool
+    where
+        Q: Hash + Equivalent<L> + ?Sized,
+    {
+        self.left_to_right.contains_key(left)
+    }
+
+    /// Returns `true` if the map contains the right value.
+    #[inline]
+    pub fn contains_right<Q>(&self, right: &Q) -> bool
+    where
+        Q: Hash + Equivalent<R> + ?Sized,
+    {
+        self.right_to_left.contains_key(right)
+    }
+
+    /// Removes the pair containing the left value.
+    pub fn remove_by_left<Q>(&mut self, left: &Q) -> Option<(L, R)>
+    where
+        Q: Hash + Equivalent<L> + ?Sized,
+    {
+        let (left, right) = self.left_to_right.remove_entry(left)?;
+        self.right_to_left.remove(&right);
+        Some((left, right))
+    }
+
+    /// Removes the pair containing the right value.
+    pub fn remove_by_right<Q>(&mut self, right: &Q) -> Option<(L, R)>
+    where
+        Q: Hash + Equivalent<R> + ?Sized,
+    {
+        let (right, left) = self.right_to_left.remove_entry(right)?;
+        self.left_to_right.remove(&left);
+        Some((left, right))
+    }
+
+    /// Inserts the pair into the map.
+    ///
+    /// Any existing pair containing either value is removed first,
+    /// the removed pairs are returned as [`Overwritten`].
+    pub fn insert(&mut self, left: L, right: R) -> Overwritten<L, R> {
+        let removed_left = self.remove_by_left(&left);
+        let removed_right = self.remove_by_right(&right);
+
+        let overwritten = match (removed_left, removed_right) {
+            (None, None) => Overwritten::Neither,
+            (Some((l, r)), None) => {
+                if r == right {
+                    Overwritten::Pair(l, r)
+                } else {
+                    Overwritten::Left(l, r)
+                }
+            }
+            (None, Some((l, r))) => Overwritten::Right(l, r),
+            (Some(left_pair), Some(right_pair)) => Overwritten::Both(left_pair, right_pair),
+        };
+
+        self.insert_unchecked(left, right);
+        overwritten
+    }
+
+    /// Inserts the pair only if neither value is already present.
+    ///
+    /// Returns the pair back if it was not inserted.
+    pub fn insert_no_overwrite(&mut self, left: L, right: R) -> Result<(), (L, R)> {
+        if self.contains_left(&left) || self.contains_right(&right) {
+            return Err((left, right));
+        }
+        self.insert_unchecked(left, right);
+        Ok(())
+    }
+
+    /// Retains only the pairs specified by the predicate.
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&L, &R) -> bool,
+    {
+        let right_to_left = &mut self.right_to_left;
+        self.left_to_right.retain(|left, right| {
+            let keep = f(left, right);
+            if !keep {
+                right_to_left.remove(right);
+            }
+            keep
+        });
+    }
+
+    #[inline]
+    fn insert_unchecked(&mut self, left: L, right: R) {
+        self.left_to_right.insert(left.clone(), right.clone());
+        self.right_to_left.insert(right, left);
+    }
+}
+
+impl<L, R, S> Clone for BiMap<L, R, S>
+where
+    HashMap<L, R, S>: Clone,
+    HashMap<R, L, S>: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            left_to_right: self.left_to_right.clone(),
+            right_to_left: self.right_to_left.clone(),
+        }
+    }
+}
+
+impl<L: Debug, R: Debug, S> Debug for BiMap<L, R, S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<L, R, S: Default> Default for BiMap<L, R, S> {
+    #[inline]
+    fn default() -> Self {
+        Self::with_hashers(S::default(), S::default())
+    }
+}
+
+impl<L, R, S> PartialEq for BiMap<L, R, S>
+where
+    HashMap<L, R, S>: PartialEq,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.left_to_right == other.left_to_right
+    }
+}
+
+impl<L, R, S> Eq for BiMap<L, R, S> where HashMap<L, R, S>: Eq {}
+
+impl<L, R, S> Extend<(L, R)> for BiMap<L, R, S>
+where
+    L: Eq + Hash + Clone,
+    R: Eq + Hash + Clone,
+    S: BuildHasher,
+{
+    fn extend<I: IntoIterator<Item = (L, R)>>(&mut self, iter: I) {
+        for (left, right) in iter {
+            self.insert(left, right);
+        }
+    }
+}
+
+impl<L, R, S> FromIterator<(L, R)> for BiMap<L, R, S>
+where
+    L: Eq + Hash + Clone,
+    R: Eq + Hash + Clone,
+    S: BuildHasher + Default,
+{
+    fn from_iter<I: IntoIterator<Item = (L, R)>>(iter: I) -> Self {
+        let mut map = Self::default();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<L, R, S> IntoIterator for BiMap<L, R, S> {
+    type Item = (L, R);
+    type IntoIter = hashbrown::hash_map::IntoIter<L, R>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.left_to_right.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bi_map() {
+        let mut map = BiMap::new();
+        assert!(map.insert(1, 'a').is_neither());
+        assert!(map.insert(2, 'b').is_neither());
+        assert_eq!(map.insert(1, 'a'), Overwritten::Pair(1, 'a'));
+        assert_eq!(map.insert(1, 'c'), Overwritten::Left(1, 'a'));
+        assert_eq!(map.insert(3, 'c'), Overwritten::Right(1, 'c'));
+        assert_eq!(map.insert(2, 'c'), Overwritten::Both((2, 'b'), (3, 'c')));
+        assert_eq!(map.len(), 1);
+
+        assert_eq!(map.insert_no_overwrite(2, 'd'), Err((2, 'd')));
+        assert_eq!(map.insert_no_overwrite(4, 'd'), Ok(()));
+
+        assert_eq!(map.get_by_left(&2), Some(&'c'));
+        assert_eq!(map.get_by_right(&'d'), Some(&4));
+
+        map.retain(|l, _| *l != 4);
+        assert!(!map.contains_right(&'d'));
+        assert_eq!(map.remove_by_right(&'c'), Some((2, 'c')));
+        assert!(map.is_empty());
+    }
+}
@@ -336,6 +336,119 @@ where
     }
 }
 
+#[cfg(feature = "rayon")]
+pub use hb::rayon::{IntoParIter, ParDrain, ParIter, ParIterMut, ParKeys, ParValues, ParValuesMut};
+
+#[cfg(feature = "rayon")]
+impl<K: Sync, V: Sync, S> HashMap<K, V, S> {
+    /// Visits (potentially in parallel) immutably borrowed keys in an arbitrary order.
+    #[inline]
+    pub fn par_keys(&self) -> ParKeys<'_, K, V> {
+        self.0.par_keys()
+    }
+
+    /// Visits (potentially in parallel) immutably borrowed values in an arbitrary order.
+    #[inline]
+    pub fn par_values(&self) -> ParValues<'_, K, V> {
+        self.0.par_values()
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<K: Send, V: Send, S> HashMap<K, V, S> {
+    /// Visits (potentially in parallel) mutably borrowed values in an arbitrary order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use vct_utils::collections::HashMap;
+    /// use rayon::prelude::*;
+    ///
+    /// let mut map: HashMap<_, _> = (0..100).map(|i| (i, i)).collect();
+    /// map.par_values_mut().for_each(|v| *v *= 2);
+    ///
+    /// assert_eq!(map[&21], 42);
+    /// ```
+    #[inline]
+    pub fn par_values_mut(&mut self) -> ParValuesMut<'_, K, V> {
+        self.0.par_values_mut()
+    }
+
+    /// Consumes (potentially in parallel) all key-value pairs in an arbitrary order,
+    /// while preserving the map's allocated memory for reuse.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use vct_utils::collections::HashMap;
+    /// use rayon::prelude::*;
+    ///
+    /// let mut map: HashMap<_, _> = (0..100).map(|i| (i, i)).collect();
+    /// let sum: i32 = map.par_drain().map(|(_, v)| v).sum();
+    ///
+    /// assert_eq!(sum, 4950);
+    /// assert!(map.is_empty());
+    /// ```
+    #[inline]
+    pub fn par_drain(&mut self) -> ParDrain<'_, K, V> {
+        self.0.par_drain()
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<K, V, S> HashMap<K, V, S>
+where
+    K: Eq + Hash + Send + Sync,
+    V: Send + Sync,
+    S: BuildHasher + Clone + Send + Sync,
+{
+    /// Retains only the elements specified by the predicate,
+    /// evaluating it (potentially) in parallel.
+    ///
+    /// Unlike [`retain`](Self::retain), the map is rebuilt from the kept elements,
+    /// so this is only worthwhile for large maps with an expensive predicate.
+    /// The hasher is preserved.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use vct_utils::collections::HashMap;
+    /// let mut map: HashMap<_, _> = (0..100).map(|i| (i, i)).collect();
+    /// map.par_retain(|&k, _| k % 2 == 0);
+    ///
+    /// assert_eq!(map.len(), 50);
+    /// ```
+    pub fn par_retain<F>(&mut self, f: F)
+    where
+        F: Fn(&K, &mut V) -> bool + Send + Sync,
+    {
+        use rayon::iter::ParallelIterator;
+
+        let hasher = self.0.hasher().clone();
+        let capacity = self.0.capacity();
+        let old = core::mem::replace(
+            &mut self.0,
+            hb::HashMap::with_capacity_and_hasher(capacity, hasher),
+        );
+        self.0.par_extend(
+            old.into_par_iter()
+                .filter_map(|(k, mut v)| f(&k, &mut v).then_some((k, v))),
+        );
+    }
+
+    /// Returns `true` if the map is equal to another,
+    /// i.e. both maps contain the same keys mapped to the same values.
+    ///
+    /// This method runs in a potentially parallel fashion.
+    #[inline]
+    pub fn par_eq(&self, other: &Self) -> bool
+    where
+        V: PartialEq,
+    {
+        self.0.par_eq(&other.0)
+    }
+}
+
 impl<K, V, S> HashMap<K, V, S> {
     /// Creates an empty [`HashMap`] which will use the given hash builder to hash keys.
     ///
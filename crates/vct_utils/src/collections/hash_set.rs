@@ -320,6 +320,143 @@ where
     }
 }
 
+#[cfg(feature = "rayon")]
+pub use hb::rayon::{
+    IntoParIter, ParDifference, ParDrain, ParIntersection, ParIter, ParSymmetricDifference,
+    ParUnion,
+};
+
+#[cfg(feature = "rayon")]
+impl<T, S> HashSet<T, S>
+where
+    T: Eq + Hash + Sync,
+    S: BuildHasher + Sync,
+{
+    /// Visits (potentially in parallel) the values representing the union,
+    /// i.e. all the values in `self` or `other`, without duplicates.
+    #[inline]
+    pub fn par_union<'a>(&'a self, other: &'a Self) -> ParUnion<'a, T, S> {
+        self.0.par_union(&other.0)
+    }
+
+    /// Visits (potentially in parallel) the values representing the difference,
+    /// i.e. the values that are in `self` but not in `other`.
+    #[inline]
+    pub fn par_difference<'a>(&'a self, other: &'a Self) -> ParDifference<'a, T, S> {
+        self.0.par_difference(&other.0)
+    }
+
+    /// Visits (potentially in parallel) the values representing the symmetric
+    /// difference, i.e. the values that are in `self` or in `other` but not in both.
+    #[inline]
+    pub fn par_symmetric_difference<'a>(
+        &'a self,
+        other: &'a Self,
+    ) -> ParSymmetricDifference<'a, T, S> {
+        self.0.par_symmetric_difference(&other.0)
+    }
+
+    /// Visits (potentially in parallel) the values representing the
+    /// intersection, i.e. the values that are both in `self` and `other`.
+    #[inline]
+    pub fn par_intersection<'a>(&'a self, other: &'a Self) -> ParIntersection<'a, T, S> {
+        self.0.par_intersection(&other.0)
+    }
+
+    /// Returns `true` if `self` has no elements in common with `other`.
+    ///
+    /// This method runs in a potentially parallel fashion.
+    #[inline]
+    pub fn par_is_disjoint(&self, other: &Self) -> bool {
+        self.0.par_is_disjoint(&other.0)
+    }
+
+    /// Returns `true` if the set is a subset of another.
+    ///
+    /// This method runs in a potentially parallel fashion.
+    #[inline]
+    pub fn par_is_subset(&self, other: &Self) -> bool {
+        self.0.par_is_subset(&other.0)
+    }
+
+    /// Returns `true` if the set is a superset of another.
+    ///
+    /// This method runs in a potentially parallel fashion.
+    #[inline]
+    pub fn par_is_superset(&self, other: &Self) -> bool {
+        self.0.par_is_superset(&other.0)
+    }
+
+    /// Returns `true` if the set is equal to another.
+    ///
+    /// This method runs in a potentially parallel fashion.
+    #[inline]
+    pub fn par_eq(&self, other: &Self) -> bool {
+        self.0.par_eq(&other.0)
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<T: Eq + Hash + Send, S> HashSet<T, S> {
+    /// Consumes (potentially in parallel) all values in an arbitrary order,
+    /// while preserving the set's allocated memory for reuse.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use vct_utils::collections::HashSet;
+    /// use rayon::prelude::*;
+    ///
+    /// let mut set: HashSet<_> = (0..100).collect();
+    /// let sum: i32 = set.par_drain().sum();
+    ///
+    /// assert_eq!(sum, 4950);
+    /// assert!(set.is_empty());
+    /// ```
+    #[inline]
+    pub fn par_drain(&mut self) -> ParDrain<'_, T> {
+        self.0.par_drain()
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<T, S> HashSet<T, S>
+where
+    T: Eq + Hash + Send + Sync,
+    S: BuildHasher + Clone + Send + Sync,
+{
+    /// Retains only the elements specified by the predicate,
+    /// evaluating it (potentially) in parallel.
+    ///
+    /// Unlike [`retain`](Self::retain), the set is rebuilt from the kept elements,
+    /// so this is only worthwhile for large sets with an expensive predicate.
+    /// The hasher is preserved.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use vct_utils::collections::HashSet;
+    /// let mut set: HashSet<_> = (0..100).collect();
+    /// set.par_retain(|&v| v % 2 == 0);
+    ///
+    /// assert_eq!(set.len(), 50);
+    /// ```
+    pub fn par_retain<F>(&mut self, f: F)
+    where
+        F: Fn(&T) -> bool + Send + Sync,
+    {
+        use rayon::iter::ParallelIterator;
+
+        let hasher = self.0.hasher().clone();
+        let capacity = self.0.capacity();
+        let old = core::mem::replace(
+            &mut self.0,
+            hb::HashSet::with_capacity_and_hasher(capacity, hasher),
+        );
+        self.0.par_extend(old.into_par_iter().filter(|v| f(v)));
+    }
+}
+
 impl<T, S> HashSet<T, S> {
     /// Returns the number of elements the set can hold without reallocating.
     ///
@@ -0,0 +1,539 @@
+//! Provides [`LruCache`], a capacity-bounded map evicting the least recently used entry.
+
+use alloc::vec::Vec;
+use core::{
+    fmt::Debug,
+    hash::{BuildHasher, Hash},
+    iter::FusedIterator,
+};
+
+use hashbrown::{Equivalent, HashTable};
+
+use crate::hash::FixedHash;
+
+/// Marks the absence of a neighbour in the intrusive list.
+const NIL: usize = usize::MAX;
+
+struct Node<K, V> {
+    key: K,
+    value: V,
+    /// More recently used neighbour.
+    prev: usize,
+    /// Less recently used neighbour.
+    next: usize,
+}
+
+/// A capacity-bounded cache that evicts the least recently used entry when full.
+///
+/// Entries are stored densely in a `Vec` and linked into an intrusive
+/// recency list by index, the lookup table only stores those indices.
+/// Like the crate's [`HashMap`](crate::collections::HashMap), it defaults to
+/// [`FixedHash`] so the behavior is deterministic.
+///
+/// # Example
+///
+/// ```
+/// # use vct_utils::collections::LruCache;
+/// let mut cache = LruCache::new(2);
+/// cache.insert("a", 1);
+/// cache.insert("b", 2);
+///
+/// // `get` promotes "a", so "b" becomes the least recently used entry.
+/// assert_eq!(cache.get("a"), Some(&1));
+/// cache.insert("c", 3);
+///
+/// assert!(!cache.contains("b"));
+/// assert_eq!(cache.peek_lru(), Some((&"a", &1)));
+/// assert_eq!(cache.pop_lru(), Some(("a", 1)));
+/// ```
+pub struct LruCache<K, V, S = FixedHash> {
+    table: HashTable<usize>,
+    nodes: Vec<Node<K, V>>,
+    /// Most recently used entry.
+    head: usize,
+    /// Least recently used entry.
+    tail: usize,
+    capacity: usize,
+    hash_builder: S,
+}
+
+impl<K, V> LruCache<K, V, FixedHash> {
+    /// Creates an empty [`LruCache`] holding at most `capacity` entries.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    #[inline]
+    pub fn new(capacity: usize) -> Self {
+        Self::with_hasher(capacity, FixedHash)
+    }
+}
+
+impl<K, V, S> LruCache<K, V, S> {
+    /// Creates an empty [`LruCache`] holding at most `capacity` entries,
+    /// using `hash_builder` to hash the keys.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    pub fn with_hasher(capacity: usize, hash_builder: S) -> Self {
+        assert!(capacity > 0, "LruCache capacity must be non-zero");
+        Self {
+            table: HashTable::new(),
+            nodes: Vec::new(),
+            head: NIL,
+            tail: NIL,
+            capacity,
+            hash_builder,
+        }
+    }
+
+    /// Returns the maximum number of entries.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of entries.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns `true` if the cache contains no entries.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Returns `true` if the cache holds `capacity` entries.
+    #[inline]
+    pub fn is_full(&self) -> bool {
+        self.nodes.len() == self.capacity
+    }
+
+    /// Removes all entries.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.table.clear();
+        self.nodes.clear();
+        self.head = NIL;
+        self.tail = NIL;
+    }
+
+    /// Returns the least recently used entry without promoting it.
+    #[inline]
+    pub fn peek_lru(&self) -> Option<(&K, &V)> {
+        self.nodes
+            .get(self.tail)
+            .map(|node| (&node.key, &node.value))
+    }
+
+    /// Returns the most recently used entry.
+    #[inline]
+    pub fn peek_mru(&self) -> Option<(&K, &V)> {
+        self.nodes
+            .get(self.head)
+            .map(|node| (&node.key, &node.value))
+    }
+
+    /// An iterator visiting all entries from the most to the least recently used.
+    ///
+    /// Iterating does not promote entries.
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            nodes: &self.nodes,
+            front: self.head,
+            back: self.tail,
+            remaining: self.nodes.len(),
+        }
+    }
+
+    /// Unlinks the node from the recency list.
+    fn detach(&mut self, index: usize) {
+        let Node { prev, next, .. } = self.nodes[index];
+        match prev {
+            NIL => self.head = next,
+            prev => self.nodes[prev].next = next,
+        }
+        match next {
+            NIL => self.tail = prev,
+            next => self.nodes[next].prev = prev,
+        }
+    }
+
+    /// Links a detached node as the most recently used one.
+    fn attach_front(&mut self, index: usize) {
+        let old_head = self.head;
+        let node = &mut self.nodes[index];
+        node.prev = NIL;
+        node.next = old_head;
+        match old_head {
+            NIL => self.tail = index,
+            old_head => self.nodes[old_head].prev = index,
+        }
+        self.head = index;
+    }
+
+    /// Moves the node to the front of the recency list.
+    #[inline]
+    fn promote(&mut self, index: usize) {
+        if self.head != index {
+            self.detach(index);
+            self.attach_front(index);
+        }
+    }
+}
+
+impl<K, V, S> LruCache<K, V, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher,
+{
+    /// Returns a reference to the value of the key and marks it as most recently used.
+    #[inline]
+    pub fn get<Q>(&mut self, key: &Q) -> Option<&V>
+    where
+        Q: Hash + Equivalent<K> + ?Sized,
+    {
+        let index = self.find(key)?;
+        self.promote(index);
+        Some(&self.nodes[index].value)
+    }
+
+    /// Returns a mutable reference to the value of the key and marks it as most recently used.
+    #[inline]
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        Q: Hash + Equivalent<K> + ?Sized,
+    {
+        let index = self.find(key)?;
+        self.promote(index);
+        Some(&mut self.nodes[index].value)
+    }
+
+    /// Returns the value of the key if present, otherwise inserts the value returned by `f`.
+    ///
+    /// The entry is marked as most recently used in both cases.
+    pub fn get_or_insert_with<F>(&mut self, key: K, f: F) -> &mut V
+    where
+        F: FnOnce() -> V,
+    {
+        let index = match self.find(&key) {
+            Some(index) => {
+                self.promote(index);
+                index
+            }
+            None => {
+                if self.is_full() {
+                    self.pop_lru();
+                }
+                self.insert_new(key, f())
+            }
+        };
+        &mut self.nodes[index].value
+    }
+
+    /// Returns a reference to the value of the key without promoting it.
+    #[inline]
+    pub fn peek<Q>(&self, key: &Q) -> Option<&V>
+    where
+        Q: Hash + Equivalent<K> + ?Sized,
+    {
+        self.find(key).map(|index| &self.nodes[index].value)
+    }
+
+    /// Returns a mutable reference to the value of the key without promoting it.
+    #[inline]
+    pub fn peek_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        Q: Hash + Equivalent<K> + ?Sized,
+    {
+        self.find(key).map(|index| &mut self.nodes[index].value)
+    }
+
+    /// Returns `true` if the cache contains the key, without promoting it.
+    #[inline]
+    pub fn contains<Q>(&self, key: &Q) -> bool
+    where
+        Q: Hash + Equivalent<K> + ?Sized,
+    {
+        self.find(key).is_some()
+    }
+
+    /// Inserts a key-value pair and marks it as most recently used.
+    ///
+    /// If the key already exists, its value is replaced and the old one is returned.
+    /// Otherwise, the least recently used entry is dropped if the cache is full.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        if let Some(index) = self.find(&key) {
+            self.promote(index);
+            return Some(core::mem::replace(&mut self.nodes[index].value, value));
+        }
+
+        if self.is_full() {
+            self.pop_lru();
+        }
+        self.insert_new(key, value);
+        None
+    }
+
+    /// Inserts a key-value pair and marks it as most recently used.
+    ///
+    /// Returns the old pair if the key already existed,
+    /// or the evicted least recently used pair if the cache was full.
+    pub fn push(&mut self, key: K, value: V) -> Option<(K, V)> {
+        if let Some(index) = self.find(&key) {
+            self.promote(index);
+            let node = &mut self.nodes[index];
+            let old_key = core::mem::replace(&mut node.key, key);
+            let old_value = core::mem::replace(&mut node.value, value);
+            return Some((old_key, old_value));
+        }
+
+        let evicted = if self.is_full() { self.pop_lru() } else { None };
+        self.insert_new(key, value);
+        evicted
+    }
+
+    /// Removes the key from the cache, returning its value.
+    #[inline]
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        Q: Hash + Equivalent<K> + ?Sized,
+    {
+        let index = self.find(key)?;
+        Some(self.remove_index(index).1)
+    }
+
+    /// Removes and returns the least recently used entry.
+    #[inline]
+    pub fn pop_lru(&mut self) -> Option<(K, V)> {
+        match self.tail {
+            NIL => None,
+            tail => Some(self.remove_index(tail)),
+        }
+    }
+
+    /// Changes the capacity, evicting least recently used entries if needed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    pub fn resize(&mut self, capacity: usize) {
+        assert!(capacity > 0, "LruCache capacity must be non-zero");
+        while self.nodes.len() > capacity {
+            self.pop_lru();
+        }
+        self.capacity = capacity;
+    }
+
+    /// Retains only the entries specified by the predicate.
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&K, &mut V) -> bool,
+    {
+        let mut index = self.head;
+        while index != NIL {
+            let next = self.nodes[index].next;
+            let node = &mut self.nodes[index];
+            if !f(&node.key, &mut node.value) {
+                // Removal moves the last node into `index`, which may be `next`.
+                let last = self.nodes.len() - 1;
+                self.remove_index(index);
+                index = if next == last { index } else { next };
+            } else {
+                index = next;
+            }
+        }
+    }
+
+    #[inline]
+    fn find<Q>(&self, key: &Q) -> Option<usize>
+    where
+        Q: Hash + Equivalent<K> + ?Sized,
+    {
+        let hash = self.hash_builder.hash_one(key);
+        self.table
+            .find(hash, |&index| key.equivalent(&self.nodes[index].key))
+            .copied()
+    }
+
+    fn insert_new(&mut self, key: K, value: V) -> usize {
+        let index = self.nodes.len();
+        let hash = self.hash_builder.hash_one(&key);
+        self.nodes.push(Node {
+            key,
+            value,
+            prev: NIL,
+            next: NIL,
+        });
+
+        let Self {
+            table,
+            nodes,
+            hash_builder,
+            ..
+        } = self;
+        table.insert_unique(hash, index, |&i| hash_builder.hash_one(&nodes[i].key));
+
+        self.attach_front(index);
+        index
+    }
+
+    fn remove_index(&mut self, index: usize) -> (K, V) {
+        self.detach(index);
+
+        let hash = self.hash_builder.hash_one(&self.nodes[index].key);
+        if let Ok(entry) = self.table.find_entry(hash, |&i| i == index) {
+            entry.remove();
+        }
+
+        // `swap_remove` moves the last node into `index`, relink it first.
+        let last = self.nodes.len() - 1;
+        if index != last {
+            let hash = self.hash_builder.hash_one(&self.nodes[last].key);
+            if let Some(slot) = self.table.find_mut(hash, |&i| i == last) {
+                *slot = index;
+            }
+            let Node { prev, next, .. } = self.nodes[last];
+            match prev {
+                NIL => self.head = index,
+                prev => self.nodes[prev].next = index,
+            }
+            match next {
+                NIL => self.tail = index,
+                next => self.nodes[next].prev = index,
+            }
+        }
+
+        let node = self.nodes.swap_remove(index);
+        (node.key, node.value)
+    }
+}
+
+impl<K: Clone, V: Clone, S: Clone> Clone for LruCache<K, V, S> {
+    fn clone(&self) -> Self {
+        Self {
+            table: self.table.clone(),
+            nodes: self
+                .nodes
+                .iter()
+                .map(|node| Node {
+                    key: node.key.clone(),
+                    value: node.value.clone(),
+                    prev: node.prev,
+                    next: node.next,
+                })
+                .collect(),
+            head: self.head,
+            tail: self.tail,
+            capacity: self.capacity,
+            hash_builder: self.hash_builder.clone(),
+        }
+    }
+}
+
+impl<K: Debug, V: Debug, S> Debug for LruCache<K, V, S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, K, V, S> IntoIterator for &'a LruCache<K, V, S> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the entries of a [`LruCache`],
+/// from the most to the least recently used.
+pub struct Iter<'a, K, V> {
+    nodes: &'a [Node<K, V>],
+    front: usize,
+    back: usize,
+    remaining: usize,
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let node = &self.nodes[self.front];
+        self.front = node.next;
+        self.remaining -= 1;
+        Some((&node.key, &node.value))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<K, V> DoubleEndedIterator for Iter<'_, K, V> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let node = &self.nodes[self.back];
+        self.back = node.prev;
+        self.remaining -= 1;
+        Some((&node.key, &node.value))
+    }
+}
+
+impl<K, V> ExactSizeIterator for Iter<'_, K, V> {}
+
+impl<K, V> FusedIterator for Iter<'_, K, V> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn lru_cache() {
+        let mut cache = LruCache::new(3);
+        assert_eq!(cache.push(1, 'a'), None);
+        assert_eq!(cache.push(2, 'b'), None);
+        assert_eq!(cache.push(3, 'c'), None);
+        assert!(cache.is_full());
+
+        // promote 1, evict 2
+        assert_eq!(cache.get(&1), Some(&'a'));
+        assert_eq!(cache.push(4, 'd'), Some((2, 'b')));
+        assert_eq!(cache.iter().map(|(k, _)| *k).collect::<Vec<_>>(), [4, 1, 3]);
+
+        // peek does not promote
+        assert_eq!(cache.peek(&3), Some(&'c'));
+        assert_eq!(cache.peek_lru(), Some((&3, &'c')));
+
+        assert_eq!(cache.insert(3, 'x'), Some('c'));
+        assert_eq!(cache.peek_mru(), Some((&3, &'x')));
+
+        assert_eq!(cache.remove(&4), Some('d'));
+        assert_eq!(
+            cache.iter().rev().map(|(k, _)| *k).collect::<Vec<_>>(),
+            [1, 3]
+        );
+
+        *cache.get_or_insert_with(5, || 'e') = 'f';
+        cache.retain(|k, _| *k != 1);
+        assert_eq!(cache.len(), 2);
+
+        cache.resize(1);
+        assert_eq!(cache.pop_lru(), Some((5, 'f')));
+        assert!(cache.is_empty());
+    }
+}
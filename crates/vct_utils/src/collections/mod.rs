@@ -5,6 +5,11 @@
 //! - [`HashMap`]
 //! - [`HashSet`]
 //! - [`HashTable`]
+//! - [`MultiMap`]
+//! - [`BiMap`]
+//! - [`LruCache`]
+//! - `OnceMap` (requires `std`)
+//! - [`VecMap`]
 //!
 //! # in alloc
 //!
@@ -14,10 +19,19 @@
 //! - [`LinkedList`]
 //! - [`VecDeque`]
 
+pub mod bi_map;
 pub mod hash_map;
 pub mod hash_set;
 pub mod hash_table;
+pub mod lru_cache;
 mod maps;
+pub mod multi_map;
+pub mod vec_map;
+
+crate::cfg::std! {
+    pub mod once_map;
+    pub use once_map::OnceMap;
+}
 
 pub use hash_map::HashMap;
 pub use hash_set::HashSet;
@@ -25,6 +39,12 @@ pub use hash_table::HashTable;
 pub use hashbrown::Equivalent;
 pub use maps::{PreHashMap, TypeIdMap};
 
+pub use bi_map::BiMap;
+pub use lru_cache::LruCache;
+pub use multi_map::MultiMap;
+pub use vec_map::VecMap;
+pub use smallvec::{SmallVec, smallvec};
+
 pub use alloc::collections::*;
 
 #[cfg(test)]
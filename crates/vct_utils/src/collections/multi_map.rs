@@ -0,0 +1,369 @@
+//! Provides [`MultiMap`], a [`HashMap`] that stores multiple values per key.
+//!
+//! Values of the same key are kept in a [`SmallVec`], so keys with a single
+//! value do not require an extra heap allocation.
+
+use core::{
+    fmt::Debug,
+    hash::{BuildHasher, Hash},
+};
+
+use hashbrown::Equivalent;
+use smallvec::SmallVec;
+
+use crate::{collections::HashMap, hash::FixedHash};
+
+/// Storage used for the values of a single key.
+pub type MultiValues<V> = SmallVec<[V; 1]>;
+
+/// A hash map that can store multiple values for the same key.
+///
+/// Built on the crate's [`HashMap`], so it defaults to [`FixedHash`] and
+/// the iteration order only depends on the order of insertions and deletions.
+/// Values of the same key preserve their insertion order.
+///
+/// # Example
+///
+/// ```
+/// # use vct_utils::collections::MultiMap;
+/// let mut map = MultiMap::new();
+/// map.insert("fruit", "apple");
+/// map.insert("fruit", "banana");
+/// map.insert("meat", "beef");
+///
+/// assert_eq!(map.get("fruit"), &["apple", "banana"]);
+/// assert_eq!(map.get("fish"), &[] as &[&str]);
+/// assert_eq!(map.len(), 2);
+/// assert_eq!(map.total_len(), 3);
+/// assert_eq!(map.iter().count(), 3);
+/// ```
+pub struct MultiMap<K, V, S = FixedHash> {
+    inner: HashMap<K, MultiValues<V>, S>,
+}
+
+impl<K, V> MultiMap<K, V, FixedHash> {
+    /// Create a empty [`MultiMap`]
+    #[inline]
+    pub const fn new() -> Self {
+        Self::with_hasher(FixedHash)
+    }
+
+    /// Create a empty [`MultiMap`] with space for at least `capacity` keys.
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity_and_hasher(capacity, FixedHash)
+    }
+}
+
+impl<K, V, S> MultiMap<K, V, S> {
+    /// Creates an empty [`MultiMap`] which will use the given hash builder to hash keys.
+    #[inline]
+    pub const fn with_hasher(hash_builder: S) -> Self {
+        Self {
+            inner: HashMap::with_hasher(hash_builder),
+        }
+    }
+
+    /// Creates an empty [`MultiMap`] with space for at least `capacity` keys,
+    /// using `hash_builder` to hash the keys.
+    #[inline]
+    pub fn with_capacity_and_hasher(capacity: usize, hash_builder: S) -> Self {
+        Self {
+            inner: HashMap::with_capacity_and_hasher(capacity, hash_builder),
+        }
+    }
+
+    /// Returns the number of keys in the map.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Returns the number of values in the map, over all keys.
+    #[inline]
+    pub fn total_len(&self) -> usize {
+        self.inner.values().map(SmallVec::len).sum()
+    }
+
+    /// Returns `true` if the map contains no keys.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Clears the map, removing all key-values pairs.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.inner.clear();
+    }
+
+    /// An iterator visiting all keys in arbitrary order.
+    #[inline]
+    pub fn keys(&self) -> impl Iterator<Item = &K> + '_ {
+        self.inner.keys()
+    }
+
+    /// An iterator visiting all values, flattened over all keys.
+    #[inline]
+    pub fn values(&self) -> impl Iterator<Item = &V> + '_ {
+        self.inner.values().flat_map(|values| values.iter())
+    }
+
+    /// An iterator visiting all values mutably, flattened over all keys.
+    #[inline]
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut V> + '_ {
+        self.inner.values_mut().flat_map(|values| values.iter_mut())
+    }
+
+    /// An iterator visiting all key-value pairs, flattened over all keys.
+    ///
+    /// A key with multiple values is yielded once per value.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> + '_ {
+        self.inner
+            .iter()
+            .flat_map(|(key, values)| values.iter().map(move |value| (key, value)))
+    }
+
+    /// An iterator visiting all key-value pairs mutably, flattened over all keys.
+    #[inline]
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (&K, &mut V)> + '_ {
+        self.inner
+            .iter_mut()
+            .flat_map(|(key, values)| values.iter_mut().map(move |value| (key, value)))
+    }
+
+    /// An iterator visiting every key together with all of its values.
+    #[inline]
+    pub fn iter_all(&self) -> impl Iterator<Item = (&K, &[V])> + '_ {
+        self.inner
+            .iter()
+            .map(|(key, values)| (key, values.as_slice()))
+    }
+
+    /// Retains only the values specified by the predicate.
+    ///
+    /// Keys whose values are all removed are removed as well.
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&K, &V) -> bool,
+    {
+        self.inner.retain(|key, values| {
+            values.retain(|value| f(key, value));
+            !values.is_empty()
+        });
+    }
+
+    /// Returns the inner [`HashMap`].
+    #[inline]
+    pub fn into_inner(self) -> HashMap<K, MultiValues<V>, S> {
+        self.inner
+    }
+}
+
+impl<K, V, S> MultiMap<K, V, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher,
+{
+    /// Appends a value to the given key.
+    #[inline]
+    pub fn insert(&mut self, key: K, value: V) {
+        self.inner.entry(key).or_default().push(value);
+    }
+
+    /// Appends all values of `iter` to the given key.
+    pub fn insert_many<I>(&mut self, key: K, iter: I)
+    where
+        I: IntoIterator<Item = V>,
+    {
+        let mut iter = iter.into_iter().peekable();
+        // Avoid keeping keys without values.
+        if iter.peek().is_some() {
+            self.inner.entry(key).or_default().extend(iter);
+        }
+    }
+
+    /// Returns all values of the key, or an empty slice if the key is absent.
+    #[inline]
+    pub fn get<Q>(&self, key: &Q) -> &[V]
+    where
+        Q: Hash + Equivalent<K> + ?Sized,
+    {
+        match self.inner.get(key) {
+            Some(values) => values.as_slice(),
+            None => &[],
+        }
+    }
+
+    /// Returns the first value inserted for the key.
+    #[inline]
+    pub fn get_first<Q>(&self, key: &Q) -> Option<&V>
+    where
+        Q: Hash + Equivalent<K> + ?Sized,
+    {
+        self.inner.get(key).and_then(|values| values.first())
+    }
+
+    /// Returns all values of the key mutably, or an empty slice if the key is absent.
+    #[inline]
+    pub fn get_mut<Q>(&mut self, key: &Q) -> &mut [V]
+    where
+        Q: Hash + Equivalent<K> + ?Sized,
+    {
+        match self.inner.get_mut(key) {
+            Some(values) => values.as_mut_slice(),
+            None => &mut [],
+        }
+    }
+
+    /// Returns `true` if the map contains at least one value for the key.
+    #[inline]
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        Q: Hash + Equivalent<K> + ?Sized,
+    {
+        self.inner.contains_key(key)
+    }
+
+    /// Removes a key from the map, returning all of its values.
+    #[inline]
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<MultiValues<V>>
+    where
+        Q: Hash + Equivalent<K> + ?Sized,
+    {
+        self.inner.remove(key)
+    }
+
+    /// Removes the first value of the key equal to `value`.
+    ///
+    /// The key is removed from the map if it has no values left.
+    pub fn remove_value<Q>(&mut self, key: &Q, value: &V) -> Option<V>
+    where
+        Q: Hash + Equivalent<K> + ?Sized,
+        V: PartialEq,
+    {
+        let values = self.inner.get_mut(key)?;
+        let index = values.iter().position(|v| v == value)?;
+        let removed = values.remove(index);
+        if values.is_empty() {
+            self.inner.remove(key);
+        }
+        Some(removed)
+    }
+}
+
+impl<K, V, S> Clone for MultiMap<K, V, S>
+where
+    HashMap<K, MultiValues<V>, S>: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<K, V, S> Debug for MultiMap<K, V, S>
+where
+    K: Debug,
+    V: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_map().entries(self.iter_all()).finish()
+    }
+}
+
+impl<K, V, S> Default for MultiMap<K, V, S>
+where
+    S: Default,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::with_hasher(S::default())
+    }
+}
+
+impl<K, V, S> PartialEq for MultiMap<K, V, S>
+where
+    HashMap<K, MultiValues<V>, S>: PartialEq,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+}
+
+impl<K, V, S> Eq for MultiMap<K, V, S> where HashMap<K, MultiValues<V>, S>: Eq {}
+
+impl<K, V, S> Extend<(K, V)> for MultiMap<K, V, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher,
+{
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+impl<K, V, S> FromIterator<(K, V)> for MultiMap<K, V, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher + Default,
+{
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = Self::default();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<K, V, S> IntoIterator for MultiMap<K, V, S> {
+    type Item = (K, MultiValues<V>);
+    type IntoIter = hashbrown::hash_map::IntoIter<K, MultiValues<V>>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn multi_map() {
+        let mut map = MultiMap::new();
+        map.insert(1, 'a');
+        map.insert(1, 'b');
+        map.insert(2, 'c');
+        map.insert_many(3, ['d', 'e', 'f']);
+
+        assert_eq!(map.len(), 3);
+        assert_eq!(map.total_len(), 6);
+        assert_eq!(map.get(&1), &['a', 'b']);
+        assert_eq!(map.get_first(&3), Some(&'d'));
+        assert!(map.get(&4).is_empty());
+
+        map.get_mut(&2)[0] = 'z';
+        assert_eq!(map.get(&2), &['z']);
+
+        assert_eq!(map.remove_value(&2, &'z'), Some('z'));
+        assert!(!map.contains_key(&2));
+
+        map.retain(|_, v| *v != 'e');
+        assert_eq!(map.get(&3), &['d', 'f']);
+
+        let mut values: Vec<_> = map.values().copied().collect();
+        values.sort_unstable();
+        assert_eq!(values, ['a', 'b', 'd', 'f']);
+
+        assert_eq!(map.remove(&1).unwrap().as_slice(), &['a', 'b']);
+        assert_eq!(map.iter().count(), 2);
+    }
+}
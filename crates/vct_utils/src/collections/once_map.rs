@@ -0,0 +1,323 @@
+#![expect(
+    unsafe_code,
+    reason = "OnceMap hands out references into locked shards."
+)]
+
+//! Provides [`OnceMap`], a sharded concurrent map whose values are never removed.
+
+use alloc::{boxed::Box, vec::Vec};
+use core::{
+    fmt::Debug,
+    hash::{BuildHasher, Hash},
+};
+
+use hashbrown::{Equivalent, HashTable};
+use vct_os::sync::{PoisonError, RwLock};
+
+use crate::hash::FixedHash;
+
+/// Default number of shards, must be a power of two.
+const DEFAULT_SHARD_AMOUNT: usize = 16;
+
+type Shard<K, V> = RwLock<HashTable<(K, Box<V>)>>;
+
+/// A concurrent, insert-only map.
+///
+/// Values are boxed and never moved or removed through `&self`, so the
+/// references returned by [`get`](OnceMap::get) and
+/// [`get_or_insert_with`](OnceMap::get_or_insert_with) stay valid for
+/// the lifetime of the map.
+///
+/// Keys are distributed over several independently locked shards,
+/// so concurrent readers and writers rarely contend on the same lock.
+/// This makes it suitable for interning and `TypeId -> &'static Info`
+/// style caches that would otherwise sit behind a global `RwLock`.
+///
+/// # Example
+///
+/// ```
+/// # use vct_utils::collections::OnceMap;
+/// let map: OnceMap<u32, String> = OnceMap::new();
+///
+/// let a = map.get_or_insert_with(1, || "one".to_string());
+/// let b = map.get_or_insert_with(1, || unreachable!());
+///
+/// // The value is created once and is not moved by later insertions.
+/// assert!(core::ptr::eq(a, b));
+/// for i in 2..100 {
+///     map.get_or_insert_with(i, || i.to_string());
+/// }
+/// assert_eq!(a, "one");
+/// ```
+pub struct OnceMap<K, V, S = FixedHash> {
+    shards: Box<[Shard<K, V>]>,
+    hash_builder: S,
+}
+
+impl<K, V> OnceMap<K, V, FixedHash> {
+    /// Creates an empty [`OnceMap`].
+    #[inline]
+    pub fn new() -> Self {
+        Self::with_hasher(FixedHash)
+    }
+}
+
+impl<K, V, S> OnceMap<K, V, S> {
+    /// Creates an empty [`OnceMap`] which will use the given hash builder to hash keys.
+    #[inline]
+    pub fn with_hasher(hash_builder: S) -> Self {
+        Self::with_shard_amount_and_hasher(DEFAULT_SHARD_AMOUNT, hash_builder)
+    }
+
+    /// Creates an empty [`OnceMap`] with the given number of shards.
+    ///
+    /// The amount is rounded up to the next power of two.
+    pub fn with_shard_amount_and_hasher(shard_amount: usize, hash_builder: S) -> Self {
+        let shard_amount = shard_amount.max(1).next_power_of_two();
+        Self {
+            shards: (0..shard_amount)
+                .map(|_| RwLock::new(HashTable::new()))
+                .collect(),
+            hash_builder,
+        }
+    }
+
+    /// Returns the number of entries in the map.
+    ///
+    /// The result may be outdated as soon as it is returned if other threads insert values.
+    pub fn len(&self) -> usize {
+        self.shards
+            .iter()
+            .map(|shard| shard.read().unwrap_or_else(PoisonError::into_inner).len())
+            .sum()
+    }
+
+    /// Returns `true` if the map contains no entries.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a reference to the map's [`BuildHasher`].
+    #[inline]
+    pub fn hasher(&self) -> &S {
+        &self.hash_builder
+    }
+
+    /// An iterator visiting all key-value pairs in arbitrary order.
+    ///
+    /// Requires exclusive access, so no lock is taken.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (&K, &mut V)> + '_ {
+        self.shards.iter_mut().flat_map(|shard| {
+            shard
+                .get_mut()
+                .unwrap_or_else(PoisonError::into_inner)
+                .iter_mut()
+                .map(|(key, value)| (&*key, &mut **value))
+        })
+    }
+
+    /// Removes all entries.
+    ///
+    /// Requires exclusive access, so no reference into the map can be alive.
+    pub fn clear(&mut self) {
+        for shard in self.shards.iter_mut() {
+            shard
+                .get_mut()
+                .unwrap_or_else(PoisonError::into_inner)
+                .clear();
+        }
+    }
+
+    #[inline]
+    fn shard(&self, hash: u64) -> &Shard<K, V> {
+        // The low bits select the bucket inside the table, use the high bits for the shard.
+        let index = (hash >> 32) as usize & (self.shards.len() - 1);
+        &self.shards[index]
+    }
+}
+
+impl<K, V, S> OnceMap<K, V, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher,
+{
+    /// Returns a reference to the value of the key.
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        Q: Hash + Equivalent<K> + ?Sized,
+    {
+        let hash = self.hash_builder.hash_one(key);
+        let shard = self
+            .shard(hash)
+            .read()
+            .unwrap_or_else(PoisonError::into_inner);
+        let ptr: *const V = &*shard.find(hash, |(k, _)| key.equivalent(k))?.1;
+        drop(shard);
+        // SAFETY: Values are boxed and never moved or dropped while `&self` is alive,
+        // insertions only move the `Box` itself, not the pointee.
+        Some(unsafe { &*ptr })
+    }
+
+    /// Returns `true` if the map contains the key.
+    #[inline]
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        Q: Hash + Equivalent<K> + ?Sized,
+    {
+        self.get(key).is_some()
+    }
+
+    /// Returns the value of the key, inserting `value` if it is absent.
+    ///
+    /// If the key is already present, `value` is dropped.
+    #[inline]
+    pub fn get_or_insert(&self, key: K, value: V) -> &V {
+        self.get_or_insert_with(key, || value)
+    }
+
+    /// Returns the value of the key, inserting the value returned by `f` if it is absent.
+    ///
+    /// `f` is called without holding any lock, so it may access this map itself.
+    /// If another thread inserts the same key in the meantime, the value
+    /// returned by `f` is dropped and the existing one is returned.
+    pub fn get_or_insert_with<F>(&self, key: K, f: F) -> &V
+    where
+        F: FnOnce() -> V,
+    {
+        let hash = self.hash_builder.hash_one(&key);
+        if let Some(value) = self.get_hashed(hash, &key) {
+            return value;
+        }
+
+        let value = Box::new(f());
+
+        let mut shard = self
+            .shard(hash)
+            .write()
+            .unwrap_or_else(PoisonError::into_inner);
+        let ptr: *const V = match shard.find(hash, |(k, _)| k == &key) {
+            Some((_, existing)) => &**existing,
+            None => {
+                let hasher = |(k, _): &(K, Box<V>)| self.hash_builder.hash_one(k);
+                &*shard.insert_unique(hash, (key, value), hasher).get().1
+            }
+        };
+        drop(shard);
+        // SAFETY: See `get`.
+        unsafe { &*ptr }
+    }
+
+    #[inline]
+    fn get_hashed(&self, hash: u64, key: &K) -> Option<&V> {
+        let shard = self
+            .shard(hash)
+            .read()
+            .unwrap_or_else(PoisonError::into_inner);
+        let ptr: *const V = &*shard.find(hash, |(k, _)| k == key)?.1;
+        drop(shard);
+        // SAFETY: See `get`.
+        Some(unsafe { &*ptr })
+    }
+}
+
+impl<K, V, S: Default> Default for OnceMap<K, V, S> {
+    #[inline]
+    fn default() -> Self {
+        Self::with_hasher(S::default())
+    }
+}
+
+impl<K: Debug, V: Debug, S> Debug for OnceMap<K, V, S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut map = f.debug_map();
+        for shard in self.shards.iter() {
+            let shard = shard.read().unwrap_or_else(PoisonError::into_inner);
+            map.entries(shard.iter().map(|(key, value)| (key, value)));
+        }
+        map.finish()
+    }
+}
+
+impl<K, V, S> IntoIterator for OnceMap<K, V, S> {
+    type Item = (K, V);
+    type IntoIter = IntoIter<K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter {
+            shards: self
+                .shards
+                .into_vec()
+                .into_iter()
+                .map(|shard| shard.into_inner().unwrap_or_else(PoisonError::into_inner))
+                .collect(),
+            current: None,
+        }
+    }
+}
+
+/// An owning iterator over the entries of a [`OnceMap`].
+pub struct IntoIter<K, V> {
+    shards: Vec<HashTable<(K, Box<V>)>>,
+    current: Option<hashbrown::hash_table::IntoIter<(K, Box<V>)>>,
+}
+
+impl<K, V> Iterator for IntoIter<K, V> {
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some((key, value)) = self.current.as_mut().and_then(Iterator::next) {
+                return Some((key, *value));
+            }
+            self.current = Some(self.shards.pop()?.into_iter());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{string::String, string::ToString};
+
+    #[test]
+    fn once_map() {
+        let map: OnceMap<u32, String> = OnceMap::new();
+        let first = map.get_or_insert_with(0, || "zero".to_string());
+        for i in 1..256 {
+            assert_eq!(map.get_or_insert(i, i.to_string()), &i.to_string());
+        }
+        assert_eq!(first, "zero");
+        assert!(core::ptr::eq(first, map.get(&0).unwrap()));
+        assert_eq!(map.len(), 256);
+        assert!(!map.contains_key(&256));
+
+        // reentrant initialization
+        let nested = map.get_or_insert_with(300, || map.get(&1).unwrap().clone());
+        assert_eq!(nested, "1");
+
+        let mut map = map;
+        for (_, value) in map.iter_mut() {
+            value.push('!');
+        }
+        assert_eq!(
+            map.into_iter().filter(|(_, v)| v.ends_with('!')).count(),
+            257
+        );
+    }
+
+    #[test]
+    fn once_map_threads() {
+        let map: OnceMap<u32, u32> = OnceMap::new();
+        std::thread::scope(|s| {
+            for _ in 0..4 {
+                s.spawn(|| {
+                    for i in 0..100 {
+                        assert_eq!(*map.get_or_insert_with(i, || i * 2), i * 2);
+                    }
+                });
+            }
+        });
+        assert_eq!(map.len(), 100);
+    }
+}
@@ -0,0 +1,378 @@
+//! Provides [`VecMap`], a linear-scan map for tiny key sets.
+
+use core::{fmt::Debug, ops::Index};
+
+use hashbrown::Equivalent;
+use smallvec::SmallVec;
+
+/// A map backed by a vector of key-value pairs, looked up by linear scan.
+///
+/// For maps with fewer than ~8 entries, comparing keys directly is
+/// cheaper than hashing them, and the memory footprint is minimal.
+/// The API mirrors [`HashMap`](crate::collections::HashMap), but keys
+/// only need to implement [`Eq`].
+///
+/// Entries are kept in insertion order. Up to `N` entries are stored
+/// inline without any heap allocation (`N` defaults to `0`).
+///
+/// # Example
+///
+/// ```
+/// # use vct_utils::collections::VecMap;
+/// let mut map: VecMap<&str, i32, 4> = VecMap::new();
+/// map.insert("a", 1);
+/// map.insert("b", 2);
+/// assert_eq!(map.insert("a", 3), Some(1));
+///
+/// assert_eq!(map.get("a"), Some(&3));
+/// assert_eq!(map["b"], 2);
+/// assert!(!map.spilled());
+/// assert_eq!(map.keys().copied().collect::<Vec<_>>(), ["a", "b"]);
+/// ```
+pub struct VecMap<K, V, const N: usize = 0> {
+    entries: SmallVec<[(K, V); N]>,
+}
+
+impl<K, V, const N: usize> VecMap<K, V, N> {
+    /// Create a empty [`VecMap`]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            entries: SmallVec::new_const(),
+        }
+    }
+
+    /// Create a empty [`VecMap`] with specific capacity
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            entries: SmallVec::with_capacity(capacity),
+        }
+    }
+
+    /// Returns the number of elements the map can hold without reallocating.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.entries.capacity()
+    }
+
+    /// Returns `true` if the entries have been moved to the heap.
+    #[inline]
+    pub fn spilled(&self) -> bool {
+        self.entries.spilled()
+    }
+
+    /// Returns the number of elements in the map.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the map contains no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Clears the map, removing all key-value pairs. Keeps the allocated memory for reuse.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Reserves capacity for at least `additional` more elements.
+    #[inline]
+    pub fn reserve(&mut self, additional: usize) {
+        self.entries.reserve(additional);
+    }
+
+    /// Shrinks the capacity of the map as much as possible.
+    #[inline]
+    pub fn shrink_to_fit(&mut self) {
+        self.entries.shrink_to_fit();
+    }
+
+    /// An iterator visiting all keys in insertion order.
+    #[inline]
+    pub fn keys(&self) -> impl DoubleEndedIterator<Item = &K> + ExactSizeIterator + '_ {
+        self.entries.iter().map(|(key, _)| key)
+    }
+
+    /// An iterator visiting all values in insertion order.
+    #[inline]
+    pub fn values(&self) -> impl DoubleEndedIterator<Item = &V> + ExactSizeIterator + '_ {
+        self.entries.iter().map(|(_, value)| value)
+    }
+
+    /// An iterator visiting all values mutably in insertion order.
+    #[inline]
+    pub fn values_mut(
+        &mut self,
+    ) -> impl DoubleEndedIterator<Item = &mut V> + ExactSizeIterator + '_ {
+        self.entries.iter_mut().map(|(_, value)| value)
+    }
+
+    /// An iterator visiting all key-value pairs in insertion order.
+    #[inline]
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = (&K, &V)> + ExactSizeIterator + '_ {
+        self.entries.iter().map(|(key, value)| (key, value))
+    }
+
+    /// An iterator visiting all key-value pairs in insertion order,
+    /// with mutable references to the values.
+    #[inline]
+    pub fn iter_mut(
+        &mut self,
+    ) -> impl DoubleEndedIterator<Item = (&K, &mut V)> + ExactSizeIterator + '_ {
+        self.entries.iter_mut().map(|(key, value)| (&*key, value))
+    }
+
+    /// Clears the map, returning all key-value pairs as an iterator.
+    #[inline]
+    pub fn drain(&mut self) -> smallvec::Drain<'_, [(K, V); N]> {
+        self.entries.drain(..)
+    }
+
+    /// Retains only the elements specified by the predicate.
+    #[inline]
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&K, &mut V) -> bool,
+    {
+        self.entries.retain(|(key, value)| f(key, value));
+    }
+
+    /// Returns the key-value pairs as a slice, in insertion order.
+    #[inline]
+    pub fn as_slice(&self) -> &[(K, V)] {
+        &self.entries
+    }
+
+    #[inline]
+    fn position<Q>(&self, key: &Q) -> Option<usize>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        self.entries.iter().position(|(k, _)| key.equivalent(k))
+    }
+}
+
+impl<K: Eq, V, const N: usize> VecMap<K, V, N> {
+    /// Returns a reference to the value corresponding to the key.
+    #[inline]
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        self.position(key).map(|index| &self.entries[index].1)
+    }
+
+    /// Returns the key-value pair corresponding to the supplied key.
+    #[inline]
+    pub fn get_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        self.position(key).map(|index| {
+            let (key, value) = &self.entries[index];
+            (key, value)
+        })
+    }
+
+    /// Returns a mutable reference to the value corresponding to the key.
+    #[inline]
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        self.position(key).map(|index| &mut self.entries[index].1)
+    }
+
+    /// Returns `true` if the map contains a value for the specified key.
+    #[inline]
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        self.position(key).is_some()
+    }
+
+    /// Inserts a key-value pair into the map.
+    ///
+    /// If the map already had the key present, the value is updated and the old value is returned.
+    /// The key keeps its original position.
+    #[inline]
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        match self.position(&key) {
+            Some(index) => Some(core::mem::replace(&mut self.entries[index].1, value)),
+            None => {
+                self.entries.push((key, value));
+                None
+            }
+        }
+    }
+
+    /// Returns the value of the key, inserting the value returned by `f` if it is absent.
+    #[inline]
+    pub fn get_or_insert_with<F>(&mut self, key: K, f: F) -> &mut V
+    where
+        F: FnOnce() -> V,
+    {
+        let index = match self.position(&key) {
+            Some(index) => index,
+            None => {
+                self.entries.push((key, f()));
+                self.entries.len() - 1
+            }
+        };
+        &mut self.entries[index].1
+    }
+
+    /// Removes a key from the map, returning the value at the key if the key was previously in the map.
+    ///
+    /// The order of the remaining entries is preserved.
+    #[inline]
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        self.remove_entry(key).map(|(_, value)| value)
+    }
+
+    /// Removes a key from the map, returning the stored key and value if the key was previously in the map.
+    ///
+    /// The order of the remaining entries is preserved.
+    #[inline]
+    pub fn remove_entry<Q>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        self.position(key).map(|index| self.entries.remove(index))
+    }
+
+    /// Removes a key from the map by swapping it with the last entry.
+    ///
+    /// This is `O(1)` after the lookup, but does not preserve the order.
+    #[inline]
+    pub fn swap_remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        self.position(key)
+            .map(|index| self.entries.swap_remove(index).1)
+    }
+}
+
+impl<K: Clone, V: Clone, const N: usize> Clone for VecMap<K, V, N> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            entries: self.entries.clone(),
+        }
+    }
+}
+
+impl<K: Debug, V: Debug, const N: usize> Debug for VecMap<K, V, N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K, V, const N: usize> Default for VecMap<K, V, N> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Eq, V: PartialEq, const N: usize> PartialEq for VecMap<K, V, N> {
+    /// Two maps are equal if they contain the same entries, regardless of the order.
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .all(|(key, value)| other.get(key) == Some(value))
+    }
+}
+
+impl<K: Eq, V: Eq, const N: usize> Eq for VecMap<K, V, N> {}
+
+impl<K: Eq, V, const N: usize> Extend<(K, V)> for VecMap<K, V, N> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+impl<K: Eq, V, const N: usize> FromIterator<(K, V)> for VecMap<K, V, N> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = Self::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<K: Eq, V, const N: usize, const M: usize> From<[(K, V); M]> for VecMap<K, V, N> {
+    #[inline]
+    fn from(arr: [(K, V); M]) -> Self {
+        arr.into_iter().collect()
+    }
+}
+
+impl<K: Eq, V, Q, const N: usize> Index<&Q> for VecMap<K, V, N>
+where
+    Q: Equivalent<K> + ?Sized,
+{
+    type Output = V;
+
+    /// # Panics
+    ///
+    /// Panics if the key is not present in the map.
+    #[inline]
+    fn index(&self, key: &Q) -> &V {
+        self.get(key).expect("VecMap: key not found")
+    }
+}
+
+impl<K, V, const N: usize> IntoIterator for VecMap<K, V, N> {
+    type Item = (K, V);
+    type IntoIter = smallvec::IntoIter<[(K, V); N]>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vec_map() {
+        let mut map: VecMap<u32, char, 2> = VecMap::new();
+        assert_eq!(map.insert(1, 'a'), None);
+        assert_eq!(map.insert(2, 'b'), None);
+        assert!(!map.spilled());
+        assert_eq!(map.insert(3, 'c'), None);
+        assert!(map.spilled());
+        assert_eq!(map.insert(1, 'x'), Some('a'));
+
+        assert_eq!(map.as_slice(), &[(1, 'x'), (2, 'b'), (3, 'c')]);
+        assert_eq!(map.remove(&2), Some('b'));
+        assert_eq!(map.as_slice(), &[(1, 'x'), (3, 'c')]);
+
+        *map.get_or_insert_with(4, || 'd') = 'y';
+        assert_eq!(map[&4], 'y');
+
+        let other: VecMap<u32, char, 2> = [(4, 'y'), (3, 'c'), (1, 'x')].into();
+        assert_eq!(map, other);
+
+        map.retain(|k, _| *k > 1);
+        assert_eq!(map.swap_remove(&3), Some('c'));
+        assert_eq!(map.into_iter().collect::<alloc::vec::Vec<_>>(), [(4, 'y')]);
+    }
+}
@@ -0,0 +1,372 @@
+#![expect(
+    unsafe_code,
+    reason = "Inline strings are stored as bytes that are known to be valid UTF-8."
+)]
+
+//! Provides [`CowStr`], a cheap to clone string for names and paths.
+
+use alloc::{borrow::Cow, boxed::Box, string::String, sync::Arc};
+use core::{
+    borrow::Borrow,
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    ops::Deref,
+};
+
+/// Maximum length of an inline string, keeps [`CowStr`] at 24 bytes.
+const INLINE_CAP: usize = 22;
+
+/// An immutable string that is cheap to create and clone.
+///
+/// Similar to `Cow<'static, str>`, but owned strings are stored more compactly:
+///
+/// - `&'static str` are borrowed.
+/// - Strings of up to 22 bytes are stored inline, without allocation.
+/// - Longer strings are stored in an [`Arc<str>`], so cloning never copies them.
+///
+/// Comparison and hashing behave like [`str`], so `&str` can be used
+/// to look up `CowStr` keys in maps.
+///
+/// # Example
+///
+/// ```
+/// # use vct_utils::CowStr;
+/// let name = CowStr::new("position");
+/// assert!(name.is_inline());
+/// assert_eq!(name, "position");
+///
+/// let path = CowStr::from_static("vct_reflect::ops::DynamicStruct");
+/// assert!(path.is_static());
+///
+/// let long = CowStr::new("a string that is too long to be inlined");
+/// assert!(!long.is_inline());
+/// assert_eq!(core::mem::size_of::<CowStr>(), 24);
+/// ```
+#[derive(Clone)]
+pub struct CowStr(Repr);
+
+#[derive(Clone)]
+enum Repr {
+    Static(&'static str),
+    Inline(InlineStr),
+    Shared(Arc<str>),
+}
+
+#[derive(Clone, Copy)]
+struct InlineStr {
+    len: u8,
+    buf: [u8; INLINE_CAP],
+}
+
+impl InlineStr {
+    #[inline]
+    fn new(value: &str) -> Option<Self> {
+        let len = value.len();
+        if len > INLINE_CAP {
+            return None;
+        }
+        let mut buf = [0; INLINE_CAP];
+        buf[..len].copy_from_slice(value.as_bytes());
+        Some(Self {
+            len: len as u8,
+            buf,
+        })
+    }
+
+    #[inline]
+    fn as_str(&self) -> &str {
+        // SAFETY: `buf[..len]` is copied from a `&str` in `new`.
+        unsafe { core::str::from_utf8_unchecked(&self.buf[..self.len as usize]) }
+    }
+}
+
+impl CowStr {
+    /// Creates an empty string.
+    #[inline]
+    pub const fn empty() -> Self {
+        Self(Repr::Static(""))
+    }
+
+    /// Creates a string that borrows `value`, without allocation.
+    #[inline]
+    pub const fn from_static(value: &'static str) -> Self {
+        Self(Repr::Static(value))
+    }
+
+    /// Creates a string by copying `value`.
+    ///
+    /// Only allocates if `value` is longer than 22 bytes.
+    #[inline]
+    pub fn new(value: &str) -> Self {
+        match InlineStr::new(value) {
+            Some(inline) => Self(Repr::Inline(inline)),
+            None => Self(Repr::Shared(Arc::from(value))),
+        }
+    }
+
+    /// Returns the string slice.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        match &self.0 {
+            Repr::Static(value) => value,
+            Repr::Inline(inline) => inline.as_str(),
+            Repr::Shared(value) => value,
+        }
+    }
+
+    /// Returns `true` if the string borrows a `&'static str`.
+    #[inline]
+    pub const fn is_static(&self) -> bool {
+        matches!(self.0, Repr::Static(_))
+    }
+
+    /// Returns `true` if the string is stored inline.
+    #[inline]
+    pub const fn is_inline(&self) -> bool {
+        matches!(self.0, Repr::Inline(_))
+    }
+
+    /// Returns the `&'static str` if the string borrows one.
+    #[inline]
+    pub const fn as_static(&self) -> Option<&'static str> {
+        match self.0 {
+            Repr::Static(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Converts into a [`String`].
+    #[inline]
+    pub fn into_string(self) -> String {
+        String::from(self.as_str())
+    }
+}
+
+impl Default for CowStr {
+    #[inline]
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+impl Deref for CowStr {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for CowStr {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Borrow<str> for CowStr {
+    #[inline]
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Debug for CowStr {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl fmt::Display for CowStr {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
+impl Hash for CowStr {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
+impl PartialEq for CowStr {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for CowStr {}
+
+impl PartialEq<str> for CowStr {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for CowStr {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl PartialEq<CowStr> for str {
+    #[inline]
+    fn eq(&self, other: &CowStr) -> bool {
+        self == other.as_str()
+    }
+}
+
+impl PartialEq<CowStr> for &str {
+    #[inline]
+    fn eq(&self, other: &CowStr) -> bool {
+        *self == other.as_str()
+    }
+}
+
+impl PartialOrd for CowStr {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for CowStr {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl From<&'static str> for CowStr {
+    #[inline]
+    fn from(value: &'static str) -> Self {
+        Self::from_static(value)
+    }
+}
+
+impl From<String> for CowStr {
+    #[inline]
+    fn from(value: String) -> Self {
+        match InlineStr::new(&value) {
+            Some(inline) => Self(Repr::Inline(inline)),
+            None => Self(Repr::Shared(Arc::from(value))),
+        }
+    }
+}
+
+impl From<Box<str>> for CowStr {
+    #[inline]
+    fn from(value: Box<str>) -> Self {
+        match InlineStr::new(&value) {
+            Some(inline) => Self(Repr::Inline(inline)),
+            None => Self(Repr::Shared(Arc::from(value))),
+        }
+    }
+}
+
+impl From<Arc<str>> for CowStr {
+    #[inline]
+    fn from(value: Arc<str>) -> Self {
+        Self(Repr::Shared(value))
+    }
+}
+
+impl From<Cow<'static, str>> for CowStr {
+    #[inline]
+    fn from(value: Cow<'static, str>) -> Self {
+        match value {
+            Cow::Borrowed(value) => Self::from_static(value),
+            Cow::Owned(value) => Self::from(value),
+        }
+    }
+}
+
+impl From<CowStr> for Cow<'static, str> {
+    #[inline]
+    fn from(value: CowStr) -> Self {
+        match value.0 {
+            Repr::Static(value) => Cow::Borrowed(value),
+            _ => Cow::Owned(value.into_string()),
+        }
+    }
+}
+
+impl From<CowStr> for String {
+    #[inline]
+    fn from(value: CowStr) -> Self {
+        value.into_string()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for CowStr {
+    #[inline]
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for CowStr {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct CowStrVisitor;
+
+        impl serde::de::Visitor<'_> for CowStrVisitor {
+            type Value = CowStr;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a string")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, value: &str) -> Result<CowStr, E> {
+                Ok(CowStr::new(value))
+            }
+
+            fn visit_string<E: serde::de::Error>(self, value: String) -> Result<CowStr, E> {
+                Ok(CowStr::from(value))
+            }
+        }
+
+        deserializer.deserialize_str(CowStrVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collections::HashMap;
+    use alloc::{format, string::ToString};
+
+    #[test]
+    fn cow_str() {
+        let inline = CowStr::new("0123456789012345678901");
+        let shared = CowStr::new("01234567890123456789012");
+        assert!(inline.is_inline());
+        assert!(!shared.is_inline() && !shared.is_static());
+        assert_eq!(inline.len(), 22);
+        assert_eq!(&shared[..22], inline);
+
+        let owned: CowStr = "é".to_string().into();
+        assert!(owned.is_inline());
+        assert_eq!(owned.as_str(), "é");
+        assert_eq!(format!("{owned:?}"), "\"é\"");
+
+        let borrowed: CowStr = Cow::Borrowed("name").into();
+        assert_eq!(borrowed.as_static(), Some("name"));
+        assert_eq!(borrowed, CowStr::new("name"));
+
+        let mut map = HashMap::<CowStr, i32>::default();
+        map.insert(borrowed, 1);
+        map.insert(shared.clone(), 2);
+        assert_eq!(map.get("name"), Some(&1));
+        assert_eq!(map.get(shared.as_str()), Some(&2));
+    }
+}
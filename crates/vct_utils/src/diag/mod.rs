@@ -0,0 +1,171 @@
+//! Cheap global counters and scoped timers for measuring hot paths.
+//!
+//! The metrics are only collected if the `debug` feature is enabled.
+//! Otherwise [`counter!`](crate::counter) and [`timer!`](crate::timer)
+//! expand to nothing, so they can stay in the code permanently.
+//!
+//! - [`counter!`](crate::counter): increments a named global counter.
+//! - [`timer!`](crate::timer): returns a guard that records the elapsed time
+//!   when dropped, requires the `std` feature.
+//! - [`tracked!`](crate::tracked): wraps a collection to record its heap memory,
+//!   requires the `track_alloc` feature.
+//! - `report()`: formats all recorded metrics, sorted by name.
+//!
+//! # Example
+//!
+//! ```
+//! fn apply() {
+//!     vct_utils::counter!("example.apply");
+//!     let _timer = vct_utils::timer!("example.apply_time");
+//!     // ...
+//! }
+//!
+//! apply();
+//! apply();
+//!
+//! # #[cfg(feature = "debug")]
+//! println!("{}", vct_utils::diag::report());
+//! ```
+
+mod tracked;
+pub use tracked::{AllocationSize, Tracked};
+
+crate::cfg::debug! {
+    mod metric;
+    pub use metric::{Metric, MetricKind, metrics, report, reset};
+
+    crate::cfg::std! {
+        mod timer;
+        pub use timer::ScopedTimer;
+    }
+}
+
+/// Increments the global counter with the given name.
+///
+/// An optional second argument specifies the amount to add, default to `1`.
+///
+/// Expands to nothing if the `debug` feature is disabled.
+///
+/// # Example
+///
+/// ```
+/// vct_utils::counter!("reflect.apply");
+/// vct_utils::counter!("reflect.bytes", 64);
+/// ```
+#[cfg(feature = "debug")]
+#[macro_export]
+macro_rules! counter {
+    ($name:literal) => {
+        $crate::counter!($name, 1)
+    };
+    ($name:literal, $amount:expr) => {{
+        static METRIC: $crate::diag::Metric = $crate::diag::Metric::counter($name);
+        METRIC.add($amount);
+    }};
+}
+
+/// Increments the global counter with the given name.
+///
+/// An optional second argument specifies the amount to add, default to `1`.
+///
+/// Expands to nothing if the `debug` feature is disabled.
+///
+/// # Example
+///
+/// ```
+/// vct_utils::counter!("reflect.apply");
+/// vct_utils::counter!("reflect.bytes", 64);
+/// ```
+#[cfg(not(feature = "debug"))]
+#[macro_export]
+macro_rules! counter {
+    ($name:literal $(, $amount:expr)?) => {{
+        $(let _ = $amount;)?
+    }};
+}
+
+/// Starts a scoped timer with the given name.
+///
+/// Returns a guard that records the elapsed time when dropped,
+/// so it must be bound to a variable (not `_`).
+///
+/// Expands to `()` if the `debug` or `std` feature is disabled.
+///
+/// # Example
+///
+/// ```
+/// let _timer = vct_utils::timer!("serde.serialize");
+/// ```
+#[cfg(all(feature = "debug", feature = "std"))]
+#[macro_export]
+macro_rules! timer {
+    ($name:literal) => {{
+        static METRIC: $crate::diag::Metric = $crate::diag::Metric::timer($name);
+        METRIC.start()
+    }};
+}
+
+/// Starts a scoped timer with the given name.
+///
+/// Returns a guard that records the elapsed time when dropped,
+/// so it must be bound to a variable (not `_`).
+///
+/// Expands to `()` if the `debug` or `std` feature is disabled.
+///
+/// # Example
+///
+/// ```
+/// let _timer = vct_utils::timer!("serde.serialize");
+/// ```
+#[cfg(not(all(feature = "debug", feature = "std")))]
+#[macro_export]
+macro_rules! timer {
+    ($name:literal) => {
+        ()
+    };
+}
+
+/// Wraps a collection in a [`Tracked`], recording its heap memory
+/// into the global memory metric with the given name.
+///
+/// Returns an untracked wrapper if the `track_alloc` feature is disabled.
+///
+/// # Example
+///
+/// ```
+/// use vct_utils::collections::HashMap;
+///
+/// let mut map = vct_utils::tracked!("example.map", HashMap::<u32, u32>::new());
+/// map.insert(1, 1);
+/// map.sync();
+/// ```
+#[cfg(feature = "track_alloc")]
+#[macro_export]
+macro_rules! tracked {
+    ($name:literal, $collection:expr) => {{
+        static METRIC: $crate::diag::Metric = $crate::diag::Metric::memory($name);
+        $crate::diag::Tracked::with_metric(&METRIC, $collection)
+    }};
+}
+
+/// Wraps a collection in a [`Tracked`], recording its heap memory
+/// into the global memory metric with the given name.
+///
+/// Returns an untracked wrapper if the `track_alloc` feature is disabled.
+///
+/// # Example
+///
+/// ```
+/// use vct_utils::collections::HashMap;
+///
+/// let mut map = vct_utils::tracked!("example.map", HashMap::<u32, u32>::new());
+/// map.insert(1, 1);
+/// map.sync();
+/// ```
+#[cfg(not(feature = "track_alloc"))]
+#[macro_export]
+macro_rules! tracked {
+    ($name:literal, $collection:expr) => {
+        $crate::diag::Tracked::new($collection)
+    };
+}
@@ -0,0 +1,40 @@
+use vct_os::time::{self, Instant};
+
+use super::Metric;
+
+/// A guard that records the time elapsed since its creation into a [`Metric`] when dropped.
+///
+/// Created by [`timer!`](crate::timer) or [`Metric::start`].
+#[must_use = "the elapsed time is recorded when the timer is dropped"]
+#[derive(Debug)]
+pub struct ScopedTimer {
+    metric: &'static Metric,
+    start: Instant,
+}
+
+impl Metric {
+    /// Starts a timer that records into this metric when dropped.
+    #[inline]
+    pub fn start(&'static self) -> ScopedTimer {
+        ScopedTimer {
+            metric: self,
+            start: time::now(),
+        }
+    }
+}
+
+impl ScopedTimer {
+    /// Stops the timer and records the elapsed time.
+    #[inline]
+    pub fn stop(self) {
+        // Recorded in `Drop`.
+    }
+}
+
+impl Drop for ScopedTimer {
+    #[inline]
+    fn drop(&mut self) {
+        self.metric
+            .record(time::now().saturating_duration_since(self.start));
+    }
+}
@@ -0,0 +1,233 @@
+use alloc::{
+    collections::{BinaryHeap, VecDeque},
+    string::String,
+    vec::Vec,
+};
+use core::{
+    fmt::Debug,
+    hash::{BuildHasher, Hash},
+    mem::size_of,
+    ops::{Deref, DerefMut},
+};
+
+use crate::collections::{HashMap, HashSet, HashTable, SmallVec, VecMap};
+
+#[cfg(feature = "track_alloc")]
+use super::Metric;
+
+/// Collections that can report the size of their heap allocation.
+pub trait AllocationSize {
+    /// Returns the number of bytes allocated on the heap,
+    /// not including the allocations of the elements.
+    fn allocation_size(&self) -> usize;
+}
+
+impl<K: Eq + Hash, V, S: BuildHasher> AllocationSize for HashMap<K, V, S> {
+    #[inline]
+    fn allocation_size(&self) -> usize {
+        HashMap::allocation_size(self)
+    }
+}
+
+impl<T: Eq + Hash, S: BuildHasher> AllocationSize for HashSet<T, S> {
+    #[inline]
+    fn allocation_size(&self) -> usize {
+        HashSet::allocation_size(self)
+    }
+}
+
+impl<T> AllocationSize for HashTable<T> {
+    #[inline]
+    fn allocation_size(&self) -> usize {
+        HashTable::allocation_size(self)
+    }
+}
+
+impl<T> AllocationSize for Vec<T> {
+    #[inline]
+    fn allocation_size(&self) -> usize {
+        self.capacity() * size_of::<T>()
+    }
+}
+
+impl<T> AllocationSize for VecDeque<T> {
+    #[inline]
+    fn allocation_size(&self) -> usize {
+        self.capacity() * size_of::<T>()
+    }
+}
+
+impl<T> AllocationSize for BinaryHeap<T> {
+    #[inline]
+    fn allocation_size(&self) -> usize {
+        self.capacity() * size_of::<T>()
+    }
+}
+
+impl AllocationSize for String {
+    #[inline]
+    fn allocation_size(&self) -> usize {
+        self.capacity()
+    }
+}
+
+impl<A: smallvec::Array> AllocationSize for SmallVec<A> {
+    #[inline]
+    fn allocation_size(&self) -> usize {
+        if self.spilled() {
+            self.capacity() * size_of::<A::Item>()
+        } else {
+            0
+        }
+    }
+}
+
+impl<K, V, const N: usize> AllocationSize for VecMap<K, V, N> {
+    #[inline]
+    fn allocation_size(&self) -> usize {
+        if self.spilled() {
+            self.capacity() * size_of::<(K, V)>()
+        } else {
+            0
+        }
+    }
+}
+
+/// A collection whose heap memory is recorded into a memory [`Metric`].
+///
+/// Created by [`tracked!`](crate::tracked). Changes are recorded when the
+/// collection is mutably accessed again or when [`Tracked::sync`] is called,
+/// so no global allocator hook is needed. The recorded memory is released
+/// when the wrapper is dropped.
+///
+/// Without the `track_alloc` feature this is a transparent wrapper.
+pub struct Tracked<C: AllocationSize> {
+    inner: C,
+    #[cfg(feature = "track_alloc")]
+    tracker: Tracker,
+}
+
+/// Releases the recorded memory on drop, kept separate so `into_inner` can move out of `Tracked`.
+#[cfg(feature = "track_alloc")]
+#[derive(Default)]
+struct Tracker {
+    metric: Option<&'static Metric>,
+    size: usize,
+}
+
+#[cfg(feature = "track_alloc")]
+impl Drop for Tracker {
+    #[inline]
+    fn drop(&mut self) {
+        if let Some(metric) = self.metric {
+            metric.record_allocation(self.size, 0);
+        }
+    }
+}
+
+impl<C: AllocationSize> Tracked<C> {
+    /// Wraps a collection without recording it.
+    #[inline]
+    pub fn new(inner: C) -> Self {
+        Self {
+            inner,
+            #[cfg(feature = "track_alloc")]
+            tracker: Tracker::default(),
+        }
+    }
+
+    /// Wraps a collection, recording its memory into `metric`.
+    #[cfg(feature = "track_alloc")]
+    #[inline]
+    pub fn with_metric(metric: &'static Metric, inner: C) -> Self {
+        let mut tracked = Self {
+            inner,
+            tracker: Tracker {
+                metric: Some(metric),
+                size: 0,
+            },
+        };
+        tracked.sync();
+        tracked
+    }
+
+    /// Records the current allocation size of the collection.
+    #[inline]
+    pub fn sync(&mut self) {
+        #[cfg(feature = "track_alloc")]
+        if let Some(metric) = self.tracker.metric {
+            let size = self.inner.allocation_size();
+            if size != self.tracker.size {
+                metric.record_allocation(self.tracker.size, size);
+                self.tracker.size = size;
+            }
+        }
+    }
+
+    /// Mutates the collection and records the change immediately.
+    #[inline]
+    pub fn modify<R>(&mut self, f: impl FnOnce(&mut C) -> R) -> R {
+        let result = f(&mut self.inner);
+        self.sync();
+        result
+    }
+
+    /// Returns the inner collection, its memory is no longer recorded.
+    #[inline]
+    pub fn into_inner(self) -> C {
+        self.inner
+    }
+}
+
+impl<C: AllocationSize> Deref for Tracked<C> {
+    type Target = C;
+
+    #[inline]
+    fn deref(&self) -> &C {
+        &self.inner
+    }
+}
+
+impl<C: AllocationSize> DerefMut for Tracked<C> {
+    /// Records the changes of the previous mutable access.
+    #[inline]
+    fn deref_mut(&mut self) -> &mut C {
+        self.sync();
+        &mut self.inner
+    }
+}
+
+impl<C: AllocationSize + Default> Default for Tracked<C> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(C::default())
+    }
+}
+
+impl<C: AllocationSize + Debug> Debug for Tracked<C> {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+#[cfg(all(test, feature = "track_alloc"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tracked() {
+        static METRIC: Metric = Metric::memory("test.tracked");
+
+        let mut vec = Tracked::with_metric(&METRIC, Vec::<u64>::with_capacity(4));
+        assert_eq!(METRIC.bytes(), 32);
+
+        vec.modify(|vec| vec.reserve_exact(8));
+        assert_eq!(METRIC.bytes(), vec.allocation_size() as u64);
+        assert_eq!(METRIC.count(), 2);
+
+        let vec = vec.into_inner();
+        assert_eq!(METRIC.bytes(), 0);
+        assert!(vec.capacity() >= 8);
+    }
+}
@@ -0,0 +1,234 @@
+//! Lightweight error utilities that work on `no_std`.
+//!
+//! - [`Error`]: the error trait, re-exported from `core`.
+//! - [`ResultExt::context`]: wraps an error with a static description.
+//! - [`define_error!`]: declares an error enum together with its
+//!   [`Display`](fmt::Display) and [`Error`] implementations.
+
+use alloc::boxed::Box;
+use core::fmt;
+
+pub use core::error::Error;
+
+pub use crate::define_error;
+
+/// A type-erased error that can be sent across threads.
+pub type BoxedError = Box<dyn Error + Send + Sync + 'static>;
+
+/// An error wrapped with a static description of what was being done.
+///
+/// The [`Display`](fmt::Display) output only contains the context,
+/// the alternate form (`{:#}`) also appends the source error.
+/// The wrapped error is available via [`Error::source`].
+///
+/// # Example
+///
+/// ```
+/// use vct_utils::error::{Error, ResultExt};
+///
+/// let res: Result<u8, _> = "256".parse::<u8>().context("failed to parse the version");
+/// let err = res.unwrap_err();
+///
+/// assert_eq!(format!("{err}"), "failed to parse the version");
+/// assert_eq!(
+///     format!("{err:#}"),
+///     "failed to parse the version: number too large to fit in target type"
+/// );
+/// assert!(err.source().is_some());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Context<E> {
+    context: &'static str,
+    source: E,
+}
+
+impl<E> Context<E> {
+    /// Wraps `source` with the given context.
+    #[inline]
+    pub const fn new(context: &'static str, source: E) -> Self {
+        Self { context, source }
+    }
+
+    /// Returns the description of what was being done.
+    #[inline]
+    pub const fn context(&self) -> &'static str {
+        self.context
+    }
+
+    /// Returns a reference to the wrapped error.
+    #[inline]
+    pub const fn inner(&self) -> &E {
+        &self.source
+    }
+
+    /// Returns the wrapped error.
+    #[inline]
+    pub fn into_inner(self) -> E {
+        self.source
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for Context<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            write!(f, "{}: {:#}", self.context, self.source)
+        } else {
+            f.write_str(self.context)
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for Context<E> {
+    #[inline]
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+/// Extension methods for attaching [`Context`] to errors.
+pub trait ResultExt<T, E> {
+    /// Wraps the error, if any, with a static description.
+    fn context(self, context: &'static str) -> Result<T, Context<E>>;
+
+    /// Wraps the error, if any, with a lazily selected static description.
+    fn with_context<F>(self, f: F) -> Result<T, Context<E>>
+    where
+        F: FnOnce(&E) -> &'static str;
+}
+
+impl<T, E> ResultExt<T, E> for Result<T, E> {
+    #[inline]
+    fn context(self, context: &'static str) -> Result<T, Context<E>> {
+        self.map_err(|source| Context::new(context, source))
+    }
+
+    #[inline]
+    fn with_context<F>(self, f: F) -> Result<T, Context<E>>
+    where
+        F: FnOnce(&E) -> &'static str,
+    {
+        self.map_err(|source| Context::new(f(&source), source))
+    }
+}
+
+/// Returns an iterator over the error and all of its sources.
+///
+/// # Example
+///
+/// ```
+/// use vct_utils::error::{ResultExt, chain};
+///
+/// let err = "x".parse::<u8>().context("invalid input").unwrap_err();
+/// assert_eq!(chain(&err).count(), 2);
+/// ```
+pub fn chain<'a>(
+    error: &'a (dyn Error + 'static),
+) -> impl Iterator<Item = &'a (dyn Error + 'static)> {
+    core::iter::successors(Some(error), |&error| error.source())
+}
+
+/// Declares an error enum and implements [`Display`](core::fmt::Display)
+/// and [`Error`](core::error::Error) for it.
+///
+/// Each variant is followed by its message, the message is a format string
+/// that can use the fields of the variant by name. Unit and struct-like
+/// variants are supported, as well as lifetime parameters on the enum.
+///
+/// # Example
+///
+/// ```
+/// use vct_utils::error::define_error;
+///
+/// define_error! {
+///     /// Errors of the loader.
+///     #[derive(Debug, PartialEq)]
+///     pub enum LoadError {
+///         /// The file is empty.
+///         Empty => "the file is empty",
+///         /// The version is not supported.
+///         UnsupportedVersion { found: u32, expected: u32 } =>
+///             "unsupported version {found}, expected {expected}",
+///     }
+/// }
+///
+/// let err = LoadError::UnsupportedVersion { found: 3, expected: 2 };
+/// assert_eq!(err.to_string(), "unsupported version 3, expected 2");
+///
+/// let err: &dyn core::error::Error = &LoadError::Empty;
+/// assert_eq!(err.to_string(), "the file is empty");
+/// ```
+#[macro_export]
+macro_rules! define_error {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident $(<$($lt:lifetime),+ $(,)?>)? {
+            $(
+                $(#[$variant_meta:meta])*
+                $variant:ident $({ $($field:ident : $ty:ty),* $(,)? })? => $message:literal
+            ),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        $vis enum $name $(<$($lt),+>)? {
+            $(
+                $(#[$variant_meta])*
+                $variant $({ $($field: $ty),* })?,
+            )*
+        }
+
+        impl $(<$($lt),+>)? ::core::fmt::Display for $name $(<$($lt),+>)? {
+            #[allow(unused_variables, reason = "messages may not use every field")]
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                match self {
+                    $(
+                        Self::$variant $({ $($field),* })? => {
+                            f.write_fmt(::core::format_args!($message))
+                        }
+                    )*
+                }
+            }
+        }
+
+        impl $(<$($lt),+>)? ::core::error::Error for $name $(<$($lt),+>)? {}
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{borrow::Cow, format, string::ToString};
+
+    define_error! {
+        #[derive(Debug)]
+        enum TestError<'a> {
+            Unit => "unit",
+            Named { name: Cow<'a, str>, len: usize } => "`{name}` has {len} items",
+            Unused { ignored: u8 } => "ignored",
+        }
+    }
+
+    #[test]
+    fn define_error() {
+        assert_eq!(TestError::Unit.to_string(), "unit");
+        let err = TestError::Named {
+            name: Cow::Borrowed("list"),
+            len: 3,
+        };
+        assert_eq!(err.to_string(), "`list` has 3 items");
+        assert_eq!(TestError::Unused { ignored: 0 }.to_string(), "ignored");
+    }
+
+    #[test]
+    fn context() {
+        let res: Result<(), _> = Err(TestError::Unit);
+        let err = res.context("outer").unwrap_err();
+        assert_eq!(format!("{err}"), "outer");
+        assert_eq!(format!("{err:#}"), "outer: unit");
+        assert_eq!(chain(&err).count(), 2);
+
+        let res: Result<(), _> = Err(TestError::Unit);
+        let err = res.with_context(|_| "lazy").unwrap_err();
+        assert_eq!(err.context(), "lazy");
+        assert!(matches!(err.into_inner(), TestError::Unit));
+    }
+}
@@ -22,3 +22,65 @@ impl<A: Any> Is for A {
         TypeId::of::<A>() == TypeId::of::<T>()
     }
 }
+
+/// Casts `a` to `B` if `A` and `B` are the same type, otherwise returns `a` back.
+///
+/// The [`TypeId`] comparison is resolved at compile time, so this compiles
+/// to a no-op (or to nothing) once monomorphized. Useful to take a fast path
+/// in generic code when two type parameters happen to be the same.
+///
+/// # Example
+///
+/// ```
+/// # use vct_utils::try_cast_same;
+/// assert_eq!(try_cast_same::<u32, u32>(5), Ok(5));
+/// assert_eq!(try_cast_same::<u32, i32>(5), Err(5));
+/// ```
+#[inline(always)]
+pub fn try_cast_same<A: Any, B: Any>(a: A) -> Result<B, A> {
+    let mut slot = Some(a);
+    match (&mut slot as &mut dyn Any).downcast_mut::<Option<B>>() {
+        Some(b) => Ok(b.take().unwrap()),
+        None => Err(slot.unwrap()),
+    }
+}
+
+/// Casts `&A` to `&B` if `A` and `B` are the same type, otherwise returns `a` back.
+///
+/// See [`try_cast_same`].
+///
+/// # Example
+///
+/// ```
+/// # use vct_utils::try_cast_same_ref;
+/// let value = String::from("text");
+/// assert_eq!(try_cast_same_ref::<String, String>(&value), Ok(&value));
+/// assert!(try_cast_same_ref::<String, &str>(&value).is_err());
+/// ```
+#[inline(always)]
+pub fn try_cast_same_ref<A: Any, B: Any>(a: &A) -> Result<&B, &A> {
+    (a as &dyn Any).downcast_ref::<B>().ok_or(a)
+}
+
+/// Casts `&mut A` to `&mut B` if `A` and `B` are the same type, otherwise returns `a` back.
+///
+/// See [`try_cast_same`].
+///
+/// # Example
+///
+/// ```
+/// # use vct_utils::try_cast_same_mut;
+/// let mut value = 1_u8;
+/// if let Ok(value) = try_cast_same_mut::<u8, u8>(&mut value) {
+///     *value += 1;
+/// }
+/// assert_eq!(value, 2);
+/// ```
+#[inline(always)]
+pub fn try_cast_same_mut<A: Any, B: Any>(a: &mut A) -> Result<&mut B, &mut A> {
+    if A::is::<B>() {
+        Ok((a as &mut dyn Any).downcast_mut::<B>().unwrap())
+    } else {
+        Err(a)
+    }
+}
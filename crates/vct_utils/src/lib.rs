@@ -6,6 +6,7 @@ extern crate alloc;
 
 pub mod cfg {
     pub use vct_cfg::std;
+    pub(crate) use vct_cfg::switch;
 
     vct_cfg::define_alias! {
         #[cfg(feature = "parallel")] => parallel,
@@ -13,6 +14,11 @@ pub mod cfg {
         #[cfg(feature = "serde")] => serde,
         #[cfg(feature = "rayon")] => rayon,
     }
+
+    /// Returns the names of the aliases of this crate that are currently active.
+    pub const fn active_aliases() -> &'static [&'static str] {
+        vct_cfg::active_aliases![std, parallel, debug, serde, rayon]
+    }
 }
 
 cfg::std! {
@@ -27,18 +33,24 @@ cfg::parallel! {
 
 pub mod cell;
 pub mod collections;
+pub mod diag;
+pub mod error;
 pub mod hash;
 pub mod name;
 pub mod temp;
 
+mod cow_str;
 mod default;
 mod is;
 mod on_drop;
+mod pool;
 mod range_invoke;
 
+pub use cow_str::CowStr;
 pub use default::default;
-pub use is::Is;
+pub use is::{Is, try_cast_same, try_cast_same_mut, try_cast_same_ref};
 pub use on_drop::OnDrop;
+pub use pool::{Pool, Pooled};
 
 pub mod prelude {
     pub use alloc::{
@@ -5,6 +5,8 @@ use super::ShortName;
 use alloc::{borrow::Cow, string::String};
 use core::fmt;
 
+#[cfg(feature = "debug")]
+use crate::CowStr;
 #[cfg(feature = "debug")]
 use core::any::type_name;
 #[cfg(not(feature = "debug"))]
@@ -21,7 +23,7 @@ const FEATURE_DISABLED: &str = "Enable the debug feature to see the name";
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct DebugName {
     #[cfg(feature = "debug")]
-    name: Cow<'static, str>,
+    name: CowStr,
 }
 
 impl DebugName {
@@ -31,7 +33,7 @@ impl DebugName {
         // TODO: Change to const fn if `type_name::<T>()` is stable const fn.
         DebugName {
             #[cfg(feature = "debug")]
-            name: Cow::Borrowed(type_name::<T>()),
+            name: CowStr::from_static(type_name::<T>()),
         }
     }
 
@@ -41,7 +43,7 @@ impl DebugName {
     #[inline]
     pub fn as_ref(&self) -> &str {
         #[cfg(feature = "debug")]
-        return self.name.as_ref();
+        return self.name.as_str();
         #[cfg(not(feature = "debug"))]
         return FEATURE_DISABLED;
     }
@@ -50,7 +52,7 @@ impl DebugName {
     #[inline]
     pub fn short_name(&self) -> ShortName<'_> {
         #[cfg(feature = "debug")]
-        return ShortName(self.name.as_ref());
+        return ShortName(self.name.as_str());
         #[cfg(not(feature = "debug"))]
         return ShortName(FEATURE_DISABLED);
     }
@@ -61,7 +63,7 @@ impl DebugName {
     pub const fn borrowed(value: &'static str) -> Self {
         DebugName {
             #[cfg(feature = "debug")]
-            name: Cow::Borrowed(value),
+            name: CowStr::from_static(value),
         }
     }
 
@@ -71,7 +73,7 @@ impl DebugName {
     pub fn owned(value: String) -> Self {
         DebugName {
             #[cfg(feature = "debug")]
-            name: Cow::Owned(value),
+            name: CowStr::from(value),
         }
     }
 }
@@ -80,7 +82,7 @@ impl fmt::Display for DebugName {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         #[cfg(feature = "debug")]
-        f.write_str(self.name.as_ref())?;
+        f.write_str(self.name.as_str())?;
         #[cfg(not(feature = "debug"))]
         f.write_str(FEATURE_DISABLED)?;
 
@@ -108,7 +110,7 @@ impl From<Cow<'static, str>> for DebugName {
     fn from(value: Cow<'static, str>) -> Self {
         Self {
             #[cfg(feature = "debug")]
-            name: value,
+            name: value.into(),
         }
     }
 }
@@ -118,7 +120,7 @@ impl From<DebugName> for Cow<'static, str> {
     #[cfg_attr(not(feature = "debug"), expect(unused_variables))]
     fn from(value: DebugName) -> Self {
         #[cfg(feature = "debug")]
-        return value.name;
+        return value.name.into();
         #[cfg(not(feature = "debug"))]
         return Cow::Borrowed(FEATURE_DISABLED);
     }
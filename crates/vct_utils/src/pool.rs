@@ -0,0 +1,249 @@
+//! Provides [`Pool`], a simple object pool with RAII handles.
+
+use alloc::vec::Vec;
+use core::{
+    fmt::Debug,
+    mem::ManuallyDrop,
+    ops::{Deref, DerefMut},
+};
+
+crate::cfg::switch! {
+    crate::cfg::parallel => {
+        use vct_os::sync::{Mutex, PoisonError};
+
+        type Storage<T> = Mutex<Vec<T>>;
+
+        #[inline]
+        fn with_storage<T, R>(storage: &Storage<T>, f: impl FnOnce(&mut Vec<T>) -> R) -> R {
+            f(&mut storage.lock().unwrap_or_else(PoisonError::into_inner))
+        }
+
+        #[inline]
+        fn storage_mut<T>(storage: &mut Storage<T>) -> &mut Vec<T> {
+            storage.get_mut().unwrap_or_else(PoisonError::into_inner)
+        }
+    }
+    _ => {
+        use core::cell::RefCell;
+
+        type Storage<T> = RefCell<Vec<T>>;
+
+        #[inline]
+        fn with_storage<T, R>(storage: &Storage<T>, f: impl FnOnce(&mut Vec<T>) -> R) -> R {
+            f(&mut storage.borrow_mut())
+        }
+
+        #[inline]
+        fn storage_mut<T>(storage: &mut Storage<T>) -> &mut Vec<T> {
+            storage.get_mut()
+        }
+    }
+}
+
+/// A pool of reusable objects.
+///
+/// [`Pool::get`] hands out a [`Pooled`] handle, which gives the object back
+/// to the pool when dropped. The reset hook runs before an object is stored,
+/// so every object taken out of the pool is in a clean state while keeping
+/// its allocations (e.g. the capacity of a buffer).
+///
+/// The pool is thread-safe if the `parallel` feature is enabled.
+///
+/// # Example
+///
+/// ```
+/// # use vct_utils::Pool;
+/// let pool: Pool<Vec<u8>> = Pool::with_reset(Vec::clear);
+///
+/// let mut buffer = pool.get();
+/// buffer.extend_from_slice(b"hello");
+/// let capacity = buffer.capacity();
+/// drop(buffer);
+///
+/// assert_eq!(pool.idle(), 1);
+/// let buffer = pool.get();
+/// assert!(buffer.is_empty());
+/// assert_eq!(buffer.capacity(), capacity);
+/// ```
+pub struct Pool<T> {
+    items: Storage<T>,
+    create: fn() -> T,
+    reset: fn(&mut T),
+    max_idle: usize,
+}
+
+impl<T: Default> Pool<T> {
+    /// Creates an empty pool that creates objects with [`Default`]
+    /// and does not reset them.
+    #[inline]
+    pub fn new() -> Self {
+        Self::with_hooks(T::default, |_| {})
+    }
+
+    /// Creates an empty pool that creates objects with [`Default`]
+    /// and resets them with `reset` when they are returned.
+    #[inline]
+    pub fn with_reset(reset: fn(&mut T)) -> Self {
+        Self::with_hooks(T::default, reset)
+    }
+}
+
+impl<T> Pool<T> {
+    /// Creates an empty pool with the given creation and reset hooks.
+    #[inline]
+    pub fn with_hooks(create: fn() -> T, reset: fn(&mut T)) -> Self {
+        Self {
+            items: Storage::new(Vec::new()),
+            create,
+            reset,
+            max_idle: usize::MAX,
+        }
+    }
+
+    /// Limits the number of idle objects kept by the pool.
+    ///
+    /// Objects returned to a full pool are dropped.
+    #[inline]
+    pub fn with_max_idle(mut self, max_idle: usize) -> Self {
+        self.max_idle = max_idle;
+        storage_mut(&mut self.items).truncate(max_idle);
+        self
+    }
+
+    /// Takes an object from the pool, creating a new one if the pool is empty.
+    #[inline]
+    pub fn get(&self) -> Pooled<'_, T> {
+        let item = with_storage(&self.items, Vec::pop).unwrap_or_else(self.create);
+        Pooled {
+            pool: self,
+            item: ManuallyDrop::new(item),
+        }
+    }
+
+    /// Returns an object to the pool, resetting it first.
+    ///
+    /// The object is dropped if the pool is full.
+    pub fn put(&self, mut item: T) {
+        (self.reset)(&mut item);
+        with_storage(&self.items, |items| {
+            if items.len() < self.max_idle {
+                items.push(item);
+            }
+        });
+    }
+
+    /// Creates `amount` objects up front, limited by the maximum idle amount.
+    pub fn prefill(&self, amount: usize) {
+        let amount = amount.min(self.max_idle.saturating_sub(self.idle()));
+        let created: Vec<T> = (0..amount).map(|_| (self.create)()).collect();
+        with_storage(&self.items, |items| {
+            let amount = self.max_idle.saturating_sub(items.len());
+            items.extend(created.into_iter().take(amount));
+        });
+    }
+
+    /// Returns the number of idle objects in the pool.
+    #[inline]
+    pub fn idle(&self) -> usize {
+        with_storage(&self.items, |items| items.len())
+    }
+
+    /// Drops all idle objects.
+    #[inline]
+    pub fn clear(&self) {
+        with_storage(&self.items, Vec::clear);
+    }
+}
+
+impl<T: Default> Default for Pool<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Debug for Pool<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Pool")
+            .field("idle", &self.idle())
+            .field("max_idle", &self.max_idle)
+            .finish_non_exhaustive()
+    }
+}
+
+/// An object borrowed from a [`Pool`], returned to the pool when dropped.
+pub struct Pooled<'a, T> {
+    pool: &'a Pool<T>,
+    item: ManuallyDrop<T>,
+}
+
+impl<T> Pooled<'_, T> {
+    /// Takes the object out of the pool, it will not be returned on drop.
+    #[inline]
+    pub fn take(this: Self) -> T {
+        #![expect(unsafe_code, reason = "ManuallyDrop::take is unsafe.")]
+        let mut this = ManuallyDrop::new(this);
+        // SAFETY: `this` is never used or dropped again.
+        unsafe { ManuallyDrop::take(&mut this.item) }
+    }
+}
+
+impl<T> Deref for Pooled<'_, T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        &self.item
+    }
+}
+
+impl<T> DerefMut for Pooled<'_, T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.item
+    }
+}
+
+impl<T: Debug> Debug for Pooled<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        T::fmt(&self.item, f)
+    }
+}
+
+impl<T> Drop for Pooled<'_, T> {
+    fn drop(&mut self) {
+        #![expect(unsafe_code, reason = "ManuallyDrop::take is unsafe.")]
+        // SAFETY: `item` is not used after this.
+        let item = unsafe { ManuallyDrop::take(&mut self.item) };
+        self.pool.put(item);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn pool() {
+        let pool = Pool::with_hooks(|| vec![0u8; 4], |v| v.fill(0)).with_max_idle(2);
+        pool.prefill(3);
+        assert_eq!(pool.idle(), 2);
+
+        let mut a = pool.get();
+        a[0] = 1;
+        let b = pool.get();
+        let c = pool.get();
+        assert_eq!(pool.idle(), 0);
+        assert_eq!(*c, [0; 4]);
+
+        assert_eq!(Pooled::take(b), [0; 4]);
+        drop(a);
+        drop(c);
+        assert_eq!(pool.idle(), 2);
+        assert_eq!(*pool.get(), [0; 4]);
+
+        pool.clear();
+        assert_eq!(pool.idle(), 0);
+    }
+}